@@ -0,0 +1,90 @@
+// decifrar.js
+// Com a criptografia ativa, os arquivos de data/ trazem o JSON cifrado como string
+// e data/cripto.js traz os parâmetros da chave. Pede a senha, decifra os dados
+// com o mesmo PBKDF2 + AES-GCM do programa e só então carrega os gráficos.
+(function(){
+  const PREFIXO = "FINCRIPTO1:";
+  const TAMANHO_NONCE = 12;
+
+  const GRAFICOS = [
+    // gráficos oficiais
+    "script/resumo.js",
+    "script/gasto-por-conta.js",
+    "script/gasto-por-categoria.js",
+    "script/gasto-por-categoria-ano.js",
+    "script/dividas.js",
+    "script/orcamentos.js",
+    "script/orientacoes.js",
+    "script/metas.js",
+    "script/objetivos.js",
+    "script/quitacao.js",
+    "script/fluxo-caixa.js",
+    "script/patrimonio.js",
+    "script/investimentos.js",
+    "script/contracheques.js",
+    // gráficos de exemplos
+    "chart-multiline.js",
+    "chart-column.js",
+    "chart-area.js",
+    "chart-pie.js",
+  ];
+
+  // async = false mantém a ordem de execução, como nas tags estáticas
+  function carregarGraficos(){
+    GRAFICOS.forEach(function(src){
+      const script = document.createElement("script");
+      script.src = src;
+      script.async = false;
+      document.body.appendChild(script);
+    });
+  }
+
+  function base64(texto){
+    return Uint8Array.from(atob(texto), function(c){ return c.charCodeAt(0); });
+  }
+
+  async function derivarChave(senha){
+    const base = await crypto.subtle.importKey(
+      "raw", new TextEncoder().encode(senha), "PBKDF2", false, ["deriveKey"]
+    );
+    return crypto.subtle.deriveKey(
+      { name: "PBKDF2", hash: "SHA-256", salt: base64(cripto.sal), iterations: cripto.iteracoes },
+      base,
+      { name: "AES-GCM", length: 256 },
+      false,
+      ["decrypt"]
+    );
+  }
+
+  async function decifrar(chave, texto){
+    const bytes = base64(texto.slice(PREFIXO.length));
+    const claro = await crypto.subtle.decrypt(
+      { name: "AES-GCM", iv: bytes.slice(0, TAMANHO_NONCE) }, chave, bytes.slice(TAMANHO_NONCE)
+    );
+    return JSON.parse(new TextDecoder().decode(claro));
+  }
+
+  async function abrir(){
+    if (typeof cripto === "undefined" || !cripto) return carregarGraficos();
+
+    const nomes = Object.keys(window).filter(function(nome){
+      return typeof window[nome] === "string" && window[nome].startsWith(PREFIXO);
+    });
+
+    for (;;) {
+      const senha = prompt("Senha dos dados do financeiro:");
+      if (senha === null) return;
+      try {
+        const chave = await derivarChave(senha);
+        const dados = {};
+        for (const nome of nomes) dados[nome] = await decifrar(chave, window[nome]);
+        Object.assign(window, dados);
+        return carregarGraficos();
+      } catch (erro) {
+        alert("Senha incorreta");
+      }
+    }
+  }
+
+  abrir();
+})();
@@ -0,0 +1,66 @@
+// contracheques.js
+// evolução mensal do bruto, descontos e líquido dos contracheques
+(function () {
+  function ready(fn) {
+    if (document.readyState !== 'loading') return fn();
+    document.addEventListener('DOMContentLoaded', fn);
+  }
+
+  ready(function () {
+    if (typeof contracheques === 'undefined' || contracheques.length === 0) return;
+    if (!window.am4core || !window.am4charts) return;
+
+    am4core.useTheme(am4themes_animated);
+
+    var chart = am4core.create("contracheques", am4charts.XYChart);
+    chart.responsive.enabled = true;
+    chart.paddingRight = 20;
+
+    var title = chart.titles.create();
+    title.text = "Contracheques - bruto x líquido";
+    title.fontSize = 16;
+    title.fill = am4core.color("#cbd5e1");
+
+    chart.data = contracheques;
+
+    var categoryAxis = chart.xAxes.push(new am4charts.CategoryAxis());
+    categoryAxis.dataFields.category = "mes";
+    categoryAxis.renderer.minGridDistance = 40;
+    categoryAxis.renderer.labels.template.fill = am4core.color("#cbd5e1");
+    categoryAxis.renderer.labels.template.fontSize = 10;
+
+    var valueAxis = chart.yAxes.push(new am4charts.ValueAxis());
+    valueAxis.renderer.labels.template.fill = am4core.color("#cbd5e1");
+
+    var descontos = chart.series.push(new am4charts.ColumnSeries());
+    descontos.dataFields.valueY = "descontos";
+    descontos.dataFields.categoryX = "mes";
+    descontos.name = "Descontos";
+    descontos.columns.template.fill = am4core.color("#ef4444");
+    descontos.columns.template.stroke = am4core.color("#ef4444");
+    descontos.columns.template.fillOpacity = 0.6;
+    descontos.tooltipText = "Descontos: R$ {valueY.formatNumber('#,###.00')}";
+
+    function createLine(field, name, color) {
+      var series = chart.series.push(new am4charts.LineSeries());
+      series.dataFields.valueY = field;
+      series.dataFields.categoryX = "mes";
+      series.name = name;
+      series.stroke = am4core.color(color);
+      series.strokeWidth = 2;
+      series.tooltipText = name + ": R$ {valueY.formatNumber('#,###.00')}";
+      var bullet = series.bullets.push(new am4charts.CircleBullet());
+      bullet.circle.fill = am4core.color(color);
+      bullet.circle.radius = 3;
+      return series;
+    }
+
+    createLine("bruto", "Bruto", "#38bdf8");
+    createLine("liquido", "Líquido", "#22c55e");
+
+    chart.legend = new am4charts.Legend();
+    chart.legend.labels.template.fill = am4core.color("#cbd5e1");
+    chart.legend.labels.template.fontSize = 10;
+    chart.cursor = new am4charts.XYCursor();
+  });
+})();
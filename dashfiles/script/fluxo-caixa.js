@@ -0,0 +1,81 @@
+// fluxo-caixa.js
+// projeção do resto do mês e dos próximos 12 meses: entradas, saídas por origem e saldo acumulado
+(function () {
+  function ready(fn) {
+    if (document.readyState !== 'loading') return fn();
+    document.addEventListener('DOMContentLoaded', fn);
+  }
+
+  ready(function () {
+    if (!window.am4core || !window.am4charts) return;
+    if (typeof fluxo_caixa === 'undefined' || fluxo_caixa.length === 0) return;
+
+    am4core.useTheme(am4themes_animated);
+
+    var chart = am4core.create("fluxoCaixa", am4charts.XYChart);
+    chart.responsive.enabled = true;
+    chart.paddingRight = 20;
+
+    var negativos = fluxo_caixa.filter(function (m) { return m.negativo; }).length;
+    var title = chart.titles.create();
+    title.text = "Fluxo de caixa projetado" +
+      (negativos > 0 ? " - " + negativos + " mês(es) com saldo negativo" : "");
+    title.fontSize = 16;
+    title.fill = am4core.color(negativos > 0 ? "#ef4444" : "#cbd5e1");
+
+    chart.data = fluxo_caixa.map(function (m) {
+      return Object.assign({}, m, {
+        fixas_neg: -m.fixas,
+        dividas_neg: -m.dividas,
+        sazonais_neg: -m.sazonais
+      });
+    });
+
+    var categoryAxis = chart.xAxes.push(new am4charts.CategoryAxis());
+    categoryAxis.dataFields.category = "mes";
+    categoryAxis.renderer.minGridDistance = 40;
+    categoryAxis.renderer.labels.template.fill = am4core.color("#cbd5e1");
+    categoryAxis.renderer.labels.template.fontSize = 10;
+
+    var valueAxis = chart.yAxes.push(new am4charts.ValueAxis());
+    valueAxis.renderer.labels.template.fill = am4core.color("#cbd5e1");
+
+    function createColumn(field, name, color, stacked) {
+      var series = chart.series.push(new am4charts.ColumnSeries());
+      series.dataFields.valueY = field;
+      series.dataFields.categoryX = "mes";
+      series.name = name;
+      series.stacked = stacked;
+      series.columns.template.fill = am4core.color(color);
+      series.columns.template.stroke = am4core.color(color);
+      series.tooltipText = name + ": R$ {valueY.formatNumber('#,###.00')}";
+      return series;
+    }
+
+    createColumn("receitas", "Receitas", "#22c55e", false);
+    createColumn("fixas_neg", "Despesas fixas", "#f59e0b", false);
+    createColumn("dividas_neg", "Dívidas", "#ef4444", true);
+    createColumn("sazonais_neg", "Sazonais", "#a855f7", true);
+
+    var acumulado = chart.series.push(new am4charts.LineSeries());
+    acumulado.dataFields.valueY = "acumulado";
+    acumulado.dataFields.categoryX = "mes";
+    acumulado.name = "Saldo acumulado";
+    acumulado.stroke = am4core.color("#38bdf8");
+    acumulado.strokeWidth = 2;
+    acumulado.tooltipText = "Acumulado: R$ {valueY.formatNumber('#,###.00')}\nNo mês: R$ {saldo.formatNumber('#,###.00')}";
+
+    var bullet = acumulado.bullets.push(new am4charts.CircleBullet());
+    bullet.circle.radius = 4;
+    bullet.circle.fill = am4core.color("#38bdf8");
+    bullet.adapter.add("fill", function (fill, target) {
+      var item = target.dataItem && target.dataItem.dataContext;
+      return item && item.negativo ? am4core.color("#ef4444") : fill;
+    });
+
+    chart.legend = new am4charts.Legend();
+    chart.legend.labels.template.fill = am4core.color("#cbd5e1");
+    chart.legend.labels.template.fontSize = 10;
+    chart.cursor = new am4charts.XYCursor();
+  });
+})();
@@ -0,0 +1,65 @@
+// investimentos.js
+// custo x valor atual das posições abertas, com o ganho total no tooltip
+(function () {
+  function ready(fn) {
+    if (document.readyState !== 'loading') return fn();
+    document.addEventListener('DOMContentLoaded', fn);
+  }
+
+  ready(function () {
+    if (typeof investimentos === 'undefined') return;
+    if (!window.am4core || !window.am4charts || investimentos.length === 0) return;
+
+    am4core.useTheme(am4themes_animated);
+
+    var chart = am4core.create("investimentos", am4charts.XYChart);
+    chart.responsive.enabled = true;
+    chart.paddingRight = 20;
+
+    var abertas = investimentos.filter(function (i) { return i.quantidade > 0; });
+    var valorTotal = abertas.reduce(function (s, i) { return s + i.valor_atual; }, 0);
+    var proventos = investimentos.reduce(function (s, i) { return s + i.proventos; }, 0);
+
+    var title = chart.titles.create();
+    title.text = "Investimentos - R$ " + valorTotal.toFixed(2) + " (proventos R$ " + proventos.toFixed(2) + ")";
+    title.fontSize = 16;
+    title.fill = am4core.color("#cbd5e1");
+
+    chart.data = abertas.map(function (i) {
+      return Object.assign({}, i, {
+        ganho: i.ganho_realizado + i.ganho_nao_realizado + i.proventos
+      });
+    });
+
+    var categoryAxis = chart.xAxes.push(new am4charts.CategoryAxis());
+    categoryAxis.dataFields.category = "ativo";
+    categoryAxis.renderer.minGridDistance = 30;
+    categoryAxis.renderer.labels.template.fill = am4core.color("#cbd5e1");
+    categoryAxis.renderer.labels.template.fontSize = 10;
+
+    var valueAxis = chart.yAxes.push(new am4charts.ValueAxis());
+    valueAxis.renderer.labels.template.fill = am4core.color("#cbd5e1");
+
+    function createColumn(field, name, color) {
+      var series = chart.series.push(new am4charts.ColumnSeries());
+      series.dataFields.valueY = field;
+      series.dataFields.categoryX = "ativo";
+      series.name = name;
+      series.columns.template.fill = am4core.color(color);
+      series.columns.template.stroke = am4core.color(color);
+      series.columns.template.fillOpacity = 0.8;
+      series.tooltipText =
+        "{nome} ({tipo})\n" + name + ": R$ {valueY.formatNumber('#,###.00')}\n" +
+        "Ganho total: R$ {ganho.formatNumber('#,###.00')} ({rentabilidade}%)";
+      return series;
+    }
+
+    createColumn("custo", "Custo", "#64748b");
+    createColumn("valor_atual", "Valor atual", "#22c55e");
+
+    chart.legend = new am4charts.Legend();
+    chart.legend.labels.template.fill = am4core.color("#cbd5e1");
+    chart.legend.labels.template.fontSize = 10;
+    chart.cursor = new am4charts.XYCursor();
+  });
+})();
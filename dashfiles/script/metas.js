@@ -0,0 +1,41 @@
+// situação → cor da barra de progresso
+function metaCor(situacao) {
+  if (situacao === "Atingida") return "#22c55e";    // verde
+  if (situacao === "EmRisco") return "#f97316";     // laranja
+  if (situacao === "NaoAtingida") return "#ef4444"; // vermelho
+  return "#60a5fa";                                 // azul — no caminho
+}
+
+function metaSituacao(situacao) {
+  return {
+    Atingida: "Atingida",
+    NoCaminho: "No caminho",
+    EmRisco: "Em risco",
+    NaoAtingida: "Não atingida"
+  }[situacao] || situacao;
+}
+
+var metaTendencia = { Subindo: "↑", Estavel: "→", Descendo: "↓" };
+
+(function () {
+  var lista = document.getElementById("metas-lista");
+  if (!lista || typeof metas === "undefined" || !metas.length) return;
+
+  metas.forEach(function (m) {
+    var item = document.createElement("div");
+    item.className = "meta-item";
+    item.style.setProperty("--meta-cor", metaCor(m.situacao));
+    item.style.setProperty("--meta-progresso", Math.min(m.progresso, 100) + "%");
+
+    item.innerHTML =
+      '<div class="meta-cabecalho">' +
+      '<span class="meta-nome">' + m.nome + '</span>' +
+      '<span class="meta-situacao">' + metaTendencia[m.tendencia] + ' ' + metaSituacao(m.situacao) + '</span>' +
+      '</div>' +
+      '<div class="meta-barra"><div class="meta-barra-valor"></div></div>' +
+      '<div class="meta-valores">R$ ' + m.realizado.toFixed(2) + ' de R$ ' + m.valor.toFixed(2) +
+      ' · falta R$ ' + m.restante.toFixed(2) + '</div>';
+
+    lista.appendChild(item);
+  });
+})();
@@ -0,0 +1,70 @@
+// objetivos.js
+// progresso dos objetivos de poupança: guardado x falta, com aporte necessário e previsão
+(function () {
+  function ready(fn) {
+    if (document.readyState !== 'loading') return fn();
+    document.addEventListener('DOMContentLoaded', fn);
+  }
+
+  ready(function () {
+    if (!window.am4core || !window.am4charts) return;
+    if (typeof objetivos === 'undefined' || objetivos.length === 0) return;
+
+    am4core.useTheme(am4themes_animated);
+
+    var chart = am4core.create("objetivos", am4charts.XYChart);
+    chart.responsive.enabled = true;
+    chart.paddingRight = 20;
+
+    var title = chart.titles.create();
+    title.text = "Objetivos de poupança";
+    title.fontSize = 16;
+    title.fill = am4core.color("#cbd5e1");
+
+    chart.data = objetivos.map(function (o) {
+      return {
+        objetivo: o.nome + " (" + o.data_alvo + ")",
+        guardado: Math.min(o.acumulado, o.valor_alvo),
+        falta: o.falta,
+        aporte: o.aporte_necessario,
+        ritmo: o.ritmo_mensal,
+        previsao: o.previsao || "sem previsão"
+      };
+    });
+
+    var categoryAxis = chart.yAxes.push(new am4charts.CategoryAxis());
+    categoryAxis.dataFields.category = "objetivo";
+    categoryAxis.renderer.inversed = true;
+    categoryAxis.renderer.minGridDistance = 10;
+    categoryAxis.renderer.labels.template.fill = am4core.color("#cbd5e1");
+    categoryAxis.renderer.labels.template.fontSize = 10;
+
+    var valueAxis = chart.xAxes.push(new am4charts.ValueAxis());
+    valueAxis.min = 0;
+    valueAxis.renderer.labels.template.fill = am4core.color("#cbd5e1");
+
+    function createColumn(field, name, color) {
+      var series = chart.series.push(new am4charts.ColumnSeries());
+      series.dataFields.valueX = field;
+      series.dataFields.categoryY = "objetivo";
+      series.name = name;
+      series.stacked = true;
+      series.columns.template.fill = am4core.color(color);
+      series.columns.template.stroke = am4core.color(color);
+      series.columns.template.height = am4core.percent(60);
+      series.tooltipText = name + ": R$ {valueX.formatNumber('#,###.00')}\n" +
+        "Aporte necessário: R$ {aporte.formatNumber('#,###.00')}/mês\n" +
+        "Ritmo atual: R$ {ritmo.formatNumber('#,###.00')}/mês\n" +
+        "Previsão: {previsao}";
+      return series;
+    }
+
+    createColumn("guardado", "Guardado", "#22c55e");
+    createColumn("falta", "Falta", "#334155");
+
+    chart.legend = new am4charts.Legend();
+    chart.legend.labels.template.fill = am4core.color("#cbd5e1");
+    chart.legend.labels.template.fontSize = 10;
+    chart.cursor = new am4charts.XYCursor();
+  });
+})();
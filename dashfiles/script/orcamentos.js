@@ -0,0 +1,65 @@
+// orcamentos.js
+// orçado x realizado do mês corrente, por categoria ou grupo
+(function () {
+  function ready(fn) {
+    if (document.readyState !== 'loading') return fn();
+    document.addEventListener('DOMContentLoaded', fn);
+  }
+
+  ready(function () {
+    if (!window.am4core || !window.am4charts) return;
+    if (typeof orcamentos === 'undefined' || orcamentos.length === 0) return;
+
+    am4core.useTheme(am4themes_animated);
+
+    var chart = am4core.create("orcamentos", am4charts.XYChart);
+    chart.responsive.enabled = true;
+    chart.paddingRight = 20;
+
+    var title = chart.titles.create();
+    title.text = "Orçado x Realizado";
+    title.fontSize = 16;
+    title.fill = am4core.color("#cbd5e1");
+
+    chart.data = orcamentos.map(function (o) {
+      return {
+        categoria: o.categoria.split("; ").slice(-1)[0],
+        disponivel: o.orcado + o.acumulado,
+        gasto: o.gasto,
+        projetado: o.projetado
+      };
+    });
+
+    var categoryAxis = chart.yAxes.push(new am4charts.CategoryAxis());
+    categoryAxis.dataFields.category = "categoria";
+    categoryAxis.renderer.inversed = true;
+    categoryAxis.renderer.minGridDistance = 10;
+    categoryAxis.renderer.labels.template.fill = am4core.color("#cbd5e1");
+    categoryAxis.renderer.labels.template.fontSize = 10;
+
+    var valueAxis = chart.xAxes.push(new am4charts.ValueAxis());
+    valueAxis.min = 0;
+    valueAxis.renderer.labels.template.fill = am4core.color("#cbd5e1");
+
+    function createColumn(field, name, color) {
+      var series = chart.series.push(new am4charts.ColumnSeries());
+      series.dataFields.valueX = field;
+      series.dataFields.categoryY = "categoria";
+      series.name = name;
+      series.columns.template.fill = am4core.color(color);
+      series.columns.template.stroke = am4core.color(color);
+      series.columns.template.height = am4core.percent(80);
+      series.tooltipText = name + ": R$ {valueX.formatNumber('#,###.00')}";
+      return series;
+    }
+
+    createColumn("disponivel", "Orçado", "#3b82f6");
+    createColumn("gasto", "Gasto", "#eb9500ff");
+    createColumn("projetado", "Projeção fim do mês", "#ce0a0aff");
+
+    chart.legend = new am4charts.Legend();
+    chart.legend.labels.template.fill = am4core.color("#cbd5e1");
+    chart.legend.labels.template.fontSize = 10;
+    chart.cursor = new am4charts.XYCursor();
+  });
+})();
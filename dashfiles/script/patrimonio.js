@@ -0,0 +1,147 @@
+// patrimonio.js
+// evolução do patrimônio líquido (ativos x passivos), saldo diário de cada conta
+// e saldo por conta no painel lateral
+(function () {
+  function ready(fn) {
+    if (document.readyState !== 'loading') return fn();
+    document.addEventListener('DOMContentLoaded', fn);
+  }
+
+  function renderContas() {
+    var lista = document.getElementById("contas-lista");
+    if (!lista || !patrimonio.contas.length) return;
+
+    patrimonio.contas.forEach(function (c) {
+      var divergente = c.diferenca !== null && Math.abs(c.diferenca) >= 0.01;
+      var item = document.createElement("div");
+      item.className = "conta-item" + (divergente ? " conta-divergente" : "");
+      item.innerHTML =
+        '<div class="meta-cabecalho">' +
+        '<span class="meta-nome">' + c.banco + ' - ' + c.conta + '</span>' +
+        '<span class="meta-situacao">' + c.tipo + '</span>' +
+        '</div>' +
+        '<div class="conta-saldo">R$ ' + c.saldo.toFixed(2) + '</div>' +
+        (c.data_extrato
+          ? '<div class="meta-valores">extrato de ' + c.data_extrato + ': R$ ' + c.extrato.toFixed(2) +
+            (divergente ? ' · diferença R$ ' + c.diferenca.toFixed(2) : ' · confere') + '</div>'
+          : '');
+      lista.appendChild(item);
+    });
+  }
+
+  ready(function () {
+    if (typeof patrimonio === 'undefined') return;
+    renderContas();
+
+    if (!window.am4core || !window.am4charts || patrimonio.meses.length === 0) return;
+
+    am4core.useTheme(am4themes_animated);
+
+    var chart = am4core.create("patrimonio", am4charts.XYChart);
+    chart.responsive.enabled = true;
+    chart.paddingRight = 20;
+
+    var atual = patrimonio.meses[patrimonio.meses.length - 1];
+    var title = chart.titles.create();
+    title.text = "Patrimônio líquido - R$ " + atual.liquido.toFixed(2);
+    title.fontSize = 16;
+    title.fill = am4core.color("#cbd5e1");
+
+    chart.data = patrimonio.meses.map(function (m) {
+      return Object.assign({}, m, { passivos_neg: -m.passivos });
+    });
+
+    var categoryAxis = chart.xAxes.push(new am4charts.CategoryAxis());
+    categoryAxis.dataFields.category = "mes";
+    categoryAxis.renderer.minGridDistance = 40;
+    categoryAxis.renderer.labels.template.fill = am4core.color("#cbd5e1");
+    categoryAxis.renderer.labels.template.fontSize = 10;
+
+    var valueAxis = chart.yAxes.push(new am4charts.ValueAxis());
+    valueAxis.renderer.labels.template.fill = am4core.color("#cbd5e1");
+
+    function createColumn(field, name, color) {
+      var series = chart.series.push(new am4charts.ColumnSeries());
+      series.dataFields.valueY = field;
+      series.dataFields.categoryX = "mes";
+      series.name = name;
+      series.clustered = false;
+      series.columns.template.fill = am4core.color(color);
+      series.columns.template.stroke = am4core.color(color);
+      series.columns.template.fillOpacity = 0.7;
+      series.tooltipText = name + ": R$ {valueY.formatNumber('#,###.00')}";
+      return series;
+    }
+
+    createColumn("ativos", "Ativos", "#22c55e");
+    createColumn("passivos_neg", "Passivos", "#ef4444");
+
+    var liquido = chart.series.push(new am4charts.LineSeries());
+    liquido.dataFields.valueY = "liquido";
+    liquido.dataFields.categoryX = "mes";
+    liquido.name = "Líquido";
+    liquido.stroke = am4core.color("#38bdf8");
+    liquido.strokeWidth = 2;
+    liquido.tooltipText = "Líquido: R$ {valueY.formatNumber('#,###.00')}";
+
+    var carteira = chart.series.push(new am4charts.LineSeries());
+    carteira.dataFields.valueY = "investimentos";
+    carteira.dataFields.categoryX = "mes";
+    carteira.name = "Carteira (nos ativos)";
+    carteira.stroke = am4core.color("#a78bfa");
+    carteira.strokeWidth = 2;
+    carteira.strokeDasharray = "4,4";
+    carteira.tooltipText = "Carteira: R$ {valueY.formatNumber('#,###.00')}";
+
+    chart.legend = new am4charts.Legend();
+    chart.legend.labels.template.fill = am4core.color("#cbd5e1");
+    chart.legend.labels.template.fontSize = 10;
+    chart.cursor = new am4charts.XYCursor();
+
+    renderSaldoDiario();
+  });
+
+  function renderSaldoDiario() {
+    if (!document.getElementById("saldoDiario") || patrimonio.dias.length === 0) return;
+
+    var chart = am4core.create("saldoDiario", am4charts.XYChart);
+    chart.responsive.enabled = true;
+    chart.paddingRight = 20;
+
+    var title = chart.titles.create();
+    title.text = "Saldo diário por conta";
+    title.fontSize = 16;
+    title.fill = am4core.color("#cbd5e1");
+
+    chart.data = patrimonio.dias.map(function (dia, i) {
+      var linha = { dia: dia };
+      patrimonio.contas.forEach(function (c, j) {
+        linha["c" + j] = c.diario[i];
+      });
+      return linha;
+    });
+
+    var categoryAxis = chart.xAxes.push(new am4charts.CategoryAxis());
+    categoryAxis.dataFields.category = "dia";
+    categoryAxis.renderer.minGridDistance = 40;
+    categoryAxis.renderer.labels.template.fill = am4core.color("#cbd5e1");
+    categoryAxis.renderer.labels.template.fontSize = 10;
+
+    var valueAxis = chart.yAxes.push(new am4charts.ValueAxis());
+    valueAxis.renderer.labels.template.fill = am4core.color("#cbd5e1");
+
+    patrimonio.contas.forEach(function (c, j) {
+      var series = chart.series.push(new am4charts.LineSeries());
+      series.dataFields.valueY = "c" + j;
+      series.dataFields.categoryX = "dia";
+      series.name = c.banco + " - " + c.conta;
+      series.strokeWidth = 2;
+      series.tooltipText = series.name + ": R$ {valueY.formatNumber('#,###.00')}";
+    });
+
+    chart.legend = new am4charts.Legend();
+    chart.legend.labels.template.fill = am4core.color("#cbd5e1");
+    chart.legend.labels.template.fontSize = 10;
+    chart.cursor = new am4charts.XYCursor();
+  }
+})();
@@ -0,0 +1,74 @@
+// quitacao.js
+// plano de quitação escolhido no simulador: saldo devedor, juros e valor liberado mês a mês
+(function () {
+  function ready(fn) {
+    if (document.readyState !== 'loading') return fn();
+    document.addEventListener('DOMContentLoaded', fn);
+  }
+
+  ready(function () {
+    if (!window.am4core || !window.am4charts) return;
+    if (typeof quitacao === 'undefined' || quitacao.meses.length === 0) return;
+
+    am4core.useTheme(am4themes_animated);
+
+    var chart = am4core.create("quitacao", am4charts.XYChart);
+    chart.responsive.enabled = true;
+    chart.paddingRight = 20;
+
+    var title = chart.titles.create();
+    title.text = "Plano de quitação - " + quitacao.estrategia +
+      " (extra R$ " + quitacao.extra_mensal.toFixed(2) + "/mês, quitação " +
+      (quitacao.quitacao || "fora do horizonte") + ", juros R$ " + quitacao.total_juros.toFixed(2) + ")";
+    title.fontSize = 16;
+    title.fill = am4core.color("#cbd5e1");
+
+    chart.data = quitacao.meses;
+
+    var categoryAxis = chart.xAxes.push(new am4charts.CategoryAxis());
+    categoryAxis.dataFields.category = "mes";
+    categoryAxis.renderer.minGridDistance = 40;
+    categoryAxis.renderer.labels.template.fill = am4core.color("#cbd5e1");
+    categoryAxis.renderer.labels.template.fontSize = 10;
+
+    var saldoAxis = chart.yAxes.push(new am4charts.ValueAxis());
+    saldoAxis.min = 0;
+    saldoAxis.renderer.labels.template.fill = am4core.color("#cbd5e1");
+
+    var mensalAxis = chart.yAxes.push(new am4charts.ValueAxis());
+    mensalAxis.min = 0;
+    mensalAxis.renderer.opposite = true;
+    mensalAxis.renderer.grid.template.disabled = true;
+    mensalAxis.renderer.labels.template.fill = am4core.color("#cbd5e1");
+
+    function createColumn(field, name, color) {
+      var series = chart.series.push(new am4charts.ColumnSeries());
+      series.dataFields.valueY = field;
+      series.dataFields.categoryX = "mes";
+      series.yAxis = mensalAxis;
+      series.name = name;
+      series.stacked = true;
+      series.columns.template.fill = am4core.color(color);
+      series.columns.template.stroke = am4core.color(color);
+      series.tooltipText = name + ": R$ {valueY.formatNumber('#,###.00')}";
+      return series;
+    }
+
+    createColumn("juros", "Juros", "#ef4444");
+    createColumn("liberado", "Liberado", "#22c55e");
+
+    var saldo = chart.series.push(new am4charts.LineSeries());
+    saldo.dataFields.valueY = "saldo";
+    saldo.dataFields.categoryX = "mes";
+    saldo.yAxis = saldoAxis;
+    saldo.name = "Saldo devedor";
+    saldo.stroke = am4core.color("#f59e0b");
+    saldo.strokeWidth = 2;
+    saldo.tooltipText = "Saldo: R$ {valueY.formatNumber('#,###.00')}\nPago no mês: R$ {pago.formatNumber('#,###.00')}";
+
+    chart.legend = new am4charts.Legend();
+    chart.legend.labels.template.fill = am4core.color("#cbd5e1");
+    chart.legend.labels.template.fontSize = 10;
+    chart.cursor = new am4charts.XYCursor();
+  });
+})();
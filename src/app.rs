@@ -2,20 +2,29 @@ use color_eyre::eyre::Result;
 use ratatui::DefaultTerminal;
 
 use crate::widget::{
-    Categorizador, ContraCheque, EditarConfiguracoes, GeradorDash, ListaCategoria, ListaDividas,
-    ListaMeta, Menu,
+    Categorizador, ContraCheque, DeclaracaoIrpf, EditarConfiguracoes, GeradorDash, ListaCarneLeao,
+    ListaCategoria, ListaContas, ListaContracheques, ListaDividas, ListaInvestimentos, ListaMeta,
+    ListaObjetivos, ListaOrcamentos, ListaSnapshots, Menu,
 };
 
 #[derive(Clone)]
 pub enum Etapa {
     Categorizar,
     Dividas,
+    Contas,
+    Investimentos,
     ContraCheque,
+    HistoricoContraCheques,
+    Irpf,
+    CarneLeao,
     Metas,
+    Orcamentos,
+    Objetivos,
     Menu,
     Dash,
     Configuracoes,
     Categorias,
+    Snapshots,
     Sair,
 }
 
@@ -37,11 +46,19 @@ impl App {
             items: vec![
                 ("Categorizar".to_string(), Etapa::Categorizar),
                 ("Dívidas".to_string(), Etapa::Dividas),
+                ("Contas e patrimônio".to_string(), Etapa::Contas),
+                ("Investimentos".to_string(), Etapa::Investimentos),
                 ("Contra-cheques".to_string(), Etapa::ContraCheque),
+                ("Histórico de contra-cheques".to_string(), Etapa::HistoricoContraCheques),
+                ("Relatório do IRPF".to_string(), Etapa::Irpf),
+                ("Carnê-leão".to_string(), Etapa::CarneLeao),
                 ("Metas".to_string(), Etapa::Metas),
+                ("Orçamentos".to_string(), Etapa::Orcamentos),
+                ("Objetivos".to_string(), Etapa::Objetivos),
                 ("Gerar Gráfico".to_string(), Etapa::Dash),
                 ("Configurações".to_string(), Etapa::Configuracoes),
                 ("Categorias".to_string(), Etapa::Categorias),
+                ("Snapshots".to_string(), Etapa::Snapshots),
                 ("Sair".to_string(), Etapa::Sair),
             ],
             etapa: Option::None,
@@ -54,11 +71,19 @@ impl App {
 
                 Etapa::Categorizar => self.categorizar(&mut terminal),
                 Etapa::Metas => self.metas(&mut terminal),
+                Etapa::Orcamentos => self.orcamentos(&mut terminal),
+                Etapa::Objetivos => self.objetivos(&mut terminal),
                 Etapa::Dividas => self.dividas(&mut terminal),
+                Etapa::Contas => self.contas(&mut terminal),
+                Etapa::Investimentos => self.investimentos(&mut terminal),
                 Etapa::Dash => self.dash(&mut terminal),
                 Etapa::Configuracoes => self.configuracoes(&mut terminal),
                 Etapa::Categorias => self.categorias(&mut terminal),
                 Etapa::ContraCheque => self.contracheque(&mut terminal),
+                Etapa::HistoricoContraCheques => self.historico_contracheques(&mut terminal),
+                Etapa::Irpf => self.irpf(&mut terminal),
+                Etapa::CarneLeao => self.carne_leao(&mut terminal),
+                Etapa::Snapshots => self.snapshots(&mut terminal),
 
                 Etapa::Sair => break,
             }
@@ -98,6 +123,36 @@ impl App {
         }
     }
 
+    fn historico_contracheques(&mut self, terminal: &mut DefaultTerminal) {
+        match ListaContracheques::default().run(terminal) {
+            Ok(_) => {}
+            Err(e) => {
+                log::info!("Falha ao abrir histórico de contra-cheques: {e}");
+            }
+        }
+        self.etapa = Etapa::Menu
+    }
+
+    fn irpf(&mut self, terminal: &mut DefaultTerminal) {
+        match DeclaracaoIrpf::default().run(terminal) {
+            Ok(_) => {}
+            Err(e) => {
+                log::info!("Falha ao abrir relatório do IRPF: {e}");
+            }
+        }
+        self.etapa = Etapa::Menu
+    }
+
+    fn carne_leao(&mut self, terminal: &mut DefaultTerminal) {
+        match ListaCarneLeao::default().run(terminal) {
+            Ok(_) => {}
+            Err(e) => {
+                log::info!("Falha ao abrir carnê-leão: {e}");
+            }
+        }
+        self.etapa = Etapa::Menu
+    }
+
     fn dash(&mut self, terminal: &mut DefaultTerminal) {
         match GeradorDash::new().run(terminal) {
             Ok(_) => {}
@@ -118,6 +173,26 @@ impl App {
         self.etapa = Etapa::Menu
     }
 
+    fn contas(&mut self, terminal: &mut DefaultTerminal) {
+        match ListaContas::default().run(terminal) {
+            Ok(_) => {}
+            Err(e) => {
+                log::info!("Falha ao abrir contas: {e}");
+            }
+        }
+        self.etapa = Etapa::Menu
+    }
+
+    fn investimentos(&mut self, terminal: &mut DefaultTerminal) {
+        match ListaInvestimentos::default().run(terminal) {
+            Ok(_) => {}
+            Err(e) => {
+                log::info!("Falha ao abrir investimentos: {e}");
+            }
+        }
+        self.etapa = Etapa::Menu
+    }
+
     fn metas(&mut self, terminal: &mut DefaultTerminal) {
         match ListaMeta::default().run(terminal) {
             Ok(_) => {}
@@ -128,6 +203,26 @@ impl App {
         self.etapa = Etapa::Menu
     }
 
+    fn orcamentos(&mut self, terminal: &mut DefaultTerminal) {
+        match ListaOrcamentos::default().run(terminal) {
+            Ok(_) => {}
+            Err(e) => {
+                log::info!("Falha ao abrir orçamentos: {e}");
+            }
+        }
+        self.etapa = Etapa::Menu
+    }
+
+    fn objetivos(&mut self, terminal: &mut DefaultTerminal) {
+        match ListaObjetivos::default().run(terminal) {
+            Ok(_) => {}
+            Err(e) => {
+                log::info!("Falha ao abrir objetivos: {e}");
+            }
+        }
+        self.etapa = Etapa::Menu
+    }
+
     fn configuracoes(&mut self, terminal: &mut DefaultTerminal) {
         match EditarConfiguracoes::default().run(terminal) {
             Ok(_) => {}
@@ -147,4 +242,14 @@ impl App {
         }
         self.etapa = Etapa::Menu
     }
+
+    fn snapshots(&mut self, terminal: &mut DefaultTerminal) {
+        match ListaSnapshots::default().run(terminal) {
+            Ok(etapa) => self.etapa = etapa,
+            Err(e) => {
+                log::info!("Falha ao abrir snapshots: {e}");
+                self.etapa = Etapa::Menu
+            }
+        }
+    }
 }
@@ -0,0 +1,136 @@
+use chrono::{Months, NaiveDate};
+
+use crate::dto::{Financiamento, ParcelaDivida, SistemaAmortizacao};
+
+/// Gera o cronograma de parcelas pelo sistema do financiamento.
+///
+/// A cada mês o saldo é corrigido pelo índice antes do cálculo dos juros; na
+/// Price a prestação é recalculada sobre o saldo corrigido e o prazo restante,
+/// no SAC a amortização é o saldo corrigido dividido pelo prazo restante. A
+/// última parcela amortiza o resíduo dos arredondamentos.
+pub fn gerar_parcelas(
+    financiamento: &Financiamento,
+    dt_inicio: NaiveDate,
+    quant_ja_pago: i32,
+) -> Vec<ParcelaDivida> {
+    let taxa = financiamento.taxa_mensal / 100.0;
+    let correcao = financiamento.correcao_mensal / 100.0;
+    let prazo = financiamento.prazo;
+
+    let mut saldo = financiamento.principal;
+    let mut parcelas: Vec<ParcelaDivida> = Vec::new();
+
+    for i in 1..=prazo {
+        let restantes = (prazo - i + 1) as f64;
+        saldo = arredondar(saldo * (1.0 + correcao));
+        let juros = arredondar(saldo * taxa);
+
+        let amortizacao = if i == prazo {
+            saldo
+        } else {
+            match financiamento.sistema {
+                SistemaAmortizacao::Price => arredondar(prestacao(saldo, taxa, restantes) - juros),
+                SistemaAmortizacao::Sac => arredondar(saldo / restantes),
+            }
+        };
+        saldo = arredondar(saldo - amortizacao);
+
+        parcelas.push(ParcelaDivida {
+            num_parcela: i,
+            valor: arredondar(juros + amortizacao),
+            pago: i <= quant_ja_pago,
+            data_vencimento: dt_inicio
+                .checked_add_months(Months::new((i as u32) - 1))
+                .unwrap_or(dt_inicio),
+            juros,
+            amortizacao,
+            saldo_devedor: saldo,
+            lancamento: None,
+            desconto: 0.0,
+            renegociada: false,
+        });
+    }
+
+    parcelas
+}
+
+fn prestacao(saldo: f64, taxa: f64, restantes: f64) -> f64 {
+    if taxa == 0.0 {
+        saldo / restantes
+    } else {
+        saldo * taxa / (1.0 - (1.0 + taxa).powf(-restantes))
+    }
+}
+
+fn arredondar(valor: f64) -> f64 {
+    (valor * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dto::{DadosDivida, IndiceCorrecao};
+
+    fn financiamento(sistema: SistemaAmortizacao, correcao_mensal: f64) -> Financiamento {
+        Financiamento {
+            principal: 12000.0,
+            taxa_mensal: 1.0,
+            prazo: 12,
+            sistema,
+            indice: if correcao_mensal == 0.0 {
+                IndiceCorrecao::Nenhum
+            } else {
+                IndiceCorrecao::Ipca
+            },
+            correcao_mensal,
+        }
+    }
+
+    fn inicio() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 1, 10).unwrap()
+    }
+
+    #[test]
+    fn price_tem_parcela_fixa_e_quita_o_principal() {
+        let parcelas = gerar_parcelas(&financiamento(SistemaAmortizacao::Price, 0.0), inicio(), 0);
+
+        assert_eq!(parcelas.len(), 12);
+        assert!(parcelas.iter().all(|p| (p.valor - 1066.19).abs() < 0.02));
+        assert_eq!(parcelas[0].juros, 120.0);
+        assert_eq!(parcelas.ultima().saldo_devedor, 0.0);
+        assert!((parcelas.saldo_devedor() - 12000.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn sac_tem_amortizacao_constante_e_parcela_decrescente() {
+        let parcelas = gerar_parcelas(&financiamento(SistemaAmortizacao::Sac, 0.0), inicio(), 3);
+
+        assert!(parcelas.iter().all(|p| p.amortizacao == 1000.0));
+        assert_eq!(parcelas[0].valor, 1120.0);
+        assert_eq!(parcelas.ultima().valor, 1010.0);
+        assert_eq!(parcelas[2].saldo_devedor, 9000.0);
+        assert_eq!(parcelas.pagas().quant(), 3);
+        assert!((parcelas.aberta().saldo_devedor() - 9000.0).abs() < 0.001);
+        assert!((parcelas.aberta().juros_total() - 450.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn indice_corrige_o_saldo_antes_dos_juros() {
+        let parcelas = gerar_parcelas(&financiamento(SistemaAmortizacao::Sac, 0.5), inicio(), 0);
+
+        assert_eq!(parcelas[0].juros, 120.6);
+        assert_eq!(parcelas[0].amortizacao, 1005.0);
+        assert_eq!(parcelas.ultima().saldo_devedor, 0.0);
+        assert!(parcelas.saldo_devedor() > 12000.0);
+    }
+
+    #[test]
+    fn sem_juros_divide_o_principal() {
+        let mut fin = financiamento(SistemaAmortizacao::Price, 0.0);
+        fin.taxa_mensal = 0.0;
+        let parcelas = gerar_parcelas(&fin, inicio(), 0);
+
+        assert!(parcelas.iter().all(|p| p.valor == 1000.0 && p.juros == 0.0));
+        assert_eq!(parcelas[1].data_vencimento, NaiveDate::from_ymd_opt(2025, 2, 10).unwrap());
+    }
+}
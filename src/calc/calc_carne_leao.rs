@@ -0,0 +1,206 @@
+use chrono::{Datelike, Duration, Months, NaiveDate, Weekday};
+
+use crate::dto::{CarneLeao, Categoria, Lancamento, OptionalLazyFn, TabelaTributaria};
+
+use super::calc_contracheque::calcular_irrf;
+
+/// Depois do vencimento, prazo em que um DARF pago com atraso ainda é procurado.
+const DIAS_ATRASO: i64 = 90;
+
+/// Carnê-leão do mês: rendimentos nas categorias marcadas como carnê-leão
+/// menos a maior entre as deduções legais (livro-caixa, limitado aos
+/// rendimentos, e dependentes) e o desconto simplificado; o imposto segue a
+/// tabela progressiva mensal. Categorias filhas herdam a marcação do pai.
+pub fn calcular_carne_leao(
+    tabela: &TabelaTributaria,
+    mes: NaiveDate,
+    lancamentos: &[Lancamento],
+    categorias: &[Categoria],
+    dependentes: i32,
+) -> CarneLeao {
+    let mes = mes.with_day(1).unwrap_or(mes);
+    let do_mes = |flag: fn(&Categoria) -> bool| -> f64 {
+        lancamentos
+            .iter()
+            .filter(|l| l.data.year() == mes.year() && l.data.month() == mes.month())
+            .filter(|l| marcada(categorias, &l.categoria.id(), flag))
+            .map(|l| l.valor)
+            .sum()
+    };
+
+    let rendimentos = do_mes(|c| c.carne_leao).max(0.0);
+    let livro_caixa = (-do_mes(|c| c.livro_caixa)).clamp(0.0, rendimentos);
+    let deducao_dependentes = dependentes as f64 * tabela.deducao_dependente;
+
+    let legais = livro_caixa + deducao_dependentes;
+    let simplificado = tabela.desconto_simplificado > legais;
+    let base = (rendimentos - legais.max(tabela.desconto_simplificado)).max(0.0);
+
+    CarneLeao {
+        mes,
+        rendimentos: arredondar(rendimentos),
+        livro_caixa: arredondar(livro_caixa),
+        dependentes: arredondar(deducao_dependentes),
+        simplificado,
+        base: arredondar(base),
+        imposto: if rendimentos > 0.0 {
+            calcular_irrf(tabela, base)
+        } else {
+            0.0
+        },
+        vencimento: vencimento_carne_leao(mes),
+        lancamento: None,
+        data_pagamento: None,
+    }
+}
+
+/// Último dia útil do mês seguinte ao dos rendimentos (não considera feriados).
+pub fn vencimento_carne_leao(mes: NaiveDate) -> NaiveDate {
+    let mes = mes.with_day(1).unwrap_or(mes);
+    let mut dia = mes + Months::new(2) - Duration::days(1);
+    while matches!(dia.weekday(), Weekday::Sat | Weekday::Sun) {
+        dia -= Duration::days(1);
+    }
+    dia
+}
+
+/// Procura o DARF de cada mês ainda não pago: uma saída com DARF ou leão na
+/// descrição, depois do fim do mês e até 90 dias após o vencimento, de valor
+/// igual ou maior que o imposto (juros e multa, se atrasado). Cada lançamento
+/// paga um só mês. Devolve quantos pagamentos foram encontrados.
+pub fn conciliar_carne_leao(meses: &mut [CarneLeao], lancamentos: &[Lancamento]) -> usize {
+    let mut usados: Vec<String> = meses.iter().filter_map(|m| m.lancamento.clone()).collect();
+    let mut encontrados = 0;
+
+    for m in meses.iter_mut().filter(|m| !m.pago() && m.imposto > 0.0) {
+        let inicio = m.mes + Months::new(1);
+        let fim = m.vencimento + Duration::days(DIAS_ATRASO);
+
+        let darf = lancamentos
+            .iter()
+            .filter(|l| !usados.contains(&l.id))
+            .filter(|l| l.data >= inicio && l.data <= fim)
+            .filter(|l| -l.valor >= m.imposto - 0.01)
+            .filter(|l| eh_darf(&l.descricao))
+            .min_by(|a, b| (-a.valor - m.imposto).total_cmp(&(-b.valor - m.imposto)));
+
+        if let Some(l) = darf {
+            m.lancamento = Some(l.id.clone());
+            m.data_pagamento = Some(l.data);
+            usados.push(l.id.clone());
+            encontrados += 1;
+        }
+    }
+
+    encontrados
+}
+
+fn eh_darf(descricao: &str) -> bool {
+    let descricao = descricao.to_uppercase();
+    descricao.contains("DARF") || descricao.contains("LEÃO") || descricao.contains("LEAO")
+}
+
+pub(super) fn marcada(categorias: &[Categoria], id: &str, flag: fn(&Categoria) -> bool) -> bool {
+    let mut atual = categorias.iter().find(|c| c.id == id);
+    let mut passos = 0;
+
+    while let Some(c) = atual {
+        if flag(c) {
+            return true;
+        }
+        passos += 1;
+        if passos > categorias.len() {
+            break;
+        }
+        atual = c
+            .pai
+            .as_ref()
+            .and_then(|pai| categorias.iter().find(|p| &p.id == pai));
+    }
+    false
+}
+
+fn arredondar(valor: f64) -> f64 {
+    (valor * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calc::teste::{data, lancamento, tabela, LancamentoTeste};
+    use crate::dto::{TipoDespesa, TipoFluxo};
+
+    fn categoria(id: &str, tipo: TipoFluxo, pai: Option<&str>, carne_leao: bool, livro_caixa: bool) -> Categoria {
+        Categoria {
+            id: id.to_string(),
+            nome: id.to_string(),
+            tipo,
+            pai: pai.map(|p| p.to_string()),
+            carne_leao,
+            livro_caixa,
+            hierarquia: Vec::new(),
+        }
+    }
+
+    fn categorias() -> Vec<Categoria> {
+        vec![
+            categoria("aluguel", TipoFluxo::Receita, None, true, false),
+            categoria("apto", TipoFluxo::Receita, Some("aluguel"), false, false),
+            categoria("salario", TipoFluxo::Receita, None, false, false),
+            categoria("condominio", TipoFluxo::Despesa(TipoDespesa::Fixa), None, false, true),
+        ]
+    }
+
+    #[test]
+    fn deducoes_legais_quando_maiores_que_o_simplificado() {
+        let lancamentos = vec![
+            lancamento(5000.0, data(5, 3)).com_id("1").com_descricao("Aluguel apto").com_categoria("apto"),
+            lancamento(8000.0, data(5, 3)).com_id("2").com_descricao("Salário").com_categoria("salario"),
+            lancamento(-800.0, data(10, 3)).com_id("3").com_descricao("Condomínio").com_categoria("condominio"),
+            lancamento(5000.0, data(5, 4)).com_id("4").com_descricao("Aluguel apto").com_categoria("apto"),
+        ];
+
+        let resp = calcular_carne_leao(&tabela(), data(1, 3), &lancamentos, &categorias(), 1);
+
+        assert_eq!(resp.rendimentos, 5000.0);
+        assert_eq!(resp.livro_caixa, 800.0);
+        assert!(!resp.simplificado);
+        assert_eq!(resp.base, 4010.41);
+        assert_eq!(resp.imposto, 226.85);
+        assert_eq!(resp.vencimento, data(30, 4));
+    }
+
+    #[test]
+    fn simplificado_quando_nao_ha_deducoes() {
+        let lancamentos = vec![lancamento(3000.0, data(5, 5)).com_id("1").com_descricao("Aluguel").com_categoria("aluguel")];
+
+        let resp = calcular_carne_leao(&tabela(), data(1, 5), &lancamentos, &categorias(), 0);
+
+        assert!(resp.simplificado);
+        assert_eq!(resp.base, 2392.80);
+        assert_eq!(resp.imposto, 0.0);
+        // 31/05/2025 cai num sábado: o vencimento de abril volta para sexta
+        assert_eq!(vencimento_carne_leao(data(1, 4)), data(30, 5));
+    }
+
+    #[test]
+    fn encontra_o_darf_pago_depois_do_mes() {
+        let mut meses = vec![calcular_carne_leao(
+            &tabela(),
+            data(1, 3),
+            &[lancamento(5000.0, data(5, 3)).com_id("1").com_descricao("Aluguel apto").com_categoria("apto")],
+            &categorias(),
+            1,
+        )];
+        let imposto = meses[0].imposto;
+        let lancamentos = vec![
+            lancamento(-imposto, data(20, 3)).com_id("a").com_descricao("PAGTO DARF").com_categoria(""),
+            lancamento(-imposto, data(20, 4)).com_id("b").com_descricao("Mercado").com_categoria(""),
+            lancamento(-imposto, data(25, 4)).com_id("c").com_descricao("PAGTO DARF").com_categoria(""),
+        ];
+
+        assert_eq!(conciliar_carne_leao(&mut meses, &lancamentos), 1);
+        assert_eq!(meses[0].lancamento, Some("c".to_string()));
+        assert!(!meses[0].atrasado(data(1, 6)));
+    }
+}
@@ -0,0 +1,122 @@
+use chrono::{Duration, NaiveDate};
+
+use crate::dto::{Divida, Lancamento, ParcelaDivida};
+
+/// Diferença aceita entre o valor do lançamento e o da parcela, em fração da parcela.
+const TOLERANCIA_VALOR: f64 = 0.02;
+/// Dias antes ou depois do vencimento em que o débito ainda é aceito.
+pub const JANELA_DIAS: i64 = 7;
+
+/// Marca como pagas as parcelas abertas das dívidas conciliadas que tenham um
+/// lançamento de saída correspondente: descrição com o padrão, mesma conta (se
+/// informada), valor dentro da tolerância e data perto do vencimento. Cada
+/// lançamento paga uma única parcela. Retorna quantas parcelas foram marcadas.
+pub fn conciliar_dividas(dividas: &mut [Divida], lancamentos: &[Lancamento]) -> usize {
+    let mut usados: Vec<String> = dividas
+        .iter()
+        .flat_map(|d| d.parcelas.iter().filter_map(|p| p.lancamento.clone()))
+        .collect();
+    let mut marcadas = 0;
+
+    for divida in dividas.iter_mut().filter(|d| d.conciliada()) {
+        let padrao = divida.padrao_descricao.trim().to_lowercase();
+        let conta = divida.conta.clone();
+
+        let candidatos: Vec<&Lancamento> = lancamentos
+            .iter()
+            .filter(|l| l.valor < 0.0)
+            .filter(|l| l.descricao.to_lowercase().contains(&padrao))
+            .filter(|l| conta.is_none() || l.conta == conta)
+            .collect();
+
+        for parcela in divida.parcelas.iter_mut().filter(|p| !p.pago) {
+            let encontrado = candidatos
+                .iter()
+                .filter(|l| !usados.contains(&l.id) && corresponde(parcela, l))
+                .min_by_key(|l| (l.data - parcela.data_vencimento).num_days().abs());
+
+            if let Some(lancamento) = encontrado {
+                parcela.pago = true;
+                parcela.lancamento = Some(lancamento.id.clone());
+                usados.push(lancamento.id.clone());
+                marcadas += 1;
+            }
+        }
+    }
+
+    marcadas
+}
+
+/// Parcela de dívida conciliada que venceu há mais que a janela sem lançamento correspondente.
+pub fn possivelmente_em_atraso(divida: &Divida, parcela: &ParcelaDivida, hoje: NaiveDate) -> bool {
+    divida.conciliada()
+        && !parcela.pago
+        && parcela.data_vencimento + Duration::days(JANELA_DIAS) < hoje
+}
+
+fn corresponde(parcela: &ParcelaDivida, lancamento: &Lancamento) -> bool {
+    (-lancamento.valor - parcela.valor).abs() <= parcela.valor.abs() * TOLERANCIA_VALOR
+        && (lancamento.data - parcela.data_vencimento).num_days().abs() <= JANELA_DIAS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calc::teste::{data, lancamento, LancamentoTeste};
+
+    fn divida(padrao: &str, conta: Option<&str>) -> Divida {
+        let mut divida = Divida::new("Carro".to_string(), false, false, 3, 500.0, data(10, 1), 0);
+        divida.padrao_descricao = padrao.to_string();
+        divida.conta = conta.map(|c| c.to_string());
+        divida
+    }
+
+    #[test]
+    fn marca_parcela_com_lancamento_correspondente() {
+        let mut dividas = vec![divida("financ", Some("cc"))];
+        let lancs = vec![
+            lancamento(-502.0, data(12, 1)).com_id("1").com_descricao("DEB FINANC VEICULO").com_conta("cc"),
+            lancamento(-500.0, data(10, 2)).com_id("2").com_descricao("DEB FINANC VEICULO").com_conta("outra"),
+        ];
+
+        assert_eq!(conciliar_dividas(&mut dividas, &lancs), 1);
+        assert!(dividas[0].parcelas[0].pago);
+        assert_eq!(dividas[0].parcelas[0].lancamento, Some("1".to_string()));
+        assert!(!dividas[0].parcelas[1].pago);
+    }
+
+    #[test]
+    fn ignora_valor_ou_data_fora_da_tolerancia() {
+        let mut dividas = vec![divida("financ", None)];
+        let lancs = vec![
+            lancamento(-450.0, data(10, 1)).com_id("1").com_descricao("financ").com_conta("cc"),
+            lancamento(-500.0, data(25, 1)).com_id("2").com_descricao("financ").com_conta("cc"),
+            lancamento(500.0, data(10, 1)).com_id("3").com_descricao("financ").com_conta("cc"),
+        ];
+
+        assert_eq!(conciliar_dividas(&mut dividas, &lancs), 0);
+    }
+
+    #[test]
+    fn lancamento_ja_vinculado_nao_paga_outra_parcela() {
+        let mut dividas = vec![divida("financ", None), divida("financ", None)];
+        let lancs = vec![lancamento(-500.0, data(10, 1)).com_id("1").com_descricao("financ").com_conta("cc")];
+
+        assert_eq!(conciliar_dividas(&mut dividas, &lancs), 1);
+        assert_eq!(conciliar_dividas(&mut dividas, &lancs), 0);
+        assert!(!dividas[1].parcelas[0].pago);
+    }
+
+    #[test]
+    fn sem_padrao_nao_concilia_nem_sinaliza_atraso() {
+        let mut dividas = vec![divida("", None)];
+        let lancs = vec![lancamento(-500.0, data(10, 1)).com_id("1").com_descricao("financ").com_conta("cc")];
+
+        assert_eq!(conciliar_dividas(&mut dividas, &lancs), 0);
+        assert!(!possivelmente_em_atraso(&dividas[0], &dividas[0].parcelas[0], data(1, 3)));
+
+        let conciliada = divida("financ", None);
+        assert!(possivelmente_em_atraso(&conciliada, &conciliada.parcelas[0], data(18, 1)));
+        assert!(!possivelmente_em_atraso(&conciliada, &conciliada.parcelas[0], data(17, 1)));
+    }
+}
@@ -0,0 +1,331 @@
+use crate::dto::{
+    ComparacaoItemContracheque, ConferenciaContracheque, Contracheque, DashContracheque,
+    ItemContracheque, TabelaTributaria, TipoContracheque,
+};
+
+/// INSS progressivo: cada faixa incide só sobre a parte do salário dentro
+/// dela, até o teto da última faixa.
+pub fn calcular_inss(tabela: &TabelaTributaria, bruto: f64) -> f64 {
+    let mut inss = 0.0;
+    let mut anterior = 0.0;
+
+    for faixa in tabela.inss.iter() {
+        if bruto <= anterior {
+            break;
+        }
+        let limite = faixa.ate.unwrap_or(f64::MAX);
+        inss += (bruto.min(limite) - anterior) * faixa.aliquota / 100.0;
+        anterior = limite;
+    }
+
+    arredondar(inss)
+}
+
+/// IRRF pela alíquota da faixa da base menos a parcela a deduzir.
+pub fn calcular_irrf(tabela: &TabelaTributaria, base: f64) -> f64 {
+    tabela
+        .irrf
+        .iter()
+        .find(|f| f.ate.is_none_or(|ate| base <= ate))
+        .map(|f| arredondar((base * f.aliquota / 100.0 - f.deducao).max(0.0)))
+        .unwrap_or_default()
+}
+
+/// Confere os descontos do contracheque: o bruto é a soma das entradas; o
+/// INSS e o IRRF informados são as saídas com esses nomes. A base do IRRF usa
+/// a dedução mais favorável entre INSS mais dependentes e o desconto simplificado.
+///
+/// PLR e a 1ª parcela do 13º não são conferidos (`None`): a PLR tem tabela própria
+/// e a 1ª parcela não tem desconto. A 2ª parcela do 13º é tributada à parte, sobre
+/// o 13º inteiro, só com as deduções legais.
+pub fn conferir_contracheque(
+    tabela: &TabelaTributaria,
+    tipo: &TipoContracheque,
+    entradas: &[(String, f64)],
+    saidas: &[(String, f64)],
+    dependentes: i32,
+) -> Option<ConferenciaContracheque> {
+    let desconto_simplificado = match tipo {
+        TipoContracheque::Plr | TipoContracheque::DecimoTerceiroPrimeira => return None,
+        TipoContracheque::DecimoTerceiroSegunda => 0.0,
+        TipoContracheque::Mensal | TipoContracheque::Ferias => tabela.desconto_simplificado,
+    };
+
+    let bruto: f64 = entradas.iter().map(|(_, valor)| valor).sum();
+    let informado = |filtro: fn(&str) -> bool| -> f64 {
+        saidas
+            .iter()
+            .filter(|(nome, _)| filtro(nome))
+            .map(|(_, valor)| valor)
+            .sum()
+    };
+    let inss_informado = informado(eh_inss);
+    let irrf_informado = informado(eh_irrf);
+
+    let inss_esperado = calcular_inss(tabela, bruto);
+    let deducoes_legais = inss_esperado + dependentes as f64 * tabela.deducao_dependente;
+    let simplificado = desconto_simplificado > deducoes_legais;
+    let base_irrf = (bruto - deducoes_legais.max(desconto_simplificado)).max(0.0);
+
+    Some(ConferenciaContracheque {
+        bruto: arredondar(bruto),
+        inss_esperado,
+        inss_informado: arredondar(inss_informado),
+        irrf_esperado: calcular_irrf(tabela, base_irrf),
+        irrf_informado: arredondar(irrf_informado),
+        base_irrf: arredondar(base_irrf),
+        simplificado,
+        aliquota_efetiva: if bruto > 0.0 {
+            arredondar((inss_informado + irrf_informado) / bruto * 100.0)
+        } else {
+            0.0
+        },
+    })
+}
+
+/// Compara dois contracheques item a item, pelo nome sem diferenciar
+/// maiúsculas: entradas primeiro, na ordem em que aparecem em `a` e depois em `b`.
+pub fn comparar_contracheques(
+    a: &Contracheque,
+    b: &Contracheque,
+) -> Vec<ComparacaoItemContracheque> {
+    let mut resp = comparar_itens(&a.entradas, &b.entradas, false);
+    resp.extend(comparar_itens(&a.saidas, &b.saidas, true));
+    resp
+}
+
+fn comparar_itens(
+    a: &[ItemContracheque],
+    b: &[ItemContracheque],
+    saida: bool,
+) -> Vec<ComparacaoItemContracheque> {
+    let mut nomes: Vec<String> = Vec::new();
+    a.iter().chain(b.iter()).for_each(|i| {
+        if !nomes.iter().any(|n| mesmo_nome(n, &i.nome)) {
+            nomes.push(i.nome.clone());
+        }
+    });
+
+    let valor = |itens: &[ItemContracheque], nome: &str| -> f64 {
+        itens
+            .iter()
+            .filter(|i| mesmo_nome(&i.nome, nome))
+            .map(|i| i.valor)
+            .sum()
+    };
+
+    nomes
+        .into_iter()
+        .map(|nome| {
+            let valor_a = valor(a, &nome);
+            let valor_b = valor(b, &nome);
+            ComparacaoItemContracheque {
+                nome,
+                saida,
+                valor_a,
+                valor_b,
+                diferenca: arredondar(valor_b - valor_a),
+            }
+        })
+        .collect()
+}
+
+fn mesmo_nome(a: &str, b: &str) -> bool {
+    a.trim().to_lowercase() == b.trim().to_lowercase()
+}
+
+/// Bruto, descontos e líquido por mês de pagamento, somando empresas e tipos.
+pub fn historico_contracheques(contracheques: &[Contracheque]) -> Vec<DashContracheque> {
+    let mut ordenados: Vec<&Contracheque> = contracheques.iter().collect();
+    ordenados.sort_by_key(|c| c.data_pagamento);
+
+    let mut resp: Vec<DashContracheque> = Vec::new();
+    for c in ordenados {
+        let mes = c.data_pagamento.format("%m/%Y").to_string();
+        if resp.last().is_none_or(|m| m.mes != mes) {
+            resp.push(DashContracheque {
+                mes,
+                bruto: 0.0,
+                descontos: 0.0,
+                liquido: 0.0,
+            });
+        }
+        if let Some(m) = resp.last_mut() {
+            m.bruto = arredondar(m.bruto + c.bruto());
+            m.descontos = arredondar(m.descontos + c.descontos());
+            m.liquido = arredondar(m.liquido + c.liquido());
+        }
+    }
+    resp
+}
+
+pub fn eh_inss(nome: &str) -> bool {
+    nome.to_uppercase().contains("INSS")
+}
+
+pub fn eh_irrf(nome: &str) -> bool {
+    let nome = nome.to_uppercase();
+    nome.contains("IRRF")
+        || nome.contains("IMPOSTO DE RENDA")
+        || nome.split(|c: char| !c.is_alphanumeric()).any(|p| p == "IR")
+}
+
+fn arredondar(valor: f64) -> f64 {
+    (valor * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    use crate::calc::teste::{data, tabela};
+
+    fn item(nome: &str, valor: f64) -> (String, f64) {
+        (nome.to_string(), valor)
+    }
+
+    fn conferir(
+        entradas: &[(String, f64)],
+        saidas: &[(String, f64)],
+        dependentes: i32,
+    ) -> ConferenciaContracheque {
+        conferir_contracheque(&tabela(), &TipoContracheque::Mensal, entradas, saidas, dependentes)
+            .unwrap()
+    }
+
+    #[test]
+    fn inss_progressivo_ate_o_teto() {
+        assert_eq!(calcular_inss(&tabela(), 1000.0), 75.0);
+        assert_eq!(calcular_inss(&tabela(), 3000.0), 253.41);
+        assert_eq!(calcular_inss(&tabela(), 10000.0), 951.63);
+    }
+
+    #[test]
+    fn irrf_usa_a_deducao_mais_favoravel() {
+        let entradas = vec![item("Salário", 4500.0), item("Hora extra", 500.0)];
+
+        let sem_dependentes = conferir(&entradas, &[], 0);
+        assert_eq!(sem_dependentes.inss_esperado, 509.6);
+        assert!(sem_dependentes.simplificado);
+        assert_eq!(sem_dependentes.base_irrf, 4392.8);
+        assert_eq!(sem_dependentes.irrf_esperado, 312.89);
+
+        let um_dependente = conferir(&entradas, &[], 1);
+        assert!(!um_dependente.simplificado);
+        assert_eq!(um_dependente.base_irrf, 4300.81);
+        assert_eq!(um_dependente.irrf_esperado, 292.19);
+
+        let isento = conferir(&[item("Salário", 2000.0)], &[], 0);
+        assert_eq!(isento.irrf_esperado, 0.0);
+    }
+
+    #[test]
+    fn compara_com_o_informado() {
+        let entradas = vec![item("Salário", 5000.0)];
+        let saidas = vec![
+            item("INSS", 509.6),
+            item("IRRF", 300.0),
+            item("Vale transporte", 150.0),
+        ];
+
+        let resp = conferir(&entradas, &saidas, 1);
+
+        assert_eq!(resp.diferenca_inss(), 0.0);
+        assert_eq!(arredondar(resp.diferenca_irrf()), 7.81);
+        assert_eq!(resp.aliquota_efetiva, 16.19);
+    }
+
+    #[test]
+    fn decimo_terceiro_e_plr_seguem_regras_proprias() {
+        let entradas = vec![item("13º salário", 5000.0)];
+        let saidas = vec![item("Adiantamento 13º", 2500.0)];
+        let conferir_tipo = |tipo| conferir_contracheque(&tabela(), &tipo, &entradas, &saidas, 0);
+
+        assert!(conferir_tipo(TipoContracheque::Plr).is_none());
+        assert!(conferir_tipo(TipoContracheque::DecimoTerceiroPrimeira).is_none());
+
+        let segunda = conferir_tipo(TipoContracheque::DecimoTerceiroSegunda).unwrap();
+        assert!(!segunda.simplificado);
+        assert_eq!(segunda.base_irrf, 4490.4);
+        assert!(segunda.irrf_esperado > conferir(&entradas, &saidas, 0).irrf_esperado);
+    }
+
+    #[test]
+    fn reconhece_os_nomes_dos_descontos() {
+        assert!(eh_inss("Contribuição INSS"));
+        assert!(eh_irrf("IR Fonte"));
+        assert!(eh_irrf("Imposto de renda"));
+        assert!(!eh_irrf("Irmão"));
+    }
+
+    fn contracheque(
+        empresa: &str,
+        pagamento: NaiveDate,
+        entradas: Vec<(String, f64)>,
+        saidas: Vec<(String, f64)>,
+    ) -> Contracheque {
+        let itens = |lista: Vec<(String, f64)>| {
+            lista
+                .into_iter()
+                .map(|(nome, valor)| ItemContracheque { nome, valor })
+                .collect()
+        };
+        Contracheque {
+            id: String::new(),
+            empresa: empresa.to_string(),
+            tipo: TipoContracheque::Mensal,
+            competencia: pagamento,
+            data_pagamento: pagamento,
+            entradas: itens(entradas),
+            saidas: itens(saidas),
+        }
+    }
+
+    #[test]
+    fn compara_item_a_item() {
+        let a = contracheque(
+            "ACME",
+            data(5, 1),
+            vec![item("Salário", 5000.0), item("Hora extra", 300.0)],
+            vec![item("INSS", 509.6)],
+        );
+        let b = contracheque(
+            "ACME",
+            data(5, 2),
+            vec![item("SALÁRIO", 5000.0), item("Adicional noturno", 200.0)],
+            vec![item("INSS", 509.6), item("Vale transporte", 150.0)],
+        );
+
+        let resp = comparar_contracheques(&a, &b);
+        let nomes: Vec<&str> = resp.iter().map(|i| i.nome.as_str()).collect();
+
+        assert_eq!(
+            nomes,
+            vec!["Salário", "Hora extra", "Adicional noturno", "INSS", "Vale transporte"]
+        );
+        assert_eq!(resp[0].diferenca, 0.0);
+        assert_eq!(resp[1].valor_b, 0.0);
+        assert_eq!(resp[1].diferenca, -300.0);
+        assert_eq!(resp[2].diferenca, 200.0);
+        assert!(resp[4].saida);
+        assert_eq!(resp[4].diferenca, 150.0);
+    }
+
+    #[test]
+    fn historico_soma_por_mes_de_pagamento() {
+        let lista = vec![
+            contracheque("B", data(20, 1), vec![item("PLR", 2000.0)], vec![item("IRRF", 100.0)]),
+            contracheque("A", data(5, 2), vec![item("Salário", 3000.0)], vec![item("INSS", 250.0)]),
+            contracheque("A", data(5, 1), vec![item("Salário", 3000.0)], vec![item("INSS", 250.0)]),
+        ];
+
+        let resp = historico_contracheques(&lista);
+
+        assert_eq!(resp.len(), 2);
+        assert_eq!(resp[0].mes, "01/2025");
+        assert_eq!(resp[0].bruto, 5000.0);
+        assert_eq!(resp[0].liquido, 4650.0);
+        assert_eq!(resp[1].liquido, 2750.0);
+    }
+}
@@ -0,0 +1,266 @@
+use chrono::{Datelike, Months, NaiveDate};
+
+use crate::dto::{
+    Banco, Configuracao, DadosDivida, DashFluxoCaixa, Divida, Lancamento, OptionalLazyFn,
+    ParcelaDivida, TipoConta, TipoDespesa, TipoFluxo,
+};
+
+use super::calc_patrimonio::saldo_em;
+
+/// Meses projetados depois do atual.
+const MESES: u32 = 12;
+/// Meses completos anteriores usados nas médias.
+const MESES_REFERENCIA: u32 = 3;
+
+/// Projeta o saldo do resto do mês atual e dos próximos 12 meses.
+///
+/// Entradas: o salário da configuração ou, sem ele, a média das receitas dos
+/// últimos 3 meses completos. Saídas: a média das despesas fixas no mesmo
+/// período, as parcelas abertas das dívidas e as despesas sazonais, que são
+/// as do mesmo mês do ano passado em categorias sem gasto nos meses de referência.
+/// No mês atual entra só o que ainda falta, descontado o que já foi lançado, e o
+/// acumulado parte do saldo de hoje das contas que não são de investimento.
+pub fn projetar_fluxo_caixa(
+    config: &Configuracao,
+    bancos: &[Banco],
+    lancamentos: &[Lancamento],
+    dividas: &[Divida],
+    hoje: NaiveDate,
+) -> Vec<DashFluxoCaixa> {
+    let mes_atual = hoje.with_day(1).unwrap_or(hoje);
+    let referencia: Vec<&Lancamento> = lancamentos
+        .iter()
+        .filter(|l| l.data < mes_atual && l.data >= mes_atual - Months::new(MESES_REFERENCIA))
+        .collect();
+    let lancados: Vec<&Lancamento> = lancamentos
+        .iter()
+        .filter(|l| l.data >= mes_atual && l.data <= hoje)
+        .collect();
+
+    let receitas = if config.salario > 0.0 {
+        config.salario
+    } else {
+        media(&referencia, |t| *t == TipoFluxo::Receita)
+    };
+    let fixas = -media(&referencia, |t| *t == TipoFluxo::Despesa(TipoDespesa::Fixa));
+
+    let parcelas: Vec<ParcelaDivida> = dividas
+        .iter()
+        .flat_map(|d| d.parcelas.aberta())
+        .collect();
+    let categorias_recentes: Vec<String> = referencia.iter().map(|l| l.categoria.id()).collect();
+
+    let mut acumulado: f64 = bancos
+        .iter()
+        .flat_map(|b| b.contas.iter())
+        .filter(|c| c.tipo != TipoConta::Investimento)
+        .map(|c| saldo_em(c, lancamentos, hoje))
+        .sum();
+    (0..=MESES)
+        .map(|m| {
+            let mes = mes_atual + Months::new(m);
+            let valor_dividas = parcelas.mes_e_ano(mes).valor_total();
+            let do_ano_passado: Vec<&Lancamento> = lancamentos
+                .iter()
+                .filter(|l| l.data.year() == mes.year() - 1 && l.data.month() == mes.month())
+                .filter(|l| eh_sazonal(l) && !categorias_recentes.contains(&l.categoria.id()))
+                .collect();
+            let mut sazonais = -soma(&do_ano_passado, |_| true);
+
+            let (mut receitas, mut fixas) = (receitas, fixas);
+            if m == 0 {
+                let categorias: Vec<String> =
+                    do_ano_passado.iter().map(|l| l.categoria.id()).collect();
+                receitas = (receitas - soma(&lancados, |t| *t == TipoFluxo::Receita)).max(0.0);
+                fixas = (fixas + soma(&lancados, |t| *t == TipoFluxo::Despesa(TipoDespesa::Fixa)))
+                    .max(0.0);
+                sazonais += lancados
+                    .iter()
+                    .filter(|l| l.valor < 0.0 && categorias.contains(&l.categoria.id()))
+                    .map(|l| l.valor)
+                    .sum::<f64>();
+                sazonais = sazonais.max(0.0);
+            }
+
+            let saldo = receitas - fixas - valor_dividas - sazonais;
+            acumulado += saldo;
+
+            DashFluxoCaixa {
+                mes: mes.format("%m/%Y").to_string(),
+                receitas: arredondar(receitas),
+                fixas: arredondar(fixas),
+                dividas: arredondar(valor_dividas),
+                sazonais: arredondar(sazonais),
+                saldo: arredondar(saldo),
+                acumulado: arredondar(acumulado),
+                negativo: acumulado < 0.0,
+            }
+        })
+        .collect()
+}
+
+fn tipo(lancamento: &Lancamento) -> Option<TipoFluxo> {
+    lancamento.categoria.some().map(|c| c.tipo)
+}
+
+fn media(lancamentos: &[&Lancamento], filtro: impl Fn(&TipoFluxo) -> bool) -> f64 {
+    soma(lancamentos, filtro) / MESES_REFERENCIA as f64
+}
+
+fn soma(lancamentos: &[&Lancamento], filtro: impl Fn(&TipoFluxo) -> bool) -> f64 {
+    lancamentos
+        .iter()
+        .filter(|l| tipo(l).is_some_and(|t| filtro(&t)))
+        .map(|l| l.valor)
+        .sum()
+}
+
+/// Despesas não fixas (as fixas já entram pela média).
+fn eh_sazonal(lancamento: &Lancamento) -> bool {
+    lancamento.valor < 0.0
+        && matches!(
+            tipo(lancamento),
+            Some(TipoFluxo::Despesa(TipoDespesa::Variavel | TipoDespesa::Perda))
+        )
+}
+
+fn arredondar(valor: f64) -> f64 {
+    (valor * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calc::teste::{data_ano, LancamentoTeste};
+    use crate::dto::{Categoria, Conta, OptionalLazy};
+
+    fn categoria(id: &str, tipo: TipoFluxo) -> Categoria {
+        Categoria {
+            id: id.to_string(),
+            nome: id.to_string(),
+            tipo,
+            pai: None,
+            carne_leao: false,
+            livro_caixa: false,
+            hierarquia: Vec::new(),
+        }
+    }
+
+    fn lancamento(valor: f64, data: NaiveDate, categoria: &Categoria) -> Lancamento {
+        Lancamento {
+            valor,
+            data,
+            categoria: OptionalLazy::Some(categoria.clone()),
+            ..Default::default()
+        }
+    }
+
+    fn config(salario: f64) -> Configuracao {
+        Configuracao {
+            salario,
+            endividamento_max: 0.0,
+            contracheque: false,
+            contracheque_entradas: Vec::new(),
+            contracheque_saidas: Vec::new(),
+            contracheque_empresa: String::new(),
+            dependentes: 0,
+        }
+    }
+
+    fn historico() -> Vec<Lancamento> {
+        let salario = categoria("salario", TipoFluxo::Receita);
+        let aluguel = categoria("aluguel", TipoFluxo::Despesa(TipoDespesa::Fixa));
+        let mercado = categoria("mercado", TipoFluxo::Despesa(TipoDespesa::Variavel));
+        let ipva = categoria("ipva", TipoFluxo::Despesa(TipoDespesa::Variavel));
+
+        let mut resp: Vec<Lancamento> = (1..=3)
+            .flat_map(|mes| {
+                vec![
+                    lancamento(3000.0, data_ano(5, mes, 2025), &salario),
+                    lancamento(-1200.0, data_ano(10, mes, 2025), &aluguel),
+                    lancamento(-500.0, data_ano(15, mes, 2025), &mercado),
+                ]
+            })
+            .collect();
+        resp.push(lancamento(-1500.0, data_ano(20, 5, 2024), &ipva));
+        resp.push(lancamento(-400.0, data_ano(20, 5, 2024), &mercado));
+        resp
+    }
+
+    fn conta(id: &str, tipo: TipoConta, saldo_inicial: f64) -> Conta {
+        Conta {
+            id: id.to_string(),
+            nome: id.to_string(),
+            tipo,
+            saldo_inicial,
+            ..Default::default()
+        }
+    }
+
+    fn banco(contas: Vec<Conta>) -> Banco {
+        Banco {
+            id: "banco".to_string(),
+            nome: "banco".to_string(),
+            contas,
+        }
+    }
+
+    #[test]
+    fn usa_medias_sazonais_e_parcelas() {
+        let dividas = vec![Divida::new("Carro".to_string(), false, false, 2, 900.0, data_ano(10, 5, 2025), 0)];
+        let bancos = vec![banco(vec![conta("cc", TipoConta::Corrente, -1500.0)])];
+        let meses = projetar_fluxo_caixa(&config(0.0), &bancos, &historico(), &dividas, data_ano(20, 4, 2025));
+
+        assert_eq!(meses.len(), 13);
+        assert_eq!(meses[0].mes, "04/2025");
+        assert_eq!(meses[0].saldo, 1800.0);
+        assert_eq!(meses[0].acumulado, 300.0);
+        assert_eq!(meses[1].mes, "05/2025");
+        assert_eq!(meses[1].receitas, 3000.0);
+        assert_eq!(meses[1].fixas, 1200.0);
+        assert_eq!(meses[1].dividas, 900.0);
+        assert_eq!(meses[1].sazonais, 1500.0);
+        assert_eq!(meses[1].saldo, -600.0);
+        assert_eq!(meses[1].acumulado, -300.0);
+        assert!(meses[1].negativo);
+        assert_eq!(meses[2].saldo, 900.0);
+        assert_eq!(meses[2].acumulado, 600.0);
+        assert!(!meses[2].negativo);
+        assert_eq!(meses[3].saldo, 1800.0);
+    }
+
+    #[test]
+    fn mes_atual_tem_so_o_que_falta_a_partir_do_saldo_de_hoje() {
+        let salario = categoria("salario", TipoFluxo::Receita);
+        let aluguel = categoria("aluguel", TipoFluxo::Despesa(TipoDespesa::Fixa));
+        let ipva = categoria("ipva", TipoFluxo::Despesa(TipoDespesa::Variavel));
+        let mut lancamentos = historico();
+        lancamentos.push(lancamento(-800.0, data_ano(15, 4, 2024), &ipva));
+        lancamentos.push(lancamento(2000.0, data_ano(5, 4, 2025), &salario).com_conta("cc"));
+        lancamentos.push(lancamento(-1200.0, data_ano(10, 4, 2025), &aluguel).com_conta("cc"));
+        lancamentos.push(lancamento(-500.0, data_ano(12, 4, 2025), &ipva).com_conta("cc"));
+        let bancos = vec![banco(vec![
+            conta("cc", TipoConta::Corrente, 1000.0),
+            conta("cartao", TipoConta::Cartao, -300.0),
+            conta("corretora", TipoConta::Investimento, 50000.0),
+        ])];
+
+        let meses = projetar_fluxo_caixa(&config(0.0), &bancos, &lancamentos, &[], data_ano(20, 4, 2025));
+
+        assert_eq!(meses[0].receitas, 1000.0);
+        assert_eq!(meses[0].fixas, 0.0);
+        assert_eq!(meses[0].sazonais, 300.0);
+        assert_eq!(meses[0].saldo, 700.0);
+        // 1300 na corrente menos 300 no cartão, sem a corretora
+        assert_eq!(meses[0].acumulado, 1700.0);
+        assert_eq!(meses[1].acumulado, 1700.0 + 3000.0 - 1200.0 - 1500.0);
+    }
+
+    #[test]
+    fn salario_da_configuracao_tem_precedencia() {
+        let meses = projetar_fluxo_caixa(&config(5000.0), &[], &historico(), &[], data_ano(20, 4, 2025));
+
+        assert!(meses.iter().all(|m| m.receitas == 5000.0));
+        assert_eq!(meses.last().unwrap().mes, "04/2026");
+    }
+}
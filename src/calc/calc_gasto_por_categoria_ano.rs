@@ -3,18 +3,22 @@ use std::collections::HashMap;
 use chrono::{Local, Months};
 
 use crate::{
-    calc::calc_lancamentos_filtros::Lancamentos,
+    calc::{
+        calc_grupos_categoria::{caminho_grupos, chave_grupo, titulo_grupo},
+        calc_lancamentos_filtros::Lancamentos,
+    },
     dto::{Categoria, DashGastoPorCategoriaAno, Lancamento, OptionalLazyFn},
 };
 
 pub fn calcular_gasto_por_categoria_ano(
     ordem: &Vec<String>,
+    categorias: &Vec<Categoria>,
     mut lancamentos: Vec<Lancamento>,
 ) -> Vec<DashGastoPorCategoriaAno> {
     lancamentos = lancamentos.ultimos_dias(365);
     let mut mapa: HashMap<String, HashMap<String, HashMap<String, f64>>> = HashMap::new();
 
-    agrupar_lancamentos(&mut mapa, lancamentos);
+    agrupar_lancamentos(&mut mapa, categorias, lancamentos);
 
     let meses_base = gerar_base_meses();
 
@@ -54,7 +58,7 @@ fn criar_novo_grafico(
     nome: &String,
     valores: &HashMap<String, HashMap<String, f64>>,
 ) {
-    let mut item = DashGastoPorCategoriaAno::new(nome.as_str());
+    let mut item = DashGastoPorCategoriaAno::new(titulo_grupo(nome).as_str());
     grafico_preencher_valores_por_categira(base, valores, &mut item);
     item.valores
         .iter_mut()
@@ -91,23 +95,26 @@ fn gerar_base_meses() -> Vec<String> {
 
 fn agrupar_lancamentos(
     mapa: &mut HashMap<String, HashMap<String, HashMap<String, f64>>>,
+    categorias: &Vec<Categoria>,
     lancamentos: Vec<Lancamento>,
 ) {
     lancamentos
         .iter()
         .filter(|l| l.valor < 0.0)
         .for_each(|lancamento| {
-            agrupar_lancamento(mapa, lancamento);
+            agrupar_lancamento(mapa, categorias, lancamento);
         });
 }
 
 fn agrupar_lancamento(
     mapa: &mut HashMap<String, HashMap<String, HashMap<String, f64>>>,
+    categorias: &Vec<Categoria>,
     lancamento: &Lancamento,
 ) {
     if let Some(cat) = lancamento.categoria.some() {
         preencher_grupos(
             mapa,
+            categorias,
             cat,
             lancamento.data.format("%m/%Y").to_string(),
             lancamento.valor,
@@ -117,38 +124,24 @@ fn agrupar_lancamento(
 
 fn preencher_grupos(
     mapa: &mut HashMap<String, HashMap<String, HashMap<String, f64>>>,
+    categorias: &Vec<Categoria>,
     cat: Categoria,
     mes: String,
     valor: f64,
 ) {
-    let agrupamento = gerar_agrupamento(cat);
-    let mut grupo_pai = String::new();
-    let mut grupo = "Saídas".to_string();
+    let caminho = caminho_grupos(categorias, &cat);
 
-    for i in 0..agrupamento.len() {
-        let nome_grupo = format!("{}{}", grupo_pai, grupo);
+    for n in 1..caminho.len() {
         grupo_somar_lancamento(
             mapa,
-            nome_grupo.clone(),
-            agrupamento[i].to_string(),
+            chave_grupo(&caminho, n),
+            caminho[n].clone(),
             mes.clone(),
             valor,
         );
-
-        grupo_pai = format!("{} >> ", grupo);
-        grupo = agrupamento[i].clone();
     }
 }
 
-fn gerar_agrupamento(cat: Categoria) -> Vec<String> {
-    let cat_str = cat.to_string();
-    let agrupamento = cat_str
-        .split(";")
-        .map(|s| s.trim().to_string())
-        .collect::<Vec<_>>();
-    agrupamento
-}
-
 fn grupo_somar_lancamento(
     mapa: &mut HashMap<String, HashMap<String, HashMap<String, f64>>>,
     nome_grupo: String,
@@ -1,18 +1,22 @@
 use std::collections::HashMap;
 
 use crate::{
-    calc::calc_lancamentos_filtros::Lancamentos,
+    calc::{
+        calc_grupos_categoria::{caminho_grupos, chave_grupo, titulo_grupo},
+        calc_lancamentos_filtros::Lancamentos,
+    },
     dto::{Categoria, DashGastoPorCategoria, Lancamento, OptionalLazyFn},
 };
 
 pub fn calcular_gasto_por_categoria_d30(
     ordem: &Vec<String>,
+    categorias: &Vec<Categoria>,
     mut lancamentos: Vec<Lancamento>,
 ) -> Vec<DashGastoPorCategoria> {
     lancamentos = lancamentos.ultimos_dias(30);
     let mut mapa: HashMap<String, HashMap<String, f64>> = HashMap::new();
 
-    agrupar_lancamentos(&mut mapa, lancamentos);
+    agrupar_lancamentos(&mut mapa, categorias, lancamentos);
 
     gerar_graficos(ordem, mapa)
 }
@@ -47,7 +51,7 @@ fn criar_novo_grafico(
     nome: &String,
     valores: &HashMap<String, f64>,
 ) {
-    let mut item = DashGastoPorCategoria::new(nome.as_str());
+    let mut item = DashGastoPorCategoria::new(titulo_grupo(nome).as_str());
     grafico_preencher_valores_por_categira(valores, &mut item);
     resultados.push(item);
 }
@@ -63,51 +67,40 @@ fn grafico_preencher_valores_por_categira(
 
 fn agrupar_lancamentos(
     mapa: &mut HashMap<String, HashMap<String, f64>>,
+    categorias: &Vec<Categoria>,
     lancamentos: Vec<Lancamento>,
 ) {
     lancamentos
         .iter()
         .filter(|l| l.valor < 0.0)
         .for_each(|lancamento| {
-            agrupar_lancamento(mapa, lancamento);
+            agrupar_lancamento(mapa, categorias, lancamento);
         });
 }
 
-fn agrupar_lancamento(mapa: &mut HashMap<String, HashMap<String, f64>>, lancamento: &Lancamento) {
+fn agrupar_lancamento(
+    mapa: &mut HashMap<String, HashMap<String, f64>>,
+    categorias: &Vec<Categoria>,
+    lancamento: &Lancamento,
+) {
     if let Some(cat) = lancamento.categoria.some() {
-        preencher_grupos(mapa, cat.clone(), lancamento.valor);
+        preencher_grupos(mapa, categorias, cat, lancamento.valor);
     }
 }
 
 fn preencher_grupos(
     mapa: &mut HashMap<String, HashMap<String, f64>>,
+    categorias: &Vec<Categoria>,
     categoria: Categoria,
     valor: f64,
 ) {
-    let agrupamento = gerar_agrupamento(categoria);
-    let mut grupo_pai = String::new();
-    let mut grupo = "Saídas".to_string();
-
-    for i in 0..agrupamento.len() {
-        grupo_somar_lancamento(
-            mapa,
-            format!("{}{}", grupo_pai.clone(), grupo.clone()),
-            agrupamento[i].clone(),
-            valor,
-        );
-        grupo_pai = format!("{} >> ", grupo.clone());
-        grupo = agrupamento[i].clone();
+    let caminho = caminho_grupos(categorias, &categoria);
+
+    for n in 1..caminho.len() {
+        grupo_somar_lancamento(mapa, chave_grupo(&caminho, n), caminho[n].clone(), valor);
     }
 }
 
-fn gerar_agrupamento(cat: Categoria) -> Vec<String> {
-    let cat_str = cat.to_string();
-    let agrupamento = cat_str
-        .split(";")
-        .map(|s| s.trim().to_string())
-        .collect::<Vec<_>>();
-    agrupamento
-}
 
 fn grupo_somar_lancamento(
     mapa: &mut HashMap<String, HashMap<String, f64>>,
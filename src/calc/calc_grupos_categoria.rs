@@ -0,0 +1,92 @@
+use crate::dto::{ArvoreCategorias, Categoria};
+
+const RAIZ: &str = "Saídas";
+const SEPARADOR: &str = " >> ";
+
+/// Caminho usado no agrupamento dos gráficos: `["Saídas", "Despesa", "Fixa", "Moradia", "Internet"]`
+pub fn caminho_grupos(categorias: &Vec<Categoria>, cat: &Categoria) -> Vec<String> {
+    let mut resp = vec![RAIZ.to_string()];
+    resp.extend(categorias.caminho(cat));
+    resp
+}
+
+/// Chave do grupo formado pelos `n` primeiros níveis do caminho.
+pub fn chave_grupo(caminho: &[String], n: usize) -> String {
+    caminho[..n].join(SEPARADOR)
+}
+
+/// Título exibido no gráfico: apenas o nível pai e o próprio grupo, ex: `"Fixa >> Moradia"`.
+pub fn titulo_grupo(chave: &str) -> String {
+    let niveis: Vec<&str> = chave.split(SEPARADOR).collect();
+    let inicio = niveis.len().saturating_sub(2);
+    niveis[inicio..].join(SEPARADOR)
+}
+
+/// Percorre a árvore de categorias e gera a ordem dos grupos que viram gráficos.
+pub fn ordem_grupos(categorias: &Vec<Categoria>) -> Vec<String> {
+    let mut resp: Vec<String> = Vec::new();
+
+    categorias.iter().for_each(|c| {
+        let caminho = caminho_grupos(categorias, c);
+        for n in 2..=caminho.len() {
+            let chave = chave_grupo(&caminho, n);
+            if !resp.contains(&chave) {
+                resp.push(chave);
+            }
+        }
+    });
+
+    resp.reverse();
+    resp
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dto::{TipoDespesa, TipoFluxo};
+
+    fn arvore() -> Vec<Categoria> {
+        let fixa = Categoria::new("Fixa", TipoFluxo::Despesa(TipoDespesa::Fixa), None);
+        let moradia = Categoria::new(
+            "Moradia",
+            TipoFluxo::Despesa(TipoDespesa::Fixa),
+            Some(fixa.id.clone()),
+        );
+        let energia = Categoria::new(
+            "Energia",
+            TipoFluxo::Despesa(TipoDespesa::Fixa),
+            Some(moradia.id.clone()),
+        );
+        vec![fixa, moradia, energia]
+    }
+
+    #[test]
+    fn caminho_percorre_a_arvore() {
+        let cats = arvore();
+        assert_eq!(
+            caminho_grupos(&cats, &cats[2]),
+            vec!["Saídas", "Despesa", "Fixa", "Moradia", "Energia"]
+        );
+    }
+
+    #[test]
+    fn titulo_usa_os_dois_ultimos_niveis() {
+        assert_eq!(titulo_grupo("Saídas >> Despesa >> Fixa"), "Despesa >> Fixa");
+        assert_eq!(titulo_grupo("Saídas"), "Saídas");
+    }
+
+    #[test]
+    fn ordem_contem_cada_grupo_uma_vez() {
+        let ordem = ordem_grupos(&arvore());
+        assert_eq!(ordem.first().unwrap(), "Saídas >> Despesa >> Fixa >> Moradia >> Energia");
+        assert_eq!(ordem.last().unwrap(), "Saídas >> Despesa");
+        assert_eq!(ordem.len(), 4);
+    }
+
+    #[test]
+    fn ciclo_na_arvore_nao_trava() {
+        let mut cats = arvore();
+        cats[0].pai = Some(cats[2].id.clone());
+        assert!(caminho_grupos(&cats, &cats[2]).len() <= 6);
+    }
+}
@@ -0,0 +1,59 @@
+use chrono::Datelike;
+use itertools::Itertools;
+
+use crate::dto::{DashHistoricoDivida, Divida};
+
+/// Soma as parcelas pagas por ano de vencimento, do mais recente para o mais antigo.
+pub fn totais_por_ano(dividas: &[Divida]) -> Vec<DashHistoricoDivida> {
+    dividas
+        .iter()
+        .flat_map(|d| d.parcelas.iter().filter(|p| p.pago && !p.renegociada))
+        .into_group_map_by(|p| p.data_vencimento.year())
+        .into_iter()
+        .map(|(ano, parcelas)| DashHistoricoDivida {
+            ano,
+            parcelas: parcelas.len() as i32,
+            principal: parcelas.iter().map(|p| p.principal()).sum(),
+            juros: parcelas.iter().map(|p| p.juros).sum(),
+        })
+        .sorted_by(|a, b| b.ano.cmp(&a.ano))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::{
+        calc::gerar_parcelas_financiamento,
+        dto::{Financiamento, SistemaAmortizacao},
+    };
+
+    #[test]
+    fn separa_principal_e_juros_por_ano() {
+        let inicio = NaiveDate::from_ymd_opt(2024, 11, 5).unwrap();
+        let financiamento = Financiamento {
+            principal: 1200.0,
+            taxa_mensal: 1.0,
+            prazo: 4,
+            sistema: SistemaAmortizacao::Sac,
+            ..Default::default()
+        };
+        let parcelas = gerar_parcelas_financiamento(&financiamento, inicio, 4);
+        let financiada = Divida::new_financiamento("Carro".to_string(), false, false, financiamento, parcelas);
+        let loja = Divida::new("Loja".to_string(), false, false, 3, 100.0, inicio, 2);
+
+        let resp = totais_por_ano(&[financiada, loja]);
+
+        assert_eq!(resp.len(), 2);
+        assert_eq!(resp[0].ano, 2025);
+        assert_eq!(resp[0].parcelas, 2);
+        assert_eq!(resp[0].principal, 600.0);
+        assert!((resp[0].juros - 9.0).abs() < 0.001);
+        assert_eq!(resp[1].ano, 2024);
+        assert_eq!(resp[1].parcelas, 4);
+        assert_eq!(resp[1].principal, 800.0);
+        assert!((resp[1].juros - 21.0).abs() < 0.001);
+    }
+}
@@ -0,0 +1,230 @@
+use chrono::NaiveDate;
+
+use crate::dto::{Ativo, Cotacao, DashInvestimento, OperacaoInvestimento, TipoOperacao};
+
+/// Quantidade abaixo desta é tratada como posição zerada.
+const RESIDUO: f64 = 1e-9;
+
+/// Posição de cada ativo com operações até `hoje`, pelo custo médio: a compra
+/// soma quantidade e custo (com taxas); a venda baixa a quantidade pelo preço
+/// médio e o ganho realizado é o líquido da venda menos esse custo. Venda acima
+/// da posição (compras faltando) só realiza a parte que havia. Ativos abertos
+/// vêm primeiro, do maior valor atual para o menor.
+pub fn calcular_posicoes(
+    ativos: &[Ativo],
+    operacoes: &[OperacaoInvestimento],
+    cotacoes: &[Cotacao],
+    hoje: NaiveDate,
+) -> Vec<DashInvestimento> {
+    let mut resp: Vec<DashInvestimento> = ativos
+        .iter()
+        .filter_map(|a| calcular_posicao(a, operacoes, cotacoes, hoje))
+        .collect();
+
+    resp.sort_by(|a, b| {
+        (b.quantidade > RESIDUO)
+            .cmp(&(a.quantidade > RESIDUO))
+            .then(b.valor_atual.total_cmp(&a.valor_atual))
+    });
+    resp
+}
+
+fn calcular_posicao(
+    ativo: &Ativo,
+    operacoes: &[OperacaoInvestimento],
+    cotacoes: &[Cotacao],
+    hoje: NaiveDate,
+) -> Option<DashInvestimento> {
+    let mut operacoes: Vec<&OperacaoInvestimento> = operacoes
+        .iter()
+        .filter(|o| o.ativo == ativo.id && o.data <= hoje)
+        .collect();
+    if operacoes.is_empty() {
+        return None;
+    }
+    operacoes.sort_by_key(|o| o.data);
+
+    let mut quantidade = 0.0;
+    let mut custo = 0.0;
+    let mut investido = 0.0;
+    let mut realizado = 0.0;
+    let mut proventos = 0.0;
+
+    for o in operacoes.iter() {
+        match o.tipo {
+            TipoOperacao::Compra => {
+                quantidade += o.quantidade;
+                custo += o.valor();
+                investido += o.valor();
+            }
+            TipoOperacao::Venda => {
+                let preco_medio = if quantidade > RESIDUO { custo / quantidade } else { 0.0 };
+                let vendida = o.quantidade.min(quantidade);
+                if vendida < o.quantidade - RESIDUO {
+                    log::warn!(
+                        "Venda de {} {} em {} acima da posição ({}); falta alguma compra",
+                        o.quantidade,
+                        o.ativo,
+                        o.data.format("%d/%m/%Y"),
+                        quantidade
+                    );
+                }
+                let liquido = if o.quantidade > RESIDUO {
+                    o.valor() * vendida / o.quantidade
+                } else {
+                    0.0
+                };
+                realizado += liquido - vendida * preco_medio;
+                custo -= vendida * preco_medio;
+                quantidade -= vendida;
+            }
+            TipoOperacao::Provento => proventos += o.valor(),
+        }
+    }
+
+    if quantidade <= RESIDUO {
+        quantidade = 0.0;
+        custo = 0.0;
+    }
+
+    let cotacao = cotacoes
+        .iter()
+        .filter(|c| c.ativo == ativo.id && c.data <= hoje)
+        .max_by_key(|c| c.data);
+    let (preco_atual, data_preco) = match cotacao {
+        Some(c) => (c.preco, Some(c.data.format("%d/%m/%Y").to_string())),
+        None => (
+            operacoes
+                .iter()
+                .rev()
+                .find(|o| o.tipo != TipoOperacao::Provento)
+                .map(|o| o.preco)
+                .unwrap_or_default(),
+            None,
+        ),
+    };
+
+    let valor_atual = quantidade * preco_atual;
+    let nao_realizado = valor_atual - custo;
+
+    Some(DashInvestimento {
+        ativo: ativo.id.clone(),
+        nome: ativo.nome.clone(),
+        tipo: ativo.tipo.to_string(),
+        quantidade,
+        preco_medio: if quantidade > 0.0 {
+            arredondar(custo / quantidade)
+        } else {
+            0.0
+        },
+        custo: arredondar(custo),
+        preco_atual,
+        data_preco,
+        valor_atual: arredondar(valor_atual),
+        ganho_realizado: arredondar(realizado),
+        ganho_nao_realizado: arredondar(nao_realizado),
+        proventos: arredondar(proventos),
+        rentabilidade: if investido > 0.0 {
+            arredondar((realizado + nao_realizado + proventos) / investido * 100.0)
+        } else {
+            0.0
+        },
+    })
+}
+
+fn arredondar(valor: f64) -> f64 {
+    (valor * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calc::teste::data;
+    use crate::dto::TipoAtivo;
+
+    fn ativo(id: &str) -> Ativo {
+        Ativo {
+            id: id.to_string(),
+            nome: id.to_string(),
+            tipo: TipoAtivo::Fii,
+        }
+    }
+
+    fn operacao(tipo: TipoOperacao, data: NaiveDate, quantidade: f64, preco: f64, taxas: f64) -> OperacaoInvestimento {
+        OperacaoInvestimento {
+            id: String::new(),
+            ativo: "HGLG11".to_string(),
+            tipo,
+            data,
+            quantidade,
+            preco,
+            taxas,
+            lancamento: None,
+            referencia: String::new(),
+        }
+    }
+
+    fn operacoes() -> Vec<OperacaoInvestimento> {
+        vec![
+            operacao(TipoOperacao::Compra, data(10, 1), 10.0, 100.0, 5.0),
+            operacao(TipoOperacao::Compra, data(10, 2), 10.0, 120.0, 5.0),
+            operacao(TipoOperacao::Provento, data(15, 2), 20.0, 1.1, 0.0),
+            operacao(TipoOperacao::Venda, data(10, 3), 5.0, 130.0, 2.0),
+        ]
+    }
+
+    #[test]
+    fn custo_medio_com_taxas_e_ganho_realizado() {
+        let resp = calcular_posicoes(&[ativo("HGLG11")], &operacoes(), &[], data(31, 3));
+        let p = &resp[0];
+
+        assert_eq!(p.quantidade, 15.0);
+        assert_eq!(p.preco_medio, 110.5);
+        assert_eq!(p.custo, 1657.5);
+        assert_eq!(p.ganho_realizado, 95.5);
+        assert_eq!(p.proventos, 22.0);
+        // sem cotação, usa o preço da última compra ou venda
+        assert_eq!(p.preco_atual, 130.0);
+        assert_eq!(p.ganho_nao_realizado, 292.5);
+    }
+
+    #[test]
+    fn usa_a_ultima_cotacao_ate_hoje() {
+        let cotacoes = vec![
+            Cotacao { ativo: "HGLG11".to_string(), data: data(20, 3), preco: 125.0 },
+            Cotacao { ativo: "HGLG11".to_string(), data: data(5, 4), preco: 140.0 },
+        ];
+        let resp = calcular_posicoes(&[ativo("HGLG11")], &operacoes(), &cotacoes, data(31, 3));
+
+        assert_eq!(resp[0].preco_atual, 125.0);
+        assert_eq!(resp[0].data_preco, Some("20/03/2025".to_string()));
+        assert_eq!(resp[0].valor_atual, 1875.0);
+        assert_eq!(resp[0].rentabilidade, 15.16);
+    }
+
+    #[test]
+    fn posicao_zerada_mantem_o_realizado() {
+        let mut ops = operacoes();
+        ops.push(operacao(TipoOperacao::Venda, data(20, 3), 15.0, 100.0, 0.0));
+        let resp = calcular_posicoes(&[ativo("HGLG11"), ativo("MXRF11")], &ops, &[], data(31, 3));
+
+        assert_eq!(resp.len(), 1);
+        assert_eq!(resp[0].quantidade, 0.0);
+        assert_eq!(resp[0].custo, 0.0);
+        assert_eq!(resp[0].ganho_nao_realizado, 0.0);
+        assert_eq!(resp[0].ganho_realizado, 95.5 - 157.5);
+    }
+
+    #[test]
+    fn venda_acima_da_posicao_realiza_so_a_parte_que_havia() {
+        let ops = vec![
+            operacao(TipoOperacao::Compra, data(10, 1), 10.0, 100.0, 0.0),
+            operacao(TipoOperacao::Venda, data(10, 2), 15.0, 120.0, 3.0),
+        ];
+        let resp = calcular_posicoes(&[ativo("HGLG11")], &ops, &[], data(31, 3));
+
+        assert_eq!(resp[0].quantidade, 0.0);
+        // 10 das 15 cotas: 2/3 de (1800 - 3) menos o custo de 1000
+        assert_eq!(resp[0].ganho_realizado, 198.0);
+    }
+}
@@ -0,0 +1,555 @@
+use chrono::{Datelike, NaiveDate};
+
+use crate::dto::{
+    Ativo, Banco, BemIrpf, CarneLeao, CarneLeaoIrpf, Categoria, Contracheque, DeducaoIrpf, Divida,
+    Lancamento, OperacaoInvestimento, OptionalLazyFn, OutroRendimentoIrpf, RendimentoIrpf,
+    TipoContracheque, TipoFluxo,
+};
+
+use super::{
+    calc_carne_leao::marcada,
+    calc_contracheque::{eh_inss, eh_irrf},
+    calc_investimentos::calcular_posicoes,
+    calc_patrimonio::{saldo_divida_em, saldo_em},
+};
+
+/// Rendimentos por fonte pagadora dos contracheques pagos no ano (regime de
+/// caixa). Salários e férias são tributáveis; 13º e PLR vão à parte, com o
+/// IRRF deles separado.
+pub fn rendimentos_irpf(ano: i32, contracheques: &[Contracheque]) -> Vec<RendimentoIrpf> {
+    let mut resp: Vec<RendimentoIrpf> = Vec::new();
+
+    for c in contracheques
+        .iter()
+        .filter(|c| c.data_pagamento.year() == ano)
+    {
+        let pos = match resp.iter().position(|r| r.fonte == c.empresa) {
+            Some(pos) => pos,
+            None => {
+                resp.push(RendimentoIrpf {
+                    fonte: c.empresa.clone(),
+                    tributavel: 0.0,
+                    inss: 0.0,
+                    irrf: 0.0,
+                    decimo_terceiro: 0.0,
+                    plr: 0.0,
+                    irrf_exclusivo: 0.0,
+                });
+                resp.len() - 1
+            }
+        };
+        let r = &mut resp[pos];
+
+        let desconto = |filtro: fn(&str) -> bool| -> f64 {
+            c.saidas
+                .iter()
+                .filter(|i| filtro(&i.nome))
+                .map(|i| i.valor)
+                .sum()
+        };
+        r.inss = arredondar(r.inss + desconto(eh_inss));
+
+        match c.tipo {
+            TipoContracheque::Mensal | TipoContracheque::Ferias => {
+                r.tributavel = arredondar(r.tributavel + c.bruto());
+                r.irrf = arredondar(r.irrf + desconto(eh_irrf));
+            }
+            TipoContracheque::DecimoTerceiroPrimeira | TipoContracheque::DecimoTerceiroSegunda => {
+                r.decimo_terceiro = arredondar(r.decimo_terceiro + c.bruto());
+                r.irrf_exclusivo = arredondar(r.irrf_exclusivo + desconto(eh_irrf));
+            }
+            TipoContracheque::Plr => {
+                r.plr = arredondar(r.plr + c.bruto());
+                r.irrf_exclusivo = arredondar(r.irrf_exclusivo + desconto(eh_irrf));
+            }
+        }
+    }
+
+    resp.sort_by(|a, b| b.tributavel.total_cmp(&a.tributavel));
+    resp
+}
+
+/// Rendimentos de pessoas físicas por mês do ano: lançamentos nas categorias de
+/// carnê-leão (ou filhas delas) e o imposto dos meses cujo DARF foi encontrado.
+pub fn carne_leao_irpf(
+    ano: i32,
+    lancamentos: &[Lancamento],
+    categorias: &[Categoria],
+    meses: &[CarneLeao],
+) -> Vec<CarneLeaoIrpf> {
+    let mut resp: Vec<CarneLeaoIrpf> = Vec::new();
+
+    for mes in (1..=12).filter_map(|m| NaiveDate::from_ymd_opt(ano, m, 1)) {
+        let no_mes = |data: NaiveDate| data.year() == ano && data.month() == mes.month();
+        let rendimentos: f64 = lancamentos
+            .iter()
+            .filter(|l| no_mes(l.data))
+            .filter(|l| marcada(categorias, &l.categoria.id(), |c| c.carne_leao))
+            .map(|l| l.valor)
+            .sum();
+        let imposto_pago: f64 = meses
+            .iter()
+            .filter(|m| no_mes(m.mes) && m.pago())
+            .map(|m| m.imposto)
+            .sum();
+
+        if rendimentos > 0.0 || imposto_pago > 0.0 {
+            resp.push(CarneLeaoIrpf {
+                mes,
+                rendimentos: arredondar(rendimentos.max(0.0)),
+                imposto_pago: arredondar(imposto_pago),
+            });
+        }
+    }
+
+    resp
+}
+
+/// Receitas do ano fora do carnê-leão, somadas por categoria. Os itens dos
+/// contracheques ficam na conta da empresa e já entram em `rendimentos_irpf`.
+pub fn outros_rendimentos_irpf(
+    ano: i32,
+    lancamentos: &[Lancamento],
+    categorias: &[Categoria],
+    contracheques: &[Contracheque],
+) -> Vec<OutroRendimentoIrpf> {
+    let mut resp: Vec<OutroRendimentoIrpf> = Vec::new();
+
+    for l in lancamentos.iter().filter(|l| l.data.year() == ano) {
+        let da_empresa = contracheques
+            .iter()
+            .any(|c| l.conta.as_deref() == Some(c.empresa.as_str()));
+        let id = l.categoria.id();
+        let categoria = match categorias.iter().find(|c| c.id == id) {
+            Some(c) if c.tipo == TipoFluxo::Receita => c,
+            _ => continue,
+        };
+        if da_empresa || marcada(categorias, &id, |c| c.carne_leao) {
+            continue;
+        }
+
+        match resp.iter_mut().find(|o| o.categoria == categoria.nome) {
+            Some(o) => o.total = arredondar(o.total + l.valor),
+            None => resp.push(OutroRendimentoIrpf {
+                categoria: categoria.nome.clone(),
+                total: arredondar(l.valor),
+            }),
+        }
+    }
+
+    resp.retain(|o| o.total > 0.0);
+    resp.sort_by(|a, b| b.total.total_cmp(&a.total));
+    resp
+}
+
+/// Gastos do ano em categorias de saúde, educação ou previdência privada (ou
+/// filhas delas), somados por tipo e beneficiário, que é a descrição do
+/// lançamento. Estornos abatem do total.
+pub fn deducoes_irpf(
+    ano: i32,
+    lancamentos: &[Lancamento],
+    categorias: &[Categoria],
+) -> Vec<DeducaoIrpf> {
+    let mut resp: Vec<DeducaoIrpf> = Vec::new();
+
+    for l in lancamentos.iter().filter(|l| l.data.year() == ano) {
+        let id = l.categoria.id();
+        let tipo = match categorias
+            .iter()
+            .find(|c| c.id == id)
+            .and_then(tipo_deducao)
+        {
+            Some(tipo) => tipo,
+            None => continue,
+        };
+        let beneficiario = l.descricao.trim().to_string();
+
+        match resp.iter_mut().find(|d| {
+            d.tipo == tipo && d.beneficiario.to_lowercase() == beneficiario.to_lowercase()
+        }) {
+            Some(d) => d.total = arredondar(d.total - l.valor),
+            None => resp.push(DeducaoIrpf {
+                tipo: tipo.to_string(),
+                beneficiario,
+                total: arredondar(-l.valor),
+            }),
+        }
+    }
+
+    resp.retain(|d| d.total > 0.0);
+    resp.sort_by(|a, b| a.tipo.cmp(&b.tipo).then(b.total.total_cmp(&a.total)));
+    resp
+}
+
+fn tipo_deducao(categoria: &Categoria) -> Option<&'static str> {
+    let nomes: Vec<String> = categoria
+        .hierarquia
+        .iter()
+        .chain(std::iter::once(&categoria.nome))
+        .map(|n| n.to_lowercase())
+        .collect();
+    let tem = |termos: &[&str]| nomes.iter().any(|n| termos.iter().any(|t| n.contains(t)));
+
+    if tem(&["saúde", "saude"]) {
+        Some("Saúde")
+    } else if tem(&["educação", "educacao"]) {
+        Some("Educação")
+    } else if tem(&["previdência privada", "previdencia privada"]) {
+        Some("Previdência privada")
+    } else {
+        None
+    }
+}
+
+/// Bens em 31/12 do ano e do ano anterior: saldo das contas que não são
+/// cartão e ativos em carteira pelo custo de aquisição, como pede a declaração.
+pub fn bens_irpf(
+    ano: i32,
+    bancos: &[Banco],
+    lancamentos: &[Lancamento],
+    ativos: &[Ativo],
+    operacoes: &[OperacaoInvestimento],
+) -> Vec<BemIrpf> {
+    let (anterior, atual) = (fim_do_ano(ano - 1), fim_do_ano(ano));
+
+    let mut resp: Vec<BemIrpf> = bancos
+        .iter()
+        .flat_map(|b| b.contas.iter().map(move |c| (b, c)))
+        .filter(|(_, c)| !c.tipo.passivo())
+        .map(|(b, c)| BemIrpf {
+            tipo: format!("Conta {}", c.tipo),
+            descricao: format!("{} - {}", b.nome, c.nome),
+            anterior: saldo_em(c, lancamentos, anterior).max(0.0),
+            atual: saldo_em(c, lancamentos, atual).max(0.0),
+        })
+        .collect();
+
+    let custos_anteriores = calcular_posicoes(ativos, operacoes, &[], anterior);
+    for p in calcular_posicoes(ativos, operacoes, &[], atual) {
+        resp.push(BemIrpf {
+            tipo: p.tipo.clone(),
+            descricao: format!("{} - {} ({} cotas)", p.ativo, p.nome, p.quantidade),
+            anterior: custos_anteriores
+                .iter()
+                .find(|a| a.ativo == p.ativo)
+                .map(|a| a.custo)
+                .unwrap_or_default(),
+            atual: p.custo,
+        });
+    }
+
+    resp.retain(|b| b.anterior > 0.0 || b.atual > 0.0);
+    resp
+}
+
+/// Dívidas em 31/12 do ano e do ano anterior: fatura em aberto dos cartões e
+/// principal das parcelas ainda não pagas.
+pub fn dividas_irpf(
+    ano: i32,
+    bancos: &[Banco],
+    lancamentos: &[Lancamento],
+    dividas: &[Divida],
+) -> Vec<BemIrpf> {
+    let (anterior, atual) = (fim_do_ano(ano - 1), fim_do_ano(ano));
+
+    let mut resp: Vec<BemIrpf> = bancos
+        .iter()
+        .flat_map(|b| b.contas.iter().map(move |c| (b, c)))
+        .filter(|(_, c)| c.tipo.passivo())
+        .map(|(b, c)| BemIrpf {
+            tipo: "Cartão".to_string(),
+            descricao: format!("{} - {}", b.nome, c.nome),
+            anterior: (-saldo_em(c, lancamentos, anterior)).max(0.0),
+            atual: (-saldo_em(c, lancamentos, atual)).max(0.0),
+        })
+        .collect();
+
+    resp.extend(dividas.iter().map(|d| BemIrpf {
+        tipo: "Dívida".to_string(),
+        descricao: d.nome.clone(),
+        anterior: arredondar(saldo_divida_em(d, anterior)),
+        atual: arredondar(saldo_divida_em(d, atual)),
+    }));
+
+    resp.retain(|d| d.anterior > 0.0 || d.atual > 0.0);
+    resp
+}
+
+fn fim_do_ano(ano: i32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(ano, 12, 31).unwrap_or_default()
+}
+
+fn arredondar(valor: f64) -> f64 {
+    (valor * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calc::teste::{data_ano, lancamento, LancamentoTeste};
+    use crate::dto::{Conta, ItemContracheque, TipoConta, TipoFluxo};
+
+    fn item(nome: &str, valor: f64) -> ItemContracheque {
+        ItemContracheque {
+            nome: nome.to_string(),
+            valor,
+        }
+    }
+
+    fn contracheque(
+        tipo: TipoContracheque,
+        pagamento: NaiveDate,
+        bruto: f64,
+        inss: f64,
+        irrf: f64,
+    ) -> Contracheque {
+        Contracheque {
+            id: String::new(),
+            empresa: "ACME".to_string(),
+            tipo,
+            competencia: pagamento,
+            data_pagamento: pagamento,
+            entradas: vec![item("Salário", bruto)],
+            saidas: vec![item("INSS", inss), item("IRRF", irrf)],
+        }
+    }
+
+    fn categoria(id: &str, nome: &str, hierarquia: &[&str]) -> Categoria {
+        Categoria {
+            id: id.to_string(),
+            nome: nome.to_string(),
+            tipo: TipoFluxo::SemCategoria,
+            pai: None,
+            carne_leao: false,
+            livro_caixa: false,
+            hierarquia: hierarquia.iter().map(|h| h.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn separa_rendimentos_tributaveis_e_exclusivos_pelo_pagamento() {
+        let contracheques = vec![
+            // competência de dezembro paga em janeiro entra no ano seguinte
+            contracheque(
+                TipoContracheque::Mensal,
+                data_ano(5, 1, 2024),
+                5000.0,
+                500.0,
+                300.0,
+            ),
+            contracheque(
+                TipoContracheque::Mensal,
+                data_ano(5, 2, 2025),
+                5000.0,
+                500.0,
+                300.0,
+            ),
+            contracheque(
+                TipoContracheque::Ferias,
+                data_ano(1, 7, 2025),
+                6000.0,
+                600.0,
+                400.0,
+            ),
+            contracheque(
+                TipoContracheque::DecimoTerceiroSegunda,
+                data_ano(20, 12, 2025),
+                5000.0,
+                500.0,
+                250.0,
+            ),
+            contracheque(
+                TipoContracheque::Plr,
+                data_ano(10, 3, 2025),
+                3000.0,
+                0.0,
+                100.0,
+            ),
+        ];
+
+        let resp = rendimentos_irpf(2025, &contracheques);
+
+        assert_eq!(resp.len(), 1);
+        assert_eq!(resp[0].tributavel, 11000.0);
+        assert_eq!(resp[0].irrf, 700.0);
+        assert_eq!(resp[0].inss, 1600.0);
+        assert_eq!(resp[0].decimo_terceiro, 5000.0);
+        assert_eq!(resp[0].plr, 3000.0);
+        assert_eq!(resp[0].irrf_exclusivo, 350.0);
+    }
+
+    #[test]
+    fn separa_carne_leao_das_outras_receitas() {
+        let categorias = vec![
+            Categoria {
+                tipo: TipoFluxo::Receita,
+                carne_leao: true,
+                ..categoria("consultas", "Consultas", &[])
+            },
+            Categoria {
+                tipo: TipoFluxo::Receita,
+                pai: Some("consultas".to_string()),
+                ..categoria("online", "Online", &["Consultas"])
+            },
+            Categoria {
+                tipo: TipoFluxo::Receita,
+                ..categoria("aluguel", "Aluguel", &[])
+            },
+            Categoria {
+                tipo: TipoFluxo::Receita,
+                ..categoria("salario", "Salário", &[])
+            },
+        ];
+        let lancamentos = vec![
+            lancamento(1000.0, data_ano(10, 3, 2025))
+                .com_categoria("consultas")
+                .com_conta("cc"),
+            lancamento(500.0, data_ano(20, 3, 2025))
+                .com_categoria("online")
+                .com_conta("cc"),
+            lancamento(800.0, data_ano(5, 4, 2025))
+                .com_categoria("aluguel")
+                .com_conta("cc"),
+            lancamento(800.0, data_ano(5, 5, 2025))
+                .com_categoria("aluguel")
+                .com_conta("cc"),
+            lancamento(800.0, data_ano(5, 5, 2024))
+                .com_categoria("aluguel")
+                .com_conta("cc"),
+            // item do contracheque, já contado como rendimento da empresa
+            lancamento(5000.0, data_ano(5, 5, 2025))
+                .com_categoria("salario")
+                .com_conta("ACME"),
+        ];
+        let apurado = |mes: u32, imposto: f64, darf: Option<&str>| CarneLeao {
+            mes: data_ano(1, mes, 2025),
+            rendimentos: 0.0,
+            livro_caixa: 0.0,
+            dependentes: 0.0,
+            simplificado: true,
+            base: 0.0,
+            imposto,
+            vencimento: data_ano(28, mes + 1, 2025),
+            lancamento: darf.map(str::to_string),
+            data_pagamento: None,
+        };
+        // o de março ainda não foi pago
+        let meses = [apurado(1, 120.0, Some("darf")), apurado(3, 90.0, None)];
+        let contracheques = vec![contracheque(
+            TipoContracheque::Mensal,
+            data_ano(5, 5, 2025),
+            5000.0,
+            0.0,
+            0.0,
+        )];
+
+        let leao = carne_leao_irpf(2025, &lancamentos, &categorias, &meses);
+        let outros = outros_rendimentos_irpf(2025, &lancamentos, &categorias, &contracheques);
+
+        assert_eq!(leao.len(), 2);
+        assert_eq!(leao[0].mes, data_ano(1, 1, 2025));
+        assert_eq!(leao[0].rendimentos, 0.0);
+        assert_eq!(leao[0].imposto_pago, 120.0);
+        assert_eq!(leao[1].rendimentos, 1500.0);
+        assert_eq!(leao[1].imposto_pago, 0.0);
+        assert_eq!(
+            outros,
+            vec![OutroRendimentoIrpf {
+                categoria: "Aluguel".to_string(),
+                total: 1600.0,
+            }]
+        );
+    }
+
+    #[test]
+    fn soma_deducoes_por_beneficiario_inclusive_subcategorias() {
+        let categorias = vec![
+            categoria("saude", "Saúde", &[]),
+            categoria("dentista", "Dentista", &["Despesas", "Saúde"]),
+            categoria("escola", "Educação", &[]),
+            categoria("mercado", "Mercado", &[]),
+        ];
+        let lancamentos = vec![
+            lancamento(-300.0, data_ano(10, 3, 2025))
+                .com_descricao("Clínica Sorriso")
+                .com_categoria("dentista")
+                .com_conta("cc"),
+            lancamento(-200.0, data_ano(10, 4, 2025))
+                .com_descricao("clínica sorriso ")
+                .com_categoria("dentista")
+                .com_conta("cc"),
+            lancamento(-1000.0, data_ano(10, 5, 2025))
+                .com_descricao("Hospital")
+                .com_categoria("saude")
+                .com_conta("cc"),
+            lancamento(1000.0, data_ano(20, 5, 2025))
+                .com_descricao("Hospital")
+                .com_categoria("saude")
+                .com_conta("cc"),
+            lancamento(-800.0, data_ano(10, 2, 2025))
+                .com_descricao("Colégio")
+                .com_categoria("escola")
+                .com_conta("cc"),
+            lancamento(-800.0, data_ano(10, 2, 2024))
+                .com_descricao("Colégio")
+                .com_categoria("escola")
+                .com_conta("cc"),
+            lancamento(-150.0, data_ano(10, 2, 2025))
+                .com_descricao("Mercado")
+                .com_categoria("mercado")
+                .com_conta("cc"),
+        ];
+
+        let resp = deducoes_irpf(2025, &lancamentos, &categorias);
+
+        assert_eq!(resp.len(), 2);
+        assert_eq!(resp[0].tipo, "Educação");
+        assert_eq!(resp[0].total, 800.0);
+        assert_eq!(resp[1].tipo, "Saúde");
+        assert_eq!(resp[1].beneficiario, "Clínica Sorriso");
+        assert_eq!(resp[1].total, 500.0);
+    }
+
+    #[test]
+    fn contas_viram_bens_e_cartoes_viram_dividas() {
+        let bancos = vec![Banco {
+            id: "b".to_string(),
+            nome: "Banco".to_string(),
+            contas: vec![
+                Conta {
+                    id: "cc".to_string(),
+                    nome: "Corrente".to_string(),
+                    saldo_inicial: 1000.0,
+                    ..Default::default()
+                },
+                Conta {
+                    id: "cartao".to_string(),
+                    nome: "Cartão".to_string(),
+                    tipo: TipoConta::Cartao,
+                    ..Default::default()
+                },
+            ],
+        }];
+        let mut lancamentos = vec![
+            lancamento(500.0, data_ano(10, 6, 2025))
+                .com_descricao("Depósito")
+                .com_categoria("x")
+                .com_conta("cc"),
+            lancamento(-300.0, data_ano(10, 12, 2025))
+                .com_descricao("Compra")
+                .com_categoria("x")
+                .com_conta("cc"),
+        ];
+        lancamentos[1].conta = Some("cartao".to_string());
+
+        let bens = bens_irpf(2025, &bancos, &lancamentos, &[], &[]);
+        let dividas = dividas_irpf(2025, &bancos, &lancamentos, &[]);
+
+        assert_eq!(bens.len(), 1);
+        assert_eq!(bens[0].anterior, 1000.0);
+        assert_eq!(bens[0].atual, 1500.0);
+        assert_eq!(dividas.len(), 1);
+        assert_eq!(dividas[0].anterior, 0.0);
+        assert_eq!(dividas[0].atual, 300.0);
+    }
+}
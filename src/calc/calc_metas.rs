@@ -0,0 +1,266 @@
+use chrono::{Datelike, Duration, Months, NaiveDate};
+
+use crate::dto::{
+    ArvoreCategorias, Categoria, DashMeta, Lancamento, Meta, OptionalLazyFn, SituacaoMeta,
+    TendenciaMeta,
+};
+
+/// Variação abaixo desta fração do período anterior é considerada estável.
+const TOLERANCIA_TENDENCIA: f64 = 0.05;
+
+/// Janela de avaliação de uma meta: dias já decorridos de um período de `total` dias.
+struct Periodo {
+    inicio: NaiveDate,
+    inicio_anterior: NaiveDate,
+    decorridos: i64,
+    total: i64,
+}
+
+/// Avalia as metas ativas contra os lançamentos, considerando o período de cada uma até `hoje`.
+pub fn avaliar_metas(
+    metas: &[Meta],
+    categorias: &Vec<Categoria>,
+    lancamentos: &[Lancamento],
+    hoje: NaiveDate,
+) -> Vec<DashMeta> {
+    metas
+        .iter()
+        .filter(|m| m.ativo)
+        .map(|m| avaliar_meta(m, categorias, lancamentos, hoje))
+        .collect()
+}
+
+pub fn avaliar_meta(
+    meta: &Meta,
+    categorias: &Vec<Categoria>,
+    lancamentos: &[Lancamento],
+    hoje: NaiveDate,
+) -> DashMeta {
+    let periodo = gerar_periodo(&meta.periodo, hoje);
+    let filtrados: Vec<&Lancamento> = filtrar(meta, categorias, lancamentos);
+
+    let realizado = somar(meta, &filtrados, periodo.inicio, periodo.decorridos);
+    let anterior = somar(
+        meta,
+        &filtrados,
+        periodo.inicio_anterior,
+        periodo
+            .decorridos
+            .min((periodo.inicio - periodo.inicio_anterior).num_days()),
+    );
+    let projetado = realizado / periodo.decorridos as f64 * periodo.total as f64;
+
+    DashMeta {
+        id: meta.id.clone(),
+        nome: meta.nome.clone(),
+        metrica: meta.metrica.clone(),
+        valor: meta.valor,
+        realizado,
+        projetado,
+        progresso: if meta.valor == 0.0 {
+            0.0
+        } else {
+            realizado / meta.valor * 100.0
+        },
+        restante: (meta.valor - realizado).max(0.0),
+        situacao: situacao(meta, &periodo, realizado, projetado),
+        tendencia: tendencia(realizado, anterior),
+    }
+}
+
+fn gerar_periodo(periodo: &str, hoje: NaiveDate) -> Periodo {
+    if let Some(dias) = periodo
+        .strip_prefix("ult. ")
+        .and_then(|p| p.strip_suffix(" dias"))
+        .and_then(|p| p.parse::<i64>().ok())
+    {
+        let inicio = hoje - Duration::days(dias - 1);
+        return Periodo {
+            inicio,
+            inicio_anterior: inicio - Duration::days(dias),
+            decorridos: dias,
+            total: dias,
+        };
+    }
+
+    if periodo == "anual" {
+        let inicio = NaiveDate::from_ymd_opt(hoje.year(), 1, 1).unwrap_or(hoje);
+        return Periodo {
+            inicio,
+            inicio_anterior: inicio - Months::new(12),
+            decorridos: hoje.ordinal() as i64,
+            total: (inicio + Months::new(12) - inicio).num_days(),
+        };
+    }
+
+    if periodo != "mensal" {
+        log::error!("Período de meta desconhecido: {}", periodo);
+    }
+
+    let inicio = hoje.with_day(1).unwrap_or(hoje);
+    Periodo {
+        inicio,
+        inicio_anterior: inicio - Months::new(1),
+        decorridos: hoje.day() as i64,
+        total: (inicio + Months::new(1) - inicio).num_days(),
+    }
+}
+
+fn filtrar<'a>(
+    meta: &Meta,
+    categorias: &Vec<Categoria>,
+    lancamentos: &'a [Lancamento],
+) -> Vec<&'a Lancamento> {
+    match meta.tipo_meta.as_str() {
+        "Conta" => {
+            // o filtro é salvo como "banco:conta", o lançamento guarda só a conta
+            let conta = meta
+                .filtro
+                .split_once(':')
+                .map(|(_, c)| c)
+                .unwrap_or(meta.filtro.as_str());
+            lancamentos
+                .iter()
+                .filter(|l| l.conta.as_deref() == Some(conta))
+                .collect()
+        }
+        "Categoria" => {
+            let mut ids = vec![meta.filtro.clone()];
+            ids.extend(categorias.descendentes(&meta.filtro).into_iter().map(|c| c.id));
+            lancamentos
+                .iter()
+                .filter(|l| ids.contains(&l.categoria.id()))
+                .collect()
+        }
+        _ => lancamentos.iter().collect(),
+    }
+}
+
+fn somar(meta: &Meta, lancamentos: &[&Lancamento], inicio: NaiveDate, dias: i64) -> f64 {
+    let fim = inicio + Duration::days(dias);
+    let entrada = meta.fluxo == "entrada";
+
+    lancamentos
+        .iter()
+        .filter(|l| l.data >= inicio && l.data < fim)
+        .filter(|l| if entrada { l.valor > 0.0 } else { l.valor < 0.0 })
+        .map(|l| l.valor.abs())
+        .sum()
+}
+
+fn situacao(meta: &Meta, periodo: &Periodo, realizado: f64, projetado: f64) -> SituacaoMeta {
+    let encerrado = periodo.decorridos >= periodo.total;
+
+    if meta.metrica.starts_with("menor") {
+        if realizado >= meta.valor {
+            SituacaoMeta::NaoAtingida
+        } else if encerrado {
+            SituacaoMeta::Atingida
+        } else if projetado > meta.valor {
+            SituacaoMeta::EmRisco
+        } else {
+            SituacaoMeta::NoCaminho
+        }
+    } else if realizado >= meta.valor {
+        SituacaoMeta::Atingida
+    } else if encerrado {
+        SituacaoMeta::NaoAtingida
+    } else if projetado >= meta.valor {
+        SituacaoMeta::NoCaminho
+    } else {
+        SituacaoMeta::EmRisco
+    }
+}
+
+/// Compara com o mesmo trecho do período anterior (ex: dia 1 a 10 do mês passado).
+fn tendencia(realizado: f64, anterior: f64) -> TendenciaMeta {
+    let diferenca = realizado - anterior;
+    if diferenca.abs() <= anterior.abs() * TOLERANCIA_TENDENCIA {
+        TendenciaMeta::Estavel
+    } else if diferenca > 0.0 {
+        TendenciaMeta::Subindo
+    } else {
+        TendenciaMeta::Descendo
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calc::teste::{data, lancamento, LancamentoTeste};
+    use crate::dto::{TipoDespesa, TipoFluxo};
+
+    fn meta(tipo: &str, filtro: &str, fluxo: &str, metrica: &str, periodo: &str) -> Meta {
+        Meta {
+            nome: "meta".to_string(),
+            ativo: true,
+            tipo_meta: tipo.to_string(),
+            filtro: filtro.to_string(),
+            fluxo: fluxo.to_string(),
+            metrica: metrica.to_string(),
+            periodo: periodo.to_string(),
+            valor: 1000.0,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn gasto_mensal_abaixo_do_limite_fica_no_caminho() {
+        let lancs = vec![
+            lancamento(-200.0, data(3, 4)).com_categoria("c").com_conta("cc"),
+            lancamento(-500.0, data(20, 3)).com_categoria("c").com_conta("cc"),
+        ];
+        let m = meta("", "", "saída", "menor que", "mensal");
+
+        let resp = avaliar_meta(&m, &vec![], &lancs, data(10, 4));
+        assert_eq!(resp.realizado, 200.0);
+        assert_eq!(resp.projetado, 600.0);
+        assert_eq!(resp.restante, 800.0);
+        assert_eq!(resp.situacao, SituacaoMeta::NoCaminho);
+    }
+
+    #[test]
+    fn projecao_acima_do_limite_fica_em_risco() {
+        let lancs = vec![lancamento(-500.0, data(3, 4)).com_categoria("c").com_conta("cc")];
+        let m = meta("", "", "saída", "menor que", "mensal");
+
+        let resp = avaliar_meta(&m, &vec![], &lancs, data(10, 4));
+        assert_eq!(resp.situacao, SituacaoMeta::EmRisco);
+        assert_eq!(resp.tendencia, TendenciaMeta::Subindo);
+    }
+
+    #[test]
+    fn filtro_de_conta_ignora_o_banco() {
+        let lancs = vec![
+            lancamento(1500.0, data(3, 4)).com_categoria("c").com_conta("cc"),
+            lancamento(700.0, data(3, 4)).com_categoria("c").com_conta("outra"),
+        ];
+        let m = meta("Conta", "banco:cc", "entrada", "marior que", "ult. 30 dias");
+
+        let resp = avaliar_meta(&m, &vec![], &lancs, data(10, 4));
+        assert_eq!(resp.realizado, 1500.0);
+        assert_eq!(resp.situacao, SituacaoMeta::Atingida);
+    }
+
+    #[test]
+    fn filtro_de_categoria_inclui_as_filhas() {
+        let tipo = TipoFluxo::Despesa(TipoDespesa::Variavel);
+        let lazer = Categoria::new("Lazer", tipo.clone(), None);
+        let bares = Categoria::new("Bares", tipo, Some(lazer.id.clone()));
+        let lancs = vec![
+            lancamento(-100.0, data(3, 4)).com_categoria(&bares.id).com_conta("cc"),
+            lancamento(-900.0, data(3, 4)).com_categoria("outra").com_conta("cc"),
+        ];
+        let m = meta("Categoria", &lazer.id, "saída", "menor que", "anual");
+
+        let resp = avaliar_meta(&m, &vec![lazer, bares], &lancs, data(10, 4));
+        assert_eq!(resp.realizado, 100.0);
+    }
+
+    #[test]
+    fn metas_inativas_nao_sao_avaliadas() {
+        let mut m = meta("", "", "saída", "menor que", "mensal");
+        m.ativo = false;
+        assert!(avaliar_metas(&[m], &vec![], &[], data(10, 4)).is_empty());
+    }
+}
@@ -0,0 +1,224 @@
+use chrono::{Datelike, Months, NaiveDate};
+
+use crate::dto::{ArvoreCategorias, Categoria, DashObjetivo, Lancamento, Objetivo, OptionalLazyFn};
+
+/// Meses usados para medir o ritmo recente de aportes.
+const MESES_RITMO: u32 = 3;
+const DIAS_POR_MES: f64 = 365.25 / 12.0;
+
+pub fn calcular_objetivos(
+    objetivos: &[Objetivo],
+    categorias: &Vec<Categoria>,
+    lancamentos: &[Lancamento],
+    hoje: NaiveDate,
+) -> Vec<DashObjetivo> {
+    objetivos
+        .iter()
+        .map(|o| calcular_objetivo(o, categorias, lancamentos, hoje))
+        .collect()
+}
+
+pub fn calcular_objetivo(
+    objetivo: &Objetivo,
+    categorias: &Vec<Categoria>,
+    lancamentos: &[Lancamento],
+    hoje: NaiveDate,
+) -> DashObjetivo {
+    let ids = ids_das_categorias(objetivo, categorias);
+
+    let acumulado = objetivo.saldo_inicial + somar_aportes(objetivo, &ids, lancamentos, objetivo.inicio, hoje);
+    let falta = (objetivo.valor_alvo - acumulado).max(0.0);
+
+    let inicio_ritmo = (hoje - Months::new(MESES_RITMO)).max(objetivo.inicio);
+    let dias_ritmo = (hoje - inicio_ritmo).num_days().max(1) as f64;
+    let ritmo_mensal =
+        somar_aportes(objetivo, &ids, lancamentos, inicio_ritmo, hoje) / dias_ritmo * DIAS_POR_MES;
+
+    let meses_restantes = meses_entre(hoje, objetivo.data_alvo);
+    let previsao = prever_conclusao(falta, ritmo_mensal, hoje);
+
+    DashObjetivo {
+        id: objetivo.id.clone(),
+        nome: objetivo.nome.clone(),
+        valor_alvo: objetivo.valor_alvo,
+        data_alvo: objetivo.data_alvo.format("%m/%Y").to_string(),
+        acumulado,
+        falta,
+        progresso: if objetivo.valor_alvo == 0.0 {
+            100.0
+        } else {
+            (acumulado / objetivo.valor_alvo * 100.0).min(100.0)
+        },
+        aporte_necessario: if meses_restantes > 0 {
+            falta / meses_restantes as f64
+        } else {
+            falta
+        },
+        ritmo_mensal,
+        previsao: previsao.map(|d| d.format("%m/%Y").to_string()),
+        no_prazo: match previsao {
+            Some(data) => meses_entre(data, objetivo.data_alvo) >= 0,
+            None => false,
+        },
+    }
+}
+
+fn ids_das_categorias(objetivo: &Objetivo, categorias: &Vec<Categoria>) -> Vec<String> {
+    let mut resp: Vec<String> = Vec::new();
+    objetivo.categorias.iter().for_each(|id| {
+        resp.push(id.clone());
+        resp.extend(categorias.descendentes(id).into_iter().map(|c| c.id));
+    });
+    resp
+}
+
+fn somar_aportes(
+    objetivo: &Objetivo,
+    ids: &[String],
+    lancamentos: &[Lancamento],
+    inicio: NaiveDate,
+    fim: NaiveDate,
+) -> f64 {
+    lancamentos
+        .iter()
+        .filter(|l| l.data >= inicio && l.data <= fim)
+        .map(|l| aporte(objetivo, ids, l, lancamentos))
+        .sum()
+}
+
+/// Na conta vinculada vale o próprio saldo (depósito soma, resgate subtrai); fora
+/// dela, a saída numa categoria de investimento vinculada é o aporte, a não ser
+/// que o dinheiro tenha caído numa conta vinculada, onde já foi contado.
+fn aporte(
+    objetivo: &Objetivo,
+    ids: &[String],
+    lancamento: &Lancamento,
+    todos: &[Lancamento],
+) -> f64 {
+    if na_conta_vinculada(objetivo, lancamento) {
+        return lancamento.valor;
+    }
+    if !ids.contains(&lancamento.categoria.id()) {
+        return 0.0;
+    }
+
+    let transferido = todos.iter().any(|l| {
+        l.data == lancamento.data
+            && (l.valor + lancamento.valor).abs() < 0.005
+            && na_conta_vinculada(objetivo, l)
+    });
+    if transferido {
+        0.0
+    } else {
+        -lancamento.valor
+    }
+}
+
+fn na_conta_vinculada(objetivo: &Objetivo, lancamento: &Lancamento) -> bool {
+    lancamento
+        .conta
+        .as_ref()
+        .is_some_and(|conta| objetivo.contas.contains(conta))
+}
+
+fn prever_conclusao(falta: f64, ritmo_mensal: f64, hoje: NaiveDate) -> Option<NaiveDate> {
+    if falta <= 0.0 {
+        Some(hoje)
+    } else if ritmo_mensal > 0.0 {
+        hoje.checked_add_months(Months::new((falta / ritmo_mensal).ceil() as u32))
+    } else {
+        None
+    }
+}
+
+fn meses_entre(de: NaiveDate, ate: NaiveDate) -> i32 {
+    (ate.year() - de.year()) * 12 + ate.month() as i32 - de.month() as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calc::teste::{data, lancamento, LancamentoTeste};
+    use crate::dto::TipoFluxo;
+
+    fn objetivo(contas: Vec<&str>, categorias: Vec<&str>) -> Objetivo {
+        Objetivo {
+            id: String::new(),
+            nome: "Reserva".to_string(),
+            valor_alvo: 10000.0,
+            data_alvo: data(1, 12),
+            saldo_inicial: 1000.0,
+            inicio: data(1, 1),
+            contas: contas.into_iter().map(|c| c.to_string()).collect(),
+            categorias: categorias.into_iter().map(|c| c.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn conta_vinculada_conta_depositos_e_resgates() {
+        let lancs = vec![
+            lancamento(3000.0, data(10, 2)).com_categoria("").com_conta("poupanca"),
+            lancamento(-500.0, data(10, 3)).com_categoria("").com_conta("poupanca"),
+            lancamento(-800.0, data(10, 3)).com_categoria("").com_conta("corrente"),
+        ];
+
+        let resp = calcular_objetivo(&objetivo(vec!["poupanca"], vec![]), &vec![], &lancs, data(1, 6));
+        assert_eq!(resp.acumulado, 3500.0);
+        assert_eq!(resp.falta, 6500.0);
+        assert_eq!(resp.aporte_necessario, 6500.0 / 6.0);
+    }
+
+    #[test]
+    fn categoria_de_investimento_inclui_as_filhas() {
+        let acoes = Categoria::new("Ações", TipoFluxo::Investimento, None);
+        let fii = Categoria::new("FII", TipoFluxo::Investimento, Some(acoes.id.clone()));
+        let lancs = vec![lancamento(-2000.0, data(10, 2)).com_categoria(&fii.id).com_conta("corrente")];
+
+        let resp = calcular_objetivo(
+            &objetivo(vec![], vec![&acoes.id]),
+            &vec![acoes.clone(), fii.clone()],
+            &lancs,
+            data(1, 6),
+        );
+        assert_eq!(resp.acumulado, 3000.0);
+    }
+
+    #[test]
+    fn transferencia_para_conta_vinculada_conta_uma_vez() {
+        let reserva = Categoria::new("Reserva", TipoFluxo::Investimento, None);
+        let lancs = vec![
+            lancamento(-2000.0, data(10, 2)).com_categoria(&reserva.id).com_conta("corrente"),
+            lancamento(2000.0, data(10, 2)).com_categoria("").com_conta("poupanca"),
+            lancamento(-500.0, data(10, 3)).com_categoria(&reserva.id).com_conta("corrente"),
+        ];
+
+        let resp = calcular_objetivo(
+            &objetivo(vec!["poupanca"], vec![&reserva.id]),
+            &vec![reserva.clone()],
+            &lancs,
+            data(1, 6),
+        );
+        assert_eq!(resp.acumulado, 3500.0);
+    }
+
+    #[test]
+    fn previsao_usa_ritmo_recente() {
+        let lancs = vec![
+            lancamento(1000.0, data(1, 4)).com_categoria("").com_conta("poupanca"),
+            lancamento(1000.0, data(1, 5)).com_categoria("").com_conta("poupanca"),
+            lancamento(1000.0, data(1, 6)).com_categoria("").com_conta("poupanca"),
+        ];
+
+        let resp = calcular_objetivo(&objetivo(vec!["poupanca"], vec![]), &vec![], &lancs, data(1, 7));
+        assert!(resp.ritmo_mensal > 950.0 && resp.ritmo_mensal < 1050.0);
+        assert_eq!(resp.previsao, Some("01/2026".to_string()));
+        assert!(!resp.no_prazo);
+    }
+
+    #[test]
+    fn sem_aportes_nao_ha_previsao() {
+        let resp = calcular_objetivo(&objetivo(vec!["poupanca"], vec![]), &vec![], &[], data(1, 7));
+        assert_eq!(resp.previsao, None);
+        assert!(!resp.no_prazo);
+    }
+}
@@ -0,0 +1,167 @@
+use chrono::{Datelike, Months, NaiveDate};
+
+use crate::dto::{
+    ArvoreCategorias, Categoria, DashOrcamento, Lancamento, LazyFn, Orcamento, OptionalLazyFn,
+};
+
+/// Situação de cada orçamento no mês de `hoje`: orçado, acumulado dos meses
+/// anteriores, gasto até agora, restante e projeção para o fim do mês.
+pub fn calcular_orcamentos(
+    orcamentos: &[Orcamento],
+    categorias: &Vec<Categoria>,
+    lancamentos: &[Lancamento],
+    hoje: NaiveDate,
+) -> Vec<DashOrcamento> {
+    orcamentos
+        .iter()
+        .map(|o| calcular_orcamento(o, categorias, lancamentos, hoje))
+        .collect()
+}
+
+fn calcular_orcamento(
+    orcamento: &Orcamento,
+    categorias: &Vec<Categoria>,
+    lancamentos: &[Lancamento],
+    hoje: NaiveDate,
+) -> DashOrcamento {
+    let categoria_id = orcamento.categoria.id();
+    let ids = ids_do_grupo(categorias, &categoria_id);
+    let mes_atual = primeiro_dia(hoje);
+
+    let acumulado = if orcamento.acumular {
+        saldo_anterior(orcamento, lancamentos, &ids, mes_atual)
+    } else {
+        0.0
+    };
+    let gasto = gasto_no_mes(lancamentos, &ids, mes_atual);
+    let projetado = gasto / hoje.day() as f64 * dias_no_mes(hoje) as f64;
+
+    DashOrcamento {
+        categoria: match categorias.buscar_id(&categoria_id) {
+            Some(cat) => cat.to_string(),
+            None => orcamento.categoria.some().to_string(),
+        },
+        orcado: orcamento.valor,
+        acumulado,
+        gasto,
+        restante: orcamento.valor + acumulado - gasto,
+        projetado,
+    }
+}
+
+/// A própria categoria e todas as filhas, para o orçamento valer como grupo.
+fn ids_do_grupo(categorias: &Vec<Categoria>, id: &str) -> Vec<String> {
+    let mut resp = vec![id.to_string()];
+    resp.extend(categorias.descendentes(id).into_iter().map(|c| c.id));
+    resp
+}
+
+/// Sobra (positiva) ou estouro (negativo) acumulado desde o início do orçamento até o mês anterior.
+fn saldo_anterior(
+    orcamento: &Orcamento,
+    lancamentos: &[Lancamento],
+    ids: &[String],
+    mes_atual: NaiveDate,
+) -> f64 {
+    let mut saldo = 0.0;
+    let mut mes = primeiro_dia(orcamento.inicio);
+
+    while mes < mes_atual {
+        saldo += orcamento.valor - gasto_no_mes(lancamentos, ids, mes);
+        mes = mes + Months::new(1);
+    }
+
+    saldo
+}
+
+/// Gasto líquido no mês: estornos na mesma categoria abatem do total.
+fn gasto_no_mes(lancamentos: &[Lancamento], ids: &[String], mes: NaiveDate) -> f64 {
+    -lancamentos
+        .iter()
+        .filter(|l| l.data.year() == mes.year() && l.data.month() == mes.month())
+        .filter(|l| ids.contains(&l.categoria.id()))
+        .map(|l| l.valor)
+        .sum::<f64>()
+}
+
+fn primeiro_dia(data: NaiveDate) -> NaiveDate {
+    data.with_day(1).unwrap_or(data)
+}
+
+fn dias_no_mes(data: NaiveDate) -> i64 {
+    let inicio = primeiro_dia(data);
+    (inicio + Months::new(1) - inicio).num_days()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calc::teste::{data, lancamento, LancamentoTeste};
+    use crate::dto::{Lazy, TipoDespesa, TipoFluxo};
+
+    fn categorias() -> Vec<Categoria> {
+        let tipo = TipoFluxo::Despesa(TipoDespesa::Variavel);
+        let grupo = Categoria::new("Abastecimento", tipo.clone(), None);
+        let mercado = Categoria::new("Supermercados", tipo.clone(), Some(grupo.id.clone()));
+        let padaria = Categoria::new("Padarias", tipo, Some(grupo.id.clone()));
+        vec![grupo, mercado, padaria]
+    }
+
+    fn orcamento(cat: &Categoria, acumular: bool) -> Orcamento {
+        Orcamento {
+            id: String::new(),
+            categoria: Lazy::Id(cat.id.clone()),
+            valor: 1200.0,
+            acumular,
+            inicio: data(1, 3),
+        }
+    }
+
+    #[test]
+    fn grupo_soma_as_categorias_filhas() {
+        let cats = categorias();
+        let lancs = vec![
+            lancamento(-300.0, data(2, 4)).com_categoria(&cats[1].id),
+            lancamento(-100.0, data(5, 4)).com_categoria(&cats[2].id),
+        ];
+
+        let resp = calcular_orcamentos(&[orcamento(&cats[0], false)], &cats, &lancs, data(10, 4));
+        assert_eq!(resp[0].gasto, 400.0);
+        assert_eq!(resp[0].restante, 800.0);
+        assert_eq!(resp[0].acumulado, 0.0);
+    }
+
+    #[test]
+    fn projecao_usa_a_media_diaria() {
+        let cats = categorias();
+        let lancs = vec![lancamento(-300.0, data(2, 4)).com_categoria(&cats[1].id)];
+
+        let resp = calcular_orcamentos(&[orcamento(&cats[1], false)], &cats, &lancs, data(10, 4));
+        assert_eq!(resp[0].projetado, 900.0);
+    }
+
+    #[test]
+    fn acumulo_leva_sobra_e_estouro_adiante() {
+        let cats = categorias();
+        let lancs = vec![
+            lancamento(-1000.0, data(10, 3)).com_categoria(&cats[1].id),
+            lancamento(-1500.0, data(10, 4)).com_categoria(&cats[1].id),
+        ];
+
+        let resp = calcular_orcamentos(&[orcamento(&cats[1], true)], &cats, &lancs, data(1, 5));
+        assert_eq!(resp[0].acumulado, -100.0);
+        assert_eq!(resp[0].restante, 1100.0);
+    }
+
+    #[test]
+    fn estorno_abate_do_gasto() {
+        let cats = categorias();
+        let lancs = vec![
+            lancamento(-500.0, data(2, 4)).com_categoria(&cats[1].id),
+            lancamento(200.0, data(3, 4)).com_categoria(&cats[1].id),
+        ];
+
+        let resp = calcular_orcamentos(&[orcamento(&cats[1], false)], &cats, &lancs, data(10, 4));
+        assert_eq!(resp[0].gasto, 300.0);
+    }
+}
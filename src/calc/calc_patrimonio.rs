@@ -0,0 +1,300 @@
+use chrono::{Datelike, Duration, Months, NaiveDate};
+
+use crate::dto::{
+    Ativo, Banco, Conta, Cotacao, DashPatrimonio, DashPatrimonioMes, DashSaldoConta, Divida,
+    Lancamento, OperacaoInvestimento,
+};
+
+use super::calc_investimentos::calcular_posicoes;
+
+/// Meses da série de patrimônio, contando o atual.
+const MESES: u32 = 12;
+
+/// Dias da série de saldo diário das contas, contando hoje.
+const DIAS: i64 = 30;
+
+/// Saldo da conta no fim do dia `data`: o saldo inicial mais os lançamentos
+/// da conta posteriores à data do saldo inicial.
+pub fn saldo_em(conta: &Conta, lancamentos: &[Lancamento], data: NaiveDate) -> f64 {
+    let movimento: f64 = lancamentos
+        .iter()
+        .filter(|l| l.conta.as_deref() == Some(conta.id.as_str()))
+        .filter(|l| l.data <= data)
+        .filter(|l| {
+            conta
+                .data_saldo_inicial
+                .is_none_or(|inicio| l.data > inicio)
+        })
+        .map(|l| l.valor)
+        .sum();
+
+    arredondar(conta.saldo_inicial + movimento)
+}
+
+/// Saldo atual de cada conta, conferido com o último extrato, o saldo diário
+/// dos últimos dias e a evolução mensal do patrimônio: ativos são as contas que
+/// não são cartão e a carteira de investimentos a valor de mercado; passivos,
+/// o saldo devedor dos cartões e das dívidas abertas no fim de cada mês.
+pub fn calcular_patrimonio(
+    bancos: &[Banco],
+    lancamentos: &[Lancamento],
+    dividas: &[Divida],
+    ativos: &[Ativo],
+    operacoes: &[OperacaoInvestimento],
+    cotacoes: &[Cotacao],
+    hoje: NaiveDate,
+) -> DashPatrimonio {
+    let dias: Vec<NaiveDate> = (0..DIAS).rev().map(|d| hoje - Duration::days(d)).collect();
+
+    let contas: Vec<DashSaldoConta> = bancos
+        .iter()
+        .flat_map(|b| b.contas.iter().map(move |c| (b, c)))
+        .map(|(b, c)| {
+            let calculado_extrato = c.extrato.as_ref().map(|e| saldo_em(c, lancamentos, e.data));
+            DashSaldoConta {
+                banco: b.nome.clone(),
+                conta: c.nome.clone(),
+                tipo: c.tipo.to_string(),
+                saldo: saldo_em(c, lancamentos, hoje),
+                extrato: c.extrato.as_ref().map(|e| e.valor),
+                data_extrato: c
+                    .extrato
+                    .as_ref()
+                    .map(|e| e.data.format("%d/%m/%Y").to_string()),
+                diferenca: c
+                    .extrato
+                    .as_ref()
+                    .zip(calculado_extrato)
+                    .map(|(e, calculado)| arredondar(e.valor - calculado)),
+                diario: dias.iter().map(|d| saldo_em(c, lancamentos, *d)).collect(),
+            }
+        })
+        .collect();
+
+    let inicio = hoje.with_day(1).unwrap_or(hoje);
+    let meses = (0..MESES)
+        .rev()
+        .map(|m| {
+            let mes = inicio - Months::new(m);
+            let fim = (mes + Months::new(1) - Duration::days(1)).min(hoje);
+            // cotação mais recente até o fim do mês, ou o preço da última operação
+            let carteira: f64 = calcular_posicoes(ativos, operacoes, cotacoes, fim)
+                .iter()
+                .map(|p| p.valor_atual)
+                .sum();
+            patrimonio_em(bancos, lancamentos, dividas, carteira, mes, fim)
+        })
+        .collect();
+
+    DashPatrimonio {
+        contas,
+        dias: dias.iter().map(|d| d.format("%d/%m").to_string()).collect(),
+        meses,
+    }
+}
+
+fn patrimonio_em(
+    bancos: &[Banco],
+    lancamentos: &[Lancamento],
+    dividas: &[Divida],
+    carteira: f64,
+    mes: NaiveDate,
+    data: NaiveDate,
+) -> DashPatrimonioMes {
+    let mut ativos = carteira;
+    let mut passivos = 0.0;
+
+    bancos.iter().flat_map(|b| b.contas.iter()).for_each(|c| {
+        let saldo = saldo_em(c, lancamentos, data);
+        if c.tipo.passivo() {
+            passivos -= saldo;
+        } else {
+            ativos += saldo;
+        }
+    });
+
+    passivos += dividas
+        .iter()
+        .map(|d| saldo_divida_em(d, data))
+        .sum::<f64>();
+
+    DashPatrimonioMes {
+        mes: mes.format("%m/%Y").to_string(),
+        ativos: arredondar(ativos),
+        investimentos: arredondar(carteira),
+        passivos: arredondar(passivos),
+        liquido: arredondar(ativos - passivos),
+    }
+}
+
+/// Principal das parcelas ainda não vencidas (ou não pagas) na data. A dívida
+/// só existe a partir de um mês antes do primeiro vencimento.
+pub fn saldo_divida_em(divida: &Divida, data: NaiveDate) -> f64 {
+    match divida.parcelas.first() {
+        Some(primeira) if primeira.data_vencimento - Months::new(1) <= data => divida
+            .parcelas
+            .iter()
+            .filter(|p| !p.pago || p.data_vencimento > data)
+            .map(|p| p.principal())
+            .sum(),
+        _ => 0.0,
+    }
+}
+
+fn arredondar(valor: f64) -> f64 {
+    (valor * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calc::teste::{data, lancamento, LancamentoTeste};
+    use crate::dto::{SaldoExtrato, TipoAtivo, TipoConta, TipoOperacao};
+
+    fn conta(id: &str, tipo: TipoConta, saldo_inicial: f64, desde: Option<NaiveDate>) -> Conta {
+        Conta {
+            id: id.to_string(),
+            nome: id.to_string(),
+            tipo,
+            saldo_inicial,
+            data_saldo_inicial: desde,
+            extrato: None,
+        }
+    }
+
+    fn lancamentos() -> Vec<Lancamento> {
+        vec![
+            lancamento(500.0, data(5, 1)).com_conta("cc"),
+            lancamento(3000.0, data(5, 2)).com_conta("cc"),
+            lancamento(-800.0, data(20, 2)).com_conta("cc"),
+            lancamento(-400.0, data(10, 2)).com_conta("cartao"),
+            lancamento(99.0, data(10, 2)).com_conta("outra"),
+        ]
+    }
+
+    #[test]
+    fn saldo_ignora_lancamentos_ate_a_data_do_saldo_inicial() {
+        let cc = conta("cc", TipoConta::Corrente, 1000.0, Some(data(31, 1)));
+
+        assert_eq!(saldo_em(&cc, &lancamentos(), data(10, 2)), 4000.0);
+        assert_eq!(saldo_em(&cc, &lancamentos(), data(28, 2)), 3200.0);
+
+        let sem_data = conta("cc", TipoConta::Corrente, 1000.0, None);
+        assert_eq!(saldo_em(&sem_data, &lancamentos(), data(28, 2)), 3700.0);
+    }
+
+    #[test]
+    fn confere_com_o_extrato() {
+        let mut cc = conta("cc", TipoConta::Corrente, 1000.0, Some(data(31, 1)));
+        cc.extrato = Some(SaldoExtrato {
+            valor: 4050.0,
+            data: data(10, 2),
+        });
+        let bancos = vec![Banco {
+            id: "001".to_string(),
+            nome: "BB".to_string(),
+            contas: vec![cc],
+        }];
+
+        let resp = calcular_patrimonio(&bancos, &lancamentos(), &[], &[], &[], &[], data(28, 2));
+
+        assert_eq!(resp.contas[0].saldo, 3200.0);
+        assert_eq!(resp.contas[0].extrato, Some(4050.0));
+        assert_eq!(resp.contas[0].diferenca, Some(50.0));
+    }
+
+    #[test]
+    fn cartao_e_dividas_sao_passivos() {
+        let bancos = vec![Banco {
+            id: "001".to_string(),
+            nome: "BB".to_string(),
+            contas: vec![
+                conta("cc", TipoConta::Corrente, 1000.0, Some(data(31, 1))),
+                conta("cartao", TipoConta::Cartao, 0.0, None),
+            ],
+        }];
+        let mut divida = Divida::new("Loja".to_string(), false, false, 4, 100.0, data(10, 3), 0);
+        divida.parcelas[0].pago = true;
+
+        let resp = calcular_patrimonio(
+            &bancos,
+            &lancamentos(),
+            &[divida],
+            &[],
+            &[],
+            &[],
+            data(15, 3),
+        );
+
+        assert_eq!(resp.meses.len(), 12);
+        let fevereiro = &resp.meses[10];
+        assert_eq!(fevereiro.mes, "02/2025");
+        assert_eq!(fevereiro.ativos, 3200.0);
+        assert_eq!(fevereiro.passivos, 800.0);
+        assert_eq!(fevereiro.liquido, 2400.0);
+
+        let marco = &resp.meses[11];
+        assert_eq!(marco.passivos, 700.0);
+        assert_eq!(resp.meses[9].passivos, 0.0);
+    }
+
+    #[test]
+    fn saldo_diario_e_carteira_a_valor_de_mercado() {
+        let bancos = vec![Banco {
+            id: "001".to_string(),
+            nome: "BB".to_string(),
+            contas: vec![conta("cc", TipoConta::Corrente, 1000.0, Some(data(31, 1)))],
+        }];
+        let ativos = vec![Ativo {
+            id: "HGLG11".to_string(),
+            nome: "HGLG11".to_string(),
+            tipo: TipoAtivo::Fii,
+        }];
+        let operacoes = vec![OperacaoInvestimento {
+            id: String::new(),
+            ativo: "HGLG11".to_string(),
+            tipo: TipoOperacao::Compra,
+            data: data(10, 1),
+            quantidade: 10.0,
+            preco: 100.0,
+            taxas: 0.0,
+            lancamento: None,
+            referencia: String::new(),
+        }];
+        let cotacoes = vec![Cotacao {
+            ativo: "HGLG11".to_string(),
+            data: data(27, 2),
+            preco: 150.0,
+        }];
+
+        let resp = calcular_patrimonio(
+            &bancos,
+            &lancamentos(),
+            &[],
+            &ativos,
+            &operacoes,
+            &cotacoes,
+            data(28, 2),
+        );
+
+        assert_eq!(resp.dias.len(), 30);
+        assert_eq!(resp.dias[29], "28/02");
+        let diario = &resp.contas[0].diario;
+        assert_eq!(diario.len(), 30);
+        // 05/02 recebe 3000 e 20/02 paga 800
+        assert_eq!(
+            diario[resp.dias.iter().position(|d| d == "04/02").unwrap()],
+            1000.0
+        );
+        assert_eq!(
+            diario[resp.dias.iter().position(|d| d == "05/02").unwrap()],
+            4000.0
+        );
+        assert_eq!(diario[29], 3200.0);
+
+        // janeiro sem cotação fica no preço da compra; fevereiro, na cotação
+        assert_eq!(resp.meses[11].investimentos, 1500.0);
+        assert_eq!(resp.meses[11].ativos, 4700.0);
+        assert_eq!(resp.meses[10].investimentos, 1000.0);
+    }
+}
@@ -0,0 +1,298 @@
+use chrono::{Datelike, Months, NaiveDate};
+
+use crate::dto::{
+    DadosDivida, DashQuitacao, DashQuitacaoDivida, DashQuitacaoMes, Divida, EstrategiaQuitacao,
+    ParcelaDivida, PlanoQuitacao,
+};
+
+/// Horizonte máximo da simulação, em meses.
+const MAX_MESES: u32 = 360;
+/// Saldo abaixo deste valor é considerado quitado.
+const CENTAVO: f64 = 0.005;
+
+struct Situacao {
+    id: String,
+    nome: String,
+    saldo_inicial: f64,
+    saldo: f64,
+    taxa: f64,
+    parcelas: Vec<ParcelaDivida>,
+    minimo: f64,
+    juros: f64,
+    quitacao: Option<NaiveDate>,
+}
+
+/// Projeta mês a mês o pagamento das dívidas abertas seguindo o plano.
+///
+/// Todo mês o saldo rende juros e cada dívida recebe as parcelas previstas
+/// para o mês. O extra, somado às parcelas das dívidas já quitadas, vai para
+/// a primeira dívida da ordem da estratégia; o que sobrar passa para a seguinte.
+pub fn simular_quitacao(dividas: &[Divida], plano: &PlanoQuitacao, hoje: NaiveDate) -> DashQuitacao {
+    simular(dividas, plano, true, hoje)
+}
+
+/// Só as parcelas previstas, sem extra: a referência para comparar as estratégias.
+pub fn simular_cronograma(dividas: &[Divida], hoje: NaiveDate) -> DashQuitacao {
+    let mut resp = simular(dividas, &PlanoQuitacao::default(), false, hoje);
+    resp.estrategia = "Cronograma atual".to_string();
+    resp
+}
+
+fn simular(
+    dividas: &[Divida],
+    plano: &PlanoQuitacao,
+    redirecionar: bool,
+    hoje: NaiveDate,
+) -> DashQuitacao {
+    let mut situacoes = ordenar(
+        dividas
+            .iter()
+            .filter(|d| d.parcelas.aberta().quant() > 0)
+            .map(situacao)
+            .collect(),
+        plano,
+    );
+
+    let inicio = hoje.with_day(1).unwrap_or(hoje);
+    let mut meses: Vec<DashQuitacaoMes> = Vec::new();
+
+    for m in 0..MAX_MESES {
+        if situacoes.iter().all(|s| s.quitacao.is_some()) {
+            break;
+        }
+
+        let mes = inicio + Months::new(m);
+        let fim_mes = mes + Months::new(1);
+        let liberado: f64 = situacoes
+            .iter()
+            .filter(|s| s.quitacao.is_some())
+            .map(|s| s.minimo)
+            .sum();
+
+        let mut pago = 0.0;
+        let mut juros_mes = 0.0;
+
+        for s in situacoes.iter_mut().filter(|s| s.quitacao.is_none()) {
+            let juros = arredondar(s.saldo * s.taxa);
+            s.saldo += juros;
+            s.juros += juros;
+            juros_mes += juros;
+
+            let previsto: f64 = if s.parcelas.iter().all(|p| p.data_vencimento >= fim_mes) {
+                // sem parcelas restantes no cronograma, o resíduo é pago de uma vez
+                if s.parcelas.is_empty() {
+                    s.saldo
+                } else {
+                    0.0
+                }
+            } else {
+                s.parcelas
+                    .iter()
+                    .filter(|p| p.data_vencimento < fim_mes)
+                    .map(|p| p.valor)
+                    .sum()
+            };
+            s.parcelas.retain(|p| p.data_vencimento >= fim_mes);
+
+            let valor = previsto.min(s.saldo);
+            s.saldo -= valor;
+            pago += valor;
+        }
+
+        let mut disponivel = plano.extra_mensal + if redirecionar { liberado } else { 0.0 };
+        for s in situacoes.iter_mut().filter(|s| s.quitacao.is_none()) {
+            if disponivel <= 0.0 {
+                break;
+            }
+            let valor = disponivel.min(s.saldo);
+            s.saldo -= valor;
+            disponivel -= valor;
+            pago += valor;
+        }
+
+        situacoes
+            .iter_mut()
+            .filter(|s| s.quitacao.is_none() && s.saldo < CENTAVO)
+            .for_each(|s| {
+                s.saldo = 0.0;
+                s.quitacao = Some(mes);
+            });
+
+        meses.push(DashQuitacaoMes {
+            mes: mes.format("%m/%Y").to_string(),
+            pago: arredondar(pago),
+            juros: arredondar(juros_mes),
+            saldo: arredondar(situacoes.iter().map(|s| s.saldo).sum()),
+            liberado: arredondar(liberado),
+        });
+    }
+
+    let quitacao = if situacoes.iter().all(|s| s.quitacao.is_some()) {
+        situacoes.iter().filter_map(|s| s.quitacao).max()
+    } else {
+        None
+    };
+
+    DashQuitacao {
+        estrategia: plano.estrategia.to_string(),
+        extra_mensal: plano.extra_mensal,
+        total_pago: arredondar(meses.iter().map(|m| m.pago).sum()),
+        total_juros: arredondar(situacoes.iter().map(|s| s.juros).sum()),
+        quitacao: quitacao.map(|d| d.format("%m/%Y").to_string()),
+        dividas: situacoes
+            .into_iter()
+            .map(|s| DashQuitacaoDivida {
+                id: s.id,
+                nome: s.nome,
+                saldo: arredondar(s.saldo_inicial),
+                taxa_mensal: s.taxa * 100.0,
+                juros: arredondar(s.juros),
+                quitacao: s.quitacao.map(|d| d.format("%m/%Y").to_string()),
+            })
+            .collect(),
+        meses,
+    }
+}
+
+fn situacao(divida: &Divida) -> Situacao {
+    let abertas = divida.parcelas.aberta();
+    let saldo = abertas.saldo_devedor();
+
+    // a correção do índice pesa no saldo como se fosse juros
+    let taxa = match &divida.financiamento {
+        Some(f) => (1.0 + f.taxa_mensal / 100.0) * (1.0 + f.correcao_mensal / 100.0) - 1.0,
+        None => 0.0,
+    };
+
+    Situacao {
+        id: divida.id.clone(),
+        nome: divida.nome.clone(),
+        saldo_inicial: saldo,
+        saldo,
+        taxa,
+        minimo: abertas.primeira().valor,
+        parcelas: abertas,
+        juros: 0.0,
+        quitacao: None,
+    }
+}
+
+fn ordenar(mut situacoes: Vec<Situacao>, plano: &PlanoQuitacao) -> Vec<Situacao> {
+    match plano.estrategia {
+        EstrategiaQuitacao::BolaDeNeve => {
+            situacoes.sort_by(|a, b| a.saldo.total_cmp(&b.saldo));
+        }
+        EstrategiaQuitacao::Avalanche => {
+            situacoes.sort_by(|a, b| b.taxa.total_cmp(&a.taxa).then(a.saldo.total_cmp(&b.saldo)));
+        }
+        EstrategiaQuitacao::Personalizada => {
+            situacoes.sort_by_key(|s| {
+                plano
+                    .ordem
+                    .iter()
+                    .position(|id| *id == s.id)
+                    .unwrap_or(usize::MAX)
+            });
+        }
+    }
+    situacoes
+}
+
+fn arredondar(valor: f64) -> f64 {
+    (valor * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calc::teste::data_ano;
+    use crate::{
+        calc::gerar_parcelas_financiamento,
+        dto::{Financiamento, SistemaAmortizacao},
+    };
+
+    fn parcelada(id: &str, quant: i32, valor: f64) -> Divida {
+        let mut divida = Divida::new(id.to_string(), false, false, quant, valor, data_ano(10, 1, 2025), 0);
+        divida.id = id.to_string();
+        divida
+    }
+
+    fn financiada(id: &str, principal: f64, taxa_mensal: f64, prazo: i32) -> Divida {
+        let financiamento = Financiamento {
+            principal,
+            taxa_mensal,
+            prazo,
+            sistema: SistemaAmortizacao::Price,
+            ..Default::default()
+        };
+        let parcelas = gerar_parcelas_financiamento(&financiamento, data_ano(10, 1, 2025), 0);
+        let mut divida = Divida::new_financiamento(id.to_string(), false, false, financiamento, parcelas);
+        divida.id = id.to_string();
+        divida
+    }
+
+    fn plano(estrategia: EstrategiaQuitacao, extra_mensal: f64, ordem: Vec<&str>) -> PlanoQuitacao {
+        PlanoQuitacao {
+            estrategia,
+            extra_mensal,
+            ordem: ordem.into_iter().map(|o| o.to_string()).collect(),
+        }
+    }
+
+    fn quitacao(resp: &DashQuitacao, id: &str) -> Option<String> {
+        resp.dividas.iter().find(|d| d.id == id).and_then(|d| d.quitacao.clone())
+    }
+
+    #[test]
+    fn sem_extra_segue_o_cronograma() {
+        let dividas = vec![parcelada("loja", 4, 100.0)];
+        let resp = simular_quitacao(&dividas, &PlanoQuitacao::default(), data_ano(1, 1, 2025));
+
+        assert_eq!(resp.quitacao, Some("04/2025".to_string()));
+        assert_eq!(resp.total_pago, 400.0);
+        assert_eq!(resp.total_juros, 0.0);
+        assert_eq!(resp.meses.len(), 4);
+    }
+
+    #[test]
+    fn bola_de_neve_paga_o_menor_saldo_primeiro() {
+        let dividas = vec![financiada("carro", 10000.0, 2.0, 24), parcelada("loja", 10, 100.0)];
+        let resp = simular_quitacao(
+            &dividas,
+            &plano(EstrategiaQuitacao::BolaDeNeve, 300.0, vec![]),
+            data_ano(1, 1, 2025),
+        );
+
+        assert_eq!(resp.dividas[0].id, "loja");
+        assert_eq!(quitacao(&resp, "loja"), Some("03/2025".to_string()));
+        assert_eq!(resp.meses[3].liberado, 100.0);
+    }
+
+    #[test]
+    fn avalanche_paga_menos_juros() {
+        let dividas = vec![financiada("carro", 10000.0, 2.0, 24), parcelada("loja", 10, 100.0)];
+        let hoje = data_ano(1, 1, 2025);
+
+        let neve = simular_quitacao(&dividas, &plano(EstrategiaQuitacao::BolaDeNeve, 300.0, vec![]), hoje);
+        let avalanche = simular_quitacao(&dividas, &plano(EstrategiaQuitacao::Avalanche, 300.0, vec![]), hoje);
+        let sem_extra = simular_cronograma(&dividas, hoje);
+
+        assert_eq!(avalanche.dividas[0].id, "carro");
+        assert!(avalanche.total_juros < neve.total_juros);
+        assert!(neve.total_juros < sem_extra.total_juros);
+        assert_eq!(sem_extra.quitacao, Some("12/2026".to_string()));
+    }
+
+    #[test]
+    fn ordem_personalizada_respeita_a_lista() {
+        let dividas = vec![parcelada("a", 10, 100.0), parcelada("b", 10, 100.0)];
+        let resp = simular_quitacao(
+            &dividas,
+            &plano(EstrategiaQuitacao::Personalizada, 200.0, vec!["b", "a"]),
+            data_ano(1, 1, 2025),
+        );
+
+        assert_eq!(resp.dividas[0].id, "b");
+        assert!(quitacao(&resp, "b") < quitacao(&resp, "a"));
+    }
+}
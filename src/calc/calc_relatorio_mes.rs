@@ -0,0 +1,109 @@
+use chrono::{Datelike, NaiveDate};
+
+use crate::dto::{
+    Categoria, Lancamento, OptionalLazyFn, RelatorioMes, TipoDespesa, TipoFluxo, TotalCategoria,
+};
+
+/// Resumo do mês de `mes` com os mesmos critérios do resumo do dash: entrada é
+/// valor positivo, saída é negativo e custo fixo são as despesas fixas. As
+/// saídas são somadas por categoria, da maior para a menor; `pendentes` são
+/// os lançamentos do mês ainda não categorizados.
+pub fn relatorio_mes(
+    mes: NaiveDate,
+    lancamentos: &[Lancamento],
+    pendentes: &[Lancamento],
+    categorias: &[Categoria],
+) -> RelatorioMes {
+    let do_mes = |l: &&Lancamento| l.data.year() == mes.year() && l.data.month() == mes.month();
+    let categoria = |l: &Lancamento| categorias.iter().find(|c| c.id == l.categoria.id());
+
+    let mut entradas = 0.0;
+    let mut saidas = 0.0;
+    let mut custo_fixo = 0.0;
+    let mut por_categoria: Vec<TotalCategoria> = Vec::new();
+
+    for l in lancamentos.iter().filter(do_mes) {
+        if l.valor > 0.0 {
+            entradas += l.valor;
+            continue;
+        }
+        saidas -= l.valor;
+
+        let cat = categoria(l);
+        if cat.is_some_and(|c| c.tipo == TipoFluxo::Despesa(TipoDespesa::Fixa)) {
+            custo_fixo -= l.valor;
+        }
+
+        let nome = cat.map(|c| c.to_string()).unwrap_or("Sem categoria".to_string());
+        match por_categoria.iter_mut().find(|t| t.categoria == nome) {
+            Some(t) => t.total -= l.valor,
+            None => por_categoria.push(TotalCategoria {
+                categoria: nome,
+                total: -l.valor,
+            }),
+        }
+    }
+
+    por_categoria.iter_mut().for_each(|t| t.total = arredondar(t.total));
+    por_categoria.sort_by(|a, b| b.total.total_cmp(&a.total));
+
+    RelatorioMes {
+        mes: mes.format("%m/%Y").to_string(),
+        entradas: arredondar(entradas),
+        saidas: arredondar(saidas),
+        custo_fixo: arredondar(custo_fixo),
+        saldo: arredondar(entradas - saidas),
+        categorias: por_categoria,
+        pendentes: pendentes.iter().filter(do_mes).count(),
+    }
+}
+
+fn arredondar(valor: f64) -> f64 {
+    (valor * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calc::teste::{data, lancamento, LancamentoTeste};
+
+    fn categoria(id: &str, tipo: TipoFluxo) -> Categoria {
+        Categoria {
+            id: id.to_string(),
+            nome: id.to_string(),
+            tipo,
+            pai: None,
+            carne_leao: false,
+            livro_caixa: false,
+            hierarquia: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn soma_o_mes_por_categoria() {
+        let categorias = vec![
+            categoria("Aluguel", TipoFluxo::Despesa(TipoDespesa::Fixa)),
+            categoria("Mercado", TipoFluxo::Despesa(TipoDespesa::Variavel)),
+            categoria("Salário", TipoFluxo::Receita),
+        ];
+        let lancamentos = vec![
+            lancamento(5000.0, data(5, 3)).com_categoria("Salário"),
+            lancamento(-1500.0, data(10, 3)).com_categoria("Aluguel"),
+            lancamento(-300.0, data(12, 3)).com_categoria("Mercado"),
+            lancamento(-200.5, data(20, 3)).com_categoria("Mercado"),
+            lancamento(-999.0, data(1, 4)).com_categoria("Mercado"),
+        ];
+        let pendentes = vec![lancamento(-10.0, data(15, 3)).com_categoria(""), lancamento(-10.0, data(15, 2)).com_categoria("")];
+
+        let resp = relatorio_mes(data(1, 3), &lancamentos, &pendentes, &categorias);
+
+        assert_eq!(resp.mes, "03/2025");
+        assert_eq!(resp.entradas, 5000.0);
+        assert_eq!(resp.saidas, 2000.5);
+        assert_eq!(resp.custo_fixo, 1500.0);
+        assert_eq!(resp.saldo, 2999.5);
+        assert_eq!(resp.categorias.len(), 2);
+        assert_eq!(resp.categorias[1].total, 500.5);
+        assert_eq!(resp.pendentes, 1);
+    }
+}
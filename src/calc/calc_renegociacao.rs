@@ -0,0 +1,187 @@
+use chrono::NaiveDate;
+
+use crate::dto::{DadosDivida, Divida, Unico};
+
+/// Encerra as parcelas abertas da dívida e abre um novo cronograma, ligado à
+/// original pelo campo `origem`. As parcelas encerradas ficam no histórico
+/// zeradas e marcadas como renegociadas.
+pub fn renegociar(
+    divida: &mut Divida,
+    quant: i32,
+    valor: f64,
+    dt_inicio: NaiveDate,
+) -> Option<Divida> {
+    if quant <= 0 || valor <= 0.0 || divida.parcelas.aberta().quant() == 0 {
+        return None;
+    }
+
+    divida.parcelas.iter_mut().filter(|p| !p.pago).for_each(|p| {
+        p.pago = true;
+        p.renegociada = true;
+        p.valor = 0.0;
+        p.juros = 0.0;
+        p.amortizacao = 0.0;
+        p.saldo_devedor = 0.0;
+    });
+
+    let mut nova = Divida::new(
+        divida.nome.clone(),
+        divida.prioritaria,
+        divida.cobranca_automatica,
+        quant,
+        valor,
+        dt_inicio,
+        0,
+    );
+    nova.padrao_descricao = divida.padrao_descricao.clone();
+    nova.conta = divida.conta.clone();
+    nova.origem = Some(divida.id.clone());
+    nova.gerar_id();
+
+    Some(nova)
+}
+
+/// Paga de uma vez as últimas `quant` parcelas abertas pelo `total` negociado.
+/// O total é rateado pelo valor original de cada parcela e a diferença fica
+/// como desconto; os juros das parcelas antecipadas deixam de existir.
+/// Retorna o desconto obtido.
+pub fn antecipar(divida: &mut Divida, quant: usize, total: f64) -> Option<f64> {
+    let abertas: Vec<usize> = divida
+        .parcelas
+        .iter()
+        .enumerate()
+        .filter(|(_, p)| !p.pago)
+        .map(|(i, _)| i)
+        .collect();
+
+    if quant == 0 || quant > abertas.len() {
+        return None;
+    }
+
+    let escolhidas = &abertas[abertas.len() - quant..];
+    let original: f64 = escolhidas.iter().map(|&i| divida.parcelas[i].valor).sum();
+    if total <= 0.0 || total > original {
+        return None;
+    }
+
+    let mut restante = total;
+    for (n, &i) in escolhidas.iter().enumerate() {
+        let parcela = &mut divida.parcelas[i];
+        let valor = if n == quant - 1 {
+            arredondar(restante)
+        } else {
+            arredondar(total * parcela.valor / original)
+        };
+        restante -= valor;
+
+        parcela.desconto = arredondar(parcela.valor - valor);
+        parcela.valor = valor;
+        parcela.juros = 0.0;
+        if parcela.amortizacao == 0.0 {
+            parcela.amortizacao = parcela.desconto + valor;
+        }
+        parcela.pago = true;
+    }
+
+    Some(arredondar(original - total))
+}
+
+/// Troca o valor das parcelas abertas, com a amortização sendo o que passa dos juros.
+pub fn alterar_valor(divida: &mut Divida, valor: f64) {
+    divida.parcelas.iter_mut().filter(|p| !p.pago).for_each(|p| {
+        p.valor = valor;
+        p.amortizacao = (valor - p.juros).max(0.0);
+    });
+    recalcular_saldos(divida);
+}
+
+/// Saldo devedor depois de cada parcela: o principal das parcelas seguintes.
+pub fn recalcular_saldos(divida: &mut Divida) {
+    let mut saldo = 0.0;
+    for parcela in divida.parcelas.iter_mut().rev() {
+        parcela.saldo_devedor = arredondar(saldo);
+        saldo += parcela.principal();
+    }
+}
+
+fn arredondar(valor: f64) -> f64 {
+    (valor * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calc::teste::data;
+
+    fn divida() -> Divida {
+        Divida::new("Loja".to_string(), false, false, 6, 100.0, data(10, 1), 2)
+    }
+
+    #[test]
+    fn renegociar_encerra_abertas_e_liga_a_nova() {
+        let mut original = divida();
+        let id = original.id.clone();
+        let nova = renegociar(&mut original, 8, 45.0, data(10, 4)).unwrap();
+
+        assert_eq!(original.id, id);
+        assert_eq!(original.parcelas.aberta().quant(), 0);
+        assert_eq!(original.parcelas.pagas().valor_total(), 200.0);
+        assert!(original.parcelas[5].renegociada);
+        assert_eq!(nova.origem, Some(id.clone()));
+        assert_ne!(nova.id, id);
+        assert_eq!(nova.parcelas.quant(), 8);
+        assert_eq!(nova.parcelas.primeira().data_vencimento, data(10, 4));
+    }
+
+    #[test]
+    fn dividas_com_mesmo_nome_e_vencimento_tem_ids_distintos() {
+        let (a, b) = (divida(), divida());
+
+        assert_ne!(a.id, b.id);
+    }
+
+    #[test]
+    fn renegociar_sem_parcelas_abertas_nao_faz_nada() {
+        let mut quitada = Divida::new("Loja".to_string(), false, false, 2, 100.0, data(10, 1), 2);
+
+        assert!(renegociar(&mut quitada, 3, 50.0, data(10, 4)).is_none());
+        assert!(quitada.parcelas.iter().all(|p| !p.renegociada));
+    }
+
+    #[test]
+    fn antecipar_paga_as_ultimas_com_desconto() {
+        let mut d = divida();
+        let id = d.id.clone();
+
+        assert_eq!(antecipar(&mut d, 3, 250.0), Some(50.0));
+        assert_eq!(d.id, id);
+        assert!(!d.parcelas[2].pago);
+        assert!(d.parcelas[3..].iter().all(|p| p.pago));
+        assert_eq!(d.parcelas[3..].to_vec().valor_total(), 250.0);
+        assert_eq!(d.parcelas[5].desconto, 16.66);
+        assert_eq!(d.parcelas[5].principal(), 100.0);
+    }
+
+    #[test]
+    fn alterar_valor_refaz_amortizacao_e_saldo() {
+        let mut d = divida();
+
+        alterar_valor(&mut d, 120.0);
+
+        assert_eq!(d.parcelas[1].valor, 100.0);
+        assert!(d.parcelas[2..].iter().all(|p| p.valor == 120.0 && p.principal() == 120.0));
+        assert_eq!(d.parcelas[1].saldo_devedor, 480.0);
+        assert_eq!(d.parcelas[2].saldo_devedor, 360.0);
+        assert_eq!(d.parcelas[5].saldo_devedor, 0.0);
+        assert_eq!(d.parcelas.aberta().saldo_devedor(), 480.0);
+    }
+
+    #[test]
+    fn antecipar_rejeita_total_maior_que_o_original() {
+        let mut d = divida();
+
+        assert!(antecipar(&mut d, 2, 250.0).is_none());
+        assert!(antecipar(&mut d, 5, 100.0).is_none());
+        assert_eq!(d.parcelas.aberta().quant(), 4);
+    }
+}
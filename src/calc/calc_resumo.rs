@@ -75,10 +75,8 @@ fn total_por(
 fn e_custo(l: Lancamento) -> bool {
     let mut resp = false;
     if let Some(c) = l.categoria.some() {
-        if let TipoFluxo::Despesa(g) = c.tipo {
-            if g.tipo == TipoDespesa::Fixa {
-                resp = true;
-            }
+        if c.tipo == TipoFluxo::Despesa(TipoDespesa::Fixa) {
+            resp = true;
         }
     }
 
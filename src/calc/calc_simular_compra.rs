@@ -0,0 +1,87 @@
+use chrono::{Datelike, Months, NaiveDate};
+
+use crate::dto::{DadosDivida, Divida, ParcelaDivida, SimulacaoCompraMes};
+
+/// Meses mostrados mesmo quando a compra termina antes.
+const MIN_MESES: u32 = 12;
+
+/// Soma mês a mês as parcelas das dívidas atuais e as da compra simulada e
+/// compara com o limite de endividamento, do mês de `hoje` até a última
+/// parcela da compra (no mínimo 12 meses).
+pub fn simular_compra(
+    dividas: &[Divida],
+    compra: &Divida,
+    limite: f64,
+    hoje: NaiveDate,
+) -> Vec<SimulacaoCompraMes> {
+    let inicio = hoje.with_day(1).unwrap_or(hoje);
+    let atuais: Vec<ParcelaDivida> = dividas
+        .iter()
+        .flat_map(|d| d.parcelas.iter().cloned())
+        .collect();
+
+    let quant_meses = compra
+        .parcelas
+        .last()
+        .map(|p| {
+            let meses = (p.data_vencimento.year() - inicio.year()) * 12
+                + p.data_vencimento.month() as i32
+                - inicio.month() as i32
+                + 1;
+            meses.max(0) as u32
+        })
+        .unwrap_or(0)
+        .max(MIN_MESES);
+
+    (0..quant_meses)
+        .map(|m| {
+            let mes = inicio + Months::new(m);
+            let valor_dividas = atuais.mes_e_ano(mes).valor_total();
+            let valor_compra = compra.parcelas.mes_e_ano(mes).valor_total();
+            let total = valor_dividas + valor_compra;
+
+            SimulacaoCompraMes {
+                mes: mes.format("%m/%Y").to_string(),
+                dividas: valor_dividas,
+                compra: valor_compra,
+                total,
+                excesso: (total - limite.max(0.0)).max(0.0),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calc::teste::data_ano;
+
+    #[test]
+    fn soma_a_compra_as_dividas_e_aponta_o_excesso() {
+        let dividas = vec![Divida::new("Carro".to_string(), false, false, 3, 800.0, data_ano(5, 1, 2025), 1)];
+        let compra = Divida::new("TV".to_string(), false, false, 10, 300.0, data_ano(20, 2, 2025), 0);
+
+        let meses = simular_compra(&dividas, &compra, 1000.0, data_ano(15, 1, 2025));
+
+        assert_eq!(meses.len(), 12);
+        assert_eq!(meses[0].mes, "01/2025");
+        assert_eq!(meses[0].dividas, 800.0);
+        assert_eq!(meses[0].excesso, 0.0);
+        assert_eq!(meses[1].total, 1100.0);
+        assert_eq!(meses[1].excesso, 100.0);
+        assert_eq!(meses[3].excesso, 0.0);
+        assert_eq!(meses[10].compra, 300.0);
+        assert_eq!(meses[11].compra, 0.0);
+    }
+
+    #[test]
+    fn estende_o_horizonte_ate_a_ultima_parcela() {
+        let compra = Divida::new("Sofá".to_string(), false, false, 18, 100.0, data_ano(10, 12, 2025), 0);
+
+        let meses = simular_compra(&[], &compra, 0.0, data_ano(1, 11, 2025));
+
+        assert_eq!(meses.len(), 19);
+        assert_eq!(meses.last().unwrap().mes, "05/2027");
+        assert!(meses[1..].iter().all(|m| m.excesso == 100.0));
+    }
+}
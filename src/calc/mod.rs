@@ -1,15 +1,57 @@
+mod calc_amortizacao;
+mod calc_carne_leao;
+mod calc_conciliacao;
+mod calc_contracheque;
 mod calc_gasto_por_conta;
 mod calc_lancamentos_filtros;
 mod calc_resumo;
 mod calc_dividas;
+mod calc_fluxo_caixa;
 mod calc_gasto_por_categoria_mes;
 mod calc_orientacoes;
 mod calc_gasto_por_categoria_ano;
+mod calc_grupos_categoria;
+mod calc_historico_dividas;
+mod calc_investimentos;
+mod calc_irpf;
+mod calc_metas;
+mod calc_objetivos;
+mod calc_orcamento;
+mod calc_patrimonio;
+mod calc_quitacao;
+mod calc_relatorio_mes;
+mod calc_renegociacao;
+mod calc_simular_compra;
+#[cfg(test)]
+mod teste;
 
 pub use calc_resumo::calcular_resumo;
+pub use calc_amortizacao::gerar_parcelas as gerar_parcelas_financiamento;
+pub use calc_carne_leao::{calcular_carne_leao, conciliar_carne_leao};
+pub use calc_conciliacao::{conciliar_dividas, possivelmente_em_atraso};
+pub use calc_contracheque::{comparar_contracheques, conferir_contracheque, historico_contracheques};
 pub use calc_gasto_por_conta::calcular_gasto_por_conta_d30;
 pub use calc_dividas::calcular_dividas;
+pub use calc_fluxo_caixa::projetar_fluxo_caixa;
 pub use calc_gasto_por_categoria_mes::calcular_gasto_por_categoria_d30;
 pub use calc_gasto_por_categoria_ano::calcular_gasto_por_categoria_ano;
+pub use calc_grupos_categoria::ordem_grupos as ordem_grupos_categoria;
+pub use calc_historico_dividas::totais_por_ano as historico_dividas_por_ano;
+pub use calc_investimentos::calcular_posicoes;
+pub use calc_irpf::{
+    bens_irpf, carne_leao_irpf, deducoes_irpf, dividas_irpf, outros_rendimentos_irpf,
+    rendimentos_irpf,
+};
+pub use calc_metas::avaliar_metas;
+pub use calc_objetivos::calcular_objetivos;
+pub use calc_orcamento::calcular_orcamentos;
+pub use calc_patrimonio::calcular_patrimonio;
+pub use calc_quitacao::{simular_cronograma, simular_quitacao};
+pub use calc_relatorio_mes::relatorio_mes;
+pub use calc_renegociacao::{
+    alterar_valor as alterar_valor_parcelas, antecipar as antecipar_parcelas, recalcular_saldos,
+    renegociar as renegociar_divida,
+};
+pub use calc_simular_compra::simular_compra;
 pub use calc_orientacoes::ordenar as ordenar_orientacoes;
 pub use calc_orientacoes::prioridade_por_desvio;
\ No newline at end of file
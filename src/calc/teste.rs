@@ -0,0 +1,61 @@
+//! Fábricas usadas pelos testes dos módulos de cálculo.
+
+use chrono::NaiveDate;
+
+use crate::dto::{Lancamento, OptionalLazy, TabelaTributaria};
+use crate::repository::tabelas_conhecidas;
+
+/// Data em 2025, o ano usado pela maioria dos testes.
+pub fn data(dia: u32, mes: u32) -> NaiveDate {
+    data_ano(dia, mes, 2025)
+}
+
+pub fn data_ano(dia: u32, mes: u32, ano: i32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(ano, mes, dia).unwrap()
+}
+
+/// Lançamento só com valor e data; os demais campos vêm de `LancamentoTeste`.
+pub fn lancamento(valor: f64, data: NaiveDate) -> Lancamento {
+    Lancamento {
+        valor,
+        data,
+        ..Default::default()
+    }
+}
+
+/// Tabela de INSS e IRRF de 2025, a mesma que o programa grava para o ano.
+pub fn tabela() -> TabelaTributaria {
+    tabelas_conhecidas()
+        .into_iter()
+        .find(|t| t.ano == 2025)
+        .unwrap()
+}
+
+pub trait LancamentoTeste {
+    fn com_id(self, id: &str) -> Self;
+    fn com_descricao(self, descricao: &str) -> Self;
+    fn com_categoria(self, categoria: &str) -> Self;
+    fn com_conta(self, conta: &str) -> Self;
+}
+
+impl LancamentoTeste for Lancamento {
+    fn com_id(mut self, id: &str) -> Self {
+        self.id = id.to_string();
+        self
+    }
+
+    fn com_descricao(mut self, descricao: &str) -> Self {
+        self.descricao = descricao.to_string();
+        self
+    }
+
+    fn com_categoria(mut self, categoria: &str) -> Self {
+        self.categoria = OptionalLazy::Id(categoria.to_string());
+        self
+    }
+
+    fn com_conta(mut self, conta: &str) -> Self {
+        self.conta = Some(conta.to_string());
+        self
+    }
+}
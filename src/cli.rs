@@ -0,0 +1,510 @@
+use std::{env, fs::write};
+
+use chrono::{Local, NaiveDate};
+
+use crate::{
+    calc::{calcular_patrimonio, possivelmente_em_atraso, relatorio_mes},
+    conciliar_dividas,
+    config_caminhos::{caminhos, perfis, OpcoesCaminhos},
+    dto::{Banco, CarneLeao, Categoria, Divida, Lancamento, OptionalLazyFn, Regra},
+    importar,
+    repository::{
+        ativar_criptografia, atualizar_base, criptografia_ativa, dados_bloqueados,
+        desativar_criptografia, importar_json, trocar_senha,
+    },
+    widget::GeradorDash,
+};
+
+/// Execução sem problemas.
+pub const SUCESSO: i32 = 0;
+/// Falha ao executar ou, em `verificar`, problema encontrado.
+pub const FALHA: i32 = 1;
+/// Subcomando ou argumento inválido.
+pub const USO: i32 = 2;
+
+const AJUDA: &str = "Uso: financeiro [opções] [subcomando]
+
+Sem subcomando, importa os extratos e abre o terminal interativo.
+
+Opções:
+  --perfil <nome>      perfil a usar (ou FINANCEIRO_PERFIL; padrão: pessoal)
+  --dados <dir>        diretório dos dados (ou FINANCEIRO_DADOS)
+  --importar <dir>     diretório dos OFX a importar (ou FINANCEIRO_IMPORTAR)
+  --dash <dir>         diretório do dashboard (ou FINANCEIRO_DASH)
+  --armazenamento <t>  json ou sqlite (ou FINANCEIRO_ARMAZENAMENTO; padrão: json)
+
+Perfis e diretórios também podem ser definidos em ~/.config/financeiro.json.
+
+Subcomandos:
+  importar             importa os OFX do diretório de importação e concilia as dívidas
+  dash                 gera o dashboard
+  exportar [arquivo]   exporta os lançamentos em CSV (sem arquivo, na saída padrão)
+  relatorio [mês]      resumo do mês (MM/AAAA ou AAAA-MM; padrão: mês atual)
+  regras aplicar       categoriza os pendentes que casam com alguma regra
+  verificar            confere a integridade dos dados; sai com 1 se achar problemas
+  perfis               lista os perfis e os diretórios do perfil ativo
+  sqlite importar      copia os dados dos arquivos JSON para o financeiro.db
+  criptografia ativar  cifra os dados, os snapshots e o dashboard com uma senha
+  criptografia desativar
+                       grava tudo de volta em texto puro
+  criptografia trocar  cifra tudo de novo com uma senha nova
+  ajuda                mostra esta mensagem
+
+Com a criptografia ativa, a senha é pedida ao abrir o terminal interativo; nos
+subcomandos vem de FINANCEIRO_SENHA. A senha nova de ativar e trocar vem de
+FINANCEIRO_SENHA_NOVA ou é pedida duas vezes.
+
+Códigos de saída: 0 sucesso, 1 falha ou problema encontrado, 2 uso inválido.";
+
+pub enum Comando {
+    Importar,
+    Dash,
+    Exportar(Option<String>),
+    Relatorio(NaiveDate),
+    AplicarRegras,
+    Verificar,
+    Perfis,
+    ImportarSqlite,
+    AtivarCriptografia,
+    DesativarCriptografia,
+    TrocarSenha,
+    Ajuda,
+}
+
+impl Comando {
+    /// Subcomandos que podem pedir a senha atual no terminal, se não vier de `FINANCEIRO_SENHA`.
+    pub fn pede_senha(&self) -> bool {
+        matches!(
+            self,
+            Comando::AtivarCriptografia | Comando::DesativarCriptografia | Comando::TrocarSenha
+        )
+    }
+
+    /// Comandos que podem concluir uma ativação ou troca de senha interrompida.
+    pub fn retoma_criptografia(&self) -> bool {
+        matches!(self, Comando::AtivarCriptografia | Comando::TrocarSenha)
+    }
+}
+
+/// Separa as opções de diretório e perfil, que podem vir antes ou depois do subcomando.
+pub fn separar_opcoes(args: &[String]) -> Result<(OpcoesCaminhos, Vec<String>), String> {
+    let mut opcoes = OpcoesCaminhos::default();
+    let mut resto = Vec::new();
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        let destino = match arg.as_str() {
+            "--perfil" => &mut opcoes.perfil,
+            "--dados" => &mut opcoes.dados,
+            "--importar" => &mut opcoes.importar,
+            "--dash" => &mut opcoes.dash,
+            "--armazenamento" => &mut opcoes.armazenamento,
+            _ => {
+                resto.push(arg.clone());
+                continue;
+            }
+        };
+        match args.next() {
+            Some(valor) if !valor.starts_with("--") => *destino = Some(valor.clone()),
+            _ => return Err(format!("{} precisa de um valor", arg)),
+        }
+    }
+    Ok((opcoes, resto))
+}
+
+/// Lê o subcomando dos argumentos (sem o nome do programa); `None` abre o terminal interativo.
+pub fn interpretar(args: &[String]) -> Result<Option<Comando>, String> {
+    let args: Vec<&str> = args.iter().map(|a| a.as_str()).collect();
+
+    let comando = match args.as_slice() {
+        [] => return Ok(None),
+        ["importar"] => Comando::Importar,
+        ["dash"] => Comando::Dash,
+        ["exportar"] => Comando::Exportar(None),
+        ["exportar", arquivo] => Comando::Exportar(Some(arquivo.to_string())),
+        ["relatorio"] => Comando::Relatorio(Local::now().date_naive()),
+        ["relatorio", mes] => Comando::Relatorio(
+            ler_mes(mes).ok_or(format!("mês inválido: {} (use MM/AAAA ou AAAA-MM)", mes))?,
+        ),
+        ["regras", "aplicar"] => Comando::AplicarRegras,
+        ["verificar"] => Comando::Verificar,
+        ["perfis"] => Comando::Perfis,
+        ["sqlite", "importar"] => Comando::ImportarSqlite,
+        ["criptografia", "ativar"] => Comando::AtivarCriptografia,
+        ["criptografia", "desativar"] => Comando::DesativarCriptografia,
+        ["criptografia", "trocar"] => Comando::TrocarSenha,
+        ["ajuda"] | ["-h"] | ["--help"] => Comando::Ajuda,
+        _ => return Err(format!("subcomando desconhecido: {}", args.join(" "))),
+    };
+    Ok(Some(comando))
+}
+
+fn ler_mes(texto: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(&format!("01/{}", texto), "%d/%m/%Y")
+        .or_else(|_| NaiveDate::parse_from_str(&format!("{}-01", texto), "%Y-%m-%d"))
+        .ok()
+}
+
+pub fn uso(erro: &str) -> i32 {
+    eprintln!("{}\n\n{}", erro, AJUDA);
+    USO
+}
+
+/// Executa o subcomando e devolve o código de saída do processo.
+pub fn executar(comando: Comando) -> i32 {
+    match comando {
+        Comando::Importar => cmd_importar(),
+        Comando::Dash => cmd_dash(),
+        Comando::Exportar(arquivo) => cmd_exportar(arquivo),
+        Comando::Relatorio(mes) => cmd_relatorio(mes),
+        Comando::AplicarRegras => cmd_aplicar_regras(),
+        Comando::Verificar => cmd_verificar(),
+        Comando::Perfis => cmd_perfis(),
+        Comando::ImportarSqlite => cmd_importar_sqlite(),
+        Comando::AtivarCriptografia => cmd_criptografia(|| ativar_criptografia(&senha_nova()?)),
+        Comando::DesativarCriptografia => cmd_criptografia(desativar_criptografia),
+        Comando::TrocarSenha => cmd_criptografia(|| trocar_senha(&senha_nova()?)),
+        Comando::Ajuda => {
+            println!("{}", AJUDA);
+            SUCESSO
+        }
+    }
+}
+
+fn cmd_importar() -> i32 {
+    let antes = Lancamento::nao_categorizados_listar().len();
+    let (lidos, operacoes) = importar();
+    let novos = Lancamento::nao_categorizados_listar()
+        .len()
+        .saturating_sub(antes);
+    let conciliadas = conciliar_dividas();
+    Divida::atualizar();
+
+    println!(
+        "{} lançamentos lidos, {} novos pendentes de categorização",
+        lidos, novos
+    );
+    if operacoes > 0 {
+        println!("{} operações de investimento importadas", operacoes);
+    }
+    println!("{} parcelas de dívidas conciliadas", conciliadas);
+    codigo_gravacao()
+}
+
+fn cmd_dash() -> i32 {
+    Divida::atualizar();
+    let etapas = GeradorDash::new().gerar();
+    println!(
+        "Dashboard gerado em {}: {}",
+        caminhos().dash.display(),
+        etapas.join(", ")
+    );
+    codigo_gravacao()
+}
+
+fn cmd_exportar(arquivo: Option<String>) -> i32 {
+    let categorias = Categoria::listar();
+    let contas: Vec<(String, String)> = Banco::listar()
+        .iter()
+        .flat_map(|b| {
+            b.contas
+                .iter()
+                .map(move |c| (c.id.clone(), format!("{} - {}", b.nome, c.nome)))
+        })
+        .collect();
+
+    let mut lancamentos = Lancamento::lancamentos_listar();
+    lancamentos.extend(Lancamento::nao_categorizados_listar());
+    lancamentos.sort_by_key(|l| l.data);
+
+    let mut linhas = vec!["data;descricao;valor;categoria;conta".to_string()];
+    linhas.extend(lancamentos.iter().map(|l| {
+        let id = l.categoria.id();
+        let conta = l.conta.clone().unwrap_or_default();
+        format!(
+            "{};{};{:.2};{};{}",
+            l.data.format("%Y-%m-%d"),
+            l.descricao.replace(';', ","),
+            l.valor,
+            categorias
+                .iter()
+                .find(|c| c.id == id)
+                .map(|c| c.to_string())
+                .unwrap_or_default(),
+            contas
+                .iter()
+                .find(|(id, _)| *id == conta)
+                .map(|(_, nome)| nome.clone())
+                .unwrap_or(conta),
+        )
+    }));
+    let csv = linhas.join("\n") + "\n";
+
+    match arquivo {
+        None => {
+            print!("{}", csv);
+            SUCESSO
+        }
+        Some(arquivo) => match write(&arquivo, csv) {
+            Ok(_) => {
+                println!(
+                    "{} lançamentos exportados para {}",
+                    lancamentos.len(),
+                    arquivo
+                );
+                SUCESSO
+            }
+            Err(erro) => {
+                eprintln!("Falha ao escrever {}: {}", arquivo, erro);
+                FALHA
+            }
+        },
+    }
+}
+
+fn cmd_relatorio(mes: NaiveDate) -> i32 {
+    let r = relatorio_mes(
+        mes,
+        &Lancamento::lancamentos_listar(),
+        &Lancamento::nao_categorizados_listar(),
+        &Categoria::listar(),
+    );
+
+    println!("Relatório de {}", r.mes);
+    println!("  Entradas:    R$ {:>12.2}", r.entradas);
+    println!("  Saídas:      R$ {:>12.2}", r.saidas);
+    println!("  Custo fixo:  R$ {:>12.2}", r.custo_fixo);
+    println!("  Saldo:       R$ {:>12.2}", r.saldo);
+    println!();
+    println!("Saídas por categoria:");
+    for c in r.categorias.iter() {
+        println!("  {:<50} R$ {:>12.2}", c.categoria, c.total);
+    }
+    if r.pendentes > 0 {
+        println!();
+        println!("{} lançamentos do mês ainda sem categoria", r.pendentes);
+    }
+    SUCESSO
+}
+
+fn cmd_aplicar_regras() -> i32 {
+    let (encontrados, pendentes) = Lancamento::aplicar_regras();
+
+    Lancamento::lancamentos_adicionar(&encontrados);
+    Lancamento::nao_categorizados_salvar(&pendentes);
+    Regra::remover_sem_categoria();
+
+    println!(
+        "{} lançamentos categorizados, {} ainda pendentes",
+        encontrados.len(),
+        pendentes.len()
+    );
+    codigo_gravacao()
+}
+
+/// Código de saída de um subcomando que grava dados: `FALHA` se algum arquivo
+/// ou tabela não pôde ser lido, porque a gravação dele foi recusada.
+fn codigo_gravacao() -> i32 {
+    let bloqueados = dados_bloqueados();
+    for arquivo in bloqueados.iter() {
+        eprintln!("PROBLEMA: {} não pôde ser lido e não foi gravado", arquivo);
+    }
+    match bloqueados.is_empty() {
+        true => SUCESSO,
+        false => FALHA,
+    }
+}
+
+fn cmd_verificar() -> i32 {
+    let hoje = Local::now().date_naive();
+    let lancamentos = Lancamento::lancamentos_listar();
+    let pendentes = Lancamento::nao_categorizados_listar();
+    let categorias = Categoria::listar();
+    let dividas = Divida::listar();
+
+    let mut problemas: Vec<String> = Vec::new();
+
+    let sem_categoria = lancamentos
+        .iter()
+        .filter(|l| {
+            let id = l.categoria.id();
+            !categorias.iter().any(|c| c.id == id)
+        })
+        .count();
+    if sem_categoria > 0 {
+        problemas.push(format!(
+            "{} lançamentos com categoria inexistente",
+            sem_categoria
+        ));
+    }
+
+    let mut ids: Vec<&str> = lancamentos.iter().map(|l| l.id.as_str()).collect();
+    ids.sort();
+    let total = ids.len();
+    ids.dedup();
+    if ids.len() < total {
+        problemas.push(format!("{} lançamentos duplicados", total - ids.len()));
+    }
+
+    let mut todos = lancamentos.clone();
+    todos.extend(pendentes.iter().cloned());
+    let mut com_arquivadas = dividas.clone();
+    com_arquivadas.extend(Divida::listar_arquivadas());
+    // só os saldos das contas interessam; a carteira fica de fora
+    calcular_patrimonio(&Banco::listar(), &todos, &com_arquivadas, &[], &[], &[], hoje)
+        .contas
+        .iter()
+        .filter(|c| c.diferenca.is_some_and(|d| d.abs() >= 0.01))
+        .for_each(|c| {
+            problemas.push(format!(
+                "saldo de {} - {} difere do extrato em R$ {:.2}",
+                c.banco,
+                c.conta,
+                c.diferenca.unwrap_or_default()
+            ))
+        });
+
+    for d in dividas.iter() {
+        let atrasadas = d
+            .parcelas
+            .iter()
+            .filter(|p| possivelmente_em_atraso(d, p, hoje))
+            .count();
+        if atrasadas > 0 {
+            problemas.push(format!(
+                "{}: {} parcelas possivelmente em atraso",
+                d.nome, atrasadas
+            ));
+        }
+    }
+
+    CarneLeao::listar()
+        .iter()
+        .filter(|c| c.atrasado(hoje))
+        .for_each(|c| {
+            problemas.push(format!(
+                "carnê-leão de {} vencido em {} sem DARF encontrado",
+                c.mes.format("%m/%Y"),
+                c.vencimento.format("%d/%m/%Y")
+            ))
+        });
+
+    dados_bloqueados().iter().for_each(|arquivo| {
+        problemas.push(format!(
+            "{} não pôde ser lido e está protegido contra gravação",
+            arquivo
+        ))
+    });
+
+    for p in problemas.iter() {
+        println!("PROBLEMA: {}", p);
+    }
+    if !pendentes.is_empty() {
+        println!(
+            "AVISO: {} lançamentos pendentes de categorização",
+            pendentes.len()
+        );
+    }
+
+    if problemas.is_empty() {
+        println!("Nenhum problema encontrado");
+        SUCESSO
+    } else {
+        println!("{} problemas encontrados", problemas.len());
+        FALHA
+    }
+}
+
+fn cmd_perfis() -> i32 {
+    let c = caminhos();
+    let perfis = perfis();
+    for perfil in perfis.iter() {
+        let marca = if *perfil == c.perfil { "*" } else { " " };
+        println!("{} {}", marca, perfil);
+    }
+    if !perfis.contains(&c.perfil) {
+        println!("* {} (sem configuração no arquivo)", c.perfil);
+    }
+    println!();
+    println!("Dados:      {}", c.dados.display());
+    println!("Importar:   {}", c.importar.display());
+    println!("Importados: {}", c.importado.display());
+    println!("Dashboard:  {}", c.dash.display());
+    println!("Exportação: {}", c.exportar.display());
+    println!("Armazenamento: {:?}", c.armazenamento);
+    println!("Snapshots mantidos: {}", c.snapshots);
+    println!("Criptografia: {}", estado_criptografia());
+    SUCESSO
+}
+
+fn cmd_importar_sqlite() -> i32 {
+    match importar_json() {
+        Ok(resumo) => {
+            for (tabela, lidos, gravados) in resumo {
+                if lidos == gravados {
+                    println!("{:<12} {}", tabela, gravados);
+                } else {
+                    println!(
+                        "{:<12} {} de {} (sem categoria válida)",
+                        tabela, gravados, lidos
+                    );
+                }
+            }
+            println!(
+                "Dados copiados para {}; use --armazenamento sqlite ou \"armazenamento\": \"sqlite\" no perfil",
+                caminhos().dados.join("financeiro.db").display()
+            );
+            SUCESSO
+        }
+        Err(erro) => {
+            eprintln!("Falha ao importar para o SQLite: {}", erro);
+            FALHA
+        }
+    }
+}
+
+fn cmd_criptografia(acao: impl FnOnce() -> Result<usize, String>) -> i32 {
+    match acao() {
+        Ok(gravados) => {
+            atualizar_base();
+            println!(
+                "{} arquivos gravados; criptografia {}",
+                gravados,
+                estado_criptografia()
+            );
+            SUCESSO
+        }
+        Err(erro) => {
+            log::error!("Erro na criptografia: {}", erro);
+            eprintln!("Falha: {}", erro);
+            FALHA
+        }
+    }
+}
+
+fn estado_criptografia() -> &'static str {
+    match criptografia_ativa() {
+        true => "ativa",
+        false => "desativada",
+    }
+}
+
+/// Senha nova de `FINANCEIRO_SENHA_NOVA` ou digitada duas vezes no terminal.
+fn senha_nova() -> Result<String, String> {
+    let senha = match env::var("FINANCEIRO_SENHA_NOVA") {
+        Ok(senha) => senha,
+        Err(_) => {
+            let senha = rpassword::prompt_password("Senha nova: ").map_err(|e| e.to_string())?;
+            let confirmacao =
+                rpassword::prompt_password("Repita a senha: ").map_err(|e| e.to_string())?;
+            if senha != confirmacao {
+                return Err("as senhas não conferem".to_string());
+            }
+            senha
+        }
+    };
+    if senha.is_empty() {
+        return Err("a senha não pode ser vazia".to_string());
+    }
+    Ok(senha)
+}
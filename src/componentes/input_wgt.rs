@@ -97,7 +97,7 @@ impl Input {
         self.cursor = 0usize;
     }
 
-    pub fn _set_data(&mut self, valor: String) {
+    pub fn set_data(&mut self, valor: String) {
         self.valor = valor;
         self.cursor = 0usize;
         self.formatar();
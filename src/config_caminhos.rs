@@ -0,0 +1,266 @@
+use std::{
+    env,
+    fs::read_to_string,
+    path::{Path, PathBuf},
+    sync::{LazyLock, OnceLock},
+};
+
+use serde::Deserialize;
+
+/// Perfil usado quando nenhum é escolhido; mantém os diretórios de sempre.
+pub const PERFIL_PADRAO: &str = "pessoal";
+
+/// Snapshots do diretório de dados mantidos quando o perfil não define outro número.
+const SNAPSHOTS_PADRAO: usize = 30;
+
+/// Arquivo de perfis, relativo à pasta do usuário.
+const ARQUIVO_PERFIS: &str = ".config/financeiro.json";
+
+static HOME_DIR: LazyLock<PathBuf> = LazyLock::new(get_home_dir_path);
+static CAMINHOS: OnceLock<Caminhos> = OnceLock::new();
+
+#[cfg(debug_assertions)]
+fn get_home_dir_path() -> PathBuf {
+    let mut home = env::current_dir().unwrap();
+    home.push("../baseTest");
+    home
+}
+
+#[cfg(not(debug_assertions))]
+fn get_home_dir_path() -> PathBuf {
+    use homedir::my_home;
+
+    PathBuf::from(my_home().unwrap().unwrap())
+}
+
+pub fn get_home_dir() -> PathBuf {
+    HOME_DIR.clone()
+}
+
+/// Diretórios do perfil ativo.
+#[derive(Debug, Clone)]
+pub struct Caminhos {
+    pub perfil: String,
+    /// JSONs, tabelas e log.
+    pub dados: PathBuf,
+    /// OFX a importar.
+    pub importar: PathBuf,
+    /// Para onde os OFX vão depois de importados.
+    pub importado: PathBuf,
+    /// Dashboard gerado.
+    pub dash: PathBuf,
+    /// Relatórios exportados.
+    pub exportar: PathBuf,
+    /// Onde ficam lançamentos, categorias, regras, dívidas, metas, bancos e configuração.
+    pub armazenamento: TipoArmazenamento,
+    /// Quantos snapshots do diretório de dados manter; 0 desliga os automáticos.
+    pub snapshots: usize,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum TipoArmazenamento {
+    /// Um arquivo JSON por entidade, reescrito a cada gravação.
+    #[default]
+    Json,
+    /// `financeiro.db` no diretório de dados, com chaves estrangeiras.
+    Sqlite,
+}
+
+impl TipoArmazenamento {
+    fn ler(valor: &str) -> Result<Self, String> {
+        match valor.trim().to_lowercase().as_str() {
+            "json" => Ok(Self::Json),
+            "sqlite" => Ok(Self::Sqlite),
+            outro => Err(format!(
+                "armazenamento inválido: {} (use json ou sqlite)",
+                outro
+            )),
+        }
+    }
+}
+
+/// Valores vindos da linha de comando; têm prioridade sobre ambiente e arquivo.
+#[derive(Debug, Default)]
+pub struct OpcoesCaminhos {
+    pub perfil: Option<String>,
+    pub dados: Option<String>,
+    pub importar: Option<String>,
+    pub dash: Option<String>,
+    pub armazenamento: Option<String>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct ArquivoPerfis {
+    perfil: Option<String>,
+    perfis: Vec<Perfil>,
+}
+
+#[derive(Deserialize, Default, Clone)]
+#[serde(default)]
+struct Perfil {
+    nome: String,
+    dados: Option<String>,
+    importar: Option<String>,
+    importado: Option<String>,
+    dash: Option<String>,
+    exportar: Option<String>,
+    armazenamento: Option<String>,
+    snapshots: Option<usize>,
+}
+
+/// Recusa o nome de perfil que sairia dos diretórios padrão: vazio, com separador
+/// de caminho ou `..`. Um arquivo de perfis ilegível fica para `configurar`.
+pub fn validar_perfil(opcoes: &OpcoesCaminhos) -> Result<(), String> {
+    let nome = nome_perfil(opcoes.perfil.clone(), &ler_arquivo().unwrap_or_default());
+    conferir_perfil(&nome)
+}
+
+/// Resolve os diretórios do perfil ativo. Deve ser chamada uma vez, antes do log
+/// e de qualquer leitura; chamadas seguintes são ignoradas.
+pub fn configurar(opcoes: OpcoesCaminhos) -> Result<(), String> {
+    let caminhos = resolver(opcoes, &ler_arquivo()?)?;
+    let _ = CAMINHOS.set(caminhos);
+    Ok(())
+}
+
+/// Diretórios do perfil ativo; sem `configurar`, usa ambiente, arquivo e padrões.
+pub fn caminhos() -> &'static Caminhos {
+    CAMINHOS.get_or_init(|| {
+        let arquivo = ler_arquivo().unwrap_or_default();
+        resolver(OpcoesCaminhos::default(), &arquivo).unwrap_or_else(|_| {
+            let opcoes = OpcoesCaminhos {
+                armazenamento: Some("json".to_string()),
+                ..Default::default()
+            };
+            resolver(opcoes, &arquivo).unwrap()
+        })
+    })
+}
+
+/// Perfis do arquivo de configuração, com o padrão sempre presente.
+pub fn perfis() -> Vec<String> {
+    let mut nomes = vec![PERFIL_PADRAO.to_string()];
+    for perfil in ler_arquivo().unwrap_or_default().perfis {
+        if !nomes.contains(&perfil.nome) {
+            nomes.push(perfil.nome);
+        }
+    }
+    nomes
+}
+
+fn ler_arquivo() -> Result<ArquivoPerfis, String> {
+    let path = get_home_dir().join(ARQUIVO_PERFIS);
+    match read_to_string(&path) {
+        Ok(json) if json.trim().is_empty() => Ok(ArquivoPerfis::default()),
+        Ok(json) => serde_json::from_str(&json)
+            .map_err(|erro| format!("Erro ao ler {}: {}", path.display(), erro)),
+        Err(_) => Ok(ArquivoPerfis::default()),
+    }
+}
+
+fn nome_perfil(opcao: Option<String>, arquivo: &ArquivoPerfis) -> String {
+    opcao
+        .or_else(|| variavel("FINANCEIRO_PERFIL"))
+        .or(arquivo.perfil.clone())
+        .unwrap_or(PERFIL_PADRAO.to_string())
+}
+
+fn conferir_perfil(nome: &str) -> Result<(), String> {
+    if nome.trim().is_empty()
+        || nome.contains(['/', '\\'])
+        || nome.contains("..")
+        || nome == "."
+    {
+        return Err(format!(
+            "perfil inválido: {:?} (o nome não pode ter /, \\ nem ..)",
+            nome
+        ));
+    }
+    Ok(())
+}
+
+fn resolver(opcoes: OpcoesCaminhos, arquivo: &ArquivoPerfis) -> Result<Caminhos, String> {
+    let home = get_home_dir();
+    let perfil = nome_perfil(opcoes.perfil, arquivo);
+    conferir_perfil(&perfil)?;
+
+    let conf = arquivo
+        .perfis
+        .iter()
+        .find(|p| p.nome == perfil)
+        .cloned()
+        .unwrap_or_default();
+
+    let padrao = |dir: &str, sufixo: &str| {
+        let mut path = home.join(dir);
+        if perfil != PERFIL_PADRAO {
+            path.push(sufixo);
+        }
+        path
+    };
+
+    let dados = opcoes
+        .dados
+        .or_else(|| variavel("FINANCEIRO_DADOS"))
+        .or(conf.dados)
+        .map(|d| expandir(&home, &d))
+        .unwrap_or_else(|| match perfil.as_str() {
+            PERFIL_PADRAO => home.join(".financeiro"),
+            outro => home.join(format!(".financeiro-{}", outro)),
+        });
+    let importar = opcoes
+        .importar
+        .or_else(|| variavel("FINANCEIRO_IMPORTAR"))
+        .or(conf.importar)
+        .map(|d| expandir(&home, &d))
+        .unwrap_or_else(|| padrao("Downloads/importar", &perfil));
+    let importado = conf
+        .importado
+        .map(|d| expandir(&home, &d))
+        .unwrap_or_else(|| padrao("Downloads/importado", &perfil));
+    let dash = opcoes
+        .dash
+        .or_else(|| variavel("FINANCEIRO_DASH"))
+        .or(conf.dash)
+        .map(|d| expandir(&home, &d))
+        .unwrap_or_else(|| padrao("Financeiro", &perfil));
+    let exportar = conf
+        .exportar
+        .map(|d| expandir(&home, &d))
+        .unwrap_or_else(|| home.join("Downloads"));
+    let armazenamento = match opcoes
+        .armazenamento
+        .or_else(|| variavel("FINANCEIRO_ARMAZENAMENTO"))
+        .or(conf.armazenamento)
+    {
+        Some(valor) => TipoArmazenamento::ler(&valor)?,
+        None => TipoArmazenamento::default(),
+    };
+    let snapshots = conf.snapshots.unwrap_or(SNAPSHOTS_PADRAO);
+
+    Ok(Caminhos {
+        perfil,
+        dados,
+        importar,
+        importado,
+        dash,
+        exportar,
+        armazenamento,
+        snapshots,
+    })
+}
+
+fn variavel(nome: &str) -> Option<String> {
+    env::var(nome).ok().filter(|v| !v.trim().is_empty())
+}
+
+/// `~/x` e caminhos relativos partem da pasta do usuário.
+fn expandir(home: &Path, dir: &str) -> PathBuf {
+    let dir = dir.strip_prefix("~/").unwrap_or(dir);
+    if dir == "~" {
+        home.to_path_buf()
+    } else {
+        home.join(dir)
+    }
+}
@@ -6,7 +6,7 @@ use log4rs::append::file::FileAppender;
 use log4rs::config::{Appender, Config, Root};
 use log4rs::encode::pattern::PatternEncoder;
 
-use crate::get_home_dir;
+use crate::config_caminhos::caminhos;
 
 pub fn config() {
     let mut path = diretorio_base();
@@ -30,9 +30,7 @@ pub fn config() {
 }
 
 fn diretorio_base() -> std::path::PathBuf {
-    let mut path = get_home_dir();
-    path.push(".financeiro/log/");
-    path
+    caminhos().dados.join("log")
 }
 
 fn remover_apos_30_dias(path: std::path::PathBuf) {
@@ -0,0 +1,33 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// Apuração mensal do carnê-leão; `mes` é o primeiro dia do mês dos rendimentos.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CarneLeao {
+    pub mes: NaiveDate,
+    pub rendimentos: f64,
+    pub livro_caixa: f64,
+    pub dependentes: f64,
+    /// Usou o desconto simplificado no lugar das deduções legais.
+    pub simplificado: bool,
+    pub base: f64,
+    pub imposto: f64,
+    /// Último dia útil do mês seguinte.
+    pub vencimento: NaiveDate,
+
+    /// Lançamento do DARF que pagou o imposto, quando encontrado.
+    #[serde(default)]
+    pub lancamento: Option<String>,
+    #[serde(default)]
+    pub data_pagamento: Option<NaiveDate>,
+}
+
+impl CarneLeao {
+    pub fn pago(&self) -> bool {
+        self.lancamento.is_some()
+    }
+
+    pub fn atrasado(&self, hoje: NaiveDate) -> bool {
+        self.imposto > 0.0 && !self.pago() && hoje > self.vencimento
+    }
+}
@@ -3,8 +3,8 @@ use std::fmt::{self, Formatter, Result};
 use serde::{Deserialize, Serialize};
 
 use super::{
-    gerar_sha1, lazy::LazyFn, optional_lazy::OptionalLazyFn, GrupoDespesa, Lazy, OptionalLazy,
-    TipoFluxo, Unico,
+    gerar_sha1, lazy::LazyFn, optional_lazy::OptionalLazyFn, CategoriaLegada, Lazy, OptionalLazy,
+    TipoDespesa, TipoFluxo, TipoFluxoLegado, Unico,
 };
 
 #[derive(Debug, Clone, Eq, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
@@ -12,6 +12,31 @@ pub struct Categoria {
     pub id: String,
     pub nome: String,
     pub tipo: TipoFluxo,
+
+    #[serde(default)]
+    pub pai: Option<String>,
+
+    /// Receita tributada pelo carnê-leão (aluguéis e trabalho recebidos de pessoa física).
+    #[serde(default)]
+    pub carne_leao: bool,
+    /// Despesa dedutível no livro-caixa do carnê-leão.
+    #[serde(default)]
+    pub livro_caixa: bool,
+
+    /// Nomes dos ancestrais (da raiz até o pai), preenchido ao listar.
+    #[serde(skip)]
+    pub hierarquia: Vec<String>,
+}
+
+pub trait ArvoreCategorias {
+    fn buscar_id(&self, id: &str) -> Option<&Categoria>;
+    fn ancestrais(&self, cat: &Categoria) -> Vec<Categoria>;
+    fn caminho(&self, cat: &Categoria) -> Vec<String>;
+    fn filhos(&self, id: &str) -> Vec<Categoria>;
+    fn descendentes(&self, id: &str) -> Vec<Categoria>;
+
+    fn garantir_grupos(&mut self, tipo: TipoFluxo, grupos: &[&str]) -> Option<String>;
+    fn preencher_hierarquia(&mut self);
 }
 
 impl LazyFn<Categoria> for Lazy<Categoria> {
@@ -28,6 +53,10 @@ impl LazyFn<Categoria> for Lazy<Categoria> {
                 id: id.clone(),
                 nome: String::new(),
                 tipo: TipoFluxo::SemCategoria,
+                pai: None,
+                carne_leao: false,
+                livro_caixa: false,
+                hierarquia: Vec::new(),
             },
             Lazy::Some(cat) => cat.clone(),
         }
@@ -49,6 +78,10 @@ impl OptionalLazyFn<Categoria> for OptionalLazy<Categoria> {
                 id: id.clone(),
                 nome: String::new(),
                 tipo: TipoFluxo::SemCategoria,
+                pai: None,
+                carne_leao: false,
+                livro_caixa: false,
+                hierarquia: Vec::new(),
             }),
             OptionalLazy::Some(cat) => Some(cat.clone()),
             OptionalLazy::None => None,
@@ -61,7 +94,8 @@ impl Unico for Categoria {
         let mut itens: Vec<String> = Vec::new();
 
         itens.push(self.nome.clone());
-        itens.push(self.tipo.to_string());
+        itens.push(format!("{:?}", self.tipo));
+        itens.push(self.pai.clone().unwrap_or_default());
 
         self.id = gerar_sha1(itens.join(":"));
     }
@@ -69,7 +103,101 @@ impl Unico for Categoria {
 
 impl fmt::Display for Categoria {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        f.write_fmt(format_args!("{}{}", self.tipo, self.nome))
+        let mut partes: Vec<String> = vec![self.tipo.to_string()];
+        partes.extend(self.hierarquia.iter().cloned());
+        if !self.nome.is_empty() {
+            partes.push(self.nome.clone());
+        }
+        f.write_str(&partes.join("; "))
+    }
+}
+
+impl ArvoreCategorias for Vec<Categoria> {
+    fn buscar_id(&self, id: &str) -> Option<&Categoria> {
+        self.iter().find(|c| c.id == id)
+    }
+
+    /// Ancestrais da categoria, da raiz até o pai direto.
+    fn ancestrais(&self, cat: &Categoria) -> Vec<Categoria> {
+        let mut resp: Vec<Categoria> = Vec::new();
+        let mut pai = cat.pai.clone();
+
+        while let Some(id) = pai {
+            match self.buscar_id(&id) {
+                Some(p) if resp.len() < self.len() && p.id != cat.id => {
+                    pai = p.pai.clone();
+                    resp.insert(0, p.clone());
+                }
+                _ => break,
+            }
+        }
+
+        resp
+    }
+
+    /// Nomes do tipo de fluxo até a própria categoria, ex: `["Despesa", "Fixa", "Moradia", "Internet"]`
+    fn caminho(&self, cat: &Categoria) -> Vec<String> {
+        let mut resp = vec![cat.tipo.to_string()];
+        resp.extend(self.ancestrais(cat).into_iter().map(|c| c.nome));
+        if !cat.nome.is_empty() {
+            resp.push(cat.nome.clone());
+        }
+        resp
+    }
+
+    fn filhos(&self, id: &str) -> Vec<Categoria> {
+        self.iter()
+            .filter(|c| c.pai.as_deref() == Some(id))
+            .cloned()
+            .collect()
+    }
+
+    fn descendentes(&self, id: &str) -> Vec<Categoria> {
+        let mut resp: Vec<Categoria> = Vec::new();
+        let mut pendentes = vec![id.to_string()];
+
+        while let Some(atual) = pendentes.pop() {
+            for filho in self.filhos(&atual) {
+                if filho.id != id && !resp.iter().any(|r| r.id == filho.id) {
+                    pendentes.push(filho.id.clone());
+                    resp.push(filho);
+                }
+            }
+        }
+
+        resp
+    }
+
+    /// Garante que a sequência de grupos exista como categorias encadeadas e
+    /// retorna o id do último, para ser usado como pai.
+    fn garantir_grupos(&mut self, tipo: TipoFluxo, grupos: &[&str]) -> Option<String> {
+        let mut pai: Option<String> = None;
+
+        for nome in grupos.iter().filter(|n| !n.is_empty()) {
+            let existente = self
+                .iter()
+                .find(|c| c.tipo == tipo && c.pai == pai && c.nome == *nome)
+                .map(|c| c.id.clone());
+
+            pai = Some(match existente {
+                Some(id) => id,
+                None => {
+                    let grupo = Categoria::new(nome, tipo.clone(), pai.clone());
+                    let id = grupo.id.clone();
+                    self.push(grupo);
+                    id
+                }
+            });
+        }
+
+        pai
+    }
+
+    fn preencher_hierarquia(&mut self) {
+        let base = self.clone();
+        self.iter_mut().for_each(|c| {
+            c.hierarquia = base.ancestrais(c).into_iter().map(|a| a.nome).collect();
+        });
     }
 }
 
@@ -77,67 +205,57 @@ impl Categoria {
     pub fn lista_padrao() -> Vec<Categoria> {
         let mut resp: Vec<Categoria> = Vec::new();
 
-        despesa(&mut resp, "Açougues", "Abastecimento", "Variavel");
-        despesa(&mut resp, "Padarias", "Abastecimento", "Variavel");
-        despesa(&mut resp, "Peixarias", "Abastecimento", "Variavel");
-        despesa(&mut resp, "Supermercados", "Abastecimento", "Variavel");
-        despesa(&mut resp, "Verdurarias", "Abastecimento", "Variavel");
-        despesa(
-            &mut resp,
-            "Cosméticos e perfumarias",
-            "Bem estar",
-            "Variavel",
-        );
-        despesa(&mut resp, "Tratamentos estéticos", "Bem estar", "Variavel");
-        despesa(&mut resp, "Vestuário", "Bem estar", "Variavel");
-        despesa(&mut resp, "Materiais escolar", "Educação", "Variavel");
-        despesa(&mut resp, "Bares", "Lazer", "Variavel");
-        despesa(&mut resp, "Eventos", "Lazer", "Variavel");
-        despesa(&mut resp, "Hospedagens", "Lazer", "Variavel");
-        despesa(&mut resp, "Lanches", "Lazer", "Variavel");
-        despesa(&mut resp, "Restaurantes", "Lazer", "Variavel");
-        despesa(&mut resp, "Ferramentas", "Moradia", "Variavel");
-        despesa(
-            &mut resp,
-            "Móveis e eletrodomésticos",
-            "Moradia",
-            "Variavel",
-        );
-        despesa(&mut resp, "Obras e manutenções", "Moradia", "Variavel");
-        despesa(&mut resp, "Medicamentos", "Saúde", "Variavel");
-        despesa(&mut resp, "Consultas", "Saúde", "Variavel");
-        despesa(&mut resp, "Planos de saúde", "Saúde", "Variavel");
-        despesa(&mut resp, "Cartão de crédito", "Transações", "Variavel");
-        despesa(&mut resp, "Doações", "Transações", "Variavel");
-        despesa(&mut resp, "Saques", "Transações", "Variavel");
-        despesa(&mut resp, "Combustível", "Transporte", "Variavel");
-        despesa(&mut resp, "Estacionamentos", "Transporte", "Variavel");
-        despesa(&mut resp, "Manutenção veicular", "Transporte", "Variavel");
-        despesa(&mut resp, "Pedágios e translados", "Transporte", "Variavel");
-        despesa(&mut resp, "Passagens", "Transporte", "Variavel");
-        despesa(&mut resp, "Taxas", "Tributos", "Variavel");
-
-        despesa(&mut resp, "Mensalidades", "Educação", "Fixa");
-        despesa(&mut resp, "Assinaturas", "Lazer", "Fixa");
-        despesa(&mut resp, "Condomínio", "Moradia", "Fixa");
-        despesa(&mut resp, "Energia elétrica", "Moradia", "Fixa");
-        despesa(&mut resp, "Financiamentos imobiliários", "Moradia", "Fixa");
-        despesa(&mut resp, "Internet", "Moradia", "Fixa");
-        despesa(&mut resp, "Parcela imóvel", "Moradia", "Fixa");
-        despesa(&mut resp, "Telefonia", "Moradia", "Fixa");
-        despesa(&mut resp, "Esporte", "Saúde", "Fixa");
-        despesa(&mut resp, "Seguro veicular", "Transporte", "Fixa");
-        despesa(&mut resp, "INSS", "Tributos", "Fixa");
-        despesa(&mut resp, "IPTU", "Tributos", "Fixa");
-        despesa(&mut resp, "IPVA", "Tributos", "Fixa");
-        despesa(&mut resp, "IR", "Tributos", "Fixa");
-        despesa(&mut resp, "FGTS", "Tributos", "Fixa");
-        despesa(&mut resp, "Licenciamento Veicular", "Tributos", "Fixa");
-
-        despesa(&mut resp, "Taxas e Juros", "Transações", "Perda");
-        despesa(&mut resp, "Empréstimos", "Transações", "Perda");
-        despesa(&mut resp, "Multas", "Transações", "Perda");
-        despesa(&mut resp, "IOF", "Tributos", "Perda");
+        despesa(&mut resp, "Açougues", "Abastecimento", TipoDespesa::Variavel);
+        despesa(&mut resp, "Padarias", "Abastecimento", TipoDespesa::Variavel);
+        despesa(&mut resp, "Peixarias", "Abastecimento", TipoDespesa::Variavel);
+        despesa(&mut resp, "Supermercados", "Abastecimento", TipoDespesa::Variavel);
+        despesa(&mut resp, "Verdurarias", "Abastecimento", TipoDespesa::Variavel);
+        despesa(&mut resp, "Cosméticos e perfumarias", "Bem estar", TipoDespesa::Variavel);
+        despesa(&mut resp, "Tratamentos estéticos", "Bem estar", TipoDespesa::Variavel);
+        despesa(&mut resp, "Vestuário", "Bem estar", TipoDespesa::Variavel);
+        despesa(&mut resp, "Materiais escolar", "Educação", TipoDespesa::Variavel);
+        despesa(&mut resp, "Bares", "Lazer", TipoDespesa::Variavel);
+        despesa(&mut resp, "Eventos", "Lazer", TipoDespesa::Variavel);
+        despesa(&mut resp, "Hospedagens", "Lazer", TipoDespesa::Variavel);
+        despesa(&mut resp, "Lanches", "Lazer", TipoDespesa::Variavel);
+        despesa(&mut resp, "Restaurantes", "Lazer", TipoDespesa::Variavel);
+        despesa(&mut resp, "Ferramentas", "Moradia", TipoDespesa::Variavel);
+        despesa(&mut resp, "Móveis e eletrodomésticos", "Moradia", TipoDespesa::Variavel);
+        despesa(&mut resp, "Obras e manutenções", "Moradia", TipoDespesa::Variavel);
+        despesa(&mut resp, "Medicamentos", "Saúde", TipoDespesa::Variavel);
+        despesa(&mut resp, "Consultas", "Saúde", TipoDespesa::Variavel);
+        despesa(&mut resp, "Planos de saúde", "Saúde", TipoDespesa::Variavel);
+        despesa(&mut resp, "Cartão de crédito", "Transações", TipoDespesa::Variavel);
+        despesa(&mut resp, "Doações", "Transações", TipoDespesa::Variavel);
+        despesa(&mut resp, "Saques", "Transações", TipoDespesa::Variavel);
+        despesa(&mut resp, "Combustível", "Transporte", TipoDespesa::Variavel);
+        despesa(&mut resp, "Estacionamentos", "Transporte", TipoDespesa::Variavel);
+        despesa(&mut resp, "Manutenção veicular", "Transporte", TipoDespesa::Variavel);
+        despesa(&mut resp, "Pedágios e translados", "Transporte", TipoDespesa::Variavel);
+        despesa(&mut resp, "Passagens", "Transporte", TipoDespesa::Variavel);
+        despesa(&mut resp, "Taxas", "Tributos", TipoDespesa::Variavel);
+
+        despesa(&mut resp, "Mensalidades", "Educação", TipoDespesa::Fixa);
+        despesa(&mut resp, "Assinaturas", "Lazer", TipoDespesa::Fixa);
+        despesa(&mut resp, "Condomínio", "Moradia", TipoDespesa::Fixa);
+        despesa(&mut resp, "Energia elétrica", "Moradia", TipoDespesa::Fixa);
+        despesa(&mut resp, "Financiamentos imobiliários", "Moradia", TipoDespesa::Fixa);
+        despesa(&mut resp, "Internet", "Moradia", TipoDespesa::Fixa);
+        despesa(&mut resp, "Parcela imóvel", "Moradia", TipoDespesa::Fixa);
+        despesa(&mut resp, "Telefonia", "Moradia", TipoDespesa::Fixa);
+        despesa(&mut resp, "Esporte", "Saúde", TipoDespesa::Fixa);
+        despesa(&mut resp, "Seguro veicular", "Transporte", TipoDespesa::Fixa);
+        despesa(&mut resp, "INSS", "Tributos", TipoDespesa::Fixa);
+        despesa(&mut resp, "IPTU", "Tributos", TipoDespesa::Fixa);
+        despesa(&mut resp, "IPVA", "Tributos", TipoDespesa::Fixa);
+        despesa(&mut resp, "IR", "Tributos", TipoDespesa::Fixa);
+        despesa(&mut resp, "FGTS", "Tributos", TipoDespesa::Fixa);
+        despesa(&mut resp, "Licenciamento Veicular", "Tributos", TipoDespesa::Fixa);
+
+        despesa(&mut resp, "Taxas e Juros", "Transações", TipoDespesa::Perda);
+        despesa(&mut resp, "Empréstimos", "Transações", TipoDespesa::Perda);
+        despesa(&mut resp, "Multas", "Transações", TipoDespesa::Perda);
+        despesa(&mut resp, "IOF", "Tributos", TipoDespesa::Perda);
 
         receita(&mut resp, "Salário", "Trabalho");
         receita(&mut resp, "Férias", "Trabalho");
@@ -155,38 +273,79 @@ impl Categoria {
         transferencias(&mut resp, "Transferência entre contas");
         transferencias(&mut resp, "Empréstimos familiar");
 
-        resp.push(Categoria::new("", TipoFluxo::SemCategoria));
+        resp.push(Categoria::new("", TipoFluxo::SemCategoria, None));
         resp
     }
 
-    fn new(nome: &str, tipo: TipoFluxo) -> Self {
-        Self {
+    pub fn new(nome: &str, tipo: TipoFluxo, pai: Option<String>) -> Self {
+        let mut resp = Self {
             id: String::new(),
             nome: nome.to_string(),
-            tipo: tipo,
+            tipo,
+            pai,
+            carne_leao: false,
+            livro_caixa: false,
+            hierarquia: Vec::new(),
+        };
+        resp.gerar_id();
+        resp
+    }
+
+    /// Converte o formato antigo (tipo/grupo fixos) para a árvore, mantendo os ids
+    /// das categorias existentes para não orfanar lançamentos e regras.
+    pub fn migrar_legado(legadas: Vec<CategoriaLegada>) -> Vec<Categoria> {
+        let mut resp: Vec<Categoria> = Vec::new();
+
+        for legada in legadas {
+            let (tipo, grupos) = match legada.tipo {
+                TipoFluxoLegado::Receita(grupo) => (TipoFluxo::Receita, vec![grupo]),
+                TipoFluxoLegado::Despesa(gd) => (
+                    TipoFluxo::Despesa(gd.tipo.clone()),
+                    vec![gd.tipo.to_string(), gd.grupo],
+                ),
+                TipoFluxoLegado::Investimento => (TipoFluxo::Investimento, vec![]),
+                TipoFluxoLegado::Retorno => (TipoFluxo::Retorno, vec![]),
+                TipoFluxoLegado::Transferencias => (TipoFluxo::Transferencias, vec![]),
+                TipoFluxoLegado::SemCategoria => (TipoFluxo::SemCategoria, vec![]),
+            };
+
+            let grupos: Vec<&str> = grupos.iter().map(|g| g.as_str()).collect();
+            let pai = resp.garantir_grupos(tipo.clone(), &grupos);
+
+            resp.push(Categoria {
+                id: legada.id,
+                nome: legada.nome,
+                tipo,
+                pai,
+                carne_leao: false,
+                livro_caixa: false,
+                hierarquia: Vec::new(),
+            });
         }
+
+        resp
     }
 }
 
-fn despesa(array: &mut Vec<Categoria>, nome: &str, grupo: &str, sub_grupo: &str) {
-    array.push(Categoria::new(
-        nome,
-        TipoFluxo::Despesa(GrupoDespesa::new(grupo, sub_grupo)),
-    ));
+fn despesa(array: &mut Vec<Categoria>, nome: &str, grupo: &str, tipo: TipoDespesa) {
+    let fluxo = TipoFluxo::Despesa(tipo.clone());
+    let pai = array.garantir_grupos(fluxo.clone(), &[tipo.to_string().as_str(), grupo]);
+    array.push(Categoria::new(nome, fluxo, pai));
 }
 
 fn receita(array: &mut Vec<Categoria>, nome: &str, grupo: &str) {
-    array.push(Categoria::new(nome, TipoFluxo::Receita(grupo.to_string())));
+    let pai = array.garantir_grupos(TipoFluxo::Receita, &[grupo]);
+    array.push(Categoria::new(nome, TipoFluxo::Receita, pai));
 }
 
 fn investimento(array: &mut Vec<Categoria>, nome: &str) {
-    array.push(Categoria::new(nome, TipoFluxo::Investimento));
+    array.push(Categoria::new(nome, TipoFluxo::Investimento, None));
 }
 
 fn retorno(array: &mut Vec<Categoria>, nome: &str) {
-    array.push(Categoria::new(nome, TipoFluxo::Retorno));
+    array.push(Categoria::new(nome, TipoFluxo::Retorno, None));
 }
 
 fn transferencias(array: &mut Vec<Categoria>, nome: &str) {
-    array.push(Categoria::new(nome, TipoFluxo::Transferencias));
+    array.push(Categoria::new(nome, TipoFluxo::Transferencias, None));
 }
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+use super::TipoDespesa;
+
+/// Formato antigo de categorias (até a 0.14), mantido apenas para migrar
+/// `categorias.json` para a árvore de categorias.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoriaLegada {
+    pub id: String,
+    pub nome: String,
+    pub tipo: TipoFluxoLegado,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum TipoFluxoLegado {
+    Receita(String),
+    Despesa(GrupoDespesa),
+    Investimento,
+    Retorno,
+    Transferencias,
+    SemCategoria,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GrupoDespesa {
+    pub grupo: String,
+    pub tipo: TipoDespesa,
+}
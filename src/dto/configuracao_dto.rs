@@ -18,4 +18,8 @@ pub struct Configuracao {
     
     #[serde(default)]
     pub contracheque_empresa: String,
+
+    /// Dependentes deduzidos na base do IRRF.
+    #[serde(default)]
+    pub dependentes: i32,
 }
\ No newline at end of file
@@ -1,5 +1,57 @@
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+use std::fmt::{Display, Formatter, Result};
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum TipoConta {
+    #[default]
+    Corrente,
+    Poupanca,
+    Investimento,
+    Cartao,
+}
+
+impl TipoConta {
+    /// Saldo devedor do cartão conta como passivo no patrimônio.
+    pub fn passivo(&self) -> bool {
+        *self == TipoConta::Cartao
+    }
+}
+
+impl Display for TipoConta {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::Corrente => f.write_str("Corrente"),
+            Self::Poupanca => f.write_str("Poupança"),
+            Self::Investimento => f.write_str("Investimento"),
+            Self::Cartao => f.write_str("Cartão"),
+        }
+    }
+}
+
+/// Saldo informado pelo banco no OFX (`LEDGERBAL`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SaldoExtrato {
+    pub valor: f64,
+    pub data: NaiveDate,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct Conta {
     pub id: String,
     pub nome: String,
-}
\ No newline at end of file
+
+    #[serde(default)]
+    pub tipo: TipoConta,
+
+    /// Saldo no fim do dia `data_saldo_inicial`; sem data, vale antes de todos os lançamentos.
+    #[serde(default)]
+    pub saldo_inicial: f64,
+    #[serde(default)]
+    pub data_saldo_inicial: Option<NaiveDate>,
+
+    /// Último saldo lido do OFX, usado para conferir o saldo calculado.
+    #[serde(default)]
+    pub extrato: Option<SaldoExtrato>,
+}
@@ -0,0 +1,84 @@
+use std::fmt::{Display, Formatter, Result};
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::{gerar_sha1, Unico};
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum TipoContracheque {
+    #[default]
+    Mensal,
+    DecimoTerceiroPrimeira,
+    DecimoTerceiroSegunda,
+    Ferias,
+    Plr,
+}
+
+impl Display for TipoContracheque {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::Mensal => f.write_str("Mensal"),
+            Self::DecimoTerceiroPrimeira => f.write_str("13º 1ª parcela"),
+            Self::DecimoTerceiroSegunda => f.write_str("13º 2ª parcela"),
+            Self::Ferias => f.write_str("Férias"),
+            Self::Plr => f.write_str("PLR"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemContracheque {
+    pub nome: String,
+    pub valor: f64,
+}
+
+/// Contracheque de uma empresa; `competencia` é o primeiro dia do mês de referência.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Contracheque {
+    pub id: String,
+    pub empresa: String,
+    pub tipo: TipoContracheque,
+    pub competencia: NaiveDate,
+    pub data_pagamento: NaiveDate,
+    pub entradas: Vec<ItemContracheque>,
+    pub saidas: Vec<ItemContracheque>,
+}
+
+impl Contracheque {
+    pub fn bruto(&self) -> f64 {
+        self.entradas.iter().map(|i| i.valor).sum()
+    }
+
+    pub fn descontos(&self) -> f64 {
+        self.saidas.iter().map(|i| i.valor).sum()
+    }
+
+    pub fn liquido(&self) -> f64 {
+        self.bruto() - self.descontos()
+    }
+}
+
+impl Unico for Contracheque {
+    /// Um contracheque por empresa, competência e tipo: salvar de novo substitui.
+    fn gerar_id(&mut self) {
+        self.id = gerar_sha1(
+            [
+                self.empresa.clone(),
+                self.competencia.format("%Y%m").to_string(),
+                self.tipo.to_string(),
+            ]
+            .join("-"),
+        );
+    }
+}
+
+/// Um item nos dois contracheques comparados; ausente em um deles vale zero.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComparacaoItemContracheque {
+    pub nome: String,
+    pub saida: bool,
+    pub valor_a: f64,
+    pub valor_b: f64,
+    pub diferenca: f64,
+}
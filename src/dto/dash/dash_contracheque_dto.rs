@@ -0,0 +1,8 @@
+/// Soma dos contracheques pagos em um mês, de todas as empresas e tipos.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DashContracheque {
+    pub mes: String,
+    pub bruto: f64,
+    pub descontos: f64,
+    pub liquido: f64,
+}
@@ -0,0 +1,16 @@
+/// Projeção de um mês do fluxo de caixa; as saídas são positivas. No mês atual
+/// os valores são só o que ainda falta.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DashFluxoCaixa {
+    pub mes: String,
+    pub receitas: f64,
+    pub fixas: f64,
+    pub dividas: f64,
+    pub sazonais: f64,
+
+    /// Resultado do mês: receitas menos as saídas.
+    pub saldo: f64,
+    /// Saldo das contas hoje somado aos resultados até este mês.
+    pub acumulado: f64,
+    pub negativo: bool,
+}
@@ -0,0 +1,14 @@
+/// Total pago em dívidas num ano, separado entre principal e juros.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DashHistoricoDivida {
+    pub ano: i32,
+    pub parcelas: i32,
+    pub principal: f64,
+    pub juros: f64,
+}
+
+impl DashHistoricoDivida {
+    pub fn total(&self) -> f64 {
+        self.principal + self.juros
+    }
+}
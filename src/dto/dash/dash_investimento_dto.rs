@@ -0,0 +1,21 @@
+/// Posição atual de um ativo pelo custo médio.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DashInvestimento {
+    pub ativo: String,
+    pub nome: String,
+    pub tipo: String,
+    pub quantidade: f64,
+    pub preco_medio: f64,
+    pub custo: f64,
+
+    /// Última cotação até hoje; sem cotação, o preço da última operação.
+    pub preco_atual: f64,
+    pub data_preco: Option<String>,
+    pub valor_atual: f64,
+
+    pub ganho_realizado: f64,
+    pub ganho_nao_realizado: f64,
+    pub proventos: f64,
+    /// Ganhos e proventos sobre o total investido, em %.
+    pub rentabilidade: f64,
+}
@@ -0,0 +1,51 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum SituacaoMeta {
+    Atingida,
+    NoCaminho,
+    EmRisco,
+    NaoAtingida,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum TendenciaMeta {
+    Subindo,
+    Estavel,
+    Descendo,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DashMeta {
+    pub id: String,
+    pub nome: String,
+    pub metrica: String,
+    pub valor: f64,
+    pub realizado: f64,
+    pub projetado: f64,
+    pub progresso: f64,
+    pub restante: f64,
+    pub situacao: SituacaoMeta,
+    pub tendencia: TendenciaMeta,
+}
+
+impl fmt::Display for SituacaoMeta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            SituacaoMeta::Atingida => "Atingida",
+            SituacaoMeta::NoCaminho => "No caminho",
+            SituacaoMeta::EmRisco => "Em risco",
+            SituacaoMeta::NaoAtingida => "Não atingida",
+        })
+    }
+}
+
+impl TendenciaMeta {
+    pub fn icone(&self) -> &str {
+        match self {
+            TendenciaMeta::Subindo => "↑",
+            TendenciaMeta::Estavel => "→",
+            TendenciaMeta::Descendo => "↓",
+        }
+    }
+}
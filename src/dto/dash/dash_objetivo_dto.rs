@@ -0,0 +1,20 @@
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DashObjetivo {
+    pub id: String,
+    pub nome: String,
+    pub valor_alvo: f64,
+    pub data_alvo: String,
+    pub acumulado: f64,
+    pub falta: f64,
+    pub progresso: f64,
+
+    /// Quanto guardar por mês, até a data alvo, para chegar no valor.
+    pub aporte_necessario: f64,
+
+    /// Média mensal dos aportes recentes.
+    pub ritmo_mensal: f64,
+
+    /// Mês previsto para concluir no ritmo atual, `None` se o ritmo não é positivo.
+    pub previsao: Option<String>,
+    pub no_prazo: bool,
+}
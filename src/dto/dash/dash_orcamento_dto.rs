@@ -0,0 +1,15 @@
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct DashOrcamento {
+    pub categoria: String,
+    pub orcado: f64,
+    pub acumulado: f64,
+    pub gasto: f64,
+    pub restante: f64,
+    pub projetado: f64,
+}
+
+impl DashOrcamento {
+    pub fn disponivel(&self) -> f64 {
+        self.orcado + self.acumulado
+    }
+}
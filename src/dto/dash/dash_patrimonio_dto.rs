@@ -0,0 +1,33 @@
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DashPatrimonio {
+    pub contas: Vec<DashSaldoConta>,
+    /// Dias da série de saldo diário de cada conta (`DashSaldoConta.diario`).
+    pub dias: Vec<String>,
+    pub meses: Vec<DashPatrimonioMes>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DashSaldoConta {
+    pub banco: String,
+    pub conta: String,
+    pub tipo: String,
+    pub saldo: f64,
+
+    /// Saldo do último extrato (`LEDGERBAL`) e a diferença para o calculado na mesma data.
+    pub extrato: Option<f64>,
+    pub data_extrato: Option<String>,
+    pub diferenca: Option<f64>,
+
+    /// Saldo no fim de cada dia de `DashPatrimonio.dias`.
+    pub diario: Vec<f64>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DashPatrimonioMes {
+    pub mes: String,
+    pub ativos: f64,
+    /// Valor de mercado da carteira, já somado aos ativos.
+    pub investimentos: f64,
+    pub passivos: f64,
+    pub liquido: f64,
+}
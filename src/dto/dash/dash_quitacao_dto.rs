@@ -0,0 +1,33 @@
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DashQuitacao {
+    pub estrategia: String,
+    pub extra_mensal: f64,
+    pub total_pago: f64,
+    pub total_juros: f64,
+
+    /// Mês em que a última dívida é quitada, `None` se passar do horizonte simulado.
+    pub quitacao: Option<String>,
+    pub dividas: Vec<DashQuitacaoDivida>,
+    pub meses: Vec<DashQuitacaoMes>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DashQuitacaoDivida {
+    pub id: String,
+    pub nome: String,
+    pub saldo: f64,
+    pub taxa_mensal: f64,
+    pub juros: f64,
+    pub quitacao: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DashQuitacaoMes {
+    pub mes: String,
+    pub pago: f64,
+    pub juros: f64,
+    pub saldo: f64,
+
+    /// Parcelas das dívidas já quitadas, redirecionadas para as seguintes.
+    pub liberado: f64,
+}
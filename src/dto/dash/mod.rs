@@ -1,13 +1,31 @@
+mod dash_contracheque_dto;
 mod dash_divida_dto;
+mod dash_fluxo_caixa_dto;
 mod dash_resumo_dto;
 mod dash_gasto_por_dto;
 mod dash_gasto_por_categoria_dto;
 pub mod orientacao_dto;
 mod dash_gasto_por_categoria_ano_dto;
+mod dash_historico_divida_dto;
+mod dash_investimento_dto;
+mod dash_meta_dto;
+mod dash_objetivo_dto;
+mod dash_orcamento_dto;
+mod dash_patrimonio_dto;
+mod dash_quitacao_dto;
 
+pub use dash_contracheque_dto::DashContracheque;
 pub use dash_divida_dto::{DashDivida, DashDividaExt};
+pub use dash_fluxo_caixa_dto::DashFluxoCaixa;
 pub use dash_resumo_dto::DashResumo;
 pub use dash_gasto_por_dto::DashGastoPor;
 pub use dash_gasto_por_categoria_dto::DashGastoPorCategoria;
 pub use dash_gasto_por_categoria_ano_dto::DashGastoPorCategoriaAno;
+pub use dash_historico_divida_dto::DashHistoricoDivida;
+pub use dash_investimento_dto::DashInvestimento;
+pub use dash_meta_dto::{DashMeta, SituacaoMeta, TendenciaMeta};
+pub use dash_objetivo_dto::DashObjetivo;
+pub use dash_orcamento_dto::DashOrcamento;
+pub use dash_patrimonio_dto::{DashPatrimonio, DashPatrimonioMes, DashSaldoConta};
+pub use dash_quitacao_dto::{DashQuitacao, DashQuitacaoDivida, DashQuitacaoMes};
 pub use orientacao_dto::Orientacao;
\ No newline at end of file
@@ -1,6 +1,6 @@
-use chrono::{Datelike, NaiveDate};
+use chrono::{Datelike, Local, NaiveDate, NaiveDateTime};
 
-use super::{gerar_sha1, ParcelaDivida, Unico};
+use super::{gerar_sha1, Financiamento, ParcelaDivida, Unico};
 
 #[derive(Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct Divida {
@@ -11,6 +11,26 @@ pub struct Divida {
     pub prioritaria: bool,
     pub cobranca_automatica: bool,
     pub parcelas: Vec<ParcelaDivida>,
+
+    #[serde(default)]
+    pub financiamento: Option<Financiamento>,
+
+    /// Trecho da descrição do lançamento que paga a parcela; vazio desliga a conciliação.
+    #[serde(default)]
+    pub padrao_descricao: String,
+
+    /// Conta em que a parcela é debitada, `None` aceita qualquer conta.
+    #[serde(default)]
+    pub conta: Option<String>,
+
+    /// Id da dívida renegociada que deu origem a esta.
+    #[serde(default)]
+    pub origem: Option<String>,
+
+    /// Momento da criação, que entra no id para que duas dívidas com o mesmo nome
+    /// e o mesmo primeiro vencimento não se sobrescrevam. `None` nas anteriores a ele.
+    #[serde(default)]
+    pub criada_em: Option<NaiveDateTime>,
 }
 
 pub trait DadosDivida {
@@ -18,6 +38,8 @@ pub trait DadosDivida {
     fn ultima(&self) -> ParcelaDivida;
 
     fn valor_total(&self) -> f64;
+    fn saldo_devedor(&self) -> f64;
+    fn juros_total(&self) -> f64;
     fn quant(&self) -> i32;
 
     fn antes_de(&self, data: NaiveDate) -> Self;
@@ -44,6 +66,11 @@ impl Divida {
             nome,
             cobranca_automatica,
             parcelas: Vec::new(),
+            financiamento: None,
+            padrao_descricao: String::new(),
+            conta: None,
+            origem: None,
+            criada_em: Some(Local::now().naive_local()),
         };
 
         for i in 1..=quant {
@@ -55,6 +82,12 @@ impl Divida {
                     .clone()
                     .checked_add_months(chrono::Months::new((i as u32) - 1))
                     .unwrap(),
+                juros: 0.0,
+                amortizacao: valor,
+                saldo_devedor: valor * (quant - i) as f64,
+                lancamento: None,
+                desconto: 0.0,
+                renegociada: false,
             });
         }
 
@@ -62,6 +95,36 @@ impl Divida {
         divida
     }
 
+    /// Dívida com juros, cujas parcelas já foram geradas a partir do `financiamento`.
+    pub fn new_financiamento(
+        nome: String,
+        prioritaria: bool,
+        cobranca_automatica: bool,
+        financiamento: Financiamento,
+        parcelas: Vec<ParcelaDivida>,
+    ) -> Self {
+        let mut divida = Divida {
+            id: String::new(),
+            nome,
+            prioritaria,
+            cobranca_automatica,
+            parcelas,
+            financiamento: Some(financiamento),
+            padrao_descricao: String::new(),
+            conta: None,
+            origem: None,
+            criada_em: Some(Local::now().naive_local()),
+        };
+
+        divida.gerar_id();
+        divida
+    }
+
+    /// Dívida cujas parcelas são marcadas como pagas pelos lançamentos importados.
+    pub fn conciliada(&self) -> bool {
+        !self.padrao_descricao.trim().is_empty()
+    }
+
     pub fn prox_parcela(&self) -> ParcelaDivida {
         if self.parcelas.aberta().quant() > 0 {
             self.parcelas.aberta().primeira()
@@ -84,6 +147,14 @@ impl DadosDivida for Vec<ParcelaDivida> {
         self.iter().map(|v| v.valor).sum()
     }
 
+    fn saldo_devedor(&self) -> f64 {
+        self.iter().map(|p| p.principal()).sum()
+    }
+
+    fn juros_total(&self) -> f64 {
+        self.iter().map(|p| p.juros).sum()
+    }
+
     fn quant(&self) -> i32 {
         self.len() as i32
     }
@@ -122,12 +193,20 @@ impl DadosDivida for Vec<ParcelaDivida> {
 }
 
 impl Unico for Divida {
+    /// Gerado só na criação: não depende de valores nem da quantidade de
+    /// parcelas, que mudam com renegociações e antecipações.
     fn gerar_id(&mut self) {
         self.id = gerar_sha1(
-            vec![
+            [
                 self.nome.clone(),
-                self.parcelas.quant().to_string(),
-                self.parcelas.valor_total().to_string(),
+                self.parcelas
+                    .first()
+                    .map(|p| p.data_vencimento.to_string())
+                    .unwrap_or_default(),
+                self.origem.clone().unwrap_or_default(),
+                self.criada_em
+                    .map(|c| c.format("%Y%m%d%H%M%S%.f").to_string())
+                    .unwrap_or_default(),
             ]
             .join("-"),
         )
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+/// Categoria lembrada para um item do contracheque, pelo nome do item.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CategoriaItemContracheque {
+    pub item: String,
+    pub categoria: String,
+}
+
+/// Empresa que paga contracheques: a conta onde os lançamentos são gravados e
+/// a categoria padrão de cada item (Salário, INSS, IR, FGTS...).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Empregador {
+    /// Nome da empresa, como digitado no contracheque.
+    pub id: String,
+
+    /// Banco e conta de destino; sem eles, os lançamentos vão para a conta da
+    /// empresa no banco "Salario".
+    #[serde(default)]
+    pub banco: Option<String>,
+    #[serde(default)]
+    pub conta: Option<String>,
+
+    #[serde(default)]
+    pub categorias: Vec<CategoriaItemContracheque>,
+}
+
+impl Empregador {
+    pub fn novo(nome: String) -> Self {
+        Self {
+            id: nome,
+            ..Default::default()
+        }
+    }
+
+    /// Categoria lembrada para o item, sem diferenciar maiúsculas.
+    pub fn categoria_do_item(&self, item: &str) -> Option<String> {
+        let item = item.trim().to_lowercase();
+        self.categorias
+            .iter()
+            .find(|c| c.item.trim().to_lowercase() == item)
+            .map(|c| c.categoria.clone())
+    }
+
+    /// Guarda a categoria do item; categoria vazia esquece o item.
+    pub fn lembrar_categoria(&mut self, item: &str, categoria: String) {
+        let chave = item.trim().to_lowercase();
+        self.categorias
+            .retain(|c| c.item.trim().to_lowercase() != chave);
+        if !categoria.is_empty() {
+            self.categorias.push(CategoriaItemContracheque {
+                item: item.trim().to_string(),
+                categoria,
+            });
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use super::{Ativo, Banco, Cotacao, Lancamento, OperacaoInvestimento};
+
+/// Tudo o que foi lido dos arquivos OFX de uma importação: extratos de conta
+/// corrente e de investimentos (`INVSTMTRS`).
+#[derive(Debug, Default)]
+pub struct ExtratoOfx {
+    pub lancamentos: Vec<Lancamento>,
+    pub bancos: Vec<Banco>,
+    pub ativos: Vec<Ativo>,
+    pub operacoes: Vec<OperacaoInvestimento>,
+    pub cotacoes: Vec<Cotacao>,
+}
@@ -0,0 +1,55 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum SistemaAmortizacao {
+    /// Parcela fixa (tabela Price)
+    #[default]
+    Price,
+    /// Amortização constante, parcela decrescente
+    Sac,
+}
+
+impl Display for SistemaAmortizacao {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SistemaAmortizacao::Price => write!(f, "Price"),
+            SistemaAmortizacao::Sac => write!(f, "SAC"),
+        }
+    }
+}
+
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum IndiceCorrecao {
+    #[default]
+    Nenhum,
+    Tr,
+    Ipca,
+}
+
+impl Display for IndiceCorrecao {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            IndiceCorrecao::Nenhum => write!(f, "Nenhum"),
+            IndiceCorrecao::Tr => write!(f, "TR"),
+            IndiceCorrecao::Ipca => write!(f, "IPCA"),
+        }
+    }
+}
+
+/// Condições do contrato usadas para gerar as parcelas de uma dívida com juros.
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Financiamento {
+    pub principal: f64,
+    /// Juros em % ao mês
+    pub taxa_mensal: f64,
+    pub prazo: i32,
+    pub sistema: SistemaAmortizacao,
+
+    #[serde(default)]
+    pub indice: IndiceCorrecao,
+    /// Estimativa do índice em % ao mês, aplicada sobre o saldo antes dos juros
+    #[serde(default)]
+    pub correcao_mensal: f64,
+}
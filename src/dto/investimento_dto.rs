@@ -0,0 +1,114 @@
+use std::fmt::{Display, Formatter, Result};
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::{gerar_sha1, Unico};
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum TipoAtivo {
+    #[default]
+    Acao,
+    Fii,
+    Tesouro,
+    Cdb,
+}
+
+impl Display for TipoAtivo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::Acao => f.write_str("Ação"),
+            Self::Fii => f.write_str("FII"),
+            Self::Tesouro => f.write_str("Tesouro"),
+            Self::Cdb => f.write_str("CDB"),
+        }
+    }
+}
+
+/// Ativo da carteira; o `id` é o código (ticker ou nome do título).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Ativo {
+    pub id: String,
+    pub nome: String,
+    pub tipo: TipoAtivo,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum TipoOperacao {
+    #[default]
+    Compra,
+    Venda,
+    /// Dividendos, JCP, rendimentos de FII e juros de cupom.
+    Provento,
+}
+
+impl Display for TipoOperacao {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::Compra => f.write_str("Compra"),
+            Self::Venda => f.write_str("Venda"),
+            Self::Provento => f.write_str("Provento"),
+        }
+    }
+}
+
+/// Compra, venda ou provento de um ativo. No provento, `preco` é o valor por cota.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OperacaoInvestimento {
+    pub id: String,
+    pub ativo: String,
+    pub tipo: TipoOperacao,
+    pub data: NaiveDate,
+    pub quantidade: f64,
+    pub preco: f64,
+
+    /// Corretagem, emolumentos e impostos retidos.
+    #[serde(default)]
+    pub taxas: f64,
+
+    /// Id do lançamento `Investimento` que pagou a compra (ou recebeu a venda).
+    #[serde(default)]
+    pub lancamento: Option<String>,
+
+    /// `FITID` do OFX ou, na operação digitada, o momento do cadastro. Entra no id
+    /// para que duas execuções iguais no mesmo dia não se sobrescrevam, sem
+    /// duplicar a operação quando o mesmo OFX é importado de novo.
+    #[serde(default)]
+    pub referencia: String,
+}
+
+impl OperacaoInvestimento {
+    /// Dinheiro que saiu (compra) ou entrou (venda e provento), já com as taxas.
+    pub fn valor(&self) -> f64 {
+        match self.tipo {
+            TipoOperacao::Compra => self.quantidade * self.preco + self.taxas,
+            TipoOperacao::Venda | TipoOperacao::Provento => {
+                self.quantidade * self.preco - self.taxas
+            }
+        }
+    }
+}
+
+impl Unico for OperacaoInvestimento {
+    fn gerar_id(&mut self) {
+        self.id = gerar_sha1(
+            [
+                self.ativo.clone(),
+                self.tipo.to_string(),
+                self.data.format("%Y%m%d").to_string(),
+                self.quantidade.to_string(),
+                self.preco.to_string(),
+                self.referencia.clone(),
+            ]
+            .join("-"),
+        );
+    }
+}
+
+/// Preço de fechamento informado manualmente.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Cotacao {
+    pub ativo: String,
+    pub data: NaiveDate,
+    pub preco: f64,
+}
@@ -0,0 +1,63 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// Rendimentos de uma fonte pagadora no ano, pelos contracheques pagos nele.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RendimentoIrpf {
+    pub fonte: String,
+    /// Salários e férias, tributáveis na declaração.
+    pub tributavel: f64,
+    pub inss: f64,
+    /// IRRF retido sobre os rendimentos tributáveis.
+    pub irrf: f64,
+    /// 13º salário, com tributação exclusiva na fonte.
+    pub decimo_terceiro: f64,
+    /// PLR, com tributação exclusiva na fonte.
+    pub plr: f64,
+    /// IRRF retido sobre o 13º e a PLR.
+    pub irrf_exclusivo: f64,
+}
+
+/// Rendimentos de pessoas físicas num mês, com o carnê-leão pago sobre eles.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CarneLeaoIrpf {
+    /// Primeiro dia do mês.
+    pub mes: NaiveDate,
+    pub rendimentos: f64,
+    pub imposto_pago: f64,
+}
+
+/// Receita tributável fora dos contracheques e do carnê-leão, somada por categoria.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutroRendimentoIrpf {
+    pub categoria: String,
+    pub total: f64,
+}
+
+/// Despesa dedutível somada por tipo e beneficiário.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeducaoIrpf {
+    pub tipo: String,
+    pub beneficiario: String,
+    pub total: f64,
+}
+
+/// Bem ou dívida com a situação em 31/12 do ano anterior e do ano do relatório.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BemIrpf {
+    pub tipo: String,
+    pub descricao: String,
+    pub anterior: f64,
+    pub atual: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RelatorioIrpf {
+    pub ano: i32,
+    pub rendimentos: Vec<RendimentoIrpf>,
+    pub carne_leao: Vec<CarneLeaoIrpf>,
+    pub outros_rendimentos: Vec<OutroRendimentoIrpf>,
+    pub deducoes: Vec<DeducaoIrpf>,
+    pub bens: Vec<BemIrpf>,
+    pub dividas: Vec<BemIrpf>,
+}
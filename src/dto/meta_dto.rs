@@ -6,6 +6,10 @@ use super::{gerar_sha1, Unico};
 pub struct Meta {
     pub id: String,
     pub nome: String,
+
+    #[serde(default = "ativa_por_padrao")]
+    pub ativo: bool,
+
     pub desc: String,
     pub tipo_meta: String,
     pub filtro: String,
@@ -15,6 +19,11 @@ pub struct Meta {
     pub valor: f64,
 }
 
+/// Metas gravadas antes do campo existir continuam sendo avaliadas.
+fn ativa_por_padrao() -> bool {
+    true
+}
+
 impl Unico for Meta {
     fn gerar_id(&mut self) {
         let mut itens: Vec<String> = Vec::new();
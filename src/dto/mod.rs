@@ -1,38 +1,70 @@
 mod banco_dto;
+mod carne_leao_dto;
 mod categoria_dto;
+mod categoria_legada_dto;
 mod configuracao_dto;
 mod conta_dto;
+mod contracheque_dto;
 mod divida_dto;
+mod empregador_dto;
+mod extrato_ofx_dto;
+mod financiamento_dto;
 mod fluxo_regra_dto;
-mod grupo_despesa_dto;
+mod investimento_dto;
+mod irpf_dto;
 mod lancamento_dto;
 mod lazy;
 mod meta_dto;
 mod nova_regra_dto;
+mod objetivo_dto;
+mod orcamento_dto;
 mod optional_lazy;
 mod parcela_divida_dto;
+mod plano_quitacao_dto;
 mod regra_dto;
+mod relatorio_mes_dto;
+mod simulacao_compra_dto;
+mod snapshot_dto;
+mod tabela_tributaria_dto;
 mod tipo_despesa_dto;
 mod tipo_fluxo_dto;
 mod dash;
 
 pub use banco_dto::Banco;
-pub use categoria_dto::{Categoria};
+pub use carne_leao_dto::CarneLeao;
+pub use categoria_dto::{ArvoreCategorias, Categoria};
+pub use categoria_legada_dto::{CategoriaLegada, TipoFluxoLegado};
 pub use configuracao_dto::Configuracao;
-pub use conta_dto::Conta;
+pub use conta_dto::{Conta, SaldoExtrato, TipoConta};
+pub use contracheque_dto::{
+    ComparacaoItemContracheque, Contracheque, ItemContracheque, TipoContracheque,
+};
 pub use divida_dto::Divida;
+pub use empregador_dto::Empregador;
+pub use extrato_ofx_dto::ExtratoOfx;
+pub use financiamento_dto::{Financiamento, IndiceCorrecao, SistemaAmortizacao};
 pub use fluxo_regra_dto::FluxoRegra;
-pub use grupo_despesa_dto::GrupoDespesa;
+pub use investimento_dto::{Ativo, Cotacao, OperacaoInvestimento, TipoAtivo, TipoOperacao};
 use hex;
+pub use irpf_dto::{
+    BemIrpf, CarneLeaoIrpf, DeducaoIrpf, OutroRendimentoIrpf, RelatorioIrpf, RendimentoIrpf,
+};
 pub use lancamento_dto::Lancamento;
 pub use lazy::{Lazy, LazyFn};
 pub use meta_dto::Meta;
+pub use objetivo_dto::Objetivo;
 pub use optional_lazy::{OptionalLazy, OptionalLazyFn};
+pub use orcamento_dto::Orcamento;
 pub use parcela_divida_dto::ParcelaDivida;
+pub use plano_quitacao_dto::{EstrategiaQuitacao, PlanoQuitacao};
 
 pub use divida_dto::DadosDivida;
 pub use nova_regra_dto::NovaRegra;
 pub use regra_dto::Regra;
+pub use relatorio_mes_dto::{RelatorioMes, TotalCategoria};
+pub use simulacao_compra_dto::SimulacaoCompraMes;
+pub use snapshot_dto::{ArquivoSnapshot, ComparacaoSnapshot, GatilhoSnapshot, Snapshot};
+pub use tabela_tributaria_dto::{ConferenciaContracheque, FaixaTributaria, TabelaTributaria};
 pub use tipo_despesa_dto::TipoDespesa;
 pub use tipo_fluxo_dto::TipoFluxo;
 
@@ -0,0 +1,35 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::{gerar_sha1, Unico};
+
+/// Objetivo de poupança (reserva de emergência, carro, viagem...). Os aportes
+/// vêm das contas e categorias de investimento vinculadas.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Objetivo {
+    pub id: String,
+    pub nome: String,
+    pub valor_alvo: f64,
+    pub data_alvo: NaiveDate,
+
+    /// Valor já guardado antes de `inicio`.
+    #[serde(default)]
+    pub saldo_inicial: f64,
+
+    /// Lançamentos a partir desta data contam como aporte.
+    pub inicio: NaiveDate,
+
+    /// Ids das contas: todo lançamento nelas é aporte (ou resgate).
+    #[serde(default)]
+    pub contas: Vec<String>,
+
+    /// Ids de categorias `Investimento`: saídas com elas, em outras contas, são aportes.
+    #[serde(default)]
+    pub categorias: Vec<String>,
+}
+
+impl Unico for Objetivo {
+    fn gerar_id(&mut self) {
+        self.id = gerar_sha1(self.nome.clone());
+    }
+}
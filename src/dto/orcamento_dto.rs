@@ -0,0 +1,26 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::{gerar_sha1, lazy::LazyFn, Categoria, Lazy, Unico};
+
+/// Valor mensal planejado para uma categoria. Quando a categoria tem filhas,
+/// o orçamento vale para o grupo inteiro.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Orcamento {
+    pub id: String,
+    pub categoria: Lazy<Categoria>,
+    pub valor: f64,
+
+    /// Leva a sobra (ou o estouro) de cada mês para o mês seguinte.
+    #[serde(default)]
+    pub acumular: bool,
+
+    /// Primeiro mês considerado no acúmulo.
+    pub inicio: NaiveDate,
+}
+
+impl Unico for Orcamento {
+    fn gerar_id(&mut self) {
+        self.id = gerar_sha1(self.categoria.id());
+    }
+}
@@ -7,4 +7,35 @@ pub struct ParcelaDivida {
     pub valor: f64,
     pub pago: bool,
     pub data_vencimento: NaiveDate,
-}
\ No newline at end of file
+
+    #[serde(default)]
+    pub juros: f64,
+    #[serde(default)]
+    pub amortizacao: f64,
+    /// Saldo devedor após o pagamento desta parcela
+    #[serde(default)]
+    pub saldo_devedor: f64,
+
+    /// Id do lançamento que pagou a parcela, quando conciliada.
+    #[serde(default)]
+    pub lancamento: Option<String>,
+
+    /// Abatimento obtido ao antecipar a parcela; `valor` já é o que foi pago.
+    #[serde(default)]
+    pub desconto: f64,
+    /// Parcela encerrada por renegociação, substituída pelo novo cronograma.
+    #[serde(default)]
+    pub renegociada: bool,
+}
+
+impl ParcelaDivida {
+    /// Parte da parcela que abate o principal; parcelas antigas, sem
+    /// detalhamento, são tratadas como amortização integral.
+    pub fn principal(&self) -> f64 {
+        if self.juros == 0.0 && self.amortizacao == 0.0 {
+            self.valor
+        } else {
+            self.amortizacao
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum EstrategiaQuitacao {
+    /// Menor saldo primeiro
+    #[default]
+    BolaDeNeve,
+    /// Maior taxa primeiro
+    Avalanche,
+    /// Ordem definida pelo usuário
+    Personalizada,
+}
+
+impl Display for EstrategiaQuitacao {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            EstrategiaQuitacao::BolaDeNeve => write!(f, "Bola de neve"),
+            EstrategiaQuitacao::Avalanche => write!(f, "Avalanche"),
+            EstrategiaQuitacao::Personalizada => write!(f, "Personalizada"),
+        }
+    }
+}
+
+/// Plano escolhido no simulador de quitação, usado no dashboard.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PlanoQuitacao {
+    pub estrategia: EstrategiaQuitacao,
+    pub extra_mensal: f64,
+
+    /// Ids das dívidas na ordem da estratégia personalizada.
+    #[serde(default)]
+    pub ordem: Vec<String>,
+}
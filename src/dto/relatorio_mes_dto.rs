@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+/// Total de uma categoria no relatório do mês.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TotalCategoria {
+    pub categoria: String,
+    pub total: f64,
+}
+
+/// Resumo de um mês: entradas, saídas, custo fixo e saídas por categoria.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RelatorioMes {
+    pub mes: String,
+    pub entradas: f64,
+    pub saidas: f64,
+    pub custo_fixo: f64,
+    pub saldo: f64,
+    pub categorias: Vec<TotalCategoria>,
+    /// Lançamentos do mês ainda sem categoria.
+    pub pendentes: usize,
+}
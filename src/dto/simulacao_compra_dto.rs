@@ -0,0 +1,8 @@
+/// Comprometimento de um mês com as dívidas atuais e a compra simulada.
+pub struct SimulacaoCompraMes {
+    pub mes: String,
+    pub dividas: f64,
+    pub compra: f64,
+    pub total: f64,
+    pub excesso: f64,
+}
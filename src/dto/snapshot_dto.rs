@@ -0,0 +1,98 @@
+use std::path::PathBuf;
+
+use chrono::NaiveDateTime;
+
+/// Cópia compactada do diretório de dados.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snapshot {
+    pub caminho: PathBuf,
+    pub data: NaiveDateTime,
+    pub gatilho: GatilhoSnapshot,
+    pub arquivos: Vec<ArquivoSnapshot>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArquivoSnapshot {
+    /// Caminho relativo ao diretório de dados, como `tabelas/2025.json`.
+    pub nome: String,
+    pub tamanho: u64,
+    pub compactado: u64,
+}
+
+/// O que levou ao snapshot; vai no nome do arquivo.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GatilhoSnapshot {
+    Diario,
+    Manual,
+    Importacao,
+    ExclusaoCategoria,
+    RemocaoRegras,
+    ImportacaoSqlite,
+    Restauracao,
+    Criptografia,
+}
+
+/// Um arquivo no snapshot e nos dados atuais. Registros só para JSON; `None`
+/// também quando o arquivo não existe de um dos lados.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComparacaoSnapshot {
+    pub arquivo: String,
+    pub registros_snapshot: Option<usize>,
+    pub registros_atual: Option<usize>,
+    pub tamanho_snapshot: Option<u64>,
+    pub tamanho_atual: Option<u64>,
+}
+
+impl Snapshot {
+    pub fn tamanho(&self) -> u64 {
+        self.arquivos.iter().map(|a| a.tamanho).sum()
+    }
+
+    pub fn compactado(&self) -> u64 {
+        self.arquivos.iter().map(|a| a.compactado).sum()
+    }
+}
+
+impl GatilhoSnapshot {
+    const TODOS: [GatilhoSnapshot; 8] = [
+        GatilhoSnapshot::Diario,
+        GatilhoSnapshot::Manual,
+        GatilhoSnapshot::Importacao,
+        GatilhoSnapshot::ExclusaoCategoria,
+        GatilhoSnapshot::RemocaoRegras,
+        GatilhoSnapshot::ImportacaoSqlite,
+        GatilhoSnapshot::Restauracao,
+        GatilhoSnapshot::Criptografia,
+    ];
+
+    /// Identificador usado no nome do arquivo.
+    pub fn id(&self) -> &'static str {
+        match self {
+            GatilhoSnapshot::Diario => "diario",
+            GatilhoSnapshot::Manual => "manual",
+            GatilhoSnapshot::Importacao => "importacao",
+            GatilhoSnapshot::ExclusaoCategoria => "exclusao-categoria",
+            GatilhoSnapshot::RemocaoRegras => "remocao-regras",
+            GatilhoSnapshot::ImportacaoSqlite => "importacao-sqlite",
+            GatilhoSnapshot::Restauracao => "restauracao",
+            GatilhoSnapshot::Criptografia => "criptografia",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<GatilhoSnapshot> {
+        GatilhoSnapshot::TODOS.into_iter().find(|g| g.id() == id)
+    }
+
+    pub fn descricao(&self) -> &'static str {
+        match self {
+            GatilhoSnapshot::Diario => "Diário",
+            GatilhoSnapshot::Manual => "Manual",
+            GatilhoSnapshot::Importacao => "Antes da importação",
+            GatilhoSnapshot::ExclusaoCategoria => "Antes de excluir categoria",
+            GatilhoSnapshot::RemocaoRegras => "Antes de remover regras",
+            GatilhoSnapshot::ImportacaoSqlite => "Antes de importar para o SQLite",
+            GatilhoSnapshot::Restauracao => "Antes de restaurar",
+            GatilhoSnapshot::Criptografia => "Antes de mudar a criptografia",
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+
+/// Faixa de uma tabela progressiva. `ate` vazio é a última faixa, sem limite.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FaixaTributaria {
+    pub ate: Option<f64>,
+    pub aliquota: f64,
+
+    /// Parcela a deduzir do IRRF; no INSS o cálculo é faixa a faixa e fica zerada.
+    #[serde(default)]
+    pub deducao: f64,
+}
+
+/// Tabelas de INSS e IRRF mensais de um ano, gravadas em `tabelas/<ano>.json`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TabelaTributaria {
+    pub ano: i32,
+
+    /// Faixas progressivas do INSS; o limite da última é o teto de contribuição.
+    pub inss: Vec<FaixaTributaria>,
+    pub irrf: Vec<FaixaTributaria>,
+    pub deducao_dependente: f64,
+    pub desconto_simplificado: f64,
+}
+
+/// INSS e IRRF esperados para o bruto do contracheque, comparados com o informado.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConferenciaContracheque {
+    pub bruto: f64,
+    pub inss_esperado: f64,
+    pub inss_informado: f64,
+    pub irrf_esperado: f64,
+    pub irrf_informado: f64,
+
+    /// Base do IRRF depois do INSS e dependentes ou do desconto simplificado.
+    pub base_irrf: f64,
+    pub simplificado: bool,
+
+    /// INSS e IRRF informados sobre o bruto, em %.
+    pub aliquota_efetiva: f64,
+}
+
+impl ConferenciaContracheque {
+    pub fn diferenca_inss(&self) -> f64 {
+        self.inss_informado - self.inss_esperado
+    }
+
+    pub fn diferenca_irrf(&self) -> f64 {
+        self.irrf_informado - self.irrf_esperado
+    }
+}
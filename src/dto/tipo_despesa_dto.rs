@@ -1,3 +1,5 @@
+use std::fmt::{Display, Formatter, Result};
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Eq, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
@@ -6,4 +8,15 @@ pub enum TipoDespesa {
     Variavel,
     Perda,
     Vazio,
-}
\ No newline at end of file
+}
+
+impl Display for TipoDespesa {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::Fixa => f.write_str("Fixa"),
+            Self::Variavel => f.write_str("Variável"),
+            Self::Perda => f.write_str("Perda"),
+            Self::Vazio => f.write_str(""),
+        }
+    }
+}
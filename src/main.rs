@@ -1,6 +1,8 @@
 mod app;
 mod calc;
+mod cli;
 mod componentes;
+mod config_caminhos;
 mod config_log;
 mod dto;
 mod estilo;
@@ -9,51 +11,79 @@ mod widget;
 
 use app::App;
 use color_eyre::eyre::Result;
-use dto::{Banco, Divida, Lancamento};
-use std::{fs::create_dir_all, path::PathBuf, sync::LazyLock};
+use dto::{
+    Ativo, Banco, Cotacao, Divida, GatilhoSnapshot, Lancamento, OperacaoInvestimento, Snapshot,
+};
+use config_caminhos::caminhos;
+use std::fs::create_dir_all;
 
 use crate::dto::Regra;
 
-static HOME_DIR: LazyLock<PathBuf> = LazyLock::new(|| get_home_dir_path());
-
-#[cfg(debug_assertions)]
-fn get_home_dir_path() -> PathBuf {
-    use std::env;
-
-    let mut home = env::current_dir().unwrap();
-    home.push("../baseTest");
-    home
-}
-
-#[cfg(not(debug_assertions))]
-fn get_home_dir_path() -> PathBuf {
-    use homedir::my_home;
-
-    PathBuf::from(my_home().unwrap().unwrap())
-}
-
-pub fn get_home_dir() -> PathBuf {
-    HOME_DIR.clone()
-}
-
 fn preparar_diretorios() {
-    let home = get_home_dir();
-    for path in ["Downloads/importar", "Downloads/importado", ".financeiro"] {
-        let mut importar = home.clone();
-        importar.push(path);
-        create_dir_all(importar.clone())
-            .unwrap_or_else(|e| log::error!("Falha ao criar diretório {path} - erro: {e:?}"))
+    let c = caminhos();
+    for path in [&c.importar, &c.importado, &c.dados] {
+        create_dir_all(path).unwrap_or_else(|e| {
+            log::error!("Falha ao criar diretório {} - erro: {e:?}", path.display())
+        })
     }
 }
 
 fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args = match cli::separar_opcoes(&args) {
+        Ok((opcoes, args)) => {
+            if let Err(erro) = config_caminhos::validar_perfil(&opcoes) {
+                std::process::exit(cli::uso(&erro));
+            }
+            if let Err(erro) = config_caminhos::configurar(opcoes) {
+                eprintln!("{}", erro);
+                std::process::exit(cli::FALHA);
+            }
+            args
+        }
+        Err(erro) => std::process::exit(cli::uso(&erro)),
+    };
+
+    let comando = match cli::interpretar(&args) {
+        Ok(comando) => comando,
+        Err(erro) => std::process::exit(cli::uso(&erro)),
+    };
+
     config_log::config();
-    log::info!("Início");
+    log::info!("Início - perfil {}", caminhos().perfil);
 
     preparar_diretorios();
-    repository::file_repy::arq_limpar_tmp(".financeiro");
+    repository::file_repy::arq_limpar_tmp(&caminhos().dados);
+
+    // Sem subcomando a senha é pedida antes de abrir o terminal interativo.
+    let pode_perguntar = comando.as_ref().is_none_or(cli::Comando::pede_senha);
+    if let Err(erro) = repository::desbloquear_dados(pode_perguntar) {
+        log::error!("Dados não desbloqueados: {}", erro);
+        eprintln!("{}", erro);
+        std::process::exit(cli::FALHA);
+    }
+    let retomando = comando.as_ref().is_some_and(cli::Comando::retoma_criptografia);
+    if let Err(erro) = repository::conferir_pendencia(retomando) {
+        log::error!("{}", erro);
+        eprintln!("{}", erro);
+        std::process::exit(cli::FALHA);
+    }
+    if let Err(erro) = repository::abrir_armazenamento() {
+        log::error!("{}", erro);
+        eprintln!("{}", erro);
+        std::process::exit(cli::FALHA);
+    }
+    Snapshot::diario();
+
+    if let Some(comando) = comando {
+        let codigo = cli::executar(comando);
+        log::info!("Finalizado com código {}", codigo);
+        std::process::exit(codigo);
+    }
+
     importar();
-    
+    conciliar_dividas();
+
     Divida::atualizar();
     Regra::garantir_integridade();
 
@@ -61,11 +91,36 @@ fn main() {
     log::info!("Finalizado");
 }
 
-fn importar() {
-    let (lancamentos, bancos) = Lancamento::from_ofx();
+/// Importa os OFX pendentes; devolve quantos lançamentos e operações de investimento foram lidos.
+fn importar() -> (usize, usize) {
+    let extrato = Lancamento::from_ofx();
+    let lidos = (extrato.lancamentos.len(), extrato.operacoes.len());
+    if lidos != (0, 0) {
+        Snapshot::criar(GatilhoSnapshot::Importacao);
+    }
 
-    Banco::salvar_lista(bancos);
-    Lancamento::categorizar(&lancamentos);
+    Banco::salvar_lista(extrato.bancos);
+    Lancamento::categorizar(&extrato.lancamentos);
+
+    if !extrato.operacoes.is_empty() || !extrato.cotacoes.is_empty() {
+        Ativo::importar(&extrato.ativos);
+        OperacaoInvestimento::salvar_lista(&extrato.operacoes);
+        Cotacao::salvar_lista(&extrato.cotacoes);
+    }
+    lidos
+}
+
+fn conciliar_dividas() -> usize {
+    let mut lancamentos = Lancamento::lancamentos_listar();
+    lancamentos.extend(Lancamento::nao_categorizados_listar());
+
+    let mut dividas = Divida::listar();
+    let conciliadas = calc::conciliar_dividas(&mut dividas, &lancamentos);
+    if conciliadas > 0 {
+        log::info!("{} parcelas de dívidas conciliadas com lançamentos", conciliadas);
+        Divida::salvar_lista(&dividas);
+    }
+    conciliadas
 }
 
 fn start_tui() -> Result<()> {
@@ -0,0 +1,125 @@
+use std::sync::OnceLock;
+
+use crate::{
+    config_caminhos::{caminhos, TipoArmazenamento},
+    dto::{Banco, Categoria, Configuracao, Divida, Lancamento, Meta, Regra},
+};
+
+use super::{esquema_repy::arquivos_bloqueados, sqlite_repy::ArmazenamentoSqlite};
+
+static ARMAZENAMENTO: OnceLock<Box<dyn Armazenamento>> = OnceLock::new();
+
+/// Gravação em arquivos JSON, um por entidade, no diretório de dados.
+pub struct ArmazenamentoJson;
+
+/// Leitura e gravação de listas inteiras; regras de negócio (mesclar, ordenar,
+/// deduplicar) ficam nos métodos dos DTOs, que chamam estes.
+pub trait RepositorioLancamento {
+    fn lancamentos_listar(&self) -> Vec<Lancamento>;
+    /// Substitui todos os lançamentos categorizados.
+    fn lancamentos_salvar(&self, itens: &[Lancamento]);
+    /// Acrescenta os lançamentos cujo id ainda não existe.
+    fn lancamentos_adicionar(&self, itens: &[Lancamento]);
+    /// Remove um lançamento categorizado, sem regravar os demais.
+    fn lancamento_remover(&self, id: &str);
+    fn nao_categorizados_listar(&self) -> Vec<Lancamento>;
+    fn nao_categorizados_salvar(&self, itens: &[Lancamento]);
+    /// Grava um pendente, substituindo o de mesmo id.
+    fn nao_categorizado_gravar(&self, item: &Lancamento);
+}
+
+pub trait RepositorioCategoria {
+    fn categorias_listar(&self) -> Vec<Categoria>;
+    fn categorias_salvar(&self, itens: &[Categoria]);
+}
+
+pub trait RepositorioRegra {
+    fn regras_listar(&self) -> Vec<Regra>;
+    fn regras_salvar(&self, itens: &[Regra]);
+}
+
+pub trait RepositorioDivida {
+    fn dividas_listar(&self, arquivadas: bool) -> Vec<Divida>;
+    /// `false` se nada foi gravado, como quando a última leitura falhou.
+    fn dividas_salvar(&self, itens: &[Divida], arquivadas: bool) -> bool;
+}
+
+pub trait RepositorioMeta {
+    fn metas_listar(&self) -> Vec<Meta>;
+    fn metas_salvar(&self, itens: &[Meta]);
+}
+
+pub trait RepositorioBanco {
+    fn bancos_listar(&self) -> Vec<Banco>;
+    fn bancos_salvar(&self, itens: &[Banco]);
+}
+
+pub trait RepositorioConfiguracao {
+    fn configuracao_buscar(&self) -> Option<Configuracao>;
+    fn configuracao_salvar(&self, config: &Configuracao);
+}
+
+pub trait Armazenamento:
+    RepositorioLancamento
+    + RepositorioCategoria
+    + RepositorioRegra
+    + RepositorioDivida
+    + RepositorioMeta
+    + RepositorioBanco
+    + RepositorioConfiguracao
+    + Send
+    + Sync
+{
+    /// O que não pôde ser lido e está protegido contra gravação, além dos
+    /// arquivos de `arquivos_bloqueados`, que valem para os dois armazenamentos.
+    fn bloqueados(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+impl Armazenamento for ArmazenamentoJson {}
+
+/// Abre o armazenamento escolhido no perfil. Chamado no início, para que um
+/// SQLite que não abre encerre o programa antes de qualquer leitura, em vez de
+/// os dados irem para arquivos JSON que o perfil não usa.
+pub fn abrir_armazenamento() -> Result<(), String> {
+    if ARMAZENAMENTO.get().is_none() {
+        let _ = ARMAZENAMENTO.set(abrir()?);
+    }
+    Ok(())
+}
+
+/// Armazenamento escolhido no perfil. Se ainda não foi aberto e não abrir, o
+/// programa sai com `cli::FALHA`.
+pub fn armazenamento() -> &'static dyn Armazenamento {
+    ARMAZENAMENTO
+        .get_or_init(|| {
+            abrir().unwrap_or_else(|erro| {
+                log::error!("Erro ao abrir o armazenamento: {}", erro);
+                eprintln!("{}", erro);
+                std::process::exit(crate::cli::FALHA)
+            })
+        })
+        .as_ref()
+}
+
+/// Arquivos e tabelas que não puderam ser lidos e estão protegidos contra gravação.
+pub fn dados_bloqueados() -> Vec<String> {
+    let mut resp: Vec<String> = arquivos_bloqueados()
+        .iter()
+        .map(|a| a.display().to_string())
+        .collect();
+    if let Some(aberto) = ARMAZENAMENTO.get() {
+        resp.extend(aberto.bloqueados());
+    }
+    resp
+}
+
+fn abrir() -> Result<Box<dyn Armazenamento>, String> {
+    match caminhos().armazenamento {
+        TipoArmazenamento::Json => Ok(Box::new(ArmazenamentoJson)),
+        TipoArmazenamento::Sqlite => ArmazenamentoSqlite::abrir()
+            .map(|sqlite| Box::new(sqlite) as Box<dyn Armazenamento>)
+            .map_err(|e| format!("Erro ao abrir o SQLite: {}", e)),
+    }
+}
@@ -1,25 +1,20 @@
 use std::collections::HashSet;
 
-use crate::dto::Banco;
+use crate::{
+    config_caminhos::caminhos,
+    dto::{Banco, Conta},
+};
 
-use super::file_repy::{arq_escrever, arq_ler};
+use super::{
+    armazenamento::{armazenamento, ArmazenamentoJson, RepositorioBanco},
+    esquema_repy::{json_escrever, json_ler},
+};
 
-const FIN: &str = ".financeiro";
 const BANC: &str = "bancos.json";
 
 impl Banco {
     pub fn listar() -> Vec<Banco> {
-        let mut json: String = arq_ler(FIN, BANC).collect();
-        if json.is_empty() {
-            json = "[]".to_string();
-        }
-        match serde_json::from_str(&json) {
-            Ok(list) => list,
-            Err(erro) => {
-                log::error!("Erro ao ler bancos: {}", erro);
-                vec![]
-            }
-        }
+        armazenamento().bancos_listar()
     }
 
     pub fn buscar_id(id: String) -> Option<Banco> {
@@ -31,15 +26,37 @@ impl Banco {
 
         merge_bancos(&mut bancos, novos);
 
-        match serde_json::to_string_pretty(&bancos) {
-            Ok(json) => arq_escrever(FIN, BANC, json),
-            Err(erro) => log::error!("Erro ao salvar bancos: {}", erro),
-        }
+        armazenamento().bancos_salvar(&bancos);
     }
 
     pub fn salvar(banco: Banco) {
         Self::salvar_lista(vec![banco]);
     }
+
+    /// Substitui os dados da conta editada pelo usuário (tipo e saldo inicial).
+    pub fn alterar_conta(banco_id: &str, conta: Conta) {
+        let mut bancos: Vec<Banco> = Banco::listar();
+
+        if let Some(atual) = bancos
+            .iter_mut()
+            .find(|b| b.id == banco_id)
+            .and_then(|b| b.contas.iter_mut().find(|c| c.id == conta.id))
+        {
+            *atual = conta;
+        }
+
+        armazenamento().bancos_salvar(&bancos);
+    }
+}
+
+impl RepositorioBanco for ArmazenamentoJson {
+    fn bancos_listar(&self) -> Vec<Banco> {
+        json_ler(&caminhos().dados, BANC).unwrap_or_default()
+    }
+
+    fn bancos_salvar(&self, itens: &[Banco]) {
+        json_escrever(&caminhos().dados, BANC, itens);
+    }
 }
 
 fn merge_bancos(atual: &mut Vec<Banco>, novos: Vec<Banco>) {
@@ -51,6 +68,13 @@ fn merge_bancos(atual: &mut Vec<Banco>, novos: Vec<Banco>) {
             for conta in banco_novo.contas {
                 if !contas_existentes.contains(&conta.id) {
                     banco_atual.contas.push(conta);
+                } else if let Some(extrato) = conta.extrato {
+                    // mantém o tipo e o saldo inicial, só atualiza o saldo do extrato mais recente
+                    if let Some(existente) = banco_atual.contas.iter_mut().find(|c| c.id == conta.id) {
+                        if existente.extrato.as_ref().is_none_or(|e| e.data <= extrato.data) {
+                            existente.extrato = Some(extrato);
+                        }
+                    }
                 }
             }
         } else {
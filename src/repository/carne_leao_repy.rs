@@ -0,0 +1,26 @@
+use crate::{config_caminhos::caminhos, dto::CarneLeao};
+
+use super::esquema_repy::{json_escrever, json_ler};
+
+const CARNE_LEAO: &str = "carne_leao.json";
+
+impl CarneLeao {
+    pub fn listar() -> Vec<CarneLeao> {
+        let mut resp: Vec<CarneLeao> =
+            json_ler(&caminhos().dados, CARNE_LEAO).unwrap_or_default();
+        resp.sort_by_key(|c| c.mes);
+        resp
+    }
+
+    /// Substitui os meses já gravados pelos da lista e mantém os demais.
+    pub fn salvar_lista(meses: &[CarneLeao]) {
+        let mut lista: Vec<CarneLeao> = CarneLeao::listar()
+            .into_iter()
+            .filter(|c| !meses.iter().any(|m| m.mes == c.mes))
+            .collect();
+        lista.extend(meses.iter().cloned());
+        lista.sort_by_key(|c| c.mes);
+
+        json_escrever(&caminhos().dados, CARNE_LEAO, &lista);
+    }
+}
@@ -1,27 +1,25 @@
-use std::vec;
-
 use itertools::Itertools;
+use serde_json::Value;
 
-use crate::dto::{Categoria, Regra, Unico};
+use crate::{
+    config_caminhos::caminhos,
+    dto::{
+        ArvoreCategorias, Categoria, CategoriaLegada, GatilhoSnapshot, Regra, Snapshot, Unico,
+    },
+};
 
-use super::file_repy::{arq_escrever, arq_ler};
+use super::{
+    armazenamento::{armazenamento, ArmazenamentoJson, RepositorioCategoria},
+    esquema_repy::{json_escrever, json_ler},
+};
 
-const FIN: &str = ".financeiro";
 const CAT: &str = "categorias.json";
 
 impl Categoria {
     pub fn listar() -> Vec<Categoria> {
-        let mut json: String = arq_ler(FIN, CAT).collect();
-        if json.is_empty() {
-            json = "[]".to_string();
-        }
-        let mut resp: Vec<Categoria> = match serde_json::from_str(&json) {
-            Ok(vec) => vec,
-            Err(error) => {
-                log::error!("Erro ao desserializar categorias: {}", error);
-                vec![]
-            }
-        };
+        let mut resp = armazenamento().categorias_listar();
+
+        resp.preencher_hierarquia();
 
         resp = resp
             .into_iter()
@@ -31,6 +29,7 @@ impl Categoria {
         if resp.len() == 0usize {
             resp = Categoria::lista_padrao();
             Categoria::salvar_lista(&mut resp);
+            resp.preencher_hierarquia();
         }
 
         resp
@@ -45,10 +44,13 @@ impl Categoria {
             lista.push(self.clone());
         }
 
-        match serde_json::to_string_pretty(&lista) {
-            Ok(json) => arq_escrever(FIN, CAT, json),
-            Err(erro) => log::error!("Erro ao serializar categorias: {}", erro),
-        };
+        lista.descendentes(&self.id).iter().for_each(|d| {
+            if let Some(filho) = lista.iter_mut().find(|c| c.id == d.id) {
+                filho.tipo = self.tipo.clone();
+            }
+        });
+
+        armazenamento().categorias_salvar(&lista);
     }
 
     pub fn salvar_lista(categorias: &mut Vec<Categoria>) {
@@ -58,24 +60,51 @@ impl Categoria {
             }
         });
 
-        match serde_json::to_string_pretty(&categorias) {
-            Ok(json) => arq_escrever(FIN, CAT, json),
-            Err(erro) => log::error!("Erro ao serializar categorias: {}", erro),
-        };
+        armazenamento().categorias_salvar(categorias);
     }
 
     pub fn deletar(&self) {
+        Snapshot::criar(GatilhoSnapshot::ExclusaoCategoria);
         let mut lista = Categoria::listar();
 
         if let Some(pos) = lista.iter().position(|a| a.id == self.id) {
             lista.remove(pos);
         }
 
-        match serde_json::to_string_pretty(&lista) {
-            Ok(json) => arq_escrever(FIN, CAT, json),
-            Err(erro) => log::error!("Erro ao serializar categorias: {}", erro),
-        };
+        lista
+            .iter_mut()
+            .filter(|c| c.pai.as_deref() == Some(self.id.as_str()))
+            .for_each(|c| c.pai = self.pai.clone());
+
+        armazenamento().categorias_salvar(&lista);
 
         Regra::garantir_integridade();
     }
-}
\ No newline at end of file
+}
+
+impl RepositorioCategoria for ArmazenamentoJson {
+    fn categorias_listar(&self) -> Vec<Categoria> {
+        json_ler(&caminhos().dados, CAT).unwrap_or_default()
+    }
+
+    fn categorias_salvar(&self, itens: &[Categoria]) {
+        json_escrever(&caminhos().dados, CAT, itens);
+    }
+}
+
+/// Versão 2: a lista plana antiga (grupo, subgrupo, nome) vira a árvore com `pai`.
+pub(super) fn migrar_categorias_arvore(dados: Value) -> Result<Value, String> {
+    if serde_json::from_value::<Vec<Categoria>>(dados.clone()).is_ok() {
+        return Ok(dados);
+    }
+
+    let legadas: Vec<CategoriaLegada> =
+        serde_json::from_value(dados).map_err(|e| e.to_string())?;
+    log::info!("Migrando {} categorias para a árvore", legadas.len());
+    let mut migradas = Categoria::migrar_legado(legadas);
+    migradas
+        .iter_mut()
+        .filter(|c| c.id.is_empty())
+        .for_each(|c| c.gerar_id());
+    serde_json::to_value(migradas).map_err(|e| e.to_string())
+}
@@ -1,34 +1,25 @@
-use crate::dto::Configuracao;
+use crate::{config_caminhos::caminhos, dto::Configuracao};
 
-use super::file_repy::{arq_escrever, arq_ler};
+use super::{
+    armazenamento::{armazenamento, ArmazenamentoJson, RepositorioConfiguracao},
+    esquema_repy::{json_escrever, json_ler},
+};
 
-const DIR: &str = ".financeiro";
 const FILE: &str = "config.json";
 
 impl Configuracao {
     pub fn salvar(&self) {
-        match serde_json::to_string_pretty(self) {
-            Ok(json) => arq_escrever(DIR, FILE, json),
-            Err(erro) => log::error!("Erro ao salvar configuração: {}", erro),
-        };
+        armazenamento().configuracao_salvar(self);
     }
 
     pub fn buscar() -> Self {
-        let json: String = arq_ler(DIR, FILE).collect();
-
-        if json.is_empty() {
-            let config = Configuracao::default();
-            config.salvar();
-            config
-        } else {
-            let resp: Configuracao = match serde_json::from_str(&json) {
-                Ok(conf) => conf,
-                Err(erro) => {
-                    println!("Erro ao ler configuração: {}", erro);
-                    Configuracao::default()
-                }
-            };
-            resp
+        match armazenamento().configuracao_buscar() {
+            Some(config) => config,
+            None => {
+                let config = Configuracao::default();
+                config.salvar();
+                config
+            }
         }
     }
 
@@ -43,6 +34,16 @@ impl Configuracao {
     }
 }
 
+impl RepositorioConfiguracao for ArmazenamentoJson {
+    fn configuracao_buscar(&self) -> Option<Configuracao> {
+        json_ler(&caminhos().dados, FILE)
+    }
+
+    fn configuracao_salvar(&self, config: &Configuracao) {
+        json_escrever(&caminhos().dados, FILE, config);
+    }
+}
+
 impl Default for Configuracao {
     fn default() -> Self {
         Self {
@@ -52,6 +53,7 @@ impl Default for Configuracao {
             contracheque_saidas: Default::default(),
             contracheque: true,
             contracheque_empresa: String::new(),
+            dependentes: 0,
         }
     }
 }
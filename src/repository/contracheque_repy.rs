@@ -0,0 +1,41 @@
+use crate::{config_caminhos::caminhos, dto::Contracheque};
+
+use super::esquema_repy::{json_escrever, json_ler};
+
+const CONTRACHEQUES: &str = "contracheques.json";
+
+impl Contracheque {
+    /// Contracheques do mais antigo para o mais recente pagamento.
+    pub fn listar() -> Vec<Contracheque> {
+        let mut resp: Vec<Contracheque> =
+            json_ler(&caminhos().dados, CONTRACHEQUES).unwrap_or_default();
+        resp.sort_by_key(|c| (c.data_pagamento, c.competencia));
+        resp
+    }
+
+    pub fn salvar(&self) {
+        let mut lista = Contracheque::listar();
+
+        if let Some(i) = lista.iter().position(|c| c.id == self.id) {
+            lista[i] = self.clone();
+        } else {
+            lista.push(self.clone());
+        }
+
+        Contracheque::escrever(&lista);
+    }
+
+    pub fn deletar(&self) {
+        let mut lista = Contracheque::listar();
+
+        if let Some(pos) = lista.iter().position(|c| c.id == self.id) {
+            lista.remove(pos);
+        }
+
+        Contracheque::escrever(&lista);
+    }
+
+    fn escrever(lista: &[Contracheque]) {
+        json_escrever(&caminhos().dados, CONTRACHEQUES, lista);
+    }
+}
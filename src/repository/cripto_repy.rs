@@ -0,0 +1,474 @@
+use std::{
+    env,
+    fs::{self, remove_file},
+    path::{Path, PathBuf},
+    sync::RwLock,
+};
+
+use aes_gcm::{
+    aead::{rand_core::RngCore, Aead, AeadCore, KeyInit, OsRng},
+    Aes256Gcm, Key, Nonce,
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use pbkdf2::pbkdf2_hmac;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use crate::{
+    config_caminhos::{caminhos, TipoArmazenamento},
+    dto::{GatilhoSnapshot, Snapshot},
+};
+
+use super::{
+    dash_repy::recodificar_dash,
+    ofx_repy::arquivos_importados,
+    snapshot_repy::{arquivos_dados, escrever, recodificar_snapshots},
+};
+
+/// Início de todo conteúdo cifrado; depois vem o base64 do nonce seguido do texto
+/// cifrado com a etiqueta do AES-GCM, o mesmo formato que o dashboard decifra.
+pub const PREFIXO: &str = "FINCRIPTO1:";
+
+/// Parâmetros da chave, em texto puro, no diretório de dados. A existência dele
+/// indica que a criptografia está ativa.
+pub const ARQUIVO: &str = "cripto.json";
+
+/// Parâmetros da chave nova enquanto os arquivos são gravados com ela. Só vira
+/// `cripto.json` quando todos estiverem gravados; se sobrar, a mudança foi
+/// interrompida e é retomada com a mesma senha.
+pub const ARQUIVO_PENDENTE: &str = "cripto.json.novo";
+
+const ITERACOES: u32 = 600_000;
+const TAMANHO_SAL: usize = 16;
+const TAMANHO_NONCE: usize = 12;
+/// Texto cifrado em `cripto.json` para conferir a senha antes de ler qualquer dado.
+const VERIFICACAO: &str = "financeiro";
+
+static CHAVE: RwLock<Option<Chave>> = RwLock::new(None);
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Parametros {
+    pub iteracoes: u32,
+    /// Base64.
+    pub sal: String,
+    verificacao: String,
+}
+
+#[derive(Clone)]
+pub struct Chave {
+    chave: [u8; 32],
+    pub parametros: Parametros,
+}
+
+impl Chave {
+    fn derivar(senha: &str, parametros: Parametros) -> Result<Chave, String> {
+        let sal = STANDARD
+            .decode(&parametros.sal)
+            .map_err(|e| format!("sal inválido em {}: {}", ARQUIVO, e))?;
+        let mut chave = [0u8; 32];
+        pbkdf2_hmac::<Sha256>(senha.as_bytes(), &sal, parametros.iteracoes, &mut chave);
+        Ok(Chave { chave, parametros })
+    }
+
+    /// Chave nova, com sal aleatório, para `ativar` e `trocar_senha`.
+    fn nova(senha: &str) -> Result<Chave, String> {
+        Chave::gerar(senha, ITERACOES)
+    }
+
+    pub(super) fn gerar(senha: &str, iteracoes: u32) -> Result<Chave, String> {
+        let mut sal = [0u8; TAMANHO_SAL];
+        OsRng.fill_bytes(&mut sal);
+        let parametros = Parametros {
+            iteracoes,
+            sal: STANDARD.encode(sal),
+            verificacao: String::new(),
+        };
+        let mut chave = Chave::derivar(senha, parametros)?;
+        chave.parametros.verificacao = chave.cifrar(VERIFICACAO.as_bytes())?;
+        Ok(chave)
+    }
+
+    fn conferir(&self) -> Result<(), String> {
+        match self.decifrar(&self.parametros.verificacao) {
+            Ok(texto) if texto == VERIFICACAO.as_bytes() => Ok(()),
+            _ => Err("senha incorreta".to_string()),
+        }
+    }
+
+    fn cifrar(&self, bytes: &[u8]) -> Result<String, String> {
+        let cifra = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&self.chave));
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let cifrado = cifra
+            .encrypt(&nonce, bytes)
+            .map_err(|_| "falha ao cifrar".to_string())?;
+
+        let mut resp = nonce.to_vec();
+        resp.extend(cifrado);
+        Ok(format!("{}{}", PREFIXO, STANDARD.encode(resp)))
+    }
+
+    fn decifrar(&self, texto: &str) -> Result<Vec<u8>, String> {
+        let bytes = STANDARD
+            .decode(texto.trim().trim_start_matches(PREFIXO))
+            .map_err(|e| e.to_string())?;
+        if bytes.len() < TAMANHO_NONCE {
+            return Err("conteúdo cifrado incompleto".to_string());
+        }
+        let (nonce, cifrado) = bytes.split_at(TAMANHO_NONCE);
+        let cifra = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&self.chave));
+        cifra
+            .decrypt(Nonce::from_slice(nonce), cifrado)
+            .map_err(|_| "não foi possível decifrar; senha diferente da usada".to_string())
+    }
+}
+
+/// Se a criptografia está ativa no perfil.
+pub fn criptografia_ativa() -> bool {
+    arquivo_parametros().exists()
+}
+
+/// Carrega a chave no início. Sem criptografia não faz nada; com ela, usa
+/// `FINANCEIRO_SENHA` ou, se `perguntar`, pede a senha no terminal.
+pub fn desbloquear_dados(perguntar: bool) -> Result<(), String> {
+    let Some(parametros) = ler_parametros(&arquivo_parametros())? else {
+        return Ok(());
+    };
+    if caminhos().armazenamento == TipoArmazenamento::Sqlite {
+        return Err(
+            "os dados estão cifrados e o SQLite não pode ser; use armazenamento json".to_string(),
+        );
+    }
+
+    if let Ok(senha) = env::var("FINANCEIRO_SENHA") {
+        let chave = Chave::derivar(&senha, parametros)?;
+        chave.conferir()?;
+        return guardar(Some(chave));
+    }
+    if !perguntar {
+        return Err("os dados estão cifrados: informe a senha em FINANCEIRO_SENHA".to_string());
+    }
+
+    for _ in 0..3 {
+        let senha = rpassword::prompt_password("Senha dos dados: ").map_err(|e| e.to_string())?;
+        let chave = Chave::derivar(&senha, parametros.clone())?;
+        match chave.conferir() {
+            Ok(_) => return guardar(Some(chave)),
+            Err(erro) => eprintln!("{}", erro),
+        }
+    }
+    Err("senha incorreta".to_string())
+}
+
+/// Recusa o uso normal enquanto houver uma ativação ou troca de senha
+/// interrompida; só `retomando` com o mesmo comando ela pode seguir.
+pub fn conferir_pendencia(retomando: bool) -> Result<(), String> {
+    if retomando || !arquivo_pendente().exists() {
+        return Ok(());
+    }
+    let comando = match criptografia_ativa() {
+        true => "criptografia trocar",
+        false => "criptografia ativar",
+    };
+    Err(format!(
+        "uma mudança de criptografia foi interrompida; rode `{}` de novo com a mesma senha",
+        comando
+    ))
+}
+
+/// Cifra os dados existentes com a senha nova. Devolve quantos arquivos foram gravados.
+pub fn ativar_criptografia(senha: &str) -> Result<usize, String> {
+    if criptografia_ativa() {
+        return Err("a criptografia já está ativa; use criptografia trocar".to_string());
+    }
+    if caminhos().armazenamento == TipoArmazenamento::Sqlite {
+        return Err("o SQLite não pode ser cifrado; use armazenamento json".to_string());
+    }
+    mudar_chave(None, senha)
+}
+
+/// Grava os dados de volta em texto puro e apaga `cripto.json`.
+pub fn desativar_criptografia() -> Result<usize, String> {
+    let atual = chave().ok_or("a criptografia não está ativa")?;
+    conferir_pendencia(false)?;
+
+    Snapshot::criar(GatilhoSnapshot::Criptografia);
+    let c = caminhos();
+    let gravados = recodificar(&c.dados, &c.dash, &c.importado, Some(&atual), None)?;
+    remove_file(arquivo_parametros()).map_err(|e| e.to_string())?;
+    guardar(None)?;
+    Ok(gravados)
+}
+
+/// Cifra de novo os dados com uma chave derivada da senha nova e de um sal novo.
+pub fn trocar_senha(senha: &str) -> Result<usize, String> {
+    let atual = chave().ok_or("a criptografia não está ativa")?;
+    mudar_chave(Some(&atual), senha)
+}
+
+/// Grava os parâmetros novos em `cripto.json.novo` antes de tocar nos dados e só
+/// os move para `cripto.json` depois que todos os arquivos estiverem gravados.
+/// Com o pendente já existente, retoma a mudança interrompida se a senha conferir.
+fn mudar_chave(atual: Option<&Chave>, senha: &str) -> Result<usize, String> {
+    let pendente = arquivo_pendente();
+    let nova = match ler_parametros(&pendente)? {
+        Some(parametros) => {
+            let nova = Chave::derivar(senha, parametros)?;
+            nova.conferir().map_err(|_| {
+                format!(
+                    "a senha não confere com a da mudança interrompida; para desistir dela apague {}",
+                    pendente.display()
+                )
+            })?;
+            log::info!("Retomando a mudança de criptografia interrompida");
+            nova
+        }
+        None => {
+            let nova = Chave::nova(senha)?;
+            escrever_parametros(&pendente, &nova.parametros)?;
+            nova
+        }
+    };
+
+    Snapshot::criar(GatilhoSnapshot::Criptografia);
+    let c = caminhos();
+    let gravados = recodificar(&c.dados, &c.dash, &c.importado, atual, Some(&nova))?;
+    fs::rename(&pendente, arquivo_parametros())
+        .map_err(|e| format!("{}: {}", pendente.display(), e))?;
+    guardar(Some(nova))?;
+    Ok(gravados)
+}
+
+/// Chave carregada, se a criptografia estiver ativa.
+pub fn chave() -> Option<Chave> {
+    CHAVE.read().ok().and_then(|c| c.clone())
+}
+
+/// Conteúdo a gravar: cifrado se houver chave, senão o próprio texto.
+pub(super) fn codificar(bytes: &[u8]) -> Result<Vec<u8>, String> {
+    codificar_com(chave().as_ref(), bytes)
+}
+
+/// Conteúdo lido: decifrado se estiver cifrado. Texto puro é aceito mesmo com a
+/// criptografia ativa e sai cifrado na próxima gravação.
+pub(super) fn decodificar(bytes: &[u8]) -> Result<Vec<u8>, String> {
+    decodificar_com(chave().as_ref(), bytes)
+}
+
+pub(super) fn codificar_com(chave: Option<&Chave>, bytes: &[u8]) -> Result<Vec<u8>, String> {
+    match chave {
+        Some(chave) => chave.cifrar(bytes).map(String::into_bytes),
+        None => Ok(bytes.to_vec()),
+    }
+}
+
+fn decodificar_com(chave: Option<&Chave>, bytes: &[u8]) -> Result<Vec<u8>, String> {
+    if !bytes.starts_with(PREFIXO.as_bytes()) {
+        return Ok(bytes.to_vec());
+    }
+    let texto = std::str::from_utf8(bytes).map_err(|e| e.to_string())?;
+    match chave {
+        Some(chave) => chave.decifrar(texto),
+        None => Err("conteúdo cifrado e nenhuma senha informada".to_string()),
+    }
+}
+
+/// Decifra com `de` o que vai ser gravado com `para`. O que já estiver com `para`,
+/// gravado antes de uma mudança ser interrompida, também é aceito.
+pub(super) fn decodificar_retomando(
+    de: Option<&Chave>,
+    para: Option<&Chave>,
+    bytes: &[u8],
+) -> Result<Vec<u8>, String> {
+    decodificar_com(de, bytes).or_else(|erro| match para {
+        Some(para) => decodificar_com(Some(para), bytes).map_err(|_| erro),
+        None => Err(erro),
+    })
+}
+
+/// Lê tudo com a chave `de` e prepara com a chave `para` antes de gravar qualquer
+/// coisa, para que uma senha errada ou um arquivo corrompido não deixe os dados
+/// com chaves misturadas: arquivos de dados, OFX já importados, entradas dos
+/// snapshots e dados do dashboard.
+fn recodificar(
+    dados: &Path,
+    dash: &Path,
+    importado: &Path,
+    de: Option<&Chave>,
+    para: Option<&Chave>,
+) -> Result<usize, String> {
+    let mut gravar: Vec<(PathBuf, Vec<u8>)> = Vec::new();
+
+    let arquivos = arquivos_dados(dados)
+        .into_iter()
+        .map(|nome| dados.join(nome))
+        .chain(arquivos_importados(importado));
+    for path in arquivos {
+        let nome = path.display().to_string();
+        let bytes = fs::read(&path).map_err(|e| format!("{}: {}", nome, e))?;
+        let claro =
+            decodificar_retomando(de, para, &bytes).map_err(|e| format!("{}: {}", nome, e))?;
+        gravar.push((path, codificar_com(para, &claro)?));
+    }
+    gravar.extend(recodificar_snapshots(dados, de, para)?);
+    gravar.extend(recodificar_dash(dash, de, para)?);
+
+    for (path, bytes) in gravar.iter() {
+        escrever(path, bytes).map_err(|e| format!("{}: {}", path.display(), e))?;
+    }
+    log::info!("{} arquivos gravados com a nova chave", gravar.len());
+    Ok(gravar.len())
+}
+
+fn guardar(chave: Option<Chave>) -> Result<(), String> {
+    let mut atual = CHAVE.write().map_err(|e| e.to_string())?;
+    *atual = chave;
+    Ok(())
+}
+
+fn arquivo_parametros() -> PathBuf {
+    caminhos().dados.join(ARQUIVO)
+}
+
+fn arquivo_pendente() -> PathBuf {
+    caminhos().dados.join(ARQUIVO_PENDENTE)
+}
+
+fn ler_parametros(path: &Path) -> Result<Option<Parametros>, String> {
+    match fs::read_to_string(path) {
+        Ok(json) => serde_json::from_str(&json)
+            .map(Some)
+            .map_err(|e| format!("Erro ao ler {}: {}", path.display(), e)),
+        Err(_) => Ok(None),
+    }
+}
+
+fn escrever_parametros(path: &Path, parametros: &Parametros) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(parametros).map_err(|e| e.to_string())?;
+    escrever(path, json.as_bytes()).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::teste::DirTeste;
+
+    fn chave(senha: &str) -> Chave {
+        Chave::gerar(senha, 1).unwrap()
+    }
+
+    fn ler_com(chave: Option<&Chave>, bytes: &[u8]) -> Vec<u8> {
+        decodificar_com(chave, bytes).unwrap()
+    }
+
+    /// Dados, um script do dashboard e um OFX importado, em texto puro.
+    fn perfil() -> (DirTeste, DirTeste, DirTeste) {
+        let dados = DirTeste::novo();
+        dados.escrever("lancamentos.json", r#"{"versao":1,"dados":[]}"#);
+        dados.escrever("tabelas/2025.json", "{}");
+        let dash = DirTeste::novo();
+        dash.escrever("data/resumo.js", r#"var resumo = {"saldo":1};"#);
+        let importado = DirTeste::novo();
+        importado.escrever("extrato.ofx", "<OFX>");
+        (dados, dash, importado)
+    }
+
+    #[test]
+    fn cifrar_e_decifrar_devolvem_o_original() {
+        let chave = chave("segredo");
+        let cifrado = chave.cifrar(b"dados").unwrap();
+
+        assert!(cifrado.starts_with(PREFIXO));
+        assert_eq!(chave.decifrar(&cifrado).unwrap(), b"dados");
+        assert_ne!(chave.cifrar(b"dados").unwrap(), cifrado);
+    }
+
+    #[test]
+    fn conferir_recusa_senha_errada() {
+        let certa = chave("segredo");
+        let errada = Chave::derivar("outra", certa.parametros.clone()).unwrap();
+        let de_novo = Chave::derivar("segredo", certa.parametros.clone()).unwrap();
+
+        assert!(certa.conferir().is_ok());
+        assert!(de_novo.conferir().is_ok());
+        assert_eq!(errada.conferir(), Err("senha incorreta".to_string()));
+        assert!(errada.decifrar(&certa.cifrar(b"x").unwrap()).is_err());
+    }
+
+    #[test]
+    fn recodificar_ativa_troca_e_desativa() {
+        let (dados, dash, importado) = perfil();
+        let original = dados.ler("lancamentos.json");
+        let (a, b) = (chave("a"), chave("b"));
+
+        let gravados =
+            recodificar(dados.path(), dash.path(), importado.path(), None, Some(&a)).unwrap();
+        // dois de dados, o OFX, o script do dashboard e cripto.js
+        assert_eq!(gravados, 5);
+        assert_eq!(ler_com(Some(&a), &importado.ler("extrato.ofx")), b"<OFX>");
+        let cifrado = dados.ler("lancamentos.json");
+        assert!(cifrado.starts_with(PREFIXO.as_bytes()));
+        assert_eq!(ler_com(Some(&a), &cifrado), original);
+        let script = String::from_utf8(dash.ler("data/resumo.js")).unwrap();
+        assert!(script.starts_with(&format!("var resumo = \"{}", PREFIXO)));
+
+        recodificar(
+            dados.path(),
+            dash.path(),
+            importado.path(),
+            Some(&a),
+            Some(&b),
+        )
+        .unwrap();
+        let cifrado = dados.ler("tabelas/2025.json");
+        assert!(decodificar_com(Some(&a), &cifrado).is_err());
+        assert_eq!(ler_com(Some(&b), &cifrado), b"{}");
+
+        recodificar(dados.path(), dash.path(), importado.path(), Some(&b), None).unwrap();
+        assert_eq!(dados.ler("lancamentos.json"), original);
+        assert_eq!(dash.ler("data/resumo.js"), b"var resumo = {\"saldo\":1};");
+        assert_eq!(dash.ler("data/cripto.js"), b"var cripto = null;");
+        assert_eq!(importado.ler("extrato.ofx"), b"<OFX>");
+    }
+
+    #[test]
+    fn recodificar_com_chave_errada_nao_grava_nada() {
+        let (dados, dash, importado) = perfil();
+        let (a, b) = (chave("a"), chave("b"));
+        recodificar(dados.path(), dash.path(), importado.path(), None, Some(&a)).unwrap();
+        let antes = dados.ler("lancamentos.json");
+
+        assert!(recodificar(dados.path(), dash.path(), importado.path(), Some(&b), None).is_err());
+        assert_eq!(dados.ler("lancamentos.json"), antes);
+    }
+
+    #[test]
+    fn recodificar_retoma_mudanca_interrompida() {
+        let (dados, dash, importado) = perfil();
+        let (a, b) = (chave("a"), chave("b"));
+        recodificar(dados.path(), dash.path(), importado.path(), None, Some(&a)).unwrap();
+        // parou depois de gravar só um arquivo com a chave nova
+        let claro = ler_com(Some(&a), &dados.ler("tabelas/2025.json"));
+        dados.escrever(
+            "tabelas/2025.json",
+            codificar_com(Some(&b), &claro).unwrap(),
+        );
+
+        recodificar(
+            dados.path(),
+            dash.path(),
+            importado.path(),
+            Some(&a),
+            Some(&b),
+        )
+        .unwrap();
+        assert_eq!(ler_com(Some(&b), &dados.ler("tabelas/2025.json")), b"{}");
+        assert!(ler_com(Some(&b), &dados.ler("lancamentos.json")).starts_with(b"{"));
+    }
+
+    #[test]
+    fn texto_puro_passa_sem_chave() {
+        assert_eq!(ler_com(None, b"{}"), b"{}");
+        assert_eq!(codificar_com(None, b"{}").unwrap(), b"{}");
+        assert!(decodificar_com(None, chave("a").cifrar(b"{}").unwrap().as_bytes()).is_err());
+    }
+}
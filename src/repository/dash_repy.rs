@@ -1,16 +1,27 @@
 use include_dir::{include_dir, Dir};
-use std::fs;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
 
 use crate::dto::{
-    DashDivida, DashGastoPor, DashGastoPorCategoria, DashGastoPorCategoriaAno, DashResumo, Orientacao,
+    DashContracheque, DashDivida, DashFluxoCaixa, DashGastoPor, DashGastoPorCategoria,
+    DashGastoPorCategoriaAno, DashInvestimento, DashMeta, DashObjetivo, DashOrcamento,
+    DashPatrimonio, DashQuitacao, DashResumo, Orientacao,
 };
 
-use super::file_repy::arq_escrever;
-use crate::get_home_dir;
+use super::{
+    cripto_repy::{chave, codificar_com, decodificar_retomando, Chave},
+    file_repy::arq_escrever,
+};
+use crate::config_caminhos::caminhos;
 
 static PROJECT_DIR: Dir = include_dir!("$CARGO_MANIFEST_DIR/dashfiles");
 
-const FIN: &str = "Financeiro/";
+const DIR_DADOS: &str = "data";
+
+/// Script em `data/` com os parâmetros da chave para o navegador.
+const ARQUIVO_CRIPTO: &str = "cripto.js";
 
 impl DashResumo {
     pub fn salvar(resumo: DashResumo) {
@@ -48,6 +59,42 @@ impl DashGastoPor {
     }
 }
 
+impl DashFluxoCaixa {
+    pub fn salvar(meses: Vec<DashFluxoCaixa>) {
+        match serde_json::to_string_pretty(&meses) {
+            Ok(json) => escrever("fluxo_caixa", json),
+            Err(erro) => log::error!("Erro ao salvar o fluxo de caixa: {}", erro),
+        };
+    }
+}
+
+impl DashPatrimonio {
+    pub fn salvar(patrimonio: DashPatrimonio) {
+        match serde_json::to_string_pretty(&patrimonio) {
+            Ok(json) => escrever("patrimonio", json),
+            Err(erro) => log::error!("Erro ao salvar o patrimônio: {}", erro),
+        };
+    }
+}
+
+impl DashContracheque {
+    pub fn salvar(meses: Vec<DashContracheque>) {
+        match serde_json::to_string_pretty(&meses) {
+            Ok(json) => escrever("contracheques", json),
+            Err(erro) => log::error!("Erro ao salvar os contracheques: {}", erro),
+        };
+    }
+}
+
+impl DashInvestimento {
+    pub fn salvar(posicoes: Vec<DashInvestimento>) {
+        match serde_json::to_string_pretty(&posicoes) {
+            Ok(json) => escrever("investimentos", json),
+            Err(erro) => log::error!("Erro ao salvar os investimentos: {}", erro),
+        };
+    }
+}
+
 impl DashDivida {
     pub fn salvar(dividas: Vec<DashDivida>) {
         match serde_json::to_string_pretty(&dividas) {
@@ -57,6 +104,42 @@ impl DashDivida {
     }
 }
 
+impl DashMeta {
+    pub fn salvar(metas: Vec<DashMeta>) {
+        match serde_json::to_string_pretty(&metas) {
+            Ok(json) => escrever("metas", json),
+            Err(erro) => log::error!("Erro ao salvar as metas: {}", erro),
+        };
+    }
+}
+
+impl DashObjetivo {
+    pub fn salvar(objetivos: Vec<DashObjetivo>) {
+        match serde_json::to_string_pretty(&objetivos) {
+            Ok(json) => escrever("objetivos", json),
+            Err(erro) => log::error!("Erro ao salvar os objetivos: {}", erro),
+        };
+    }
+}
+
+impl DashOrcamento {
+    pub fn salvar(orcamentos: Vec<DashOrcamento>) {
+        match serde_json::to_string_pretty(&orcamentos) {
+            Ok(json) => escrever("orcamentos", json),
+            Err(erro) => log::error!("Erro ao salvar os orçamentos: {}", erro),
+        };
+    }
+}
+
+impl DashQuitacao {
+    pub fn salvar(plano: DashQuitacao) {
+        match serde_json::to_string_pretty(&plano) {
+            Ok(json) => escrever("quitacao", json),
+            Err(erro) => log::error!("Erro ao salvar o plano de quitação: {}", erro),
+        };
+    }
+}
+
 impl Orientacao {
     pub fn salvar(orientacoes: &Vec<Orientacao>) {
         match serde_json::to_string_pretty(orientacoes) {
@@ -68,27 +151,95 @@ impl Orientacao {
 
 pub fn atualizar_base() {
     transferir_diretorio(&PROJECT_DIR, "");
+    escrever_cripto(chave().as_ref());
 }
 
+/// Com a criptografia ativa, grava o JSON cifrado como string; `decifrar.js` pede
+/// a senha no navegador e troca a string pelos dados.
 fn escrever(nome: &str, conteudo: String) {
-    let valor = format!("var {} = {};", nome, conteudo);
-    arq_escrever(
-        format!("{}/data", FIN).as_str(),
-        format!("{}.js", nome).as_str(),
-        valor,
-    );
+    match script_dados(nome, conteudo.as_bytes(), chave().as_ref()) {
+        Ok(valor) => arq_escrever(&dir_dados(), format!("{}.js", nome).as_str(), valor),
+        Err(erro) => log::error!("Erro ao gravar dados do dashboard {}: {}", nome, erro),
+    }
+}
+
+fn script_dados(nome: &str, json: &[u8], chave: Option<&Chave>) -> Result<String, String> {
+    match chave {
+        Some(_) => {
+            let cifrado =
+                String::from_utf8(codificar_com(chave, json)?).map_err(|e| e.to_string())?;
+            Ok(format!("var {} = \"{}\";", nome, cifrado))
+        }
+        None => Ok(format!("var {} = {};", nome, String::from_utf8_lossy(json))),
+    }
+}
+
+/// Parâmetros que o navegador usa para derivar a chave; `null` sem criptografia.
+fn escrever_cripto(chave: Option<&Chave>) {
+    arq_escrever(&dir_dados(), ARQUIVO_CRIPTO, script_cripto(chave));
+}
+
+fn script_cripto(chave: Option<&Chave>) -> String {
+    match chave {
+        Some(chave) => format!(
+            "var cripto = {{ \"sal\": \"{}\", \"iteracoes\": {} }};",
+            chave.parametros.sal, chave.parametros.iteracoes
+        ),
+        None => "var cripto = null;".to_string(),
+    }
+}
+
+/// Dados já gerados do dashboard decifrados com `de` e cifrados com `para`, sem
+/// gravar nada, como em `recodificar_snapshots`.
+pub(super) fn recodificar_dash(
+    dash: &Path,
+    de: Option<&Chave>,
+    para: Option<&Chave>,
+) -> Result<Vec<(PathBuf, Vec<u8>)>, String> {
+    let dir = dash.join(DIR_DADOS);
+    let mut resp = vec![(dir.join(ARQUIVO_CRIPTO), script_cripto(para).into_bytes())];
+
+    let Ok(entradas) = fs::read_dir(&dir) else {
+        return Ok(resp);
+    };
+    for entrada in entradas.flatten() {
+        let path = entrada.path();
+        let arquivo = entrada.file_name().to_string_lossy().to_string();
+        let Some(nome) = arquivo.strip_suffix(".js") else {
+            continue;
+        };
+        if arquivo == ARQUIVO_CRIPTO {
+            continue;
+        }
+
+        let texto = fs::read_to_string(&path).map_err(|e| format!("{}: {}", arquivo, e))?;
+        let valor = texto
+            .trim()
+            .strip_prefix(&format!("var {} = ", nome))
+            .and_then(|v| v.strip_suffix(';'))
+            .ok_or(format!("{}: formato inesperado", arquivo))?;
+        let json = match valor.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+            Some(cifrado) => decodificar_retomando(de, para, cifrado.as_bytes())
+                .map_err(|e| format!("{}: {}", arquivo, e))?,
+            None => valor.as_bytes().to_vec(),
+        };
+        resp.push((path, script_dados(nome, &json, para)?.into_bytes()));
+    }
+    Ok(resp)
+}
+
+fn dir_dados() -> PathBuf {
+    caminhos().dash.join(DIR_DADOS)
 }
 
 fn transferir_diretorio(dir: &Dir, destino: &str) {
     for file in dir.files() {
         if let Some(content) = file.contents_utf8() {
             if let Some(nome) = file.path().file_name() {
-                let dir_destino = FIN.to_string() + destino;
-                let mut path = get_home_dir();
-                path.push(&dir_destino);
-                path.push(nome);
+                let dir_destino = caminhos().dash.join(destino);
+                let path = dir_destino.join(nome);
                 if fs::read_to_string(&path).unwrap_or_default() != content {
-                    arq_escrever(&dir_destino, nome.to_str().unwrap(), content.to_string());
+                    arq_escrever(&dir_destino, nome.to_str().unwrap(), content);
                 }
             }
         }
@@ -2,28 +2,20 @@ use std::cmp::Ordering;
 
 use itertools::Itertools;
 
-use crate::dto::Divida;
+use crate::{config_caminhos::caminhos, dto::Divida};
 
-use super::file_repy::{arq_escrever, arq_ler};
+use super::{
+    armazenamento::{armazenamento, ArmazenamentoJson, RepositorioDivida},
+    esquema_repy::{json_escrever, json_ler},
+};
 
-const FIN: &str = ".financeiro";
 const CAT: &str = "dividas.json";
+const ARQUIVO: &str = "dividas_arquivadas.json";
 
 impl Divida {
     pub fn listar() -> Vec<Divida> {
         let corte = chrono::Local::now().naive_local().date() + chrono::Duration::days(10);
-        let mut json: String = arq_ler(FIN, CAT).collect();
-        if json.is_empty() {
-            json = "[]".to_string();
-        }
-
-        let resp: Vec<Divida> = match serde_json::from_str(&json) {
-            Ok(d) => d,
-            Err(erro) => {
-                log::error!("Erro ao ler dívidas: {}", erro);
-                Vec::new()
-            }
-        };
+        let resp: Vec<Divida> = armazenamento().dividas_listar(false);
 
         let sorted: Vec<Divida> = resp
             .into_iter()
@@ -58,16 +50,50 @@ impl Divida {
             lista.push(self.clone());
         }
 
-        match serde_json::to_string_pretty(&lista) {
-            Ok(json) => arq_escrever(FIN, CAT, json),
-            Err(erro) => log::error!("Erro ao salvar dívidas: {}", erro),
+        armazenamento().dividas_salvar(&lista, false);
+    }
+
+    pub fn salvar_lista(lista: &[Divida]) {
+        armazenamento().dividas_salvar(lista, false);
+    }
+
+    pub fn listar_arquivadas() -> Vec<Divida> {
+        armazenamento()
+            .dividas_listar(true)
+            .into_iter()
+            .sorted_by(|a, b| {
+                b.parcelas
+                    .last()
+                    .map(|p| p.data_vencimento)
+                    .cmp(&a.parcelas.last().map(|p| p.data_vencimento))
+            })
+            .collect()
+    }
+
+    /// Move as dívidas quitadas para o arquivo, substituindo as de mesmo id.
+    /// `false` se o arquivo não foi gravado; aí as quitadas continuam ativas.
+    fn arquivar(quitadas: &[Divida]) -> bool {
+        if quitadas.is_empty() {
+            return true;
         }
+
+        let mut lista = Divida::listar_arquivadas();
+        quitadas.iter().for_each(|divida| {
+            if let Some(i) = lista.iter().position(|a| a.id == divida.id) {
+                lista[i] = divida.clone();
+            } else {
+                lista.push(divida.clone());
+            }
+        });
+
+        armazenamento().dividas_salvar(&lista, true)
     }
 
     pub fn atualizar() {
         let corte = chrono::Local::now().naive_local().date() - chrono::Duration::days(30);
 
         let mut lista: Vec<Divida> = Vec::new();
+        let mut quitadas: Vec<Divida> = Vec::new();
         Divida::listar().into_iter().for_each(|divida| {
             if divida
                 .parcelas
@@ -75,11 +101,18 @@ impl Divida {
                 .any(|p| !p.pago || p.data_vencimento >= corte)
             {
                 lista.push(divida);
+            } else {
+                quitadas.push(divida);
             }
         });
+        if !Divida::arquivar(&quitadas) {
+            log::error!("Dívidas quitadas mantidas como ativas: o arquivo não foi gravado");
+            lista.extend(quitadas);
+        }
 
         for divida in lista.iter_mut() {
-            if divida.cobranca_automatica {
+            // dívida conciliada só é paga quando o débito aparece no extrato
+            if divida.cobranca_automatica && !divida.conciliada() {
                 for parcela in divida.parcelas.iter_mut() {
                     if parcela.data_vencimento < chrono::Local::now().naive_local().date() {
                         parcela.pago = true;
@@ -88,9 +121,18 @@ impl Divida {
             }
         }
 
-        match serde_json::to_string_pretty(&lista) {
-            Ok(json) => arq_escrever(FIN, CAT, json),
-            Err(erro) => log::error!("Erro ao salvar dívidas: {}", erro),
-        }
+        armazenamento().dividas_salvar(&lista, false);
+    }
+}
+
+impl RepositorioDivida for ArmazenamentoJson {
+    fn dividas_listar(&self, arquivadas: bool) -> Vec<Divida> {
+        let arquivo = if arquivadas { ARQUIVO } else { CAT };
+        json_ler(&caminhos().dados, arquivo).unwrap_or_default()
+    }
+
+    fn dividas_salvar(&self, itens: &[Divida], arquivadas: bool) -> bool {
+        let arquivo = if arquivadas { ARQUIVO } else { CAT };
+        json_escrever(&caminhos().dados, arquivo, itens)
     }
 }
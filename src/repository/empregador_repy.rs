@@ -0,0 +1,31 @@
+use crate::{config_caminhos::caminhos, dto::Empregador};
+
+use super::esquema_repy::{json_escrever, json_ler};
+
+const EMPREGADORES: &str = "empregadores.json";
+
+impl Empregador {
+    pub fn listar() -> Vec<Empregador> {
+        json_ler(&caminhos().dados, EMPREGADORES).unwrap_or_default()
+    }
+
+    /// O empregador gravado com esse nome ou um novo, ainda sem conta nem categorias.
+    pub fn buscar(nome: &str) -> Empregador {
+        Empregador::listar()
+            .into_iter()
+            .find(|e| e.id == nome)
+            .unwrap_or_else(|| Empregador::novo(nome.to_string()))
+    }
+
+    pub fn salvar(&self) {
+        let mut lista = Empregador::listar();
+
+        if let Some(i) = lista.iter().position(|e| e.id == self.id) {
+            lista[i] = self.clone();
+        } else {
+            lista.push(self.clone());
+        }
+
+        json_escrever(&caminhos().dados, EMPREGADORES, &lista);
+    }
+}
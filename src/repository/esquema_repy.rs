@@ -0,0 +1,338 @@
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+    sync::{LazyLock, Mutex},
+};
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+use super::{
+    categoria_repy::migrar_categorias_arvore,
+    cripto_repy::{codificar, decodificar},
+    file_repy::{arq_escrever, arq_ler},
+};
+
+/// Pasta, dentro do diretório do arquivo, com as cópias anteriores a cada migração.
+const DIR_BACKUP: &str = "backup";
+
+/// Arquivos que não puderam ser lidos nesta execução; gravar neles apagaria os dados.
+static BLOQUEADOS: LazyLock<Mutex<HashSet<PathBuf>>> = LazyLock::new(Default::default);
+
+/// Formato gravado; a versão vem antes para ser vista ao abrir o arquivo.
+#[derive(Serialize)]
+struct Envelope<'a, T: ?Sized> {
+    versao: u32,
+    dados: &'a T,
+}
+
+/// Passo que leva `arquivo` da versão anterior para `versao`. Recebe e devolve só os
+/// dados, sem o envelope.
+struct Migracao {
+    arquivo: &'static str,
+    versao: u32,
+    migrar: fn(Value) -> Result<Value, String>,
+}
+
+/// Migrações em ordem de versão. A versão 1 só coloca os dados no envelope
+/// `{"versao", "dados"}`; arquivos sem envelope são da versão 0.
+const MIGRACOES: &[Migracao] = &[Migracao {
+    arquivo: "categorias.json",
+    versao: 2,
+    migrar: migrar_categorias_arvore,
+}];
+
+/// Versão gravada hoje para o arquivo.
+fn versao_atual(migracoes: &[Migracao], arquivo: &str) -> u32 {
+    migracoes
+        .iter()
+        .filter(|m| m.arquivo == arquivo)
+        .map(|m| m.versao)
+        .max()
+        .unwrap_or(1)
+}
+
+/// Lê um arquivo versionado, decifrando e migrando se preciso. `None` se o
+/// arquivo estiver vazio ou não puder ser lido; no segundo caso ele fica
+/// bloqueado para gravação até ser lido com sucesso.
+pub fn json_ler<T: DeserializeOwned>(dir: &Path, arquivo: &str) -> Option<T> {
+    ler(MIGRACOES, dir, arquivo)
+}
+
+fn ler<T: DeserializeOwned>(migracoes: &[Migracao], dir: &Path, arquivo: &str) -> Option<T> {
+    let path = dir.join(arquivo);
+    let texto: String = arq_ler(dir, arquivo).collect();
+
+    let lido = texto_claro(&texto).and_then(|claro| match claro.trim().is_empty() {
+        true => Ok(None),
+        false => interpretar(migracoes, dir, arquivo, &texto, &claro).map(Some),
+    });
+    match lido {
+        Ok(dados) => {
+            desbloquear(&path);
+            dados
+        }
+        Err(erro) => {
+            log::error!(
+                "Erro ao ler {}: {}; o arquivo não será regravado",
+                path.display(),
+                erro
+            );
+            if let Ok(mut bloqueados) = BLOQUEADOS.lock() {
+                bloqueados.insert(path);
+            }
+            None
+        }
+    }
+}
+
+/// Grava os dados no envelope da versão atual, exceto se o arquivo estiver bloqueado.
+pub fn json_escrever<T: Serialize + ?Sized>(dir: &Path, arquivo: &str, dados: &T) -> bool {
+    let path = dir.join(arquivo);
+    if bloqueado(&path) {
+        log::error!(
+            "Gravação de {} recusada: o arquivo não pôde ser lido e seria substituído",
+            path.display()
+        );
+        return false;
+    }
+
+    gravar(dir, arquivo, versao_atual(MIGRACOES, arquivo), dados)
+}
+
+/// Arquivos que não puderam ser lidos e estão protegidos contra gravação.
+pub fn arquivos_bloqueados() -> Vec<PathBuf> {
+    match BLOQUEADOS.lock() {
+        Ok(bloqueados) => bloqueados.iter().cloned().collect(),
+        Err(_) => vec![],
+    }
+}
+
+/// Quantos registros há no conteúdo de um arquivo versionado: itens da lista ou 1
+/// para um objeto. `None` se não for JSON ou não puder ser decifrado.
+pub(super) fn contar_registros(texto: &str) -> Option<usize> {
+    let texto = texto_claro(texto).ok()?;
+    if texto.trim().is_empty() {
+        return Some(0);
+    }
+    let valor: Value = serde_json::from_str(&texto).ok()?;
+    match separar_envelope(valor).1 {
+        Value::Array(itens) => Some(itens.len()),
+        Value::Null => Some(0),
+        _ => Some(1),
+    }
+}
+
+fn bloqueado(path: &Path) -> bool {
+    BLOQUEADOS.lock().map_or(true, |b| b.contains(path))
+}
+
+fn desbloquear(path: &Path) {
+    if let Ok(mut bloqueados) = BLOQUEADOS.lock() {
+        bloqueados.remove(path);
+    }
+}
+
+fn texto_claro(texto: &str) -> Result<String, String> {
+    let bytes = decodificar(texto.as_bytes())?;
+    String::from_utf8(bytes).map_err(|e| e.to_string())
+}
+
+/// `texto` é o conteúdo como está no disco, guardado no backup; `claro`, o JSON decifrado.
+fn interpretar<T: DeserializeOwned>(
+    migracoes: &[Migracao],
+    dir: &Path,
+    arquivo: &str,
+    texto: &str,
+    claro: &str,
+) -> Result<T, String> {
+    let valor: Value = serde_json::from_str(claro).map_err(|e| e.to_string())?;
+    let (versao, mut dados) = separar_envelope(valor);
+    let atual = versao_atual(migracoes, arquivo);
+
+    if versao > atual {
+        return Err(format!(
+            "versão {} é mais nova que a suportada ({})",
+            versao, atual
+        ));
+    }
+
+    if versao < atual {
+        guardar_backup(dir, arquivo, versao, texto)?;
+        for migracao in migracoes
+            .iter()
+            .filter(|m| m.arquivo == arquivo && m.versao > versao)
+        {
+            dados = (migracao.migrar)(dados)
+                .map_err(|e| format!("migração para a versão {}: {}", migracao.versao, e))?;
+        }
+    }
+
+    let resp = serde_json::from_value(dados.clone()).map_err(|e| e.to_string())?;
+
+    if versao < atual {
+        log::info!("{} migrado da versão {} para a {}", arquivo, versao, atual);
+        gravar(dir, arquivo, atual, &dados);
+    }
+    Ok(resp)
+}
+
+fn gravar<T: Serialize + ?Sized>(dir: &Path, arquivo: &str, versao: u32, dados: &T) -> bool {
+    let json = serde_json::to_string_pretty(&Envelope { versao, dados }).map_err(|e| e.to_string());
+    match json.and_then(|json| codificar(json.as_bytes())) {
+        Ok(conteudo) => {
+            arq_escrever(dir, arquivo, conteudo);
+            true
+        }
+        Err(erro) => {
+            log::error!("Erro ao gravar {}: {}", arquivo, erro);
+            false
+        }
+    }
+}
+
+/// Versão e dados do arquivo; sem envelope, o conteúdo todo é a versão 0.
+fn separar_envelope(valor: Value) -> (u32, Value) {
+    if let Value::Object(mut obj) = valor {
+        let versao = obj.get("versao").and_then(Value::as_u64);
+        if let (Some(versao), true, 2) = (versao, obj.contains_key("dados"), obj.len()) {
+            let dados = obj.remove("dados").unwrap_or(Value::Null);
+            return (versao as u32, dados);
+        }
+        return (0, Value::Object(obj));
+    }
+    (0, valor)
+}
+
+/// Cópia do arquivo antes da migração; a primeira cópia de cada versão é mantida.
+fn guardar_backup(dir: &Path, arquivo: &str, versao: u32, texto: &str) -> Result<(), String> {
+    let dir_backup = dir.join(DIR_BACKUP);
+    let nome = format!("{}.v{}", arquivo, versao);
+    if dir_backup.join(&nome).exists() {
+        return Ok(());
+    }
+
+    std::fs::create_dir_all(&dir_backup).map_err(|e| format!("backup: {}", e))?;
+    std::fs::write(dir_backup.join(&nome), texto).map_err(|e| format!("backup: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::repository::teste::DirTeste;
+
+    fn com_ativo(dados: Value) -> Result<Value, String> {
+        let mut itens = dados.as_array().ok_or("não é lista")?.clone();
+        itens.iter_mut().for_each(|i| i["ativo"] = json!(true));
+        Ok(Value::Array(itens))
+    }
+
+    /// Depende do campo criado por `com_ativo`, então só passa se vier depois dele.
+    fn com_estado(dados: Value) -> Result<Value, String> {
+        let mut itens = dados.as_array().ok_or("não é lista")?.clone();
+        for item in itens.iter_mut() {
+            let obj = item.as_object_mut().ok_or("não é objeto")?;
+            let ativo = obj.remove("ativo").and_then(|a| a.as_bool()).ok_or("sem ativo")?;
+            obj.insert("estado".to_string(), json!(if ativo { "ativo" } else { "inativo" }));
+        }
+        Ok(Value::Array(itens))
+    }
+
+    const TESTE: &[Migracao] = &[
+        Migracao {
+            arquivo: "teste.json",
+            versao: 2,
+            migrar: com_ativo,
+        },
+        Migracao {
+            arquivo: "teste.json",
+            versao: 3,
+            migrar: com_estado,
+        },
+    ];
+
+    fn gravado(dir: &DirTeste) -> Value {
+        serde_json::from_slice(&dir.ler("teste.json")).unwrap()
+    }
+
+    #[test]
+    fn separar_envelope_distingue_v0_de_envelope() {
+        assert_eq!(
+            separar_envelope(json!({"versao": 2, "dados": [1]})),
+            (2, json!([1]))
+        );
+        assert_eq!(separar_envelope(json!([1, 2])), (0, json!([1, 2])));
+        assert_eq!(separar_envelope(json!({"salario": 1.0})), (0, json!({"salario": 1.0})));
+
+        // objeto v0 que por acaso tem as chaves do envelope
+        let v0 = json!({"versao": 1, "dados": "x", "nome": "y"});
+        assert_eq!(separar_envelope(v0.clone()), (0, v0));
+        let v0 = json!({"versao": "1", "dados": "x"});
+        assert_eq!(separar_envelope(v0.clone()), (0, v0));
+    }
+
+    #[test]
+    fn migra_em_ordem_e_guarda_backup() {
+        let dir = DirTeste::novo();
+        let original = r#"[{"nome":"a"}]"#;
+        dir.escrever("teste.json", original);
+
+        let lido: Value = ler(TESTE, dir.path(), "teste.json").unwrap();
+
+        let esperado = json!([{"nome": "a", "estado": "ativo"}]);
+        assert_eq!(lido, esperado);
+        assert_eq!(dir.ler("backup/teste.json.v0"), original.as_bytes());
+        assert_eq!(gravado(&dir), json!({"versao": 3, "dados": esperado}));
+    }
+
+    #[test]
+    fn migra_so_os_passos_que_faltam() {
+        let dir = DirTeste::novo();
+        let original = r#"{"versao":2,"dados":[{"nome":"b","ativo":false}]}"#;
+        dir.escrever("teste.json", original);
+
+        let lido: Value = ler(TESTE, dir.path(), "teste.json").unwrap();
+
+        assert_eq!(lido, json!([{"nome": "b", "estado": "inativo"}]));
+        assert_eq!(dir.ler("backup/teste.json.v2"), original.as_bytes());
+        assert!(!dir.path().join("backup/teste.json.v0").exists());
+        assert_eq!(gravado(&dir)["versao"], json!(3));
+    }
+
+    #[test]
+    fn recusa_versao_mais_nova_e_bloqueia_a_gravacao() {
+        let dir = DirTeste::novo();
+        let original = r#"{"versao":4,"dados":[]}"#;
+        dir.escrever("teste.json", original);
+        let path = dir.path().join("teste.json");
+
+        assert_eq!(ler::<Value>(TESTE, dir.path(), "teste.json"), None);
+        assert!(arquivos_bloqueados().contains(&path));
+
+        json_escrever(dir.path(), "teste.json", &json!([1]));
+        assert_eq!(dir.ler("teste.json"), original.as_bytes());
+        assert!(!dir.path().join(DIR_BACKUP).exists());
+    }
+
+    #[test]
+    fn arquivo_invalido_fica_bloqueado_ate_ser_lido() {
+        let dir = DirTeste::novo();
+        dir.escrever("dados.json", "{ quebrado");
+        let path = dir.path().join("dados.json");
+
+        assert_eq!(json_ler::<Value>(dir.path(), "dados.json"), None);
+        json_escrever(dir.path(), "dados.json", &json!([]));
+        assert_eq!(dir.ler("dados.json"), b"{ quebrado");
+
+        dir.escrever("dados.json", "[1]");
+        assert_eq!(json_ler::<Value>(dir.path(), "dados.json"), Some(json!([1])));
+        assert!(!arquivos_bloqueados().contains(&path));
+        json_escrever(dir.path(), "dados.json", &json!([2]));
+        assert_eq!(
+            serde_json::from_slice::<Value>(&dir.ler("dados.json")).unwrap(),
+            json!({"versao": 1, "dados": [2]})
+        );
+    }
+}
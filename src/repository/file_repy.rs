@@ -8,8 +8,6 @@ use std::{
 use chardet::detect;
 use encoding_rs::Encoding;
 
-use crate::get_home_dir;
-
 pub fn arq_externo_ler(arquivo: &str) -> Vec<String> {
     let mut file = File::open(arquivo).expect("Failed to open file");
     let mut buffer = Vec::new();
@@ -25,9 +23,8 @@ pub fn arq_externo_ler(arquivo: &str) -> Vec<String> {
         .collect()
 }
 
-pub fn arq_ler(dir: &str, file: &str) -> Flatten<Lines<BufReader<File>>> {
-    let mut path = get_home_dir();
-    path.push(&dir);
+pub fn arq_ler(dir: &Path, file: &str) -> Flatten<Lines<BufReader<File>>> {
+    let mut path = dir.to_path_buf();
     checar_dir(&path);
 
     path.push(&file);
@@ -36,9 +33,8 @@ pub fn arq_ler(dir: &str, file: &str) -> Flatten<Lines<BufReader<File>>> {
     BufReader::new(File::open(&path).unwrap()).lines().flatten()
 }
 
-pub fn arq_escrever(dir: &str, file: &str, texto: String) {
-    let mut path = get_home_dir();
-    path.push(&dir);
+pub fn arq_escrever(dir: &Path, file: &str, texto: impl AsRef<[u8]>) {
+    let mut path = dir.to_path_buf();
     checar_dir(&path);
 
     path.push(&file);
@@ -51,10 +47,8 @@ pub fn arq_escrever(dir: &str, file: &str, texto: String) {
     rename(&tmp, &path).expect("Falha ao renomear arquivo temporário");
 }
 
-pub fn arq_limpar_tmp(dir: &str) {
-    let mut path = get_home_dir();
-    path.push(&dir);
-    if let Ok(entries) = std::fs::read_dir(&path) {
+pub fn arq_limpar_tmp(dir: &Path) {
+    if let Ok(entries) = std::fs::read_dir(dir) {
         entries.flatten().for_each(|e| {
             let p = e.path();
             if p.extension().map_or(false, |ext| ext == "tmp") {
@@ -64,12 +58,9 @@ pub fn arq_limpar_tmp(dir: &str) {
     }
 }
 
-pub fn arq_deletar_dir(dir: &str) {
-    let mut path = get_home_dir();
-    path.push(&dir);
-
-    if Path::new(&path).exists() {
-        match remove_dir_all(&path) {
+pub fn arq_deletar_dir(dir: &Path) {
+    if dir.exists() {
+        match remove_dir_all(dir) {
             Ok(_) => {}
             Err(erro) => log::error!("Erro ao deletar diretório: {}", erro),
         }
@@ -0,0 +1,121 @@
+use crate::{
+    config_caminhos::caminhos,
+    dto::{Ativo, Cotacao, OperacaoInvestimento},
+};
+
+use super::esquema_repy::{json_escrever, json_ler};
+
+const ATIVOS: &str = "ativos.json";
+const OPERACOES: &str = "operacoes_investimento.json";
+const COTACOES: &str = "cotacoes.json";
+
+impl Ativo {
+    pub fn listar() -> Vec<Ativo> {
+        let mut resp: Vec<Ativo> = json_ler(&caminhos().dados, ATIVOS).unwrap_or_default();
+        resp.sort_by(|a, b| a.id.cmp(&b.id));
+        resp
+    }
+
+    pub fn salvar(&self) {
+        let mut lista = Ativo::listar();
+
+        if let Some(i) = lista.iter().position(|a| a.id == self.id) {
+            lista[i] = self.clone();
+        } else {
+            lista.push(self.clone());
+        }
+
+        Ativo::escrever(&lista);
+    }
+
+    /// Cadastra os ativos ainda desconhecidos; os já cadastrados mantêm nome e
+    /// tipo editados pelo usuário.
+    pub fn importar(novos: &[Ativo]) {
+        let mut lista = Ativo::listar();
+        let antes = lista.len();
+
+        novos.iter().for_each(|novo| {
+            if !lista.iter().any(|a| a.id == novo.id) {
+                lista.push(novo.clone());
+            }
+        });
+
+        if lista.len() > antes {
+            Ativo::escrever(&lista);
+        }
+    }
+
+    fn escrever(lista: &[Ativo]) {
+        json_escrever(&caminhos().dados, ATIVOS, lista);
+    }
+}
+
+impl OperacaoInvestimento {
+    pub fn listar() -> Vec<OperacaoInvestimento> {
+        let mut resp: Vec<OperacaoInvestimento> =
+            json_ler(&caminhos().dados, OPERACOES).unwrap_or_default();
+        resp.sort_by_key(|a| a.data);
+        resp
+    }
+
+    pub fn salvar(&self) {
+        OperacaoInvestimento::salvar_lista(std::slice::from_ref(self));
+    }
+
+    /// Inclui ou substitui pelo `id`; reimportar o mesmo extrato não duplica.
+    pub fn salvar_lista(novas: &[OperacaoInvestimento]) {
+        let mut lista = OperacaoInvestimento::listar();
+
+        novas.iter().for_each(|nova| {
+            if let Some(i) = lista.iter().position(|a| a.id == nova.id) {
+                lista[i] = nova.clone();
+            } else {
+                lista.push(nova.clone());
+            }
+        });
+
+        OperacaoInvestimento::escrever(&lista);
+    }
+
+    pub fn deletar(&self) {
+        let mut lista = OperacaoInvestimento::listar();
+
+        if let Some(pos) = lista.iter().position(|a| a.id == self.id) {
+            lista.remove(pos);
+        }
+
+        OperacaoInvestimento::escrever(&lista);
+    }
+
+    fn escrever(lista: &[OperacaoInvestimento]) {
+        json_escrever(&caminhos().dados, OPERACOES, lista);
+    }
+}
+
+impl Cotacao {
+    pub fn listar() -> Vec<Cotacao> {
+        json_ler(&caminhos().dados, COTACOES).unwrap_or_default()
+    }
+
+    pub fn salvar(&self) {
+        Cotacao::salvar_lista(std::slice::from_ref(self));
+    }
+
+    /// Uma cotação por ativo e dia: salvar de novo substitui o preço.
+    pub fn salvar_lista(novas: &[Cotacao]) {
+        let mut lista = Cotacao::listar();
+
+        novas.iter().for_each(|nova| {
+            if let Some(i) = lista
+                .iter()
+                .position(|c| c.ativo == nova.ativo && c.data == nova.data)
+            {
+                lista[i] = nova.clone();
+            } else {
+                lista.push(nova.clone());
+            }
+        });
+
+        json_escrever(&caminhos().dados, COTACOES, &lista);
+    }
+}
@@ -0,0 +1,21 @@
+use crate::{config_caminhos::caminhos, dto::RelatorioIrpf};
+
+use super::file_repy::arq_escrever;
+
+impl RelatorioIrpf {
+    /// Grava o relatório em `irpf-<ano>.json` no diretório de exportação e devolve o caminho.
+    pub fn exportar(&self) -> Option<String> {
+        let arquivo = format!("irpf-{}.json", self.ano);
+        match serde_json::to_string_pretty(self) {
+            Ok(json) => {
+                let dir = &caminhos().exportar;
+                arq_escrever(dir, &arquivo, json);
+                Some(dir.join(arquivo).display().to_string())
+            }
+            Err(erro) => {
+                log::error!("Erro ao serializar relatório do IRPF: {}", erro);
+                None
+            }
+        }
+    }
+}
@@ -1,14 +1,63 @@
-use std::vec;
+use itertools::Itertools;
 
-use crate::dto::{Lancamento, OptionalLazy};
+use crate::{
+    config_caminhos::caminhos,
+    dto::{FluxoRegra, Lancamento, LazyFn, OptionalLazy, Regra},
+};
 
-use super::file_repy::{arq_escrever, arq_ler};
+use super::{
+    armazenamento::{armazenamento, ArmazenamentoJson, RepositorioLancamento},
+    esquema_repy::{json_escrever, json_ler},
+    Buscar,
+};
 
-const FIN: &str = ".financeiro";
 const NAO_CAT: &str = "nao-cat.json";
 const LANCAMENTOS: &str = "lancamentos.json";
 
 impl Lancamento {
+    /// Aplica as regras aos lançamentos não categorizados: devolve os que
+    /// encontraram categoria, em ordem de data, e os que continuam pendentes.
+    pub fn aplicar_regras() -> (Vec<Lancamento>, Vec<Lancamento>) {
+        let regras = Regra::listar_full();
+        let mut encontrados: Vec<Lancamento> = Vec::new();
+        let mut nao_encontrado: Vec<Lancamento> = Vec::new();
+
+        let pendente: Vec<Lancamento> = Lancamento::nao_categorizados_listar();
+        for mut item in pendente {
+            match &regras.buscar(
+                &item.descricao.to_lowercase(),
+                if item.valor > 0.0 {
+                    FluxoRegra::Entrada
+                } else {
+                    FluxoRegra::Saida
+                },
+            ) {
+                Some(regra) => {
+                    item.categoria = OptionalLazy::Some(regra.categoria.some());
+                    item.regra = OptionalLazy::Some(regra.clone());
+                    encontrados.push(item);
+                }
+                None => {
+                    nao_encontrado.push(item);
+                }
+            }
+        }
+
+        log::info!(
+            "{} lançamento(s) categorizado(s), restando {}",
+            encontrados.len(),
+            nao_encontrado.len()
+        );
+
+        (
+            encontrados
+                .into_iter()
+                .sorted_by(|a, b| a.data.cmp(&b.data))
+                .collect(),
+            nao_encontrado,
+        )
+    }
+
     pub fn checar_ja_importados() {
         let categorizados = Lancamento::lancamentos_listar();
 
@@ -42,81 +91,96 @@ impl Lancamento {
     }
 
     pub fn nao_categorizados_listar() -> Vec<Lancamento> {
-        let mut json: String = arq_ler(FIN, NAO_CAT).collect();
-        if json.is_empty() {
-            json = "[]".to_string();
-        }
-
-        match serde_json::from_str(&json) {
-            Ok(vec) => vec,
-            Err(erro) => {
-                log::error!("Erro ao desserializar lançamentos: {}", erro);
-                vec![]
-            }
-        }
+        armazenamento().nao_categorizados_listar()
     }
 
-    pub fn nao_categorizados_salvar(itens: &Vec<Lancamento>) {
-        match serde_json::to_string_pretty(&itens) {
-            Ok(json) => arq_escrever(FIN, NAO_CAT, json),
-            Err(erro) => log::error!("Erro ao serializar lançamentos: {}", erro),
-        };
+    pub fn nao_categorizados_salvar(itens: &[Lancamento]) {
+        armazenamento().nao_categorizados_salvar(itens);
     }
 
     pub fn lancamentos_listar() -> Vec<Lancamento> {
-        let mut json: String = arq_ler(FIN, LANCAMENTOS).collect();
-        if json.is_empty() {
-            json = "[]".to_string();
-        }
+        armazenamento().lancamentos_listar()
+    }
 
-        match serde_json::from_str(&json) {
-            Ok(vec) => vec,
-            Err(erro) => {
-                log::error!("Erro ao desserializar lançamentos: {}", erro);
-                vec![]
-            }
-        }
+    pub fn lancamentos_adicionar(itens: &[Lancamento]) {
+        armazenamento().lancamentos_adicionar(&sem_objetos(itens));
     }
 
-    pub fn lancamentos_adicionar(itens: &Vec<Lancamento>) {
-        let mut lista = Lancamento::lancamentos_listar();
+    /// Devolve o lançamento aos pendentes.
+    pub fn lancamentos_recategorizar(&self) {
+        armazenamento().nao_categorizado_gravar(&sem_objeto(self));
+        armazenamento().lancamento_remover(&self.id);
+    }
+}
 
-        itens.into_iter().for_each(|novo| {
+/// Só os ids de categoria e regra vão para o armazenamento.
+fn sem_objetos(itens: &[Lancamento]) -> Vec<Lancamento> {
+    itens.iter().map(sem_objeto).collect()
+}
+
+fn sem_objeto(item: &Lancamento) -> Lancamento {
+    let mut lanc = item.clone();
+    if let OptionalLazy::Some(t) = lanc.categoria.clone() {
+        lanc.categoria = OptionalLazy::Id(t.id);
+    }
+    if let OptionalLazy::Some(r) = lanc.regra.clone() {
+        lanc.regra = OptionalLazy::Id(r.id);
+    }
+    lanc
+}
+
+impl RepositorioLancamento for ArmazenamentoJson {
+    fn lancamentos_listar(&self) -> Vec<Lancamento> {
+        ler_lista(LANCAMENTOS)
+    }
+
+    fn lancamentos_salvar(&self, itens: &[Lancamento]) {
+        escrever_lista(LANCAMENTOS, itens);
+    }
+
+    fn lancamentos_adicionar(&self, itens: &[Lancamento]) {
+        let mut lista = self.lancamentos_listar();
+
+        itens.iter().for_each(|novo| {
             if !lista.iter().any(|a| a.id == novo.id) {
                 lista.push(novo.clone());
             }
         });
 
-        Lancamento::lancamentos_salvar(&lista);
+        self.lancamentos_salvar(&lista);
     }
 
-    pub fn lancamentos_salvar(itens: &Vec<Lancamento>) {
-        let mut salvar = itens.clone();
-
-        for lanc in salvar.iter_mut() {
-            if let OptionalLazy::Some(t) = lanc.categoria.clone() {
-                lanc.categoria = OptionalLazy::Id(t.id);
-            }
-            if let OptionalLazy::Some(r) = lanc.regra.clone() {
-                lanc.regra = OptionalLazy::Id(r.id);
-            }
-        }
+    fn lancamento_remover(&self, id: &str) {
+        let lista: Vec<Lancamento> = self
+            .lancamentos_listar()
+            .into_iter()
+            .filter(|l| l.id != id)
+            .collect();
+        self.lancamentos_salvar(&lista);
+    }
 
-        match serde_json::to_string_pretty(&salvar) {
-            Ok(json) => arq_escrever(FIN, LANCAMENTOS, json),
-            Err(erro) => log::error!("Erro ao serializar lançamentos: {}", erro),
-        };
+    fn nao_categorizados_listar(&self) -> Vec<Lancamento> {
+        ler_lista(NAO_CAT)
     }
 
-    pub fn lancamentos_recategorizar(&self) {
-        let mut nao_cat = Lancamento::nao_categorizados_listar();
-        nao_cat.push(self.clone());
-        Lancamento::nao_categorizados_salvar(&nao_cat);
+    fn nao_categorizados_salvar(&self, itens: &[Lancamento]) {
+        escrever_lista(NAO_CAT, itens);
+    }
 
-        let cat: Vec<Lancamento> = Lancamento::lancamentos_listar()
-            .into_iter()
-            .filter(|l| l.id != self.id)
-            .collect();
-        Lancamento::lancamentos_salvar(&cat);
+    fn nao_categorizado_gravar(&self, item: &Lancamento) {
+        let mut lista = self.nao_categorizados_listar();
+        match lista.iter_mut().find(|l| l.id == item.id) {
+            Some(atual) => *atual = item.clone(),
+            None => lista.push(item.clone()),
+        }
+        self.nao_categorizados_salvar(&lista);
     }
 }
+
+fn ler_lista(arquivo: &str) -> Vec<Lancamento> {
+    json_ler(&caminhos().dados, arquivo).unwrap_or_default()
+}
+
+fn escrever_lista(arquivo: &str, itens: &[Lancamento]) {
+    json_escrever(&caminhos().dados, arquivo, itens);
+}
@@ -1,24 +1,15 @@
+use crate::{config_caminhos::caminhos, dto::Meta};
 
-use crate::dto::Meta;
+use super::{
+    armazenamento::{armazenamento, ArmazenamentoJson, RepositorioMeta},
+    esquema_repy::{json_escrever, json_ler},
+};
 
-use super::file_repy::{arq_escrever, arq_ler};
-
-const FIN: &str = ".financeiro";
 const METAS: &str = "metas.json";
 
 impl Meta {
     pub fn listar() -> Vec<Meta> {
-        let mut json: String = arq_ler(FIN, METAS).collect();
-        if json.is_empty() {
-            json = "[]".to_string();
-        }
-        match serde_json::from_str(&json) {
-            Ok(resp) => resp,
-            Err(_) => {
-                log::error!("Erro ao desserializar metas");
-                vec![]
-            }
-        }
+        armazenamento().metas_listar()
     }
 
     pub fn salvar(&self) {
@@ -30,10 +21,7 @@ impl Meta {
             lista.push(self.clone());
         }
 
-        match serde_json::to_string_pretty(&lista) {
-            Ok(json) => arq_escrever(FIN, METAS, json),
-            Err(erro) => log::error!("Erro ao serializar metas: {}", erro),
-        };
+        armazenamento().metas_salvar(&lista);
     }
 
     pub fn deletar(&self) {
@@ -43,9 +31,16 @@ impl Meta {
             lista.remove(pos);
         }
 
-        match serde_json::to_string_pretty(&lista) {
-            Ok(json) => arq_escrever(FIN, METAS, json),
-            Err(erro) => log::error!("Erro ao serializar metas: {}", erro),
-        };
+        armazenamento().metas_salvar(&lista);
+    }
+}
+
+impl RepositorioMeta for ArmazenamentoJson {
+    fn metas_listar(&self) -> Vec<Meta> {
+        json_ler(&caminhos().dados, METAS).unwrap_or_default()
+    }
+
+    fn metas_salvar(&self, itens: &[Meta]) {
+        json_escrever(&caminhos().dados, METAS, itens);
     }
 }
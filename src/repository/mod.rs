@@ -1,13 +1,37 @@
+mod armazenamento;
 mod banco_repy;
+mod carne_leao_repy;
 mod categoria_repy;
+mod contracheque_repy;
+mod cripto_repy;
 mod dash_repy;
 mod divida_repy;
+mod empregador_repy;
+mod esquema_repy;
+mod investimento_repy;
+mod irpf_repy;
 pub mod file_repy;
 mod lancamento_repy;
 mod meta_repy;
+mod objetivo_repy;
+mod orcamento_repy;
+mod plano_quitacao_repy;
 mod ofx_repy;
 mod regra_repy;
+mod snapshot_repy;
+mod sqlite_repy;
+mod tabela_tributaria_repy;
+#[cfg(test)]
+mod teste;
 mod configuracao_repy;
 
+pub use armazenamento::{abrir_armazenamento, dados_bloqueados};
+pub use cripto_repy::{
+    ativar_criptografia, conferir_pendencia, criptografia_ativa, desativar_criptografia,
+    desbloquear_dados, trocar_senha,
+};
 pub use dash_repy::atualizar_base;
 pub use regra_repy::Buscar;
+pub use sqlite_repy::importar_json;
+#[cfg(test)]
+pub(crate) use tabela_tributaria_repy::tabelas_conhecidas;
@@ -0,0 +1,33 @@
+use crate::{config_caminhos::caminhos, dto::Objetivo};
+
+use super::esquema_repy::{json_escrever, json_ler};
+
+const OBJETIVOS: &str = "objetivos.json";
+
+impl Objetivo {
+    pub fn listar() -> Vec<Objetivo> {
+        json_ler(&caminhos().dados, OBJETIVOS).unwrap_or_default()
+    }
+
+    pub fn salvar(&self) {
+        let mut lista = Objetivo::listar();
+
+        if let Some(i) = lista.iter().position(|a| a.id == self.id) {
+            lista[i] = self.clone();
+        } else {
+            lista.push(self.clone());
+        }
+
+        json_escrever(&caminhos().dados, OBJETIVOS, &lista);
+    }
+
+    pub fn deletar(&self) {
+        let mut lista = Objetivo::listar();
+
+        if let Some(pos) = lista.iter().position(|a| a.id == self.id) {
+            lista.remove(pos);
+        }
+
+        json_escrever(&caminhos().dados, OBJETIVOS, &lista);
+    }
+}
@@ -1,43 +1,245 @@
-use std::fs::{read_dir, rename};
+use std::{
+    fs::{self, copy, read_dir, remove_file, rename},
+    mem,
+    path::{Path, PathBuf},
+};
 
 use chrono::NaiveDate;
 
 use crate::{
-    dto::{Banco, Conta, Lancamento, Unico},
-    get_home_dir,
+    config_caminhos::caminhos,
+    dto::{
+        Ativo, Banco, Conta, Cotacao, ExtratoOfx, Lancamento, OperacaoInvestimento, SaldoExtrato,
+        TipoAtivo, TipoConta, TipoOperacao, Unico,
+    },
     repository::file_repy::arq_externo_ler,
 };
+
+use super::{
+    cripto_repy::{chave, codificar_com, Chave},
+    snapshot_repy::escrever,
+};
 impl Lancamento {
-    pub fn from_ofx() -> (Vec<Lancamento>, Vec<Banco>) {
-        let mut dir = get_home_dir();
-        dir.push("Downloads/importar");
+    pub fn from_ofx() -> ExtratoOfx {
+        let dir = &caminhos().importar;
 
         log::info!("Importando XSD");
 
-        let mut lancamentos: Vec<Lancamento> = Vec::new();
-        let mut bancos: Vec<Banco> = Vec::new();
+        let mut extrato = ExtratoOfx::default();
 
         match read_dir(dir) {
             Ok(read_dir) => {
-                importar_arquivos_ofx_do_diretorio(&mut lancamentos, &mut bancos, read_dir);
+                importar_arquivos_ofx_do_diretorio(&mut extrato, read_dir);
             }
             Err(erro) => log::error!("Erro ao ler o diretório: {}", erro),
         }
 
-        (lancamentos, bancos)
+        extrato
     }
 }
 
-fn importar_arquivos_ofx_do_diretorio(
-    lancamentos: &mut Vec<Lancamento>,
-    bancos: &mut Vec<Banco>,
-    read_dir: std::fs::ReadDir,
-) {
+/// Agregado de investimento (`BUYSTOCK`, `SELLDEBT`, `INCOME`, `POSSTOCK`,
+/// `STOCKINFO`...) em leitura.
+#[derive(Default)]
+struct TransacaoInvestimento {
+    agregado: String,
+    conta: String,
+    fitid: String,
+    papel: String,
+    ticker: String,
+    nome: String,
+    data: Option<NaiveDate>,
+    memo: String,
+    quantidade: f64,
+    preco: f64,
+    taxas: f64,
+    total: Option<f64>,
+}
+
+/// Transações, posições e cadastro de papéis (`SECLIST`) de um arquivo. O
+/// `SECLIST` vem depois do extrato, então os ativos só são resolvidos no fim.
+#[derive(Default)]
+struct LeituraInvestimento {
+    atual: TransacaoInvestimento,
+    transacoes: Vec<TransacaoInvestimento>,
+    posicoes: Vec<TransacaoInvestimento>,
+    papeis: Vec<TransacaoInvestimento>,
+}
+
+impl LeituraInvestimento {
+    fn preencher(&mut self, chave: &str, valor: &str) {
+        let valor = valor.trim();
+        let atual = &mut self.atual;
+        match chave {
+            "FITID" => atual.fitid = valor.to_string(),
+            "UNIQUEID" => atual.papel = valor.to_string(),
+            "TICKER" => atual.ticker = valor.to_uppercase(),
+            "SECNAME" => atual.nome = valor.to_string(),
+            "MEMO" => atual.memo = valor.to_ascii_lowercase(),
+            "DTTRADE" | "DTPRICEASOF" => {
+                atual.data = valor
+                    .get(..8)
+                    .and_then(|d| NaiveDate::parse_from_str(d, "%Y%m%d").ok())
+            }
+            "UNITS" => atual.quantidade = valor.parse::<f64>().unwrap_or_default().abs(),
+            "UNITPRICE" => atual.preco = valor.parse().unwrap_or_default(),
+            "COMMISSION" | "FEES" | "TAXES" | "WITHHOLDING" => {
+                atual.taxas += valor.parse::<f64>().unwrap_or_default().abs()
+            }
+            "TOTAL" => atual.total = valor.parse().ok(),
+            _ => {}
+        }
+    }
+
+    /// Abre ou fecha um agregado pela tag sem valor (`BUYSTOCK>`, `/BUYSTOCK>`).
+    fn marcar(&mut self, tag: &str, conta: &str) {
+        let Some(nome) = tag.strip_suffix('>') else {
+            return;
+        };
+        match nome.strip_prefix('/') {
+            None if eh_agregado(nome) => {
+                self.atual = TransacaoInvestimento {
+                    agregado: nome.to_string(),
+                    conta: conta.to_string(),
+                    ..Default::default()
+                }
+            }
+            Some(fim) if eh_agregado(fim) && fim == self.atual.agregado => {
+                let transacao = mem::take(&mut self.atual);
+                if transacao.agregado.starts_with("POS") {
+                    self.posicoes.push(transacao);
+                } else if transacao.agregado.ends_with("INFO") {
+                    self.papeis.push(transacao);
+                } else {
+                    self.transacoes.push(transacao);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Código e tipo do ativo: o ticker do `SECLIST` ou, sem ele, o `UNIQUEID`.
+    fn ativo(&self, transacao: &TransacaoInvestimento) -> Ativo {
+        let papel = self.papeis.iter().find(|p| p.papel == transacao.papel);
+        let id = papel
+            .map(|p| p.ticker.clone())
+            .filter(|t| !t.is_empty())
+            .unwrap_or(transacao.papel.to_uppercase());
+        let agregado = papel.map(|p| &p.agregado).unwrap_or(&transacao.agregado);
+
+        Ativo {
+            nome: papel
+                .map(|p| p.nome.clone())
+                .filter(|n| !n.is_empty())
+                .unwrap_or(id.clone()),
+            tipo: if agregado.contains("DEBT") {
+                TipoAtivo::Tesouro
+            } else if agregado.contains("OTHER") {
+                TipoAtivo::Cdb
+            } else if id.ends_with("11") {
+                TipoAtivo::Fii
+            } else {
+                TipoAtivo::Acao
+            },
+            id,
+        }
+    }
+
+    /// Converte o que foi lido em ativos, operações, cotações (pelas posições)
+    /// e nos lançamentos da parte em dinheiro, vinculados às operações.
+    fn finalizar(self, extrato: &mut ExtratoOfx) -> u32 {
+        let mut count: u32 = 0;
+
+        for transacao in self.transacoes.iter() {
+            let Some(data) = transacao.data else {
+                log::error!("Operação de investimento sem DTTRADE: {}", transacao.papel);
+                continue;
+            };
+            let ativo = self.ativo(transacao);
+
+            let mut operacao = OperacaoInvestimento {
+                id: String::new(),
+                ativo: ativo.id.clone(),
+                tipo: if transacao.agregado.starts_with("BUY") {
+                    TipoOperacao::Compra
+                } else if transacao.agregado.starts_with("SELL") {
+                    TipoOperacao::Venda
+                } else {
+                    TipoOperacao::Provento
+                },
+                data,
+                quantidade: transacao.quantidade,
+                preco: transacao.preco,
+                taxas: transacao.taxas,
+                lancamento: None,
+                referencia: transacao.fitid.clone(),
+            };
+            // o INCOME só informa o total
+            if operacao.tipo == TipoOperacao::Provento && operacao.quantidade == 0.0 {
+                operacao.quantidade = 1.0;
+                operacao.preco = transacao.total.unwrap_or_default().abs();
+            }
+            operacao.gerar_id();
+
+            let mut lancamento = Lancamento {
+                descricao: if transacao.memo.is_empty() {
+                    format!("{} {}", operacao.tipo, ativo.id).to_ascii_lowercase()
+                } else {
+                    transacao.memo.clone()
+                },
+                valor: match (&operacao.tipo, transacao.total) {
+                    (TipoOperacao::Provento, _) => operacao.valor(),
+                    (_, Some(total)) => total,
+                    (TipoOperacao::Compra, None) => -operacao.valor(),
+                    (TipoOperacao::Venda, None) => operacao.valor(),
+                },
+                data,
+                conta: Some(transacao.conta.clone()),
+                ..Default::default()
+            };
+            lancamento.gerar_id();
+            operacao.lancamento = Some(lancamento.id.clone());
+
+            add_ativo(&mut extrato.ativos, ativo);
+            extrato.lancamentos.push(lancamento);
+            extrato.operacoes.push(operacao);
+            count += 1;
+        }
+
+        for posicao in self.posicoes.iter() {
+            let ativo = self.ativo(posicao);
+            if let Some(data) = posicao.data {
+                extrato.cotacoes.push(Cotacao {
+                    ativo: ativo.id.clone(),
+                    data,
+                    preco: posicao.preco,
+                });
+            }
+            add_ativo(&mut extrato.ativos, ativo);
+        }
+
+        count
+    }
+}
+
+fn eh_agregado(nome: &str) -> bool {
+    nome == "INCOME"
+        || ["BUY", "SELL", "POS"].iter().any(|p| nome.starts_with(p))
+        || (nome.ends_with("INFO") && nome != "SECINFO")
+}
+
+fn add_ativo(ativos: &mut Vec<Ativo>, ativo: Ativo) {
+    if !ativos.iter().any(|a| a.id == ativo.id) {
+        ativos.push(ativo);
+    }
+}
+
+fn importar_arquivos_ofx_do_diretorio(extrato: &mut ExtratoOfx, read_dir: std::fs::ReadDir) {
     read_dir
         .map(|r| validar_dir_entry(r))
         .filter(|s| s.ends_with("ofx"))
         .for_each(|arquivo| {
-            importar_lancts(lancamentos, bancos, &arquivo);
+            importar_lancts(extrato, &arquivo);
         });
 }
 
@@ -51,19 +253,34 @@ fn validar_dir_entry(r: Result<std::fs::DirEntry, std::io::Error>) -> String {
     }
 }
 
-fn importar_lancts(lista: &mut Vec<Lancamento>, bancos: &mut Vec<Banco>, arquivo: &str) {
-    let mut item = Lancamento::default();
-    let mut banco = String::new();
-    let mut conta = String::new();
-
-    let mut count: u32 = 0;
+/// Saldo do extrato em leitura; só vira `SaldoExtrato` com valor e data lidos
+/// no mesmo extrato.
+#[derive(Default)]
+struct SaldoLido {
+    valor: Option<f64>,
+    data: Option<NaiveDate>,
+}
 
-    for linha in arq_externo_ler(arquivo) {
-        if let Some(pos) = linha.find('>') {
-            interpretar_linha(lista, bancos, &mut item, &mut banco, &mut conta, &mut count, linha, pos);
-        }
+impl SaldoLido {
+    fn extrato(&self) -> Option<SaldoExtrato> {
+        Some(SaldoExtrato {
+            valor: self.valor?,
+            data: self.data?,
+        })
     }
-    
+}
+
+/// Lançamento em leitura; valor e data só valem se vierem bem formados.
+#[derive(Default)]
+struct LancamentoLido {
+    lancamento: Lancamento,
+    valor: Option<f64>,
+    data: Option<NaiveDate>,
+}
+
+fn importar_lancts(extrato: &mut ExtratoOfx, arquivo: &str) {
+    let count = ler_extrato(extrato, arq_externo_ler(arquivo));
+
     log::info!(
         "arquivo: {}: {count} itens",
         arquivo.split('/').last().unwrap()
@@ -72,53 +289,551 @@ fn importar_lancts(lista: &mut Vec<Lancamento>, bancos: &mut Vec<Banco>, arquivo
     mover_para_importado(&arquivo);
 }
 
-fn interpretar_linha(lista: &mut Vec<Lancamento>, bancos: &mut Vec<Banco>, item: &mut Lancamento, banco: &mut String, conta: &mut String, count: &mut u32, mut linha: String, pos: usize) {
+/// Lê as linhas de um arquivo OFX. Devolve quantos lançamentos e operações leu.
+fn ler_extrato(extrato: &mut ExtratoOfx, linhas: impl IntoIterator<Item = String>) -> u32 {
+    let mut item = LancamentoLido::default();
+    let mut investimentos = LeituraInvestimento::default();
+    let mut banco = String::new();
+    let mut conta = String::new();
+    let mut saldo = SaldoLido::default();
+
+    let mut count: u32 = 0;
+
+    for linha in linhas {
+        if let Some(pos) = linha.find('>') {
+            interpretar_linha(
+                extrato,
+                &mut item,
+                &mut investimentos,
+                &mut banco,
+                &mut conta,
+                &mut saldo,
+                &mut count,
+                linha,
+                pos,
+            );
+        }
+    }
+    count + investimentos.finalizar(extrato)
+}
+
+fn interpretar_linha(
+    extrato: &mut ExtratoOfx,
+    item: &mut LancamentoLido,
+    investimentos: &mut LeituraInvestimento,
+    banco: &mut String,
+    conta: &mut String,
+    saldo: &mut SaldoLido,
+    count: &mut u32,
+    mut linha: String,
+    pos: usize,
+) {
     linha = linha[1..].to_string();
 
     if pos + 1 < linha.len() {
         let chave = &linha[..pos - 1];
         let valor = &linha[pos..linha.find('<').unwrap_or(linha.len())];
-        preencher_atributo_pela_tag(item, banco, conta, chave, valor);
+        preencher_atributo_pela_tag(item, banco, conta, saldo, chave, valor);
+        investimentos.preencher(chave, valor);
     } else if linha.eq("/STMTTRN>") {
-        add_lancamento(item, &*conta, lista);
-        *count += 1;
+        if add_lancamento(item, &*conta, &mut extrato.lancamentos) {
+            *count += 1;
+        }
     } else if linha.eq("/BANKACCTFROM>") {
-        bancos.push(add_banco(&*banco, &*conta));
+        extrato
+            .bancos
+            .push(add_banco(&*banco, &*conta, TipoConta::Corrente));
+    } else if linha.eq("/INVACCTFROM>") {
+        extrato
+            .bancos
+            .push(add_banco(&*banco, &*conta, TipoConta::Investimento));
+    } else if linha.eq("STMTRS>") || linha.eq("CCSTMTRS>") || linha.eq("INVSTMTRS>") {
+        // cada conta tem o seu saldo; nada do extrato anterior vale para ela
+        *saldo = SaldoLido::default();
+    } else if linha.eq("/LEDGERBAL>") || linha.eq("/INVBAL>") {
+        if let Some(extrato_saldo) = saldo.extrato() {
+            add_saldo(&mut extrato.bancos, &*banco, &*conta, extrato_saldo);
+        }
+    } else {
+        investimentos.marcar(&linha, &*conta);
     }
 }
 
-fn preencher_atributo_pela_tag(item: &mut Lancamento, banco: &mut String, conta: &mut String, chave: &str, valor: &str) {
+fn preencher_atributo_pela_tag(
+    item: &mut LancamentoLido,
+    banco: &mut String,
+    conta: &mut String,
+    saldo: &mut SaldoLido,
+    chave: &str,
+    valor: &str,
+) {
     match chave {
-        "BANKID" => *banco = valor.to_uppercase(),
+        "BALAMT" | "AVAILCASH" => saldo.valor = valor.trim().parse().ok(),
+        "DTASOF" => {
+            if let Some(data) = valor
+                .get(..8)
+                .and_then(|d| NaiveDate::parse_from_str(d, "%Y%m%d").ok())
+            {
+                saldo.data = Some(data);
+            }
+        }
+        "BANKID" | "BROKERID" => *banco = valor.to_uppercase(),
         "ACCTID" => *conta = valor.to_lowercase(),
-        "MEMO" => item.descricao = valor.to_ascii_lowercase(),
-        "TRNAMT" => item.valor = valor.parse().unwrap(),
+        "MEMO" => item.lancamento.descricao = valor.to_ascii_lowercase(),
+        "TRNAMT" => item.valor = valor.trim().parse().ok(),
         "DTPOSTED" => {
-            item.data = NaiveDate::parse_from_str(&valor[..8], "%Y%m%d").unwrap()
+            item.data = valor
+                .get(..8)
+                .and_then(|d| NaiveDate::parse_from_str(d, "%Y%m%d").ok())
         }
         _ => {}
     }
 }
 
-fn add_lancamento(item: &mut Lancamento, conta: &String, lista: &mut Vec<Lancamento>) {
-    item.conta = Some(conta.clone());
-    item.gerar_id();
-    lista.push(item.clone());
-    *item = Lancamento::default();
+/// Sem `TRNAMT` ou `DTPOSTED` válidos o lançamento é descartado e vai para o log.
+fn add_lancamento(item: &mut LancamentoLido, conta: &String, lista: &mut Vec<Lancamento>) -> bool {
+    let lido = mem::take(item);
+    let (Some(valor), Some(data)) = (lido.valor, lido.data) else {
+        log::error!(
+            "Lançamento descartado, sem TRNAMT ou DTPOSTED válido: {}",
+            lido.lancamento.descricao
+        );
+        return false;
+    };
+
+    let mut lancamento = Lancamento {
+        valor,
+        data,
+        conta: Some(conta.clone()),
+        ..lido.lancamento
+    };
+    lancamento.gerar_id();
+    lista.push(lancamento);
+    true
 }
 
-fn add_banco(banco: &String, conta: &String) -> Banco {
+fn add_banco(banco: &String, conta: &String, tipo: TipoConta) -> Banco {
     Banco {
         id: banco.clone(),
         nome: banco.clone(),
         contas: vec![Conta {
             id: conta.clone(),
             nome: conta.clone(),
+            tipo,
+            ..Default::default()
         }],
     }
 }
 
+fn add_saldo(bancos: &mut [Banco], banco: &str, conta: &str, saldo: SaldoExtrato) {
+    if let Some(c) = bancos
+        .iter_mut()
+        .rev()
+        .find(|b| b.id == banco)
+        .and_then(|b| b.contas.iter_mut().find(|c| c.id == conta))
+    {
+        c.extrato = Some(saldo);
+    }
+}
+
+/// Com a criptografia ativa o OFX chega cifrado em `importado`.
 fn mover_para_importado(arquivo: &str) {
-    let novo = arquivo.to_string().replace("importar", "importado");
-    rename(arquivo, novo).expect("Erro ao mover");
+    match chave() {
+        Some(chave) => mover_cifrado(Path::new(arquivo), &caminhos().importado, &chave),
+        None => mover(Path::new(arquivo), &caminhos().importado),
+    }
+}
+
+/// OFX já importados, que a criptografia também cobre. Só os do próprio
+/// diretório: no perfil padrão as subpastas são dos outros perfis.
+pub(super) fn arquivos_importados(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entradas) = read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut resp: Vec<PathBuf> = entradas
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.is_file() && p.to_string_lossy().ends_with("ofx"))
+        .collect();
+    resp.sort();
+    resp
+}
+
+/// Grava a cópia cifrada em `dir` e só então apaga o original; um erro vai
+/// para o log e o arquivo fica onde estava.
+fn mover_cifrado(origem: &Path, dir: &Path, chave: &Chave) {
+    let novo = match origem.file_name() {
+        Some(nome) => dir.join(nome),
+        None => return,
+    };
+    let resp = fs::read(origem)
+        .map_err(|e| e.to_string())
+        .and_then(|bytes| codificar_com(Some(chave), &bytes))
+        .and_then(|cifrado| escrever(&novo, &cifrado).map_err(|e| e.to_string()));
+    match resp {
+        Ok(_) => {
+            if let Err(erro) = remove_file(origem) {
+                log::error!(
+                    "Erro ao apagar {} depois de cifrar: {}",
+                    origem.display(),
+                    erro
+                );
+            }
+        }
+        Err(erro) => log::error!(
+            "Erro ao cifrar {} em {}: {}",
+            origem.display(),
+            dir.display(),
+            erro
+        ),
+    }
+}
+
+/// Move o arquivo para `dir`. Com `importar` e `importado` em sistemas de arquivos
+/// diferentes o `rename` falha, então copia e apaga o original. Um erro vai para o
+/// log e o arquivo fica onde estava.
+fn mover(origem: &Path, dir: &Path) {
+    let novo = match origem.file_name() {
+        Some(nome) => dir.join(nome),
+        None => return,
+    };
+    if rename(origem, &novo).is_ok() {
+        return;
+    }
+    match copy(origem, &novo) {
+        Ok(_) => {
+            if let Err(erro) = remove_file(origem) {
+                log::error!(
+                    "Erro ao apagar {} depois de copiar: {}",
+                    origem.display(),
+                    erro
+                );
+            }
+        }
+        Err(erro) => log::error!(
+            "Erro ao mover {} para {}: {}",
+            origem.display(),
+            dir.display(),
+            erro
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::{cripto_repy::PREFIXO, teste::DirTeste};
+
+    /// Conta corrente com saldo, corretora sem `INVBAL` e outra conta com saldo sem data.
+    const OFX: &str = "
+        <OFX>
+        <BANKMSGSRSV1>
+        <STMTTRNRS>
+        <STMTRS>
+        <BANKACCTFROM>
+        <BANKID>0341
+        <ACCTID>1234-5
+        </BANKACCTFROM>
+        <BANKTRANLIST>
+        <STMTTRN>
+        <TRNTYPE>DEBIT
+        <DTPOSTED>20250105
+        <TRNAMT>-45.90
+        <FITID>B1
+        <MEMO>Padaria
+        </STMTTRN>
+        </BANKTRANLIST>
+        <LEDGERBAL>
+        <BALAMT>1500.00
+        <DTASOF>20250131
+        </LEDGERBAL>
+        </STMTRS>
+        </STMTTRNRS>
+        </BANKMSGSRSV1>
+        <INVSTMTMSGSRSV1>
+        <INVSTMTTRNRS>
+        <INVSTMTRS>
+        <CURDEF>BRL
+        <INVACCTFROM>
+        <BROKERID>corretora.com
+        <ACCTID>987
+        </INVACCTFROM>
+        <INVTRANLIST>
+        <BUYSTOCK>
+        <INVBUY>
+        <INVTRAN>
+        <FITID>T1
+        <DTTRADE>20250110
+        </INVTRAN>
+        <SECID>
+        <UNIQUEID>BRITSAACNPR7
+        <UNIQUEIDTYPE>ISIN
+        </SECID>
+        <UNITS>100
+        <UNITPRICE>10.50
+        <COMMISSION>2.00
+        <TOTAL>-1052.00
+        </INVBUY>
+        <BUYTYPE>BUY
+        </BUYSTOCK>
+        <SELLSTOCK>
+        <INVSELL>
+        <INVTRAN>
+        <FITID>T2
+        <DTTRADE>20250115
+        <MEMO>Venda ITSA4
+        </INVTRAN>
+        <SECID>
+        <UNIQUEID>BRITSAACNPR7
+        </SECID>
+        <UNITS>-40
+        <UNITPRICE>11.00
+        <FEES>1.00
+        <TOTAL>439.00
+        </INVSELL>
+        <SELLTYPE>SELL
+        </SELLSTOCK>
+        <INCOME>
+        <INVTRAN>
+        <FITID>T3
+        <DTTRADE>20250120
+        </INVTRAN>
+        <SECID>
+        <UNIQUEID>BRHGLGCTF004
+        </SECID>
+        <INCOMETYPE>DIV
+        <TOTAL>55.20
+        </INCOME>
+        </INVTRANLIST>
+        <INVPOSLIST>
+        <POSSTOCK>
+        <INVPOS>
+        <SECID>
+        <UNIQUEID>BRITSAACNPR7
+        </SECID>
+        <UNITS>60
+        <UNITPRICE>11.20
+        <DTPRICEASOF>20250131
+        </INVPOS>
+        </POSSTOCK>
+        </INVPOSLIST>
+        </INVSTMTRS>
+        </INVSTMTTRNRS>
+        </INVSTMTMSGSRSV1>
+        <BANKMSGSRSV1>
+        <STMTTRNRS>
+        <STMTRS>
+        <BANKACCTFROM>
+        <BANKID>0341
+        <ACCTID>999
+        </BANKACCTFROM>
+        <LEDGERBAL>
+        <BALAMT>200.00
+        </LEDGERBAL>
+        </STMTRS>
+        </STMTTRNRS>
+        </BANKMSGSRSV1>
+        <SECLISTMSGSRSV1>
+        <SECLIST>
+        <STOCKINFO>
+        <SECINFO>
+        <SECID>
+        <UNIQUEID>BRITSAACNPR7
+        </SECID>
+        <SECNAME>ITAUSA PN
+        <TICKER>itsa4
+        </SECINFO>
+        </STOCKINFO>
+        <STOCKINFO>
+        <SECINFO>
+        <SECID>
+        <UNIQUEID>BRHGLGCTF004
+        </SECID>
+        <SECNAME>CSHG LOGISTICA
+        <TICKER>HGLG11
+        </SECINFO>
+        </STOCKINFO>
+        </SECLIST>
+        </SECLISTMSGSRSV1>
+        </OFX>";
+
+    fn ler() -> (ExtratoOfx, u32) {
+        let mut extrato = ExtratoOfx::default();
+        let linhas = OFX.lines().map(|l| l.trim().to_string());
+        let count = ler_extrato(&mut extrato, linhas);
+        (extrato, count)
+    }
+
+    fn data(dia: u32, mes: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, mes, dia).unwrap()
+    }
+
+    #[test]
+    fn le_operacoes_de_investimento() {
+        let (extrato, count) = ler();
+        assert_eq!(count, 4);
+
+        let ops = &extrato.operacoes;
+        assert_eq!(ops.len(), 3);
+        assert_eq!(
+            (ops[0].ativo.as_str(), &ops[0].tipo),
+            ("ITSA4", &TipoOperacao::Compra)
+        );
+        assert_eq!(
+            (ops[0].quantidade, ops[0].preco, ops[0].taxas),
+            (100.0, 10.5, 2.0)
+        );
+        assert_eq!(ops[0].referencia, "T1");
+        assert_eq!(ops[0].data, data(10, 1));
+
+        assert_eq!(ops[1].tipo, TipoOperacao::Venda);
+        assert_eq!((ops[1].quantidade, ops[1].taxas), (40.0, 1.0));
+
+        // INCOME só com TOTAL
+        assert_eq!(
+            (ops[2].ativo.as_str(), &ops[2].tipo),
+            ("HGLG11", &TipoOperacao::Provento)
+        );
+        assert_eq!((ops[2].quantidade, ops[2].preco), (1.0, 55.2));
+        assert!(ops.iter().all(|o| !o.id.is_empty()));
+        assert_ne!(ops[0].id, ops[1].id);
+    }
+
+    #[test]
+    fn parte_em_dinheiro_vira_lancamento_vinculado() {
+        let (extrato, _) = ler();
+        let valores: Vec<(f64, &str)> = extrato
+            .lancamentos
+            .iter()
+            .map(|l| (l.valor, l.descricao.as_str()))
+            .collect();
+
+        assert_eq!(
+            valores,
+            vec![
+                (-45.9, "padaria"),
+                (-1052.0, "compra itsa4"),
+                (439.0, "venda itsa4"),
+                (55.2, "provento hglg11"),
+            ]
+        );
+        assert_eq!(extrato.lancamentos[1].conta, Some("987".to_string()));
+        assert_eq!(
+            extrato.operacoes[0].lancamento,
+            Some(extrato.lancamentos[1].id.clone())
+        );
+    }
+
+    #[test]
+    fn posicao_vira_cotacao_e_seclist_resolve_o_ticker() {
+        let (extrato, _) = ler();
+
+        assert_eq!(
+            extrato.cotacoes,
+            vec![Cotacao {
+                ativo: "ITSA4".to_string(),
+                data: data(31, 1),
+                preco: 11.2,
+            }]
+        );
+        let ativos: Vec<(&str, &str, &TipoAtivo)> = extrato
+            .ativos
+            .iter()
+            .map(|a| (a.id.as_str(), a.nome.as_str(), &a.tipo))
+            .collect();
+        assert_eq!(
+            ativos,
+            vec![
+                ("ITSA4", "ITAUSA PN", &TipoAtivo::Acao),
+                ("HGLG11", "CSHG LOGISTICA", &TipoAtivo::Fii),
+            ]
+        );
+    }
+
+    #[test]
+    fn saldo_nao_passa_de_uma_conta_para_outra() {
+        let (extrato, _) = ler();
+        let saldo = |conta: &str| {
+            extrato
+                .bancos
+                .iter()
+                .flat_map(|b| b.contas.iter())
+                .find(|c| c.id == conta)
+                .map(|c| c.extrato.clone())
+                .unwrap()
+        };
+
+        assert_eq!(
+            saldo("1234-5"),
+            Some(SaldoExtrato {
+                valor: 1500.0,
+                data: data(31, 1),
+            })
+        );
+        // sem INVBAL
+        assert_eq!(saldo("987"), None);
+        // LEDGERBAL sem DTASOF não herda a data da primeira conta
+        assert_eq!(saldo("999"), None);
+    }
+
+    #[test]
+    fn lancamento_malformado_e_descartado() {
+        let ofx = "
+            <STMTTRN>
+            <TRNAMT>abc
+            <DTPOSTED>20250105
+            <MEMO>Valor ruim
+            </STMTTRN>
+            <STMTTRN>
+            <TRNAMT>-10.00
+            <DTPOSTED>2025
+            <MEMO>Data ruim
+            </STMTTRN>
+            <STMTTRN>
+            <TRNAMT>-20.00
+            <DTPOSTED>20250106
+            <MEMO>Certo
+            </STMTTRN>";
+        let mut extrato = ExtratoOfx::default();
+
+        let count = ler_extrato(&mut extrato, ofx.lines().map(|l| l.trim().to_string()));
+
+        assert_eq!(count, 1);
+        assert_eq!(extrato.lancamentos.len(), 1);
+        assert_eq!(extrato.lancamentos[0].descricao, "certo");
+        assert_eq!(extrato.lancamentos[0].valor, -20.0);
+    }
+
+    #[test]
+    fn mover_leva_o_arquivo_ou_deixa_onde_estava() {
+        let dir = DirTeste::novo();
+        let origem = dir.escrever("importar/extrato.ofx", "<OFX>");
+
+        mover(&origem, &dir.path().join("inexistente"));
+        assert!(origem.exists());
+
+        let importado = dir.path().join("importado");
+        std::fs::create_dir_all(&importado).unwrap();
+        mover(&origem, &importado);
+        assert!(!origem.exists());
+        assert_eq!(dir.ler("importado/extrato.ofx"), b"<OFX>");
+    }
+
+    #[test]
+    fn mover_cifrado_grava_so_a_copia_cifrada() {
+        let dir = DirTeste::novo();
+        let origem = dir.escrever("importar/extrato.ofx", "<OFX>");
+        dir.escrever("importado/outro/velho.ofx", "<OFX>");
+        let chave = Chave::gerar("segredo", 1).unwrap();
+
+        mover_cifrado(&origem, &dir.path().join("importado"), &chave);
+
+        assert!(!origem.exists());
+        let cifrado = dir.ler("importado/extrato.ofx");
+        assert!(cifrado.starts_with(PREFIXO.as_bytes()));
+        assert_eq!(
+            arquivos_importados(&dir.path().join("importado")),
+            vec![dir.path().join("importado/extrato.ofx")]
+        );
+    }
 }
@@ -0,0 +1,33 @@
+use crate::{config_caminhos::caminhos, dto::Orcamento};
+
+use super::esquema_repy::{json_escrever, json_ler};
+
+const ORCAMENTOS: &str = "orcamentos.json";
+
+impl Orcamento {
+    pub fn listar() -> Vec<Orcamento> {
+        json_ler(&caminhos().dados, ORCAMENTOS).unwrap_or_default()
+    }
+
+    pub fn salvar(&self) {
+        let mut lista = Orcamento::listar();
+
+        if let Some(i) = lista.iter().position(|a| a.id == self.id) {
+            lista[i] = self.clone();
+        } else {
+            lista.push(self.clone());
+        }
+
+        json_escrever(&caminhos().dados, ORCAMENTOS, &lista);
+    }
+
+    pub fn deletar(&self) {
+        let mut lista = Orcamento::listar();
+
+        if let Some(pos) = lista.iter().position(|a| a.id == self.id) {
+            lista.remove(pos);
+        }
+
+        json_escrever(&caminhos().dados, ORCAMENTOS, &lista);
+    }
+}
@@ -0,0 +1,15 @@
+use crate::{config_caminhos::caminhos, dto::PlanoQuitacao};
+
+use super::esquema_repy::{json_escrever, json_ler};
+
+const PLANO: &str = "plano_quitacao.json";
+
+impl PlanoQuitacao {
+    pub fn buscar() -> Self {
+        json_ler(&caminhos().dados, PLANO).unwrap_or_default()
+    }
+
+    pub fn salvar(&self) {
+        json_escrever(&caminhos().dados, PLANO, self);
+    }
+}
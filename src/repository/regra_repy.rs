@@ -1,10 +1,16 @@
-use std::vec;
+use crate::{
+    config_caminhos::caminhos,
+    dto::{
+        Categoria, FluxoRegra, GatilhoSnapshot, Lancamento, Lazy, LazyFn, OptionalLazyFn, Regra,
+        Snapshot, TipoFluxo,
+    },
+};
+
+use super::{
+    armazenamento::{armazenamento, ArmazenamentoJson, RepositorioRegra},
+    esquema_repy::{json_escrever, json_ler},
+};
 
-use crate::dto::{Categoria, FluxoRegra, Lancamento, Lazy, LazyFn, OptionalLazyFn, Regra, TipoFluxo};
-
-use super::file_repy::{arq_escrever, arq_ler};
-
-const FIN: &str = ".financeiro";
 const REGRAS: &str = "regras.json";
 
 pub trait Buscar {
@@ -31,20 +37,9 @@ impl Buscar for Vec<Regra> {
 
 impl Regra {
     pub fn listar_lazy() -> Vec<Regra> {
-        let mut json: String = arq_ler(FIN, REGRAS).collect();
-        if json.is_empty() {
-            json = "[]".to_string();
-        }
-        let resp: Vec<Regra> = match serde_json::from_str(&json) {
-            Ok(vec) => vec,
-            Err(erro) => {
-                log::error!("Erro ao ler regras: {}", erro);
-                vec![]
-            }
-        };
-
-        resp
+        armazenamento().regras_listar()
     }
+
     pub fn listar_full() -> Vec<Regra> {
         let mut resp = Regra::listar_lazy();
 
@@ -93,6 +88,12 @@ impl Regra {
 
     pub fn remover_sem_categoria() {
         let regras = Regra::listar_full();
+        let sem_categoria = regras.iter().any(|r| {
+            matches!(&r.categoria, Lazy::Some(cat) if matches!(cat.tipo, TipoFluxo::SemCategoria))
+        });
+        if sem_categoria {
+            Snapshot::criar(GatilhoSnapshot::RemocaoRegras);
+        }
 
         regras.iter().for_each(|r| {
             if let Lazy::Some(cat) = r.categoria.clone() {
@@ -111,11 +112,16 @@ impl Regra {
     pub fn garantir_integridade() {
         let mut regras = Regra::listar_lazy();
         let lancamentos = Lancamento::lancamentos_listar();
+        let antes = regras.len();
 
         regras = remover_regras_duplicadas(regras);
         regras = remover_regras_sem_categoria(regras);
         regras = remover_regras_em_desuso(regras, lancamentos);
 
+        if regras.len() < antes {
+            Snapshot::criar(GatilhoSnapshot::RemocaoRegras);
+        }
+
         Regra::salvar_lista(&regras);
     }
 }
@@ -208,8 +214,15 @@ fn salvar(mut regras: Vec<Regra>) {
         }
     }
 
-    match serde_json::to_string_pretty(&regras) {
-        Ok(json) => arq_escrever(FIN, REGRAS, json),
-        Err(erro) => log::error!("Erro ao salvar regras: {}", erro),
-    };
+    armazenamento().regras_salvar(&regras);
+}
+
+impl RepositorioRegra for ArmazenamentoJson {
+    fn regras_listar(&self) -> Vec<Regra> {
+        json_ler(&caminhos().dados, REGRAS).unwrap_or_default()
+    }
+
+    fn regras_salvar(&self, itens: &[Regra]) {
+        json_escrever(&caminhos().dados, REGRAS, itens);
+    }
 }
@@ -0,0 +1,451 @@
+use std::{
+    cmp::Reverse,
+    collections::BTreeSet,
+    fs::{self, File},
+    io::{Cursor, Read, Write},
+    path::{Path, PathBuf},
+};
+
+use chrono::{Local, NaiveDateTime};
+use zip::{write::SimpleFileOptions, CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::{
+    config_caminhos::caminhos,
+    dto::{ArquivoSnapshot, ComparacaoSnapshot, GatilhoSnapshot, Snapshot},
+};
+
+use super::{
+    cripto_repy::{self, codificar_com, decodificar_retomando, Chave},
+    esquema_repy::contar_registros,
+};
+
+const DIR_SNAPSHOTS: &str = "snapshots";
+const FORMATO_DATA: &str = "%Y%m%d-%H%M%S";
+
+/// Itens do diretório de dados que ficam fora dos snapshots. Os parâmetros da
+/// criptografia ficam de fora para que restaurar não troque a senha em uso.
+const IGNORADOS: [&str; 4] = [
+    DIR_SNAPSHOTS,
+    "log",
+    cripto_repy::ARQUIVO,
+    cripto_repy::ARQUIVO_PENDENTE,
+];
+
+impl Snapshot {
+    /// Snapshot antes de uma operação destrutiva. Uma falha vai para o log e não
+    /// impede a operação; com `snapshots: 0` no perfil não faz nada.
+    pub fn criar(gatilho: GatilhoSnapshot) -> Option<Snapshot> {
+        if caminhos().snapshots == 0 {
+            return None;
+        }
+        let dados = &caminhos().dados;
+        match criar_zip(dados, gatilho) {
+            Ok(snapshot) => {
+                aplicar_retencao(dados, caminhos().snapshots);
+                Some(snapshot)
+            }
+            Err(erro) => {
+                log::error!("Erro ao criar snapshot ({}): {}", gatilho.id(), erro);
+                None
+            }
+        }
+    }
+
+    /// Cria o snapshot diário se ainda não houver um de hoje.
+    pub fn diario() {
+        let hoje = Local::now().date_naive();
+        let feito = Snapshot::listar()
+            .iter()
+            .any(|s| s.gatilho == GatilhoSnapshot::Diario && s.data.date() == hoje);
+        if !feito && !arquivos_dados(&caminhos().dados).is_empty() {
+            Snapshot::criar(GatilhoSnapshot::Diario);
+        }
+    }
+
+    /// Snapshots do perfil, do mais recente para o mais antigo.
+    pub fn listar() -> Vec<Snapshot> {
+        listar_em(&caminhos().dados)
+    }
+
+    /// Registros e tamanhos de cada arquivo no snapshot e nos dados atuais.
+    pub fn comparar(&self) -> Vec<ComparacaoSnapshot> {
+        let dados = &caminhos().dados;
+        let mut arquivo = match abrir(&self.caminho) {
+            Ok(arquivo) => arquivo,
+            Err(erro) => {
+                log::error!(
+                    "Erro ao abrir snapshot {}: {}",
+                    self.caminho.display(),
+                    erro
+                );
+                return vec![];
+            }
+        };
+
+        let atuais = arquivos_dados(dados);
+        let nomes: BTreeSet<String> = self
+            .arquivos
+            .iter()
+            .map(|a| a.nome.clone())
+            .chain(atuais.iter().cloned())
+            .collect();
+
+        nomes
+            .into_iter()
+            .map(|nome| {
+                let no_snapshot = arquivo.by_name(&nome).ok().map(|mut entrada| {
+                    let mut bytes = Vec::new();
+                    let _ = entrada.read_to_end(&mut bytes);
+                    bytes
+                });
+                let atual = if atuais.contains(&nome) {
+                    fs::read(dados.join(&nome)).ok()
+                } else {
+                    None
+                };
+                ComparacaoSnapshot {
+                    registros_snapshot: no_snapshot.as_deref().and_then(|b| registros(&nome, b)),
+                    registros_atual: atual.as_deref().and_then(|b| registros(&nome, b)),
+                    tamanho_snapshot: no_snapshot.map(|b| b.len() as u64),
+                    tamanho_atual: atual.map(|b| b.len() as u64),
+                    arquivo: nome,
+                }
+            })
+            .collect()
+    }
+
+    /// Substitui os dados atuais pelos do snapshot, depois de guardar os atuais
+    /// num snapshot de restauração. Arquivos que não existiam no snapshot são apagados.
+    pub fn restaurar(&self) -> Result<(), String> {
+        restaurar(self, &caminhos().dados)
+    }
+}
+
+/// Um snapshot com entrada que sairia do diretório de dados (caminho absoluto
+/// ou com `..`) é recusado antes de mexer em qualquer arquivo.
+fn restaurar(snapshot: &Snapshot, dados: &Path) -> Result<(), String> {
+    let mut arquivo = abrir(&snapshot.caminho)?;
+
+    for i in 0..arquivo.len() {
+        let entrada = arquivo.by_index(i).map_err(|e| e.to_string())?;
+        if entrada.enclosed_name().is_none() {
+            return Err(format!("entrada inválida no snapshot: {}", entrada.name()));
+        }
+    }
+
+    criar_zip(dados, GatilhoSnapshot::Restauracao)?;
+
+    let restaurados: BTreeSet<&str> = snapshot.arquivos.iter().map(|a| a.nome.as_str()).collect();
+    for nome in arquivos_dados(dados) {
+        if !restaurados.contains(nome.as_str()) {
+            fs::remove_file(dados.join(&nome)).map_err(|e| format!("{}: {}", nome, e))?;
+        }
+    }
+
+    for i in 0..arquivo.len() {
+        let mut entrada = arquivo.by_index(i).map_err(|e| e.to_string())?;
+        let relativo = match entrada.enclosed_name() {
+            Some(relativo) if entrada.is_file() => relativo,
+            _ => continue,
+        };
+        let destino = dados.join(relativo);
+        let mut bytes = Vec::new();
+        entrada.read_to_end(&mut bytes).map_err(|e| e.to_string())?;
+        escrever(&destino, &bytes).map_err(|e| format!("{}: {}", destino.display(), e))?;
+    }
+
+    log::info!("Snapshot {} restaurado", snapshot.caminho.display());
+    Ok(())
+}
+
+fn listar_em(dados: &Path) -> Vec<Snapshot> {
+    let mut resp: Vec<Snapshot> = match fs::read_dir(dados.join(DIR_SNAPSHOTS)) {
+        Ok(entradas) => entradas
+            .flatten()
+            .filter_map(|e| ler_snapshot(&e.path()))
+            .collect(),
+        Err(_) => vec![],
+    };
+    resp.sort_by_key(|s| Reverse(s.data));
+    resp
+}
+
+fn criar_zip(dados: &Path, gatilho: GatilhoSnapshot) -> Result<Snapshot, String> {
+    let dir = dados.join(DIR_SNAPSHOTS);
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+
+    let agora = Local::now().naive_local();
+    let caminho = dir.join(format!(
+        "{}-{}.zip",
+        agora.format(FORMATO_DATA),
+        gatilho.id()
+    ));
+    if let Some(existente) = ler_snapshot(&caminho) {
+        return Ok(existente);
+    }
+
+    let mut tmp = caminho.clone();
+    tmp.set_extension("zip.tmp");
+
+    let mut zip = ZipWriter::new(File::create(&tmp).map_err(|e| e.to_string())?);
+    let opcoes = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    for nome in arquivos_dados(dados) {
+        let bytes = fs::read(dados.join(&nome)).map_err(|e| format!("{}: {}", nome, e))?;
+        zip.start_file(nome.as_str(), opcoes)
+            .map_err(|e| e.to_string())?;
+        zip.write_all(&bytes).map_err(|e| e.to_string())?;
+    }
+    zip.finish().map_err(|e| e.to_string())?;
+    fs::rename(&tmp, &caminho).map_err(|e| e.to_string())?;
+
+    log::info!("Snapshot criado: {}", caminho.display());
+    ler_snapshot(&caminho).ok_or(format!("{} não pôde ser lido", caminho.display()))
+}
+
+/// Apaga os snapshots mais antigos que passarem de `manter`.
+fn aplicar_retencao(dados: &Path, manter: usize) {
+    listar_em(dados)
+        .iter()
+        .skip(manter)
+        .for_each(|s| match fs::remove_file(&s.caminho) {
+            Ok(_) => log::info!("Snapshot removido: {}", s.caminho.display()),
+            Err(erro) => log::error!("Erro ao remover snapshot {}: {}", s.caminho.display(), erro),
+        });
+}
+
+/// Data e gatilho vêm do nome do arquivo (`AAAAMMDD-HHMMSS-gatilho.zip`).
+fn ler_snapshot(caminho: &Path) -> Option<Snapshot> {
+    if caminho.extension().is_none_or(|e| e != "zip") {
+        return None;
+    }
+    let nome = caminho.file_stem()?.to_str()?;
+    let data = NaiveDateTime::parse_from_str(nome.get(..15)?, FORMATO_DATA).ok()?;
+    let gatilho = GatilhoSnapshot::from_id(nome.get(16..)?)?;
+
+    let mut arquivo = abrir(caminho).ok()?;
+    let mut arquivos = Vec::new();
+    for i in 0..arquivo.len() {
+        if let Ok(e) = arquivo.by_index(i) {
+            if e.is_file() {
+                arquivos.push(ArquivoSnapshot {
+                    nome: e.name().to_string(),
+                    tamanho: e.size(),
+                    compactado: e.compressed_size(),
+                });
+            }
+        }
+    }
+
+    Some(Snapshot {
+        caminho: caminho.to_path_buf(),
+        data,
+        gatilho,
+        arquivos,
+    })
+}
+
+fn abrir(caminho: &Path) -> Result<ZipArchive<File>, String> {
+    let arquivo = File::open(caminho).map_err(|e| e.to_string())?;
+    ZipArchive::new(arquivo).map_err(|e| e.to_string())
+}
+
+/// Arquivos do diretório de dados, relativos a ele e com `/`, sem log,
+/// snapshots, `cripto.json` e temporários.
+pub(super) fn arquivos_dados(dados: &Path) -> Vec<String> {
+    let mut resp = Vec::new();
+    listar_dir(dados, "", &mut resp);
+    resp.sort();
+    resp
+}
+
+fn listar_dir(dir: &Path, prefixo: &str, resp: &mut Vec<String>) {
+    let Ok(entradas) = fs::read_dir(dir) else {
+        return;
+    };
+    for entrada in entradas.flatten() {
+        let nome = entrada.file_name().to_string_lossy().to_string();
+        let relativo = format!("{}{}", prefixo, nome);
+        let path = entrada.path();
+        if prefixo.is_empty() && IGNORADOS.contains(&nome.as_str()) {
+            continue;
+        }
+        if path.is_dir() {
+            listar_dir(&path, &format!("{}/", relativo), resp);
+        } else if !nome.ends_with(".tmp") {
+            resp.push(relativo);
+        }
+    }
+}
+
+fn registros(nome: &str, bytes: &[u8]) -> Option<usize> {
+    if !nome.ends_with(".json") {
+        return None;
+    }
+    contar_registros(std::str::from_utf8(bytes).ok()?)
+}
+
+/// Conteúdo novo de cada snapshot com as entradas decifradas com `de` e cifradas
+/// com `para`. Nada é gravado aqui; um erro em qualquer entrada cancela tudo.
+pub(super) fn recodificar_snapshots(
+    dados: &Path,
+    de: Option<&Chave>,
+    para: Option<&Chave>,
+) -> Result<Vec<(PathBuf, Vec<u8>)>, String> {
+    let mut resp = Vec::new();
+    for snapshot in listar_em(dados) {
+        let mut arquivo = abrir(&snapshot.caminho)?;
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        let opcoes = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+        for i in 0..arquivo.len() {
+            let mut entrada = arquivo.by_index(i).map_err(|e| e.to_string())?;
+            if !entrada.is_file() {
+                continue;
+            }
+            let nome = entrada.name().to_string();
+            let mut bytes = Vec::new();
+            entrada.read_to_end(&mut bytes).map_err(|e| e.to_string())?;
+            let claro = decodificar_retomando(de, para, &bytes)
+                .map_err(|e| format!("{} em {}: {}", nome, snapshot.caminho.display(), e))?;
+
+            zip.start_file(nome.as_str(), opcoes)
+                .map_err(|e| e.to_string())?;
+            zip.write_all(&codificar_com(para, &claro)?)
+                .map_err(|e| e.to_string())?;
+        }
+        let bytes = zip.finish().map_err(|e| e.to_string())?.into_inner();
+        resp.push((snapshot.caminho, bytes));
+    }
+    Ok(resp)
+}
+
+/// Gravação atômica, como em `arq_escrever`, criando os diretórios que faltarem.
+pub(super) fn escrever(destino: &Path, bytes: &[u8]) -> std::io::Result<()> {
+    if let Some(pai) = destino.parent() {
+        fs::create_dir_all(pai)?;
+    }
+    let mut tmp = destino.as_os_str().to_owned();
+    tmp.push(".tmp");
+    fs::write(&tmp, bytes)?;
+    fs::rename(&tmp, destino)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::teste::DirTeste;
+
+    /// Grava um snapshot com as entradas dadas, sem passar por `criar_zip`.
+    fn gravar_zip(dir: &DirTeste, nome: &str, entradas: &[(&str, &str)]) -> PathBuf {
+        let caminho = dir.path().join(DIR_SNAPSHOTS).join(nome);
+        fs::create_dir_all(caminho.parent().unwrap()).unwrap();
+        let mut zip = ZipWriter::new(File::create(&caminho).unwrap());
+        for (nome, conteudo) in entradas {
+            zip.start_file(*nome, SimpleFileOptions::default()).unwrap();
+            zip.write_all(conteudo.as_bytes()).unwrap();
+        }
+        zip.finish().unwrap();
+        caminho
+    }
+
+    #[test]
+    fn data_e_gatilho_vem_do_nome() {
+        let dir = DirTeste::novo();
+        let caminho = gravar_zip(
+            &dir,
+            "20250301-142530-exclusao-categoria.zip",
+            &[("categorias.json", "[]")],
+        );
+
+        let snapshot = ler_snapshot(&caminho).unwrap();
+        assert_eq!(
+            snapshot.data,
+            NaiveDateTime::parse_from_str("2025-03-01 14:25:30", "%Y-%m-%d %H:%M:%S").unwrap()
+        );
+        assert_eq!(snapshot.gatilho, GatilhoSnapshot::ExclusaoCategoria);
+        assert_eq!(snapshot.arquivos.len(), 1);
+        assert_eq!(snapshot.arquivos[0].nome, "categorias.json");
+        assert_eq!(snapshot.arquivos[0].tamanho, 2);
+
+        for invalido in [
+            "20250301-142530-desconhecido.zip",
+            "2025-03-01-manual.zip",
+            "20250301-142530-manual.zip.tmp",
+        ] {
+            let caminho = gravar_zip(&dir, invalido, &[]);
+            assert!(ler_snapshot(&caminho).is_none(), "{}", invalido);
+        }
+    }
+
+    #[test]
+    fn retencao_apaga_os_mais_antigos() {
+        let dir = DirTeste::novo();
+        for nome in [
+            "20250101-080000-diario.zip",
+            "20250103-080000-diario.zip",
+            "20250102-080000-manual.zip",
+        ] {
+            gravar_zip(&dir, nome, &[]);
+        }
+
+        aplicar_retencao(dir.path(), 2);
+
+        let restantes: Vec<String> = listar_em(dir.path())
+            .iter()
+            .map(|s| s.caminho.file_name().unwrap().to_string_lossy().to_string())
+            .collect();
+        assert_eq!(
+            restantes,
+            vec!["20250103-080000-diario.zip", "20250102-080000-manual.zip"]
+        );
+    }
+
+    #[test]
+    fn restaurar_troca_os_dados_pelos_do_snapshot() {
+        let dir = DirTeste::novo();
+        let caminho = gravar_zip(
+            &dir,
+            "20250101-080000-manual.zip",
+            &[("categorias.json", "antigo"), ("regras/nao-cat.json", "[]")],
+        );
+        dir.escrever("categorias.json", "atual");
+        dir.escrever("novo.json", "criado depois");
+        dir.escrever("log/financeiro.log", "log");
+
+        restaurar(&ler_snapshot(&caminho).unwrap(), dir.path()).unwrap();
+
+        assert_eq!(
+            arquivos_dados(dir.path()),
+            vec!["categorias.json", "regras/nao-cat.json"]
+        );
+        assert_eq!(dir.ler("categorias.json"), b"antigo");
+        assert_eq!(dir.ler("log/financeiro.log"), b"log");
+
+        // os dados de antes ficam num snapshot de restauração
+        let anterior = listar_em(dir.path())
+            .into_iter()
+            .find(|s| s.gatilho == GatilhoSnapshot::Restauracao)
+            .unwrap();
+        let nomes: Vec<&str> = anterior.arquivos.iter().map(|a| a.nome.as_str()).collect();
+        assert_eq!(nomes, vec!["categorias.json", "novo.json"]);
+    }
+
+    #[test]
+    fn restaurar_recusa_entrada_fora_dos_dados() {
+        let dir = DirTeste::novo();
+        let caminho = gravar_zip(
+            &dir,
+            "20250101-080000-manual.zip",
+            &[("categorias.json", "antigo"), ("../fora.json", "[]")],
+        );
+        dir.escrever("categorias.json", "atual");
+        dir.escrever("novo.json", "criado depois");
+
+        assert!(restaurar(&ler_snapshot(&caminho).unwrap(), dir.path()).is_err());
+
+        assert_eq!(dir.ler("categorias.json"), b"atual");
+        assert_eq!(dir.ler("novo.json"), b"criado depois");
+        assert!(!dir.path().join("../fora.json").exists());
+        assert_eq!(listar_em(dir.path()).len(), 1);
+    }
+}
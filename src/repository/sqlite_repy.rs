@@ -0,0 +1,1323 @@
+use std::{
+    collections::HashSet,
+    sync::{Mutex, MutexGuard},
+};
+
+use rusqlite::{params, types::Type, Connection, Row, Transaction};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    config_caminhos::caminhos,
+    dto::{
+        Banco, Categoria, Configuracao, Conta, Divida, GatilhoSnapshot, Lancamento, Lazy, LazyFn,
+        Meta, OptionalLazy, OptionalLazyFn, ParcelaDivida, Regra, SaldoExtrato, Snapshot,
+    },
+};
+
+use super::{
+    armazenamento::{
+        Armazenamento, ArmazenamentoJson, RepositorioBanco, RepositorioCategoria,
+        RepositorioConfiguracao, RepositorioDivida, RepositorioLancamento, RepositorioMeta,
+        RepositorioRegra,
+    },
+    cripto_repy::criptografia_ativa,
+    esquema_repy::arquivos_bloqueados,
+};
+
+const BANCO: &str = "financeiro.db";
+
+/// Gravada em `PRAGMA user_version`; alterações no esquema sobem este número.
+const VERSAO_ESQUEMA: i32 = 3;
+
+/// Alterações para bancos já criados, em ordem: a de índice `i` leva da versão
+/// `i + 1` para a `i + 2`. `ESQUEMA` já cria as tabelas na versão atual.
+/// Rodam com as chaves estrangeiras desligadas, o que permite recriar tabelas;
+/// o banco das contas é preenchido por `ligar_contas` depois delas.
+const MIGRACOES: [&str; 2] = [
+    "ALTER TABLE dividas ADD COLUMN criada_em TEXT;",
+    // chave estrangeira composta não entra por ALTER TABLE
+    "
+CREATE TABLE lancamentos_v3 (
+    id TEXT PRIMARY KEY,
+    descricao TEXT NOT NULL,
+    valor REAL NOT NULL,
+    data TEXT NOT NULL,
+    categoria TEXT REFERENCES categorias(id) ON DELETE SET NULL DEFERRABLE INITIALLY DEFERRED,
+    banco TEXT,
+    conta TEXT,
+    regra TEXT REFERENCES regras(id) ON DELETE SET NULL DEFERRABLE INITIALLY DEFERRED,
+    FOREIGN KEY (banco, conta) REFERENCES contas(banco, id)
+        ON DELETE SET NULL DEFERRABLE INITIALLY DEFERRED
+);
+INSERT INTO lancamentos_v3 (id, descricao, valor, data, categoria, banco, conta, regra)
+    SELECT id, descricao, valor, data, categoria, NULL, conta, regra FROM lancamentos ORDER BY rowid;
+DROP TABLE lancamentos;
+ALTER TABLE lancamentos_v3 RENAME TO lancamentos;
+CREATE TABLE nao_categorizados_v3 (
+    id TEXT PRIMARY KEY,
+    descricao TEXT NOT NULL,
+    valor REAL NOT NULL,
+    data TEXT NOT NULL,
+    categoria TEXT REFERENCES categorias(id) ON DELETE SET NULL DEFERRABLE INITIALLY DEFERRED,
+    banco TEXT,
+    conta TEXT,
+    regra TEXT REFERENCES regras(id) ON DELETE SET NULL DEFERRABLE INITIALLY DEFERRED,
+    FOREIGN KEY (banco, conta) REFERENCES contas(banco, id)
+        ON DELETE SET NULL DEFERRABLE INITIALLY DEFERRED
+);
+INSERT INTO nao_categorizados_v3 (id, descricao, valor, data, categoria, banco, conta, regra)
+    SELECT id, descricao, valor, data, categoria, NULL, conta, regra FROM nao_categorizados ORDER BY rowid;
+DROP TABLE nao_categorizados;
+ALTER TABLE nao_categorizados_v3 RENAME TO nao_categorizados;
+CREATE TABLE dividas_v3 (
+    id TEXT PRIMARY KEY,
+    nome TEXT NOT NULL,
+    prioritaria INTEGER NOT NULL,
+    cobranca_automatica INTEGER NOT NULL,
+    financiamento TEXT,
+    padrao_descricao TEXT NOT NULL,
+    banco TEXT,
+    conta TEXT,
+    origem TEXT REFERENCES dividas(id) ON DELETE SET NULL DEFERRABLE INITIALLY DEFERRED,
+    arquivada INTEGER NOT NULL,
+    criada_em TEXT,
+    FOREIGN KEY (banco, conta) REFERENCES contas(banco, id)
+        ON DELETE SET NULL DEFERRABLE INITIALLY DEFERRED
+);
+INSERT INTO dividas_v3 (id, nome, prioritaria, cobranca_automatica, financiamento,
+                        padrao_descricao, banco, conta, origem, arquivada, criada_em)
+    SELECT id, nome, prioritaria, cobranca_automatica, financiamento,
+           padrao_descricao, NULL, conta, origem, arquivada, criada_em
+    FROM dividas ORDER BY rowid;
+DROP TABLE dividas;
+ALTER TABLE dividas_v3 RENAME TO dividas;
+",
+];
+
+const LANCAMENTOS: &str = "lancamentos";
+const NAO_CAT: &str = "nao_categorizados";
+
+/// Enums e listas vão como JSON em colunas de texto; relações viram chaves
+/// estrangeiras, adiadas para o commit para que a ordem de gravação não importe.
+/// Como `contas` tem chave composta, lançamentos e dívidas guardam também o
+/// banco da conta, buscado pelo id dela (o primeiro, se o id se repetir em dois
+/// bancos); conta sem cadastro fica sem banco e sem a checagem.
+const ESQUEMA: &str = "
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE IF NOT EXISTS categorias (
+    id TEXT PRIMARY KEY,
+    nome TEXT NOT NULL,
+    tipo TEXT NOT NULL,
+    pai TEXT REFERENCES categorias(id) ON DELETE SET NULL DEFERRABLE INITIALLY DEFERRED,
+    carne_leao INTEGER NOT NULL DEFAULT 0,
+    livro_caixa INTEGER NOT NULL DEFAULT 0
+);
+
+CREATE TABLE IF NOT EXISTS regras (
+    id TEXT PRIMARY KEY,
+    ordem INTEGER NOT NULL,
+    fluxo TEXT NOT NULL,
+    regex TEXT NOT NULL,
+    categoria TEXT NOT NULL
+        REFERENCES categorias(id) ON DELETE CASCADE DEFERRABLE INITIALLY DEFERRED
+);
+CREATE INDEX IF NOT EXISTS regras_categoria ON regras(categoria);
+
+CREATE TABLE IF NOT EXISTS lancamentos (
+    id TEXT PRIMARY KEY,
+    descricao TEXT NOT NULL,
+    valor REAL NOT NULL,
+    data TEXT NOT NULL,
+    categoria TEXT REFERENCES categorias(id) ON DELETE SET NULL DEFERRABLE INITIALLY DEFERRED,
+    banco TEXT,
+    conta TEXT,
+    regra TEXT REFERENCES regras(id) ON DELETE SET NULL DEFERRABLE INITIALLY DEFERRED,
+    FOREIGN KEY (banco, conta) REFERENCES contas(banco, id)
+        ON DELETE SET NULL DEFERRABLE INITIALLY DEFERRED
+);
+CREATE INDEX IF NOT EXISTS lancamentos_data ON lancamentos(data);
+CREATE INDEX IF NOT EXISTS lancamentos_categoria ON lancamentos(categoria);
+CREATE INDEX IF NOT EXISTS lancamentos_regra ON lancamentos(regra);
+CREATE INDEX IF NOT EXISTS lancamentos_conta ON lancamentos(banco, conta);
+
+CREATE TABLE IF NOT EXISTS nao_categorizados (
+    id TEXT PRIMARY KEY,
+    descricao TEXT NOT NULL,
+    valor REAL NOT NULL,
+    data TEXT NOT NULL,
+    categoria TEXT REFERENCES categorias(id) ON DELETE SET NULL DEFERRABLE INITIALLY DEFERRED,
+    banco TEXT,
+    conta TEXT,
+    regra TEXT REFERENCES regras(id) ON DELETE SET NULL DEFERRABLE INITIALLY DEFERRED,
+    FOREIGN KEY (banco, conta) REFERENCES contas(banco, id)
+        ON DELETE SET NULL DEFERRABLE INITIALLY DEFERRED
+);
+CREATE INDEX IF NOT EXISTS nao_categorizados_conta ON nao_categorizados(banco, conta);
+
+CREATE TABLE IF NOT EXISTS bancos (
+    id TEXT PRIMARY KEY,
+    nome TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS contas (
+    banco TEXT NOT NULL REFERENCES bancos(id) ON DELETE CASCADE,
+    id TEXT NOT NULL,
+    nome TEXT NOT NULL,
+    tipo TEXT NOT NULL,
+    saldo_inicial REAL NOT NULL,
+    data_saldo_inicial TEXT,
+    extrato_valor REAL,
+    extrato_data TEXT,
+    PRIMARY KEY (banco, id)
+);
+CREATE INDEX IF NOT EXISTS contas_id ON contas(id);
+
+CREATE TABLE IF NOT EXISTS dividas (
+    id TEXT PRIMARY KEY,
+    nome TEXT NOT NULL,
+    prioritaria INTEGER NOT NULL,
+    cobranca_automatica INTEGER NOT NULL,
+    financiamento TEXT,
+    padrao_descricao TEXT NOT NULL,
+    banco TEXT,
+    conta TEXT,
+    origem TEXT REFERENCES dividas(id) ON DELETE SET NULL DEFERRABLE INITIALLY DEFERRED,
+    arquivada INTEGER NOT NULL,
+    criada_em TEXT,
+    FOREIGN KEY (banco, conta) REFERENCES contas(banco, id)
+        ON DELETE SET NULL DEFERRABLE INITIALLY DEFERRED
+);
+CREATE INDEX IF NOT EXISTS dividas_conta ON dividas(banco, conta);
+
+CREATE TABLE IF NOT EXISTS parcelas_divida (
+    divida TEXT NOT NULL REFERENCES dividas(id) ON DELETE CASCADE,
+    ordem INTEGER NOT NULL,
+    num_parcela INTEGER NOT NULL,
+    valor REAL NOT NULL,
+    pago INTEGER NOT NULL,
+    data_vencimento TEXT NOT NULL,
+    juros REAL NOT NULL,
+    amortizacao REAL NOT NULL,
+    saldo_devedor REAL NOT NULL,
+    lancamento TEXT,
+    desconto REAL NOT NULL,
+    renegociada INTEGER NOT NULL,
+    PRIMARY KEY (divida, ordem)
+);
+
+CREATE TABLE IF NOT EXISTS metas (
+    id TEXT PRIMARY KEY,
+    nome TEXT NOT NULL,
+    ativo INTEGER NOT NULL,
+    descricao TEXT NOT NULL,
+    tipo_meta TEXT NOT NULL,
+    filtro TEXT NOT NULL,
+    metrica TEXT NOT NULL,
+    fluxo TEXT NOT NULL,
+    periodo TEXT NOT NULL,
+    valor REAL NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS configuracao (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    salario REAL NOT NULL,
+    endividamento_max REAL NOT NULL,
+    contracheque INTEGER NOT NULL,
+    contracheque_entradas TEXT NOT NULL,
+    contracheque_saidas TEXT NOT NULL,
+    contracheque_empresa TEXT NOT NULL,
+    dependentes INTEGER NOT NULL
+);
+";
+
+/// Gravação em `financeiro.db` no diretório de dados.
+pub struct ArmazenamentoSqlite {
+    conn: Mutex<Connection>,
+    /// Tabelas cuja última leitura falhou. Como os arquivos bloqueados do JSON,
+    /// não são gravadas até serem lidas com sucesso, para que a lista vazia
+    /// devolvida no lugar não apague o que está no banco.
+    bloqueadas: Mutex<HashSet<&'static str>>,
+}
+
+impl ArmazenamentoSqlite {
+    pub fn abrir() -> Result<Self, String> {
+        let conn = Connection::open(caminhos().dados.join(BANCO)).map_err(|e| e.to_string())?;
+        ArmazenamentoSqlite::preparar(conn)
+    }
+
+    /// Confere a versão e cria ou atualiza o esquema.
+    fn preparar(conn: Connection) -> Result<Self, String> {
+        let versao: i32 = conn
+            .query_row("PRAGMA user_version", [], |r| r.get(0))
+            .map_err(|e| e.to_string())?;
+        if versao > VERSAO_ESQUEMA {
+            return Err(format!(
+                "{} está na versão {}, mais nova que a suportada ({})",
+                BANCO, versao, VERSAO_ESQUEMA
+            ));
+        }
+        // versão 0 é banco novo, criado direto na atual
+        if versao > 0 {
+            // recriar uma tabela com as chaves ligadas apagaria as linhas dependentes
+            conn.pragma_update(None, "foreign_keys", false)
+                .map_err(|e| e.to_string())?;
+            for migracao in &MIGRACOES[(versao - 1) as usize..] {
+                conn.execute_batch(migracao).map_err(|e| e.to_string())?;
+            }
+        }
+        conn.execute_batch(ESQUEMA).map_err(|e| e.to_string())?;
+        if versao < VERSAO_ESQUEMA {
+            ligar_contas(&conn).map_err(|e| e.to_string())?;
+        }
+        conn.pragma_update(None, "user_version", VERSAO_ESQUEMA)
+            .map_err(|e| e.to_string())?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+            bloqueadas: Mutex::default(),
+        })
+    }
+
+    fn conexao(&self) -> MutexGuard<'_, Connection> {
+        self.conn.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn bloqueadas(&self) -> MutexGuard<'_, HashSet<&'static str>> {
+        self.bloqueadas.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn ler<T>(&self, oque: &'static str, f: impl FnOnce(&Connection) -> rusqlite::Result<T>) -> T
+    where
+        T: Default,
+    {
+        let resp = f(&self.conexao());
+        match resp {
+            Ok(resp) => {
+                self.bloqueadas().remove(oque);
+                resp
+            }
+            Err(erro) => {
+                log::error!("Erro ao ler {} do SQLite: {}", oque, erro);
+                self.bloqueadas().insert(oque);
+                T::default()
+            }
+        }
+    }
+
+    /// `false` se a gravação foi recusada ou falhou.
+    fn gravar(
+        &self,
+        oque: &'static str,
+        f: impl FnOnce(&Transaction) -> rusqlite::Result<()>,
+    ) -> bool {
+        if self.bloqueadas().contains(oque) {
+            log::error!(
+                "Gravação de {} no SQLite recusada: a última leitura falhou",
+                oque
+            );
+            return false;
+        }
+        let mut conn = self.conexao();
+        let resp = conn.transaction().and_then(|tx| {
+            f(&tx)?;
+            tx.commit()
+        });
+        if let Err(erro) = &resp {
+            log::error!("Erro ao gravar {} no SQLite: {}", oque, erro);
+        }
+        resp.is_ok()
+    }
+}
+
+impl RepositorioLancamento for ArmazenamentoSqlite {
+    fn lancamentos_listar(&self) -> Vec<Lancamento> {
+        self.ler("lançamentos", |c| ler_lancamentos(c, LANCAMENTOS))
+    }
+
+    fn lancamentos_salvar(&self, itens: &[Lancamento]) {
+        self.gravar("lançamentos", |tx| {
+            gravar_lancamentos(tx, LANCAMENTOS, itens)
+        });
+    }
+
+    fn lancamentos_adicionar(&self, itens: &[Lancamento]) {
+        self.gravar("lançamentos", |tx| {
+            inserir_lancamentos(tx, LANCAMENTOS, itens, false)
+        });
+    }
+
+    fn lancamento_remover(&self, id: &str) {
+        self.gravar("lançamentos", |tx| {
+            tx.execute("DELETE FROM lancamentos WHERE id = ?1", [id])?;
+            Ok(())
+        });
+    }
+
+    fn nao_categorizados_listar(&self) -> Vec<Lancamento> {
+        self.ler("pendentes", |c| ler_lancamentos(c, NAO_CAT))
+    }
+
+    fn nao_categorizados_salvar(&self, itens: &[Lancamento]) {
+        self.gravar("pendentes", |tx| gravar_lancamentos(tx, NAO_CAT, itens));
+    }
+
+    fn nao_categorizado_gravar(&self, item: &Lancamento) {
+        self.gravar("pendentes", |tx| {
+            inserir_lancamentos(tx, NAO_CAT, std::slice::from_ref(item), true)
+        });
+    }
+}
+
+impl RepositorioCategoria for ArmazenamentoSqlite {
+    fn categorias_listar(&self) -> Vec<Categoria> {
+        self.ler("categorias", ler_categorias)
+    }
+
+    fn categorias_salvar(&self, itens: &[Categoria]) {
+        self.gravar("categorias", |tx| gravar_categorias(tx, itens));
+    }
+}
+
+impl RepositorioRegra for ArmazenamentoSqlite {
+    fn regras_listar(&self) -> Vec<Regra> {
+        self.ler("regras", ler_regras)
+    }
+
+    fn regras_salvar(&self, itens: &[Regra]) {
+        self.gravar("regras", |tx| gravar_regras(tx, itens));
+    }
+}
+
+impl RepositorioDivida for ArmazenamentoSqlite {
+    fn dividas_listar(&self, arquivadas: bool) -> Vec<Divida> {
+        self.ler(grupo_dividas(arquivadas), |c| ler_dividas(c, arquivadas))
+    }
+
+    fn dividas_salvar(&self, itens: &[Divida], arquivadas: bool) -> bool {
+        self.gravar(grupo_dividas(arquivadas), |tx| {
+            gravar_dividas(tx, itens, arquivadas)
+        })
+    }
+}
+
+impl RepositorioMeta for ArmazenamentoSqlite {
+    fn metas_listar(&self) -> Vec<Meta> {
+        self.ler("metas", ler_metas)
+    }
+
+    fn metas_salvar(&self, itens: &[Meta]) {
+        self.gravar("metas", |tx| gravar_metas(tx, itens));
+    }
+}
+
+impl RepositorioBanco for ArmazenamentoSqlite {
+    fn bancos_listar(&self) -> Vec<Banco> {
+        self.ler("bancos", ler_bancos)
+    }
+
+    fn bancos_salvar(&self, itens: &[Banco]) {
+        self.gravar("bancos", |tx| gravar_bancos(tx, itens));
+    }
+}
+
+impl RepositorioConfiguracao for ArmazenamentoSqlite {
+    fn configuracao_buscar(&self) -> Option<Configuracao> {
+        self.ler("configuração", ler_configuracao)
+    }
+
+    fn configuracao_salvar(&self, config: &Configuracao) {
+        self.gravar("configuração", |tx| gravar_configuracao(tx, config));
+    }
+}
+
+impl Armazenamento for ArmazenamentoSqlite {
+    fn bloqueados(&self) -> Vec<String> {
+        let mut resp: Vec<String> = self
+            .bloqueadas()
+            .iter()
+            .map(|t| format!("{} em {}", t, BANCO))
+            .collect();
+        resp.sort();
+        resp
+    }
+}
+
+fn grupo_dividas(arquivadas: bool) -> &'static str {
+    match arquivadas {
+        true => "dívidas arquivadas",
+        false => "dívidas",
+    }
+}
+
+/// Tudo o que `importar_json` copia, lido de uma vez antes de gravar.
+struct Copia {
+    bancos: Vec<Banco>,
+    categorias: Vec<Categoria>,
+    regras: Vec<Regra>,
+    lancamentos: Vec<Lancamento>,
+    pendentes: Vec<Lancamento>,
+    dividas: Vec<Divida>,
+    arquivadas: Vec<Divida>,
+    metas: Vec<Meta>,
+    config: Option<Configuracao>,
+}
+
+impl Copia {
+    fn ler(origem: &dyn Armazenamento) -> Copia {
+        Copia {
+            bancos: origem.bancos_listar(),
+            categorias: origem.categorias_listar(),
+            regras: origem.regras_listar(),
+            lancamentos: origem.lancamentos_listar(),
+            pendentes: origem.nao_categorizados_listar(),
+            dividas: origem.dividas_listar(false),
+            arquivadas: origem.dividas_listar(true),
+            metas: origem.metas_listar(),
+            config: origem.configuracao_buscar(),
+        }
+    }
+
+    /// Substitui tudo em `destino` numa única transação. Devolve, por tabela,
+    /// quantos registros foram lidos e gravados.
+    fn gravar(
+        &self,
+        destino: &ArmazenamentoSqlite,
+    ) -> Result<Vec<(&'static str, usize, usize)>, String> {
+        let mut conn = destino.conexao();
+        let tx = conn.transaction().map_err(|e| e.to_string())?;
+        let resp = (|| -> rusqlite::Result<Vec<(&'static str, usize, usize)>> {
+            gravar_bancos(&tx, &self.bancos)?;
+            gravar_categorias(&tx, &self.categorias)?;
+            gravar_regras(&tx, &self.regras)?;
+            gravar_lancamentos(&tx, LANCAMENTOS, &self.lancamentos)?;
+            gravar_lancamentos(&tx, NAO_CAT, &self.pendentes)?;
+            gravar_dividas(&tx, &self.dividas, false)?;
+            gravar_dividas(&tx, &self.arquivadas, true)?;
+            ligar_origens(&tx, &self.dividas)?;
+            gravar_metas(&tx, &self.metas)?;
+            if let Some(config) = self.config.as_ref() {
+                gravar_configuracao(&tx, config)?;
+            }
+
+            let contar = |sql: &str| -> rusqlite::Result<usize> {
+                tx.query_row(sql, [], |r| r.get::<_, i64>(0).map(|n| n as usize))
+            };
+            Ok(vec![
+                (
+                    "bancos",
+                    self.bancos.len(),
+                    contar("SELECT COUNT(*) FROM bancos")?,
+                ),
+                (
+                    "categorias",
+                    self.categorias.len(),
+                    contar("SELECT COUNT(*) FROM categorias")?,
+                ),
+                (
+                    "regras",
+                    self.regras.len(),
+                    contar("SELECT COUNT(*) FROM regras")?,
+                ),
+                (
+                    "lançamentos",
+                    self.lancamentos.len(),
+                    contar("SELECT COUNT(*) FROM lancamentos")?,
+                ),
+                (
+                    "pendentes",
+                    self.pendentes.len(),
+                    contar("SELECT COUNT(*) FROM nao_categorizados")?,
+                ),
+                (
+                    "dívidas",
+                    self.dividas.len() + self.arquivadas.len(),
+                    contar("SELECT COUNT(*) FROM dividas")?,
+                ),
+                (
+                    "metas",
+                    self.metas.len(),
+                    contar("SELECT COUNT(*) FROM metas")?,
+                ),
+            ])
+        })();
+
+        match resp {
+            Ok(resumo) => {
+                tx.commit().map_err(|e| e.to_string())?;
+                destino.bloqueadas().clear();
+                Ok(resumo)
+            }
+            Err(erro) => Err(erro.to_string()),
+        }
+    }
+}
+
+/// Copia tudo dos arquivos JSON para o SQLite numa única transação, substituindo
+/// o que houver lá. Devolve, por tabela, quantos registros foram lidos e gravados;
+/// a diferença são regras sem categoria, que as chaves estrangeiras não aceitam.
+pub fn importar_json() -> Result<Vec<(&'static str, usize, usize)>, String> {
+    if criptografia_ativa() {
+        return Err(
+            "os dados estão cifrados e o SQLite não pode ser; desative a criptografia antes"
+                .to_string(),
+        );
+    }
+    let sqlite = ArmazenamentoSqlite::abrir()?;
+    let copia = Copia::ler(&ArmazenamentoJson);
+
+    // um JSON ilegível viraria tabela vazia
+    if let Some(arquivo) = arquivos_bloqueados().first() {
+        return Err(format!("{} não pôde ser lido", arquivo.display()));
+    }
+    Snapshot::criar(GatilhoSnapshot::ImportacaoSqlite);
+    copia.gravar(&sqlite)
+}
+
+fn texto<T: Serialize>(valor: &T) -> String {
+    serde_json::to_string(valor).unwrap_or_default()
+}
+
+fn de_texto<T: DeserializeOwned>(coluna: usize, texto: String) -> rusqlite::Result<T> {
+    serde_json::from_str(&texto)
+        .map_err(|e| rusqlite::Error::FromSqlConversionFailure(coluna, Type::Text, Box::new(e)))
+}
+
+fn opcional<T>(id: Option<String>) -> OptionalLazy<T> {
+    id.map_or(OptionalLazy::None, OptionalLazy::Id)
+}
+
+/// Apaga as linhas cujo id não está na lista.
+fn remover_ausentes(
+    tx: &Transaction,
+    tabela: &str,
+    ids: &[&str],
+    filtro: &str,
+) -> rusqlite::Result<()> {
+    tx.execute(
+        &format!(
+            "DELETE FROM {} WHERE id NOT IN (SELECT value FROM json_each(?1)) {}",
+            tabela, filtro
+        ),
+        [texto(&ids)],
+    )?;
+    Ok(())
+}
+
+fn ler_lancamentos(conn: &Connection, tabela: &str) -> rusqlite::Result<Vec<Lancamento>> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT id, descricao, valor, data, categoria, conta, regra FROM {} ORDER BY rowid",
+        tabela
+    ))?;
+    let resp = stmt
+        .query_map([], |row: &Row| {
+            Ok(Lancamento {
+                id: row.get(0)?,
+                descricao: row.get(1)?,
+                valor: row.get(2)?,
+                data: row.get(3)?,
+                categoria: opcional(row.get(4)?),
+                conta: row.get(5)?,
+                regra: opcional(row.get(6)?),
+            })
+        })?
+        .collect();
+    resp
+}
+
+fn gravar_lancamentos(
+    tx: &Transaction,
+    tabela: &str,
+    itens: &[Lancamento],
+) -> rusqlite::Result<()> {
+    inserir_lancamentos(tx, tabela, itens, true)?;
+    let ids: Vec<&str> = itens.iter().map(|l| l.id.as_str()).collect();
+    remover_ausentes(tx, tabela, &ids, "")
+}
+
+/// Categoria e regra inexistentes ficam nulas em vez de quebrar a chave estrangeira.
+/// Com `atualizar`, o lançamento de mesmo id é substituído; sem, é mantido.
+fn inserir_lancamentos(
+    tx: &Transaction,
+    tabela: &str,
+    itens: &[Lancamento],
+    atualizar: bool,
+) -> rusqlite::Result<()> {
+    let conflito = match atualizar {
+        true => {
+            "DO UPDATE SET descricao = excluded.descricao, valor = excluded.valor,
+                 data = excluded.data, categoria = excluded.categoria,
+                 banco = excluded.banco, conta = excluded.conta, regra = excluded.regra"
+        }
+        false => "DO NOTHING",
+    };
+    let mut stmt = tx.prepare(&format!(
+        "INSERT INTO {} (id, descricao, valor, data, categoria, banco, conta, regra)
+         VALUES (?1, ?2, ?3, ?4,
+             (SELECT id FROM categorias WHERE id = ?5),
+             (SELECT banco FROM contas WHERE id = ?6 ORDER BY banco LIMIT 1), ?6,
+             (SELECT id FROM regras WHERE id = ?7))
+         ON CONFLICT(id) {}",
+        tabela, conflito
+    ))?;
+    for l in itens {
+        stmt.execute(params![
+            l.id,
+            l.descricao,
+            l.valor,
+            l.data,
+            l.categoria.id(),
+            l.conta,
+            l.regra.id()
+        ])?;
+    }
+    Ok(())
+}
+
+fn ler_categorias(conn: &Connection) -> rusqlite::Result<Vec<Categoria>> {
+    let mut stmt =
+        conn.prepare("SELECT id, nome, tipo, pai, carne_leao, livro_caixa FROM categorias")?;
+    let resp = stmt
+        .query_map([], |row| {
+            Ok(Categoria {
+                id: row.get(0)?,
+                nome: row.get(1)?,
+                tipo: de_texto(2, row.get(2)?)?,
+                pai: row.get(3)?,
+                carne_leao: row.get(4)?,
+                livro_caixa: row.get(5)?,
+                hierarquia: Vec::new(),
+            })
+        })?
+        .collect();
+    resp
+}
+
+/// Grava sem o pai e depois o preenche, para que a ordem da lista não importe;
+/// remover uma categoria apaga as regras dela e solta os lançamentos.
+fn gravar_categorias(tx: &Transaction, itens: &[Categoria]) -> rusqlite::Result<()> {
+    {
+        let mut stmt = tx.prepare(
+            "INSERT INTO categorias (id, nome, tipo, pai, carne_leao, livro_caixa)
+             VALUES (?1, ?2, ?3, NULL, ?4, ?5)
+             ON CONFLICT(id) DO UPDATE SET nome = ?2, tipo = ?3, carne_leao = ?4, livro_caixa = ?5",
+        )?;
+        for c in itens {
+            stmt.execute(params![
+                c.id,
+                c.nome,
+                texto(&c.tipo),
+                c.carne_leao,
+                c.livro_caixa
+            ])?;
+        }
+
+        let mut stmt = tx.prepare(
+            "UPDATE categorias SET pai = (SELECT id FROM categorias WHERE id = ?2) WHERE id = ?1",
+        )?;
+        for c in itens {
+            stmt.execute(params![c.id, c.pai])?;
+        }
+    }
+
+    let ids: Vec<&str> = itens.iter().map(|c| c.id.as_str()).collect();
+    remover_ausentes(tx, "categorias", &ids, "")
+}
+
+fn ler_regras(conn: &Connection) -> rusqlite::Result<Vec<Regra>> {
+    let mut stmt = conn.prepare("SELECT id, fluxo, regex, categoria FROM regras ORDER BY ordem")?;
+    let resp = stmt
+        .query_map([], |row| {
+            Ok(Regra {
+                id: row.get(0)?,
+                fluxo: de_texto(1, row.get(1)?)?,
+                regex: row.get(2)?,
+                categoria: Lazy::Id(row.get(3)?),
+            })
+        })?
+        .collect();
+    resp
+}
+
+/// Regras cuja categoria não existe são descartadas.
+fn gravar_regras(tx: &Transaction, itens: &[Regra]) -> rusqlite::Result<()> {
+    {
+        let mut stmt = tx.prepare(
+            "INSERT INTO regras (id, ordem, fluxo, regex, categoria)
+             SELECT ?1, ?2, ?3, ?4, id FROM categorias WHERE id = ?5
+             ON CONFLICT(id) DO UPDATE SET
+                 ordem = excluded.ordem, fluxo = excluded.fluxo,
+                 regex = excluded.regex, categoria = excluded.categoria",
+        )?;
+        for (ordem, r) in itens.iter().enumerate() {
+            stmt.execute(params![
+                r.id,
+                ordem as i64,
+                texto(&r.fluxo),
+                r.regex,
+                r.categoria.id()
+            ])?;
+        }
+    }
+
+    let ids: Vec<&str> = itens.iter().map(|r| r.id.as_str()).collect();
+    remover_ausentes(tx, "regras", &ids, "")
+}
+
+fn ler_dividas(conn: &Connection, arquivadas: bool) -> rusqlite::Result<Vec<Divida>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, nome, prioritaria, cobranca_automatica, financiamento, padrao_descricao,
+                conta, origem, criada_em
+         FROM dividas WHERE arquivada = ?1 ORDER BY rowid",
+    )?;
+    let mut dividas: Vec<Divida> = stmt
+        .query_map([arquivadas], |row| {
+            let financiamento: Option<String> = row.get(4)?;
+            Ok(Divida {
+                id: row.get(0)?,
+                nome: row.get(1)?,
+                prioritaria: row.get(2)?,
+                cobranca_automatica: row.get(3)?,
+                parcelas: Vec::new(),
+                financiamento: match financiamento {
+                    Some(f) => Some(de_texto(4, f)?),
+                    None => None,
+                },
+                padrao_descricao: row.get(5)?,
+                conta: row.get(6)?,
+                origem: row.get(7)?,
+                criada_em: row.get(8)?,
+            })
+        })?
+        .collect::<rusqlite::Result<_>>()?;
+
+    let mut stmt = conn.prepare(
+        "SELECT num_parcela, valor, pago, data_vencimento, juros, amortizacao, saldo_devedor,
+                lancamento, desconto, renegociada
+         FROM parcelas_divida WHERE divida = ?1 ORDER BY ordem",
+    )?;
+    for divida in dividas.iter_mut() {
+        divida.parcelas = stmt
+            .query_map([&divida.id], |row| {
+                Ok(ParcelaDivida {
+                    num_parcela: row.get(0)?,
+                    valor: row.get(1)?,
+                    pago: row.get(2)?,
+                    data_vencimento: row.get(3)?,
+                    juros: row.get(4)?,
+                    amortizacao: row.get(5)?,
+                    saldo_devedor: row.get(6)?,
+                    lancamento: row.get(7)?,
+                    desconto: row.get(8)?,
+                    renegociada: row.get(9)?,
+                })
+            })?
+            .collect::<rusqlite::Result<_>>()?;
+    }
+    Ok(dividas)
+}
+
+/// Ativas e arquivadas dividem a tabela; gravar uma lista só remove as ausentes do mesmo grupo.
+fn gravar_dividas(tx: &Transaction, itens: &[Divida], arquivadas: bool) -> rusqlite::Result<()> {
+    {
+        let mut divida = tx.prepare(
+            "INSERT INTO dividas (id, nome, prioritaria, cobranca_automatica, financiamento,
+                                  padrao_descricao, banco, conta, origem, arquivada, criada_em)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6,
+                     (SELECT banco FROM contas WHERE id = ?7 ORDER BY banco LIMIT 1), ?7,
+                     NULL, ?8, ?9)
+             ON CONFLICT(id) DO UPDATE SET
+                 nome = ?2, prioritaria = ?3, cobranca_automatica = ?4, financiamento = ?5,
+                 padrao_descricao = ?6, banco = excluded.banco, conta = ?7, arquivada = ?8,
+                 criada_em = ?9",
+        )?;
+
+        let mut limpar = tx.prepare("DELETE FROM parcelas_divida WHERE divida = ?1")?;
+        let mut parcela = tx.prepare(
+            "INSERT INTO parcelas_divida (divida, ordem, num_parcela, valor, pago, data_vencimento,
+                                          juros, amortizacao, saldo_devedor, lancamento, desconto,
+                                          renegociada)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+        )?;
+        for d in itens {
+            divida.execute(params![
+                d.id,
+                d.nome,
+                d.prioritaria,
+                d.cobranca_automatica,
+                d.financiamento.as_ref().map(texto),
+                d.padrao_descricao,
+                d.conta,
+                arquivadas,
+                d.criada_em
+            ])?;
+            limpar.execute([&d.id])?;
+            for (ordem, p) in d.parcelas.iter().enumerate() {
+                parcela.execute(params![
+                    d.id,
+                    ordem as i64,
+                    p.num_parcela,
+                    p.valor,
+                    p.pago,
+                    p.data_vencimento,
+                    p.juros,
+                    p.amortizacao,
+                    p.saldo_devedor,
+                    p.lancamento,
+                    p.desconto,
+                    p.renegociada
+                ])?;
+            }
+        }
+    }
+    ligar_origens(tx, itens)?;
+
+    let ids: Vec<&str> = itens.iter().map(|d| d.id.as_str()).collect();
+    remover_ausentes(
+        tx,
+        "dividas",
+        &ids,
+        if arquivadas {
+            "AND arquivada = 1"
+        } else {
+            "AND arquivada = 0"
+        },
+    )
+}
+
+/// A dívida de origem pode estar no outro grupo, gravado depois.
+fn ligar_origens(tx: &Transaction, itens: &[Divida]) -> rusqlite::Result<()> {
+    let mut stmt = tx.prepare(
+        "UPDATE dividas SET origem = (SELECT id FROM dividas WHERE id = ?2) WHERE id = ?1",
+    )?;
+    for d in itens {
+        stmt.execute(params![d.id, d.origem])?;
+    }
+    Ok(())
+}
+
+fn ler_metas(conn: &Connection) -> rusqlite::Result<Vec<Meta>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, nome, ativo, descricao, tipo_meta, filtro, metrica, fluxo, periodo, valor
+         FROM metas ORDER BY rowid",
+    )?;
+    let resp = stmt
+        .query_map([], |row| {
+            Ok(Meta {
+                id: row.get(0)?,
+                nome: row.get(1)?,
+                ativo: row.get(2)?,
+                desc: row.get(3)?,
+                tipo_meta: row.get(4)?,
+                filtro: row.get(5)?,
+                metrica: row.get(6)?,
+                fluxo: row.get(7)?,
+                periodo: row.get(8)?,
+                valor: row.get(9)?,
+            })
+        })?
+        .collect();
+    resp
+}
+
+fn gravar_metas(tx: &Transaction, itens: &[Meta]) -> rusqlite::Result<()> {
+    tx.execute("DELETE FROM metas", [])?;
+    let mut stmt = tx.prepare(
+        "INSERT OR REPLACE INTO metas
+             (id, nome, ativo, descricao, tipo_meta, filtro, metrica, fluxo, periodo, valor)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+    )?;
+    for m in itens {
+        stmt.execute(params![
+            m.id,
+            m.nome,
+            m.ativo,
+            m.desc,
+            m.tipo_meta,
+            m.filtro,
+            m.metrica,
+            m.fluxo,
+            m.periodo,
+            m.valor
+        ])?;
+    }
+    Ok(())
+}
+
+fn ler_bancos(conn: &Connection) -> rusqlite::Result<Vec<Banco>> {
+    let mut stmt = conn.prepare("SELECT id, nome FROM bancos ORDER BY rowid")?;
+    let mut bancos: Vec<Banco> = stmt
+        .query_map([], |row| {
+            Ok(Banco {
+                id: row.get(0)?,
+                nome: row.get(1)?,
+                contas: Vec::new(),
+            })
+        })?
+        .collect::<rusqlite::Result<_>>()?;
+
+    let mut stmt = conn.prepare(
+        "SELECT id, nome, tipo, saldo_inicial, data_saldo_inicial, extrato_valor, extrato_data
+         FROM contas WHERE banco = ?1 ORDER BY rowid",
+    )?;
+    for banco in bancos.iter_mut() {
+        banco.contas = stmt
+            .query_map([&banco.id], |row| {
+                let extrato_valor: Option<f64> = row.get(5)?;
+                let extrato_data = row.get(6)?;
+                Ok(Conta {
+                    id: row.get(0)?,
+                    nome: row.get(1)?,
+                    tipo: de_texto(2, row.get(2)?)?,
+                    saldo_inicial: row.get(3)?,
+                    data_saldo_inicial: row.get(4)?,
+                    extrato: extrato_valor
+                        .zip(extrato_data)
+                        .map(|(valor, data)| SaldoExtrato { valor, data }),
+                })
+            })?
+            .collect::<rusqlite::Result<_>>()?;
+    }
+    Ok(bancos)
+}
+
+/// Atualiza no lugar: apagar e reinserir soltaria as contas de todos os
+/// lançamentos. Remover uma conta deixa os lançamentos dela sem conta.
+fn gravar_bancos(tx: &Transaction, itens: &[Banco]) -> rusqlite::Result<()> {
+    {
+        let mut banco = tx.prepare(
+            "INSERT INTO bancos (id, nome) VALUES (?1, ?2)
+             ON CONFLICT(id) DO UPDATE SET nome = ?2",
+        )?;
+        let mut conta = tx.prepare(
+            "INSERT INTO contas (banco, id, nome, tipo, saldo_inicial, data_saldo_inicial,
+                                 extrato_valor, extrato_data)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+             ON CONFLICT(banco, id) DO UPDATE SET
+                 nome = ?3, tipo = ?4, saldo_inicial = ?5, data_saldo_inicial = ?6,
+                 extrato_valor = ?7, extrato_data = ?8",
+        )?;
+        let mut sobras = tx.prepare(
+            "DELETE FROM contas
+             WHERE banco = ?1 AND id NOT IN (SELECT value FROM json_each(?2))",
+        )?;
+        for b in itens {
+            banco.execute(params![b.id, b.nome])?;
+            for c in b.contas.iter() {
+                conta.execute(params![
+                    b.id,
+                    c.id,
+                    c.nome,
+                    texto(&c.tipo),
+                    c.saldo_inicial,
+                    c.data_saldo_inicial,
+                    c.extrato.as_ref().map(|e| e.valor),
+                    c.extrato.as_ref().map(|e| e.data)
+                ])?;
+            }
+            let contas: Vec<&str> = b.contas.iter().map(|c| c.id.as_str()).collect();
+            sobras.execute(params![b.id, texto(&contas)])?;
+        }
+    }
+
+    let ids: Vec<&str> = itens.iter().map(|b| b.id.as_str()).collect();
+    remover_ausentes(tx, "bancos", &ids, "")?;
+    ligar_contas(tx)
+}
+
+/// Preenche o banco das linhas cuja conta passou a ter cadastro.
+fn ligar_contas(conn: &Connection) -> rusqlite::Result<()> {
+    for tabela in [LANCAMENTOS, NAO_CAT, "dividas"] {
+        conn.execute(
+            &format!(
+                "UPDATE {0} SET banco = (SELECT banco FROM contas WHERE contas.id = {0}.conta
+                                         ORDER BY banco LIMIT 1)
+                 WHERE banco IS NULL AND conta IS NOT NULL",
+                tabela
+            ),
+            [],
+        )?;
+    }
+    Ok(())
+}
+
+fn ler_configuracao(conn: &Connection) -> rusqlite::Result<Option<Configuracao>> {
+    let mut stmt = conn.prepare(
+        "SELECT salario, endividamento_max, contracheque, contracheque_entradas,
+                contracheque_saidas, contracheque_empresa, dependentes
+         FROM configuracao WHERE id = 1",
+    )?;
+    let mut linhas = stmt.query_map([], |row| {
+        Ok(Configuracao {
+            salario: row.get(0)?,
+            endividamento_max: row.get(1)?,
+            contracheque: row.get(2)?,
+            contracheque_entradas: de_texto(3, row.get(3)?)?,
+            contracheque_saidas: de_texto(4, row.get(4)?)?,
+            contracheque_empresa: row.get(5)?,
+            dependentes: row.get(6)?,
+        })
+    })?;
+    linhas.next().transpose()
+}
+
+fn gravar_configuracao(tx: &Transaction, config: &Configuracao) -> rusqlite::Result<()> {
+    tx.execute(
+        "INSERT OR REPLACE INTO configuracao
+             (id, salario, endividamento_max, contracheque, contracheque_entradas,
+              contracheque_saidas, contracheque_empresa, dependentes)
+         VALUES (1, ?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        params![
+            config.salario,
+            config.endividamento_max,
+            config.contracheque,
+            texto(&config.contracheque_entradas),
+            texto(&config.contracheque_saidas),
+            config.contracheque_empresa,
+            config.dependentes
+        ],
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::dto::{FluxoRegra, TipoFluxo};
+
+    fn memoria() -> ArmazenamentoSqlite {
+        ArmazenamentoSqlite::preparar(Connection::open_in_memory().unwrap()).unwrap()
+    }
+
+    fn lancamento(id: &str, valor: f64, categoria: &Categoria) -> Lancamento {
+        Lancamento {
+            id: id.to_string(),
+            descricao: format!("compra {}", id),
+            valor,
+            data: NaiveDate::from_ymd_opt(2025, 3, 10).unwrap(),
+            categoria: OptionalLazy::Id(categoria.id.clone()),
+            conta: Some("cc".to_string()),
+            regra: OptionalLazy::None,
+        }
+    }
+
+    /// Um pouco de cada entidade, com as relações entre elas.
+    fn preencher(banco: &ArmazenamentoSqlite) -> Categoria {
+        let mercado = Categoria::new("Mercado", TipoFluxo::Receita, None);
+        banco.categorias_salvar(std::slice::from_ref(&mercado));
+        banco.regras_salvar(&[Regra {
+            id: "r1".to_string(),
+            fluxo: FluxoRegra::Saida,
+            regex: "MERCADO".to_string(),
+            categoria: Lazy::Id(mercado.id.clone()),
+        }]);
+        banco.lancamentos_salvar(&[
+            lancamento("1", -50.0, &mercado),
+            lancamento("2", -20.0, &mercado),
+        ]);
+        banco.nao_categorizados_salvar(&[lancamento("3", -5.0, &mercado)]);
+        let inicio = NaiveDate::from_ymd_opt(2025, 1, 10).unwrap();
+        banco.dividas_salvar(
+            &[Divida::new(
+                "Loja".to_string(),
+                false,
+                false,
+                3,
+                100.0,
+                inicio,
+                1,
+            )],
+            false,
+        );
+        banco.metas_salvar(&[Meta {
+            id: "m1".to_string(),
+            nome: "Mercado".to_string(),
+            valor: 500.0,
+            ..Default::default()
+        }]);
+        banco.bancos_salvar(&[Banco {
+            id: "b1".to_string(),
+            nome: "Banco".to_string(),
+            contas: vec![Conta {
+                id: "cc".to_string(),
+                nome: "Corrente".to_string(),
+                saldo_inicial: 10.0,
+                ..Default::default()
+            }],
+        }]);
+        mercado
+    }
+
+    #[test]
+    fn salvar_e_listar_devolvem_o_gravado() {
+        let banco = memoria();
+        let mercado = preencher(&banco);
+
+        assert_eq!(banco.categorias_listar(), vec![mercado.clone()]);
+        assert_eq!(banco.regras_listar()[0].categoria.id(), mercado.id);
+        let lancamentos = banco.lancamentos_listar();
+        assert_eq!(lancamentos.len(), 2);
+        assert_eq!(lancamentos[1].valor, -20.0);
+        assert_eq!(lancamentos[1].categoria.id(), mercado.id);
+        assert_eq!(banco.nao_categorizados_listar()[0].id, "3");
+
+        let dividas = banco.dividas_listar(false);
+        assert_eq!(dividas.len(), 1);
+        assert_eq!(dividas[0].parcelas.len(), 3);
+        assert!(dividas[0].parcelas[0].pago);
+        assert!(dividas[0].criada_em.is_some());
+        assert!(banco.dividas_listar(true).is_empty());
+
+        assert_eq!(banco.metas_listar()[0].valor, 500.0);
+        assert_eq!(banco.bancos_listar()[0].contas[0].saldo_inicial, 10.0);
+
+        banco.lancamentos_salvar(&lancamentos[..1]);
+        assert_eq!(banco.lancamentos_listar().len(), 1);
+    }
+
+    #[test]
+    fn leitura_com_erro_recusa_a_gravacao() {
+        let banco = memoria();
+        preencher(&banco);
+        banco
+            .conexao()
+            .execute("UPDATE metas SET valor = 'muito'", [])
+            .unwrap();
+
+        assert!(banco.metas_listar().is_empty());
+        assert_eq!(banco.bloqueados(), vec![format!("metas em {}", BANCO)]);
+
+        banco.metas_salvar(&[]);
+        let linhas: i64 = banco
+            .conexao()
+            .query_row("SELECT COUNT(*) FROM metas", [], |r| r.get(0))
+            .unwrap();
+        assert_eq!(linhas, 1);
+        // outras tabelas seguem gravando
+        banco.bancos_salvar(&[]);
+        assert!(banco.bancos_listar().is_empty());
+    }
+
+    #[test]
+    fn gravacao_recusada_devolve_false() {
+        let banco = memoria();
+        let vencimento = NaiveDate::from_ymd_opt(2025, 5, 10).unwrap();
+        let dividas = [Divida::new(
+            "Carro".to_string(),
+            false,
+            false,
+            2,
+            900.0,
+            vencimento,
+            0,
+        )];
+        banco.bloqueadas().insert(grupo_dividas(true));
+
+        assert!(!banco.dividas_salvar(&dividas, true));
+        assert!(banco.dividas_listar(true).is_empty());
+        assert!(banco.dividas_salvar(&dividas, false));
+    }
+
+    #[test]
+    fn alterar_um_lancamento_nao_regrava_os_outros() {
+        let banco = memoria();
+        let mercado = preencher(&banco);
+        let mut pendente = lancamento("1", -50.0, &mercado);
+        pendente.descricao = "revisar".to_string();
+
+        banco.nao_categorizado_gravar(&pendente);
+        banco.nao_categorizado_gravar(&pendente);
+        banco.lancamento_remover("1");
+
+        let ids: Vec<String> = banco
+            .lancamentos_listar()
+            .into_iter()
+            .map(|l| l.id)
+            .collect();
+        assert_eq!(ids, vec!["2"]);
+        let pendentes = banco.nao_categorizados_listar();
+        assert_eq!(pendentes.len(), 2);
+        assert_eq!(pendentes[1].descricao, "revisar");
+    }
+
+    #[test]
+    fn conta_removida_solta_lancamentos_e_dividas() {
+        let banco = memoria();
+        preencher(&banco);
+        banco
+            .conexao()
+            .execute("UPDATE dividas SET conta = 'cc'", [])
+            .unwrap();
+        let mut bancos = banco.bancos_listar();
+        banco.bancos_salvar(&bancos);
+        let ligados: i64 = banco
+            .conexao()
+            .query_row(
+                "SELECT COUNT(*) FROM lancamentos WHERE banco = 'b1' AND conta = 'cc'",
+                [],
+                |r| r.get(0),
+            )
+            .unwrap();
+        assert_eq!(ligados, 2);
+        assert_eq!(banco.lancamentos_listar()[0].conta.as_deref(), Some("cc"));
+
+        bancos[0].contas.clear();
+        banco.bancos_salvar(&bancos);
+
+        assert!(banco.lancamentos_listar().iter().all(|l| l.conta.is_none()));
+        assert!(banco.nao_categorizados_listar()[0].conta.is_none());
+        assert!(banco.dividas_listar(false)[0].conta.is_none());
+    }
+
+    #[test]
+    fn migracao_preserva_os_dados_e_liga_as_contas() {
+        let banco = memoria();
+        preencher(&banco);
+        let conn = banco.conn.into_inner().unwrap();
+        conn.execute_batch("UPDATE lancamentos SET banco = NULL; PRAGMA user_version = 2;")
+            .unwrap();
+
+        let banco = ArmazenamentoSqlite::preparar(conn).unwrap();
+
+        assert_eq!(banco.lancamentos_listar().len(), 2);
+        assert_eq!(banco.dividas_listar(false)[0].parcelas.len(), 3);
+        let ligados: i64 = banco
+            .conexao()
+            .query_row(
+                "SELECT COUNT(*) FROM lancamentos WHERE banco = 'b1'",
+                [],
+                |r| r.get(0),
+            )
+            .unwrap();
+        assert_eq!(ligados, 2);
+    }
+
+    #[test]
+    fn copia_substitui_o_destino_e_confere_as_contagens() {
+        let origem = memoria();
+        preencher(&origem);
+        let destino = memoria();
+        destino.metas_salvar(&[Meta {
+            id: "antiga".to_string(),
+            ..Default::default()
+        }]);
+
+        let resumo = Copia::ler(&origem).gravar(&destino).unwrap();
+
+        assert_eq!(
+            resumo,
+            vec![
+                ("bancos", 1, 1),
+                ("categorias", 1, 1),
+                ("regras", 1, 1),
+                ("lançamentos", 2, 2),
+                ("pendentes", 1, 1),
+                ("dívidas", 1, 1),
+                ("metas", 1, 1),
+            ]
+        );
+        assert_eq!(destino.metas_listar()[0].id, "m1");
+        assert!(destino.dividas_listar(false) == origem.dividas_listar(false));
+    }
+}
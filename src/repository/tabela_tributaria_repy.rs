@@ -0,0 +1,106 @@
+use std::path::PathBuf;
+
+use crate::{
+    config_caminhos::caminhos,
+    dto::{FaixaTributaria, TabelaTributaria},
+};
+
+use super::esquema_repy::{json_escrever, json_ler};
+
+const DIR: &str = "tabelas";
+
+impl TabelaTributaria {
+    /// Tabela do ano. Sem arquivo, grava a tabela conhecida do ano ou, se não
+    /// houver, uma cópia da mais recente anterior, que deve ser revisada.
+    pub fn buscar(ano: i32) -> TabelaTributaria {
+        let arquivo = format!("{}.json", ano);
+        if let Some(tabela) = json_ler(&dir_tabelas(), &arquivo) {
+            return tabela;
+        }
+
+        // vazio ou ilegível; no segundo caso a gravação é recusada
+        let tabela = tabela_conhecida(ano);
+        tabela.salvar();
+        tabela
+    }
+
+    pub fn salvar(&self) {
+        json_escrever(&dir_tabelas(), &format!("{}.json", self.ano), self);
+    }
+}
+
+fn dir_tabelas() -> PathBuf {
+    caminhos().dados.join(DIR)
+}
+
+fn tabela_conhecida(ano: i32) -> TabelaTributaria {
+    let conhecidas = tabelas_conhecidas();
+
+    let mut tabela = conhecidas
+        .iter()
+        .rev()
+        .find(|t| t.ano <= ano)
+        .unwrap_or(&conhecidas[0])
+        .clone();
+
+    if tabela.ano != ano {
+        log::warn!(
+            "Sem tabela de INSS e IRRF para {}, usando a de {}; revise tabelas/{}.json",
+            ano,
+            tabela.ano,
+            ano
+        );
+        tabela.ano = ano;
+    }
+    tabela
+}
+
+fn faixa(ate: Option<f64>, aliquota: f64, deducao: f64) -> FaixaTributaria {
+    FaixaTributaria {
+        ate,
+        aliquota,
+        deducao,
+    }
+}
+
+/// Tabelas mensais vigentes no fim de cada ano; também a base dos testes de cálculo.
+pub(crate) fn tabelas_conhecidas() -> Vec<TabelaTributaria> {
+    vec![
+        TabelaTributaria {
+            ano: 2024,
+            inss: vec![
+                faixa(Some(1412.00), 7.5, 0.0),
+                faixa(Some(2666.68), 9.0, 0.0),
+                faixa(Some(4000.03), 12.0, 0.0),
+                faixa(Some(7786.02), 14.0, 0.0),
+            ],
+            irrf: vec![
+                faixa(Some(2259.20), 0.0, 0.0),
+                faixa(Some(2826.65), 7.5, 169.44),
+                faixa(Some(3751.05), 15.0, 381.44),
+                faixa(Some(4664.68), 22.5, 662.77),
+                faixa(None, 27.5, 896.00),
+            ],
+            deducao_dependente: 189.59,
+            desconto_simplificado: 564.80,
+        },
+        TabelaTributaria {
+            ano: 2025,
+            inss: vec![
+                faixa(Some(1518.00), 7.5, 0.0),
+                faixa(Some(2793.88), 9.0, 0.0),
+                faixa(Some(4190.83), 12.0, 0.0),
+                faixa(Some(8157.41), 14.0, 0.0),
+            ],
+            irrf: vec![
+                faixa(Some(2428.80), 0.0, 0.0),
+                faixa(Some(2826.65), 7.5, 182.16),
+                faixa(Some(3751.05), 15.0, 394.16),
+                faixa(Some(4664.68), 22.5, 675.49),
+                faixa(None, 27.5, 908.73),
+            ],
+            deducao_dependente: 189.59,
+            desconto_simplificado: 607.20,
+        },
+    ]
+}
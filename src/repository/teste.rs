@@ -0,0 +1,53 @@
+//! Apoio aos testes dos repositórios.
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+static CONTADOR: AtomicUsize = AtomicUsize::new(0);
+
+/// Diretório temporário só do teste, apagado no fim.
+pub struct DirTeste(PathBuf);
+
+impl DirTeste {
+    pub fn novo() -> DirTeste {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.subsec_nanos())
+            .unwrap_or_default();
+        let dir = env::temp_dir().join(format!(
+            "financeiro-teste-{}-{}-{}",
+            process::id(),
+            CONTADOR.fetch_add(1, Ordering::SeqCst),
+            nanos
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        DirTeste(dir)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+
+    /// Grava `conteudo` em `relativo`, criando os diretórios.
+    pub fn escrever(&self, relativo: &str, conteudo: impl AsRef<[u8]>) -> PathBuf {
+        let path = self.0.join(relativo);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, conteudo).unwrap();
+        path
+    }
+
+    pub fn ler(&self, relativo: &str) -> Vec<u8> {
+        fs::read(self.0.join(relativo)).unwrap()
+    }
+}
+
+impl Drop for DirTeste {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
@@ -0,0 +1,173 @@
+use color_eyre::eyre::Result;
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
+    layout::{Constraint, Layout, Rect},
+    widgets::Widget,
+    DefaultTerminal,
+};
+
+use crate::{
+    componentes::{
+        input_wgt::Input,
+        lista_suspensa::{ItemListaSuspensa, ListaSuspensa},
+    },
+    dto::{Ativo, TipoAtivo},
+    estilo::{principal_comandos, principal_titulo},
+};
+
+const TIPOS: [TipoAtivo; 4] = [
+    TipoAtivo::Acao,
+    TipoAtivo::Fii,
+    TipoAtivo::Tesouro,
+    TipoAtivo::Cdb,
+];
+
+#[derive(PartialEq)]
+enum Status {
+    AltCodigo,
+    AltNome,
+    AltTipo,
+
+    Sair(Option<Ativo>),
+}
+
+pub struct EditarAtivo {
+    status: Status,
+
+    codigo: Input,
+    nome: Input,
+    tipo: ListaSuspensa,
+}
+
+impl Widget for &mut EditarAtivo {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let [titulo, corpo, rodape] = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Fill(1),
+            Constraint::Length(1),
+        ])
+        .areas(area);
+
+        principal_titulo("Novo Ativo", titulo, buf);
+        principal_comandos(
+            vec!["(Editar)", "TAB (próximo)", "Esc (sair)", "F5 (salvar)"],
+            rodape,
+            buf,
+        );
+        self.render(corpo, buf)
+    }
+}
+
+impl EditarAtivo {
+    pub fn new() -> Self {
+        Self {
+            status: Status::AltCodigo,
+
+            codigo: Input::new_texto("Código (ticker ou título)", String::new()),
+            nome: Input::new_texto("Nome", String::new()),
+            tipo: ListaSuspensa::new(
+                "Tipo",
+                TIPOS
+                    .iter()
+                    .map(|t| ItemListaSuspensa::new(&t.to_string()))
+                    .collect(),
+                false,
+            ),
+        }
+    }
+
+    pub fn run(mut self, terminal: &mut DefaultTerminal) -> Result<Option<Ativo>> {
+        while !matches!(self.status, Status::Sair(_)) {
+            if let Err(erro) = terminal.draw(|frame| frame.render_widget(&mut self, frame.area())) {
+                log::error!("Erro ao desenhar tela EditarAtivo: {}", erro);
+            };
+
+            if let Event::Key(key) = event::read()? {
+                self.handle_key(key, terminal);
+            };
+        }
+
+        if let Status::Sair(ativo) = self.status {
+            return Ok(ativo);
+        }
+        Ok(None)
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent, terminal: &mut DefaultTerminal) {
+        if key.kind != KeyEventKind::Press {
+            return;
+        }
+
+        match key.code {
+            KeyCode::Esc => self.status = Status::Sair(None),
+            KeyCode::Tab => self.proximo_input(),
+            KeyCode::BackTab => self.anterior_input(),
+            KeyCode::F(5) => self.salvar(),
+            _ => self.alterar_input(key, terminal),
+        }
+    }
+
+    fn proximo_input(&mut self) {
+        match self.status {
+            Status::AltCodigo => self.status = Status::AltNome,
+            Status::AltNome => self.status = Status::AltTipo,
+            Status::AltTipo => self.status = Status::AltCodigo,
+
+            Status::Sair(_) => {}
+        }
+    }
+
+    fn anterior_input(&mut self) {
+        match self.status {
+            Status::AltCodigo => self.status = Status::AltTipo,
+            Status::AltNome => self.status = Status::AltCodigo,
+            Status::AltTipo => self.status = Status::AltNome,
+
+            Status::Sair(_) => {}
+        }
+    }
+
+    fn salvar(&mut self) {
+        let codigo = self.codigo.to_string().trim().to_uppercase();
+        if codigo.is_empty() {
+            return;
+        }
+
+        let nome = self.nome.to_string().trim().to_string();
+        self.status = Status::Sair(Some(Ativo {
+            nome: if nome.is_empty() { codigo.clone() } else { nome },
+            id: codigo,
+            tipo: TIPOS
+                .iter()
+                .find(|t| self.tipo.id_selecionado_eh(t.to_string()))
+                .cloned()
+                .unwrap_or_default(),
+        }));
+    }
+
+    fn alterar_input(&mut self, key: KeyEvent, terminal: &mut DefaultTerminal) {
+        match self.status {
+            Status::AltCodigo => self.codigo.handle_key(key),
+            Status::AltNome => self.nome.handle_key(key),
+            Status::AltTipo => self.tipo.handle_key(key, terminal),
+
+            Status::Sair(_) => {}
+        }
+    }
+
+    fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        let [linha1, _] = Layout::vertical([Constraint::Length(3), Constraint::Fill(1)]).areas(area);
+
+        let [codigo, nome, tipo] = Layout::horizontal([
+            Constraint::Fill(1),
+            Constraint::Fill(2),
+            Constraint::Fill(1),
+        ])
+        .areas(linha1);
+
+        self.codigo.render(self.status == Status::AltCodigo, codigo, buf);
+        self.nome.render(self.status == Status::AltNome, nome, buf);
+        self.tipo.render(self.status == Status::AltTipo, tipo, buf);
+    }
+}
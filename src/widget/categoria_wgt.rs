@@ -12,7 +12,7 @@ use crate::{
         input_wgt::Input,
         lista_suspensa::{ItemListaSuspensa, ListaSuspensa},
     },
-    dto::{Categoria, GrupoDespesa, TipoDespesa, TipoFluxo, Unico},
+    dto::{ArvoreCategorias, Categoria, TipoDespesa, TipoFluxo, Unico},
     estilo::{principal_comandos, principal_titulo},
 };
 
@@ -20,8 +20,9 @@ use crate::{
 enum Status {
     AltTipo,
     AltTipoDespesa,
-    AltGrupo,
+    AltPai,
     AltNome,
+    AltTributacao,
 
     Sair(Option<Categoria>),
 }
@@ -29,11 +30,14 @@ enum Status {
 pub struct EditarCategoria {
     status: Status,
     id: String,
+    categorias: Vec<Categoria>,
 
     nome: Input,
     tipo: ListaSuspensa,
     tipo_despesa: ListaSuspensa,
-    grupo: Input,
+    pai: ListaSuspensa,
+    /// Carnê-leão nas receitas, livro-caixa nas despesas.
+    tributacao: ListaSuspensa,
 }
 
 impl Widget for &mut EditarCategoria {
@@ -83,11 +87,13 @@ impl EditarCategoria {
         Self {
             status: Status::AltTipo,
             id: String::new(),
+            categorias: Categoria::listar(),
 
             nome: Input::new_texto("Nome", "".to_string()),
             tipo: ListaSuspensa::new("Tipo", tipos, true),
             tipo_despesa: ListaSuspensa::new("Tipo de despesa", tipo_despesas, true),
-            grupo: Input::new_texto("Grupo", "".to_string()),
+            pai: ListaSuspensa::new("Categoria pai", vec![], true),
+            tributacao: ListaSuspensa::new_string("Carnê-leão", vec!["Não", "Sim"], false),
         }
     }
 
@@ -98,38 +104,32 @@ impl EditarCategoria {
         resp.nome.set_texto(cat.nome);
 
         match cat.tipo {
-            crate::dto::TipoFluxo::Receita(grupo) => {
-                resp.tipo.set_id_selecionado("Receita".to_string());
-                resp.grupo.set_texto(grupo);
-            }
-            crate::dto::TipoFluxo::Despesa(gd) => {
+            TipoFluxo::Receita => resp.tipo.set_id_selecionado("Receita".to_string()),
+            TipoFluxo::Despesa(tipo) => {
                 resp.tipo.set_id_selecionado("Despesa".to_string());
-                match gd.tipo {
-                    crate::dto::TipoDespesa::Fixa => {
-                        resp.tipo_despesa.set_id_selecionado("Fixa".to_string())
-                    }
-                    crate::dto::TipoDespesa::Variavel => {
+                match tipo {
+                    TipoDespesa::Fixa => resp.tipo_despesa.set_id_selecionado("Fixa".to_string()),
+                    TipoDespesa::Variavel => {
                         resp.tipo_despesa.set_id_selecionado("Variavel".to_string())
                     }
-                    crate::dto::TipoDespesa::Perda => {
+                    TipoDespesa::Perda => {
                         resp.tipo_despesa.set_id_selecionado("Perda".to_string())
                     }
-                    crate::dto::TipoDespesa::Vazio => {
-                        resp.tipo_despesa.set_id_selecionado("Vazio".to_string())
-                    }
+                    TipoDespesa::Vazio => resp.tipo_despesa.set_id_selecionado(String::new()),
                 }
-                resp.grupo.set_texto(gd.grupo);
             }
-            crate::dto::TipoFluxo::Investimento => {
-                resp.tipo.set_id_selecionado("Investimento".to_string())
-            }
-            crate::dto::TipoFluxo::Retorno => resp.tipo.set_id_selecionado("Retorno".to_string()),
-            crate::dto::TipoFluxo::Transferencias => {
+            TipoFluxo::Investimento => resp.tipo.set_id_selecionado("Investimento".to_string()),
+            TipoFluxo::Retorno => resp.tipo.set_id_selecionado("Retorno".to_string()),
+            TipoFluxo::Transferencias => {
                 resp.tipo.set_id_selecionado("Transferencias".to_string())
             }
-            crate::dto::TipoFluxo::SemCategoria => {
-                resp.tipo.set_id_selecionado("SemCategoria".to_string())
-            }
+            TipoFluxo::SemCategoria => resp.tipo.set_id_selecionado("SemCategoria".to_string()),
+        }
+
+        resp.atualizar_pais();
+        resp.pai.set_id_selecionado(cat.pai.unwrap_or_default());
+        if cat.carne_leao || cat.livro_caixa {
+            resp.tributacao.set_id_selecionado("Sim".to_string());
         }
 
         resp
@@ -169,25 +169,22 @@ impl EditarCategoria {
     fn proximo_input(&mut self) {
         match self.status {
             Status::AltTipo => match self.tipo.get_id_selecionado().as_str() {
-                "Receita" => self.status = Status::AltGrupo,
                 "Despesa" => self.status = Status::AltTipoDespesa,
-                "Investimento" | "Retorno" | "Transferencias" | "SemCategoria" => {
-                    self.status = Status::AltNome
-                }
-
-                _ => {}
+                "SemCategoria" => self.status = Status::AltNome,
+                "" => {}
+                _ => self.status = Status::AltPai,
             },
             Status::AltTipoDespesa => {
                 if !self.tipo_despesa.id_selecionado_eh(String::new()) {
-                    self.status = Status::AltGrupo
+                    self.status = Status::AltPai
                 }
             }
-            Status::AltGrupo => {
-                if self.grupo.to_string().len() >= 3 {
-                    self.status = Status::AltNome
-                }
-            }
-            Status::AltNome => self.status = Status::AltTipo,
+            Status::AltPai => self.status = Status::AltNome,
+            Status::AltNome => match self.tipo.get_id_selecionado().as_str() {
+                "Receita" | "Despesa" => self.status = Status::AltTributacao,
+                _ => self.status = Status::AltTipo,
+            },
+            Status::AltTributacao => self.status = Status::AltTipo,
 
             Status::Sair(_) => {}
         }
@@ -195,11 +192,12 @@ impl EditarCategoria {
 
     fn anterior_input(&mut self) {
         match self.status {
+            Status::AltTributacao => self.status = Status::AltNome,
             Status::AltNome => match self.tipo.get_id_selecionado().as_str() {
-                "Receita" | "Despesa" => self.status = Status::AltGrupo,
-                _ => self.status = Status::AltTipo,
+                "SemCategoria" => self.status = Status::AltTipo,
+                _ => self.status = Status::AltPai,
             },
-            Status::AltGrupo => match self.tipo.get_id_selecionado().as_str() {
+            Status::AltPai => match self.tipo.get_id_selecionado().as_str() {
                 "Despesa" => self.status = Status::AltTipoDespesa,
                 _ => self.status = Status::AltTipo,
             },
@@ -209,26 +207,57 @@ impl EditarCategoria {
         }
     }
 
+    fn tipo_fluxo(&mut self) -> TipoFluxo {
+        match self.tipo.get_id_selecionado().as_str() {
+            "Receita" => TipoFluxo::Receita,
+            "Despesa" => TipoFluxo::Despesa(match self.tipo_despesa.get_id_selecionado().as_str() {
+                "Fixa" => TipoDespesa::Fixa,
+                "Variavel" => TipoDespesa::Variavel,
+                "Perda" => TipoDespesa::Perda,
+                _ => TipoDespesa::Vazio,
+            }),
+            "Investimento" => TipoFluxo::Investimento,
+            "Retorno" => TipoFluxo::Retorno,
+            "Transferencias" => TipoFluxo::Transferencias,
+            _ => TipoFluxo::SemCategoria,
+        }
+    }
+
+    /// Recarrega as opções de pai: categorias do mesmo tipo de fluxo, exceto a
+    /// própria categoria e seus descendentes, para não formar ciclos.
+    fn atualizar_pais(&mut self) {
+        let tipo = self.tipo_fluxo();
+        let proibidos: Vec<String> = self
+            .categorias
+            .descendentes(&self.id)
+            .into_iter()
+            .map(|c| c.id)
+            .collect();
+
+        let mut itens: Vec<ItemListaSuspensa> = vec![ItemListaSuspensa::new("")];
+        itens.extend(
+            self.categorias
+                .iter()
+                .filter(|c| c.tipo == tipo && c.id != self.id && !proibidos.contains(&c.id))
+                .filter(|c| !c.nome.is_empty())
+                .map(|c| ItemListaSuspensa::new2(&c.id, &c.to_string())),
+        );
+
+        self.pai.set_lista(itens);
+    }
+
     fn salvar(&mut self) {
+        let pai = self.pai.get_id_selecionado();
+        let tipo = self.tipo_fluxo();
+        let tributada = self.tributacao.id_selecionado_eh("Sim".to_string());
         let mut categoria = Categoria {
             id: self.id.clone(),
             nome: self.nome.to_string(),
-            tipo: match self.tipo.get_id_selecionado().as_str() {
-                "Receita" => TipoFluxo::Receita(self.grupo.to_string()),
-                "Despesa" => TipoFluxo::Despesa(GrupoDespesa {
-                    grupo: self.grupo.to_string(),
-                    tipo: match self.tipo_despesa.get_id_selecionado().as_str() {
-                        "Fixa" => TipoDespesa::Fixa,
-                        "Variavel" => TipoDespesa::Variavel,
-                        "Perda" => TipoDespesa::Perda,
-                        _ => TipoDespesa::Vazio,
-                    },
-                }),
-                "Investimento" => TipoFluxo::Investimento,
-                "Retorno" => TipoFluxo::Retorno,
-                "Transferencias" => TipoFluxo::Transferencias,
-                _ => TipoFluxo::SemCategoria,
-            },
+            carne_leao: tributada && tipo == TipoFluxo::Receita,
+            livro_caixa: tributada && matches!(tipo, TipoFluxo::Despesa(_)),
+            tipo,
+            pai: if pai.is_empty() { None } else { Some(pai) },
+            hierarquia: Vec::new(),
         };
 
         if categoria.id.is_empty() {
@@ -243,26 +272,22 @@ impl EditarCategoria {
             Status::AltTipo => {
                 self.tipo.handle_key(key, terminal);
                 self.tipo_despesa.set_id_selecionado(String::new());
-                self.grupo.set_texto(String::new());
-                self.nome.set_texto(String::new());
+                self.atualizar_pais();
             }
             Status::AltTipoDespesa => {
                 self.tipo_despesa.handle_key(key, terminal);
-                self.grupo.set_texto(String::new());
-                self.nome.set_texto(String::new());
-            }
-            Status::AltGrupo => {
-                self.grupo.handle_key(key);
-                self.nome.set_texto(String::new());
+                self.atualizar_pais();
             }
+            Status::AltPai => self.pai.handle_key(key, terminal),
             Status::AltNome => self.nome.handle_key(key),
+            Status::AltTributacao => self.tributacao.handle_key(key, terminal),
 
             Status::Sair(_) => {}
         }
     }
 
     fn render(&mut self, area: Rect, buf: &mut Buffer) {
-        let mut mostrar_nome = false;
+        let mut mostrar_pai = false;
 
         let [linha1, linha2] =
             Layout::vertical([Constraint::Length(3), Constraint::Length(3)]).areas(area);
@@ -277,30 +302,32 @@ impl EditarCategoria {
         self.tipo.render(self.status == Status::AltTipo, col1, buf);
 
         match self.tipo.get_id_selecionado().as_str() {
-            "Receita" => {
-                self.grupo
-                    .render(self.status == Status::AltGrupo, col2, buf);
-                mostrar_nome = self.grupo.to_string().len() >= 3;
-            }
             "Despesa" => {
                 self.tipo_despesa
                     .render(self.status == Status::AltTipoDespesa, col2, buf);
-
-                if !self.tipo_despesa.id_selecionado_eh(String::new()) {
-                    self.grupo
-                        .render(self.status == Status::AltGrupo, col3, buf);
-                    mostrar_nome = self.grupo.to_string().len() >= 3;
-                }
-            }
-            "Investimento" | "Retorno" | "Transferencias" | "SemCategoria" => {
-                mostrar_nome = true;
+                mostrar_pai = !self.tipo_despesa.id_selecionado_eh(String::new());
             }
-            _ => {}
+            "SemCategoria" | "" => {}
+            _ => mostrar_pai = true,
         }
 
-        if mostrar_nome {
+        if mostrar_pai {
+            self.pai.render(self.status == Status::AltPai, col3, buf);
+        }
+
+        if mostrar_pai || self.tipo.id_selecionado_eh("SemCategoria".to_string()) {
+            let [nome, tributacao] =
+                Layout::horizontal([Constraint::Fill(2), Constraint::Fill(1)]).areas(linha2);
             self.nome
-                .render(self.status == Status::AltNome, linha2, buf);
+                .render(self.status == Status::AltNome, nome, buf);
+
+            match self.tipo.get_id_selecionado().as_str() {
+                "Receita" => self.tributacao.nome = "Carnê-leão".to_string(),
+                "Despesa" => self.tributacao.nome = "Livro-caixa".to_string(),
+                _ => return,
+            }
+            self.tributacao
+                .render(self.status == Status::AltTributacao, tributacao, buf);
         }
     }
 }
@@ -42,7 +42,7 @@ impl Default for Categorizador {
         Categoria::listar()
             .into_iter()
             .for_each(|c| match c.tipo.clone() {
-                TipoFluxo::Receita(_) => receitas.push(c),
+                TipoFluxo::Receita => receitas.push(c),
                 TipoFluxo::Retorno => receitas.push(c),
 
                 TipoFluxo::Despesa(_) => despesas.push(c),
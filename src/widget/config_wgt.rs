@@ -17,6 +17,7 @@ use crate::{
 enum Status {
     EditarSalario,
     Endividamento,
+    Dependentes,
     Sair,
 }
 
@@ -25,6 +26,7 @@ pub struct EditarConfiguracoes {
 
     salario: Input,
     endividamento_max: Input,
+    dependentes: Input,
 }
 
 impl Default for EditarConfiguracoes {
@@ -35,6 +37,7 @@ impl Default for EditarConfiguracoes {
             status: Status::EditarSalario,
             salario: Input::new_monetario("Salário", configs.salario),
             endividamento_max: Input::new_monetario("Endividamento máximo", configs.endividamento_max),
+            dependentes: Input::new_inteiro("Dependentes (IRRF)", configs.dependentes),
         }
     }
 }
@@ -63,13 +66,19 @@ impl Widget for &mut EditarConfiguracoes {
 
 impl EditarConfiguracoes {
     fn render(&mut self, area: Rect, buf: &mut Buffer) {
-        let [linha1, linha2] =
-            Layout::vertical([Constraint::Length(3), Constraint::Length(3)]).areas(area);
+        let [linha1, linha2, linha3] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(3),
+        ])
+        .areas(area);
 
         self.salario
             .render(self.status == Status::EditarSalario, linha1, buf);
         self.endividamento_max
             .render(self.status == Status::Endividamento, linha2, buf);
+        self.dependentes
+            .render(self.status == Status::Dependentes, linha3, buf);
     }
 
     pub fn run(mut self, terminal: &mut DefaultTerminal) -> Result<()> {
@@ -107,7 +116,8 @@ impl EditarConfiguracoes {
 
         match self.status {
             Status::EditarSalario => self.status = Status::Endividamento,
-            Status::Endividamento => self.status = Status::EditarSalario,
+            Status::Endividamento => self.status = Status::Dependentes,
+            Status::Dependentes => self.status = Status::EditarSalario,
             Status::Sair => {}
         }
     }
@@ -116,8 +126,9 @@ impl EditarConfiguracoes {
         self.salvar();
 
         match self.status {
+            Status::Dependentes => self.status = Status::Endividamento,
             Status::Endividamento => self.status = Status::EditarSalario,
-            Status::EditarSalario => self.status = Status::Endividamento,
+            Status::EditarSalario => self.status = Status::Dependentes,
             Status::Sair => {}
         }
     }
@@ -130,6 +141,7 @@ impl EditarConfiguracoes {
             self.endividamento_max.set_monetario(self.salario.to_f64()*0.4);
         }
         conf.endividamento_max = self.endividamento_max.to_f64();
+        conf.dependentes = self.dependentes.to_i32().max(0);
 
         Configuracao::salvar(&conf);
     }
@@ -138,6 +150,7 @@ impl EditarConfiguracoes {
         match self.status {
             Status::EditarSalario => self.salario.handle_key(key),
             Status::Endividamento => self.endividamento_max.handle_key(key),
+            Status::Dependentes => self.dependentes.handle_key(key),
             Status::Sair => {}
         }
     }
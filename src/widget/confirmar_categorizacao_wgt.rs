@@ -1,13 +1,11 @@
 use crate::{
     dto::{
-        Categoria, FluxoRegra, Lancamento, Lazy, LazyFn, OptionalLazy, OptionalLazyFn, Regra,
+        Categoria, FluxoRegra, Lancamento, Lazy, OptionalLazy, OptionalLazyFn, Regra,
         TipoFluxo, Unico,
     },
     estilo::alternate_colors,
-    repository::Buscar,
 };
 use color_eyre::Result;
-use itertools::Itertools;
 use ratatui::{
     buffer::Buffer,
     crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
@@ -40,7 +38,7 @@ impl Default for ConfirmarCategorias {
         Categoria::listar()
             .into_iter()
             .for_each(|c| match c.tipo.clone() {
-                TipoFluxo::Receita(_) => receitas.push(c),
+                TipoFluxo::Receita => receitas.push(c),
                 TipoFluxo::Retorno => receitas.push(c),
 
                 TipoFluxo::Despesa(_) => despesas.push(c),
@@ -56,7 +54,7 @@ impl Default for ConfirmarCategorias {
                     receitas.push(c);
                 }
             });
-        let (enc, nenc) = encontrar_categoria();
+        let (enc, nenc) = Lancamento::aplicar_regras();
         Self {
             should_exit: false,
             itens: enc,
@@ -68,47 +66,6 @@ impl Default for ConfirmarCategorias {
     }
 }
 
-fn encontrar_categoria() -> (Vec<Lancamento>, Vec<Lancamento>) {
-    let regras = Regra::listar_full();
-    let mut encontrados: Vec<Lancamento> = Vec::new();
-    let mut nao_encontrado: Vec<Lancamento> = Vec::new();
-
-    let pendente: Vec<Lancamento> = Lancamento::nao_categorizados_listar();
-    for mut item in pendente {
-        match &regras.buscar(
-            &item.descricao.to_lowercase(),
-            if item.valor > 0.0 {
-                FluxoRegra::Entrada
-            } else {
-                FluxoRegra::Saida
-            },
-        ) {
-            Some(regra) => {
-                item.categoria = OptionalLazy::Some(regra.categoria.some());
-                item.regra = OptionalLazy::Some(regra.clone());
-                encontrados.push(item);
-            }
-            None => {
-                nao_encontrado.push(item);
-            }
-        }
-    }
-
-    log::info!(
-        "{} lançamento(s) categorizado(s), restando {}",
-        encontrados.len(),
-        nao_encontrado.len()
-    );
-
-    (
-        encontrados
-            .into_iter()
-            .sorted_by(|a, b| a.data.cmp(&b.data))
-            .collect(),
-        nao_encontrado,
-    )
-}
-
 impl ConfirmarCategorias {
     pub fn run(mut self, terminal: &mut DefaultTerminal) -> Result<()> {
         self.state.select_first();
@@ -0,0 +1,187 @@
+use color_eyre::eyre::Result;
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
+    layout::{Constraint, Layout, Rect},
+    widgets::Widget,
+    DefaultTerminal,
+};
+
+use crate::{
+    componentes::{
+        input_wgt::Input,
+        lista_suspensa::{ItemListaSuspensa, ListaSuspensa},
+    },
+    dto::{Conta, TipoConta},
+    estilo::{principal_comandos, principal_titulo},
+};
+
+const TIPOS: [TipoConta; 4] = [
+    TipoConta::Corrente,
+    TipoConta::Poupanca,
+    TipoConta::Investimento,
+    TipoConta::Cartao,
+];
+
+#[derive(PartialEq)]
+enum Status {
+    AltNome,
+    AltTipo,
+    AltSaldo,
+    AltData,
+
+    Sair(Option<Conta>),
+}
+
+pub struct EditarConta {
+    status: Status,
+    conta: Conta,
+
+    nome: Input,
+    tipo: ListaSuspensa,
+    saldo: Input,
+    data: Input,
+}
+
+impl Widget for &mut EditarConta {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let [titulo, corpo, rodape] = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Fill(1),
+            Constraint::Length(1),
+        ])
+        .areas(area);
+
+        principal_titulo("Edição de Conta", titulo, buf);
+        principal_comandos(
+            vec!["(Editar)", "TAB (próximo)", "Esc (sair)", "F5 (salvar)"],
+            rodape,
+            buf,
+        );
+        self.render(corpo, buf)
+    }
+}
+
+impl EditarConta {
+    pub fn set(conta: Conta) -> Self {
+        let mut tipo = ListaSuspensa::new(
+            "Tipo",
+            TIPOS
+                .iter()
+                .map(|t| ItemListaSuspensa::new(&t.to_string()))
+                .collect(),
+            false,
+        );
+        tipo.set_id_selecionado(conta.tipo.to_string());
+
+        Self {
+            status: Status::AltNome,
+
+            nome: Input::new_texto("Nome", conta.nome.clone()),
+            tipo,
+            saldo: Input::new_monetario("Saldo inicial", conta.saldo_inicial),
+            data: Input::new_data(
+                "Data do saldo inicial (vazio = antes de tudo)",
+                conta
+                    .data_saldo_inicial
+                    .map(|d| d.format("%d/%m/%y").to_string())
+                    .unwrap_or("00/00/00".to_string()),
+            ),
+            conta,
+        }
+    }
+
+    pub fn run(mut self, terminal: &mut DefaultTerminal) -> Result<Option<Conta>> {
+        while !matches!(self.status, Status::Sair(_)) {
+            if let Err(erro) = terminal.draw(|frame| frame.render_widget(&mut self, frame.area())) {
+                log::error!("Erro ao desenhar tela EditarConta: {}", erro);
+            };
+
+            if let Event::Key(key) = event::read()? {
+                self.handle_key(key, terminal);
+            };
+        }
+
+        if let Status::Sair(conta) = self.status {
+            return Ok(conta);
+        }
+        Ok(None)
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent, terminal: &mut DefaultTerminal) {
+        if key.kind != KeyEventKind::Press {
+            return;
+        }
+
+        match key.code {
+            KeyCode::Esc => self.status = Status::Sair(None),
+            KeyCode::Tab => self.proximo_input(),
+            KeyCode::BackTab => self.anterior_input(),
+            KeyCode::F(5) => self.salvar(),
+            _ => self.alterar_input(key, terminal),
+        }
+    }
+
+    fn proximo_input(&mut self) {
+        match self.status {
+            Status::AltNome => self.status = Status::AltTipo,
+            Status::AltTipo => self.status = Status::AltSaldo,
+            Status::AltSaldo => self.status = Status::AltData,
+            Status::AltData => self.status = Status::AltNome,
+
+            Status::Sair(_) => {}
+        }
+    }
+
+    fn anterior_input(&mut self) {
+        match self.status {
+            Status::AltNome => self.status = Status::AltData,
+            Status::AltTipo => self.status = Status::AltNome,
+            Status::AltSaldo => self.status = Status::AltTipo,
+            Status::AltData => self.status = Status::AltSaldo,
+
+            Status::Sair(_) => {}
+        }
+    }
+
+    fn salvar(&mut self) {
+        let mut conta = self.conta.clone();
+        conta.nome = self.nome.to_string();
+        conta.tipo = TIPOS
+            .iter()
+            .find(|t| self.tipo.id_selecionado_eh(t.to_string()))
+            .cloned()
+            .unwrap_or_default();
+        conta.saldo_inicial = self.saldo.to_f64();
+        // data inválida ou zerada: o saldo inicial vale antes de todos os lançamentos
+        conta.data_saldo_inicial = self.data.to_naivedate().ok();
+
+        self.status = Status::Sair(Some(conta));
+    }
+
+    fn alterar_input(&mut self, key: KeyEvent, terminal: &mut DefaultTerminal) {
+        match self.status {
+            Status::AltNome => self.nome.handle_key(key),
+            Status::AltTipo => self.tipo.handle_key(key, terminal),
+            Status::AltSaldo => self.saldo.handle_key(key),
+            Status::AltData => self.data.handle_key(key),
+
+            Status::Sair(_) => {}
+        }
+    }
+
+    fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        let [linha1, linha2] =
+            Layout::vertical([Constraint::Length(3), Constraint::Length(3)]).areas(area);
+
+        let [nome, tipo] =
+            Layout::horizontal([Constraint::Fill(2), Constraint::Fill(1)]).areas(linha1);
+        let [saldo, data] =
+            Layout::horizontal([Constraint::Fill(1), Constraint::Fill(2)]).areas(linha2);
+
+        self.nome.render(self.status == Status::AltNome, nome, buf);
+        self.tipo.render(self.status == Status::AltTipo, tipo, buf);
+        self.saldo.render(self.status == Status::AltSaldo, saldo, buf);
+        self.data.render(self.status == Status::AltData, data, buf);
+    }
+}
@@ -1,22 +1,38 @@
-use chrono::Utc;
+use chrono::{Datelike, NaiveDate, Utc};
 use ratatui::{
     buffer::Buffer,
     crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
     layout::{Constraint, Direction, Layout, Rect},
-    style::Stylize,
+    style::{palette::tailwind::AMBER, Stylize},
+    text::Line,
     widgets::{Paragraph, Widget},
     DefaultTerminal,
 };
 
 use crate::{
     app::Etapa,
-    componentes::input_wgt::Input,
-    dto::{Banco, Configuracao, Conta, Lancamento, OptionalLazy, Unico},
-    estilo::{principal_comandos, principal_titulo},
+    calc::conferir_contracheque,
+    componentes::{
+        input_wgt::Input,
+        lista_suspensa::{ItemListaSuspensa, ListaSuspensa},
+    },
+    dto::{
+        Banco, Categoria, ConferenciaContracheque, Configuracao, Conta, Contracheque, Empregador,
+        ItemContracheque, Lancamento, OptionalLazy, TabelaTributaria, TipoContracheque, Unico,
+    },
+    estilo::{principal_comandos, principal_titulo, GERAL_TEXT_FG},
     widget::alerta_wgt::Alerta,
 };
 use color_eyre::Result;
 
+const TIPOS: [TipoContracheque; 5] = [
+    TipoContracheque::Mensal,
+    TipoContracheque::DecimoTerceiroPrimeira,
+    TipoContracheque::DecimoTerceiroSegunda,
+    TipoContracheque::Ferias,
+    TipoContracheque::Plr,
+];
+
 #[derive(Clone, PartialEq)]
 struct Tupla {
     linha: usize,
@@ -26,7 +42,10 @@ struct Tupla {
 #[derive(Clone, PartialEq)]
 enum Editar {
     Empresa,
+    Tipo,
+    Competencia,
     Data,
+    Conta,
     Tupla(Tupla),
 }
 
@@ -37,6 +56,7 @@ enum Bloco {
 enum Tipo {
     Nome,
     Valor,
+    Categoria,
 }
 
 impl Editar {
@@ -46,10 +66,11 @@ impl Editar {
                 linha,
                 coluna: match bloco {
                     Bloco::Entrada => 0,
-                    Bloco::Saida => 2,
+                    Bloco::Saida => 3,
                 } + match tipo {
                     Tipo::Nome => 0,
                     Tipo::Valor => 1,
+                    Tipo::Categoria => 2,
                 },
             })
         } else {
@@ -61,51 +82,93 @@ impl Editar {
 struct ContraChequeItem {
     nome: Input,
     valor: Input,
+    categoria: ListaSuspensa,
+    /// Nome para o qual a categoria do empregador já foi sugerida.
+    nome_sugerido: String,
 }
 pub struct ContraCheque {
     sair: bool,
     editar: Editar,
     empresa: Input,
+    tipo: ListaSuspensa,
+    competencia: Input,
     data_pagamento: Input,
+    conta: ListaSuspensa,
     entradas: Vec<ContraChequeItem>,
     saidas: Vec<ContraChequeItem>,
+
+    empregador: Empregador,
+    categorias: Vec<ItemListaSuspensa>,
+
+    dependentes: i32,
+    tabela: TabelaTributaria,
 }
 
 impl Default for ContraCheque {
     fn default() -> Self {
         let config = Configuracao::buscar();
 
-        let entradas: Vec<ContraChequeItem> = config
-            .contracheque_entradas
-            .iter()
-            .enumerate()
-            .map(|(_, nome_item)| ContraChequeItem {
-                nome: Input::new_texto("nome", nome_item.clone()),
-                valor: Input::new_monetario("valor", 0.0),
+        // os itens do último contracheque da empresa ou, sem histórico, os da configuração
+        let ultimo = Contracheque::listar()
+            .into_iter()
+            .rfind(|c| c.empresa == config.contracheque_empresa);
+        let (nomes_entradas, nomes_saidas) = match &ultimo {
+            Some(c) => (
+                c.entradas.iter().map(|i| i.nome.clone()).collect(),
+                c.saidas.iter().map(|i| i.nome.clone()).collect(),
+            ),
+            None => (
+                config.contracheque_entradas.clone(),
+                config.contracheque_saidas.clone(),
+            ),
+        };
+
+        let categorias: Vec<ItemListaSuspensa> = Categoria::listar()
+            .into_iter()
+            .map(|c| ItemListaSuspensa {
+                id: c.id.clone(),
+                texto: c.to_string(),
             })
             .collect();
 
-        let saidas: Vec<ContraChequeItem> = config
-            .contracheque_saidas
+        let entradas: Vec<ContraChequeItem> = nomes_entradas
             .iter()
-            .enumerate()
-            .map(|(_, nome_item)| ContraChequeItem {
-                nome: Input::new_texto("nome", nome_item.clone()),
-                valor: Input::new_monetario("valor", 0.0),
-            })
+            .map(|nome_item| novo_item(nome_item.clone(), &categorias))
             .collect();
 
-        Self {
+        let saidas: Vec<ContraChequeItem> = nomes_saidas
+            .iter()
+            .map(|nome_item| novo_item(nome_item.clone(), &categorias))
+            .collect();
+
+        let hoje = Utc::now().naive_utc().date();
+
+        let mut resp = Self {
             sair: Default::default(),
             empresa: Input::new_texto("Empresa", config.contracheque_empresa),
-            data_pagamento: Input::new_data(
-                "Empresa",
-                Utc::now().naive_utc().date().format("%d/%m/%y").to_string(),
+            tipo: ListaSuspensa::new(
+                "Tipo",
+                TIPOS
+                    .iter()
+                    .map(|t| ItemListaSuspensa::new(&t.to_string()))
+                    .collect(),
+                false,
             ),
+            competencia: Input::new_data("Competência", hoje.format("01/%m/%y").to_string()),
+            data_pagamento: Input::new_data("Pagamento", hoje.format("%d/%m/%y").to_string()),
+            conta: ListaSuspensa::new("Conta de destino", contas_destino(), false),
             editar: Editar::Empresa,
             entradas: entradas,
             saidas: saidas,
-        }
+
+            empregador: Empregador::default(),
+            categorias,
+
+            dependentes: config.dependentes,
+            tabela: TabelaTributaria::buscar(hoje.year()),
+        };
+        resp.carregar_empregador();
+        resp
     }
 }
 
@@ -120,7 +183,12 @@ impl Widget for &mut ContraCheque {
 
         principal_titulo("Adicionar Contra-cheque", titulo, buf);
         principal_comandos(
-            vec!["Tab e ↓↑ (mover)", "ESC Sair", "F5 (salvar)"],
+            vec![
+                "Tab e ↓↑ (mover)",
+                "ENTER na categoria (escolher)",
+                "ESC Sair",
+                "F5 (salvar)",
+            ],
             rodape,
             buf,
         );
@@ -132,11 +200,13 @@ impl ContraCheque {
     pub fn run(mut self, terminal: &mut DefaultTerminal) -> Result<Etapa> {
         while !self.sair {
             self.atualizar_listas();
+            self.sugerir_categorias();
+            self.atualizar_tabela();
 
-            if let Err(erro) = terminal.draw(|frame| frame.render_widget(&mut self, frame.area())){
+            if let Err(erro) = terminal.draw(|frame| frame.render_widget(&mut self, frame.area())) {
                 log::error!("Erro ao desenhar tela Contracheque: {}", erro);
             };
-            
+
             if let Event::Key(key) = event::read()? {
                 self.handle_key(key, terminal);
             };
@@ -156,8 +226,11 @@ impl ContraCheque {
 
             _ => match &self.editar {
                 Editar::Empresa => self.handle_key_alt_empresa(key),
+                Editar::Tipo => self.handle_key_alt_tipo(key, terminal),
+                Editar::Competencia => self.handle_key_alt_competencia(key),
                 Editar::Data => self.handle_key_alt_data(key),
-                Editar::Tupla(_) => self.handle_key_alt_coluna(key),
+                Editar::Conta => self.handle_key_alt_conta(key, terminal),
+                Editar::Tupla(_) => self.handle_key_alt_coluna(key, terminal),
             },
         }
     }
@@ -167,15 +240,22 @@ impl ContraCheque {
         let saidas: f64 = self.saidas.iter().map(|c| c.valor.to_f64()).sum();
         let total = entradas - saidas;
 
-        if let Ok(resp) = Alerta::atencao(vec![
+        let mut mensagem = vec![
             "Certifique-se que todos os dados estão corretos".to_string(),
             String::new(),
             format!("Entradas:      R$ {:0.02}", entradas),
             format!("Saídas:        R$ {:0.02}", saidas),
             format!("Total:         R$ {:0.02}", total),
-        ])
-        .run(terminal)
-        {
+        ];
+        let divergencia = self
+            .conferencia()
+            .is_some_and(|c| divergente(c.diferenca_inss()) || divergente(c.diferenca_irrf()));
+        if divergencia {
+            mensagem.push(String::new());
+            mensagem.push("INSS ou IRRF diferente do esperado pelas tabelas".to_string());
+        }
+
+        if let Ok(resp) = Alerta::atencao(mensagem).run(terminal) {
             if resp {
                 self.salvar(total);
                 self.sair = true;
@@ -183,21 +263,31 @@ impl ContraCheque {
         }
     }
 
-    fn salvar(&self, total: f64) {
-        let conta = buscar_conta(self.empresa.to_string());
+    /// Os itens vão para a conta da empresa em "Salario". Com uma conta de destino
+    /// real, o líquido sai dessa conta e entra no destino pelo crédito do próprio
+    /// OFX do banco; sem ela, fica na conta da empresa, como antes.
+    fn salvar(&mut self, total: f64) {
+        let conta = buscar_conta(self.empresa.to_string()).id;
+        let transferencia = match self.conta_destino() {
+            Some(_) => -total,
+            None => total,
+        };
         let data = self.data_pagamento.to_naivedate().unwrap();
+        self.salvar_contracheque(data);
+        self.salvar_empregador();
+
         let mut lancamentos: Vec<Lancamento> = Vec::new();
 
         let mut nome_entradas: Vec<String> = Vec::new();
-        self.entradas.iter().for_each(|f| {
+        self.entradas.iter_mut().for_each(|f| {
             if !f.nome.to_string().is_empty() && f.valor.to_f64() != 0f64 {
                 lancamentos.push(Lancamento {
                     id: String::new(),
                     descricao: f.nome.to_string(),
                     valor: f.valor.to_f64(),
                     data: data,
-                    categoria: OptionalLazy::None,
-                    conta: Some(conta.id.clone()),
+                    categoria: categoria_do_item(f),
+                    conta: Some(conta.clone()),
                     regra: OptionalLazy::None,
                 });
                 nome_entradas.push(f.nome.to_string());
@@ -205,15 +295,15 @@ impl ContraCheque {
         });
 
         let mut nome_saidas: Vec<String> = Vec::new();
-        self.saidas.iter().for_each(|f| {
+        self.saidas.iter_mut().for_each(|f| {
             if !f.nome.to_string().is_empty() && f.valor.to_f64() != 0f64 {
                 lancamentos.push(Lancamento {
                     id: String::new(),
                     descricao: f.nome.to_string(),
                     valor: f.valor.to_f64() * -1f64,
                     data: data,
-                    categoria: OptionalLazy::None,
-                    conta: Some(conta.id.clone()),
+                    categoria: categoria_do_item(f),
+                    conta: Some(conta.clone()),
                     regra: OptionalLazy::None,
                 });
                 nome_saidas.push(f.nome.to_string());
@@ -223,10 +313,10 @@ impl ContraCheque {
         lancamentos.push(Lancamento {
             id: String::new(),
             descricao: "Transferencia de Salário Liquido".to_string(),
-            valor: total,
+            valor: transferencia,
             data: data,
             categoria: OptionalLazy::None,
-            conta: Some(conta.id.clone()),
+            conta: Some(conta.clone()),
             regra: OptionalLazy::None,
         });
 
@@ -234,12 +324,128 @@ impl ContraCheque {
             l.gerar_id();
         });
 
-        Lancamento::categorizar(&lancamentos);
-        Configuracao::atualizar_contracheque(
-            self.empresa.to_string(),
-            nome_entradas,
-            nome_saidas,
-        );
+        // itens com categoria vão direto para os lançamentos; o resto para a categorização
+        let (categorizados, pendentes): (Vec<Lancamento>, Vec<Lancamento>) = lancamentos
+            .into_iter()
+            .partition(|l| !matches!(l.categoria, OptionalLazy::None));
+        Lancamento::lancamentos_adicionar(&categorizados);
+        Lancamento::categorizar(&pendentes);
+        Configuracao::atualizar_contracheque(self.empresa.to_string(), nome_entradas, nome_saidas);
+    }
+
+    fn salvar_contracheque(&self, data: NaiveDate) {
+        let itens = |lista: &Vec<ContraChequeItem>| -> Vec<ItemContracheque> {
+            lista
+                .iter()
+                .filter(|i| !i.nome.to_string().is_empty() && i.valor.to_f64() != 0f64)
+                .map(|i| ItemContracheque {
+                    nome: i.nome.to_string(),
+                    valor: i.valor.to_f64(),
+                })
+                .collect()
+        };
+
+        let mut contracheque = Contracheque {
+            id: String::new(),
+            empresa: self.empresa.to_string(),
+            tipo: self.tipo_selecionado(),
+            competencia: self
+                .competencia
+                .to_naivedate()
+                .unwrap_or(data)
+                .with_day(1)
+                .unwrap_or(data),
+            data_pagamento: data,
+            entradas: itens(&self.entradas),
+            saidas: itens(&self.saidas),
+        };
+        contracheque.gerar_id();
+        contracheque.salvar();
+    }
+
+    /// Ao trocar de empresa, traz os itens do último contracheque dela se
+    /// nenhum valor foi digitado ainda.
+    fn carregar_empresa(&mut self) {
+        let digitado = self
+            .entradas
+            .iter()
+            .chain(self.saidas.iter())
+            .any(|i| i.valor.to_f64() != 0f64);
+        if digitado {
+            return;
+        }
+
+        let empresa = self.empresa.to_string();
+        if let Some(ultimo) = Contracheque::listar()
+            .into_iter()
+            .rfind(|c| c.empresa == empresa)
+        {
+            let itens = |lista: &Vec<ItemContracheque>| -> Vec<ContraChequeItem> {
+                lista
+                    .iter()
+                    .map(|i| novo_item(i.nome.clone(), &self.categorias))
+                    .collect()
+            };
+            self.entradas = itens(&ultimo.entradas);
+            self.saidas = itens(&ultimo.saidas);
+        }
+    }
+
+    /// Conta de destino e categorias lembradas da empresa digitada.
+    fn carregar_empregador(&mut self) {
+        self.empregador = Empregador::buscar(&self.empresa.to_string());
+        self.conta
+            .set_id_selecionado(match (&self.empregador.banco, &self.empregador.conta) {
+                (Some(banco), Some(conta)) => format!("{}:{}", banco, conta),
+                _ => String::new(),
+            });
+
+        self.entradas
+            .iter_mut()
+            .chain(self.saidas.iter_mut())
+            .for_each(|i| i.nome_sugerido = String::new());
+        self.sugerir_categorias();
+    }
+
+    /// Preenche a categoria dos itens cujo nome mudou com a lembrada pelo empregador.
+    fn sugerir_categorias(&mut self) {
+        let empregador = &self.empregador;
+        self.entradas
+            .iter_mut()
+            .chain(self.saidas.iter_mut())
+            .filter(|i| i.nome.to_string() != i.nome_sugerido)
+            .for_each(|i| {
+                let nome = i.nome.to_string();
+                if let Some(categoria) = empregador.categoria_do_item(&nome) {
+                    i.categoria.set_id_selecionado(categoria);
+                }
+                i.nome_sugerido = nome;
+            });
+    }
+
+    /// Banco e conta escolhidos; `None` para a conta da empresa em "Salario".
+    fn conta_destino(&mut self) -> Option<(String, String)> {
+        self.conta
+            .get_id_selecionado()
+            .split_once(':')
+            .map(|(banco, conta)| (banco.to_string(), conta.to_string()))
+    }
+
+    fn salvar_empregador(&mut self) {
+        let mut empregador = Empregador::buscar(&self.empresa.to_string());
+        let destino = self.conta_destino();
+        empregador.banco = destino.as_ref().map(|(banco, _)| banco.clone());
+        empregador.conta = destino.map(|(_, conta)| conta);
+
+        for item in self.entradas.iter_mut().chain(self.saidas.iter_mut()) {
+            let nome = item.nome.to_string();
+            if !nome.is_empty() {
+                empregador.lembrar_categoria(&nome, item.categoria.get_id_selecionado());
+            }
+        }
+
+        empregador.salvar();
+        self.empregador = empregador;
     }
 
     fn sair(&mut self, terminal: &mut DefaultTerminal) {
@@ -255,28 +461,60 @@ impl ContraCheque {
 
     fn handle_key_alt_empresa(&mut self, key: KeyEvent) {
         match key.code {
-            KeyCode::Tab | KeyCode::Enter => self.editar = Editar::Data,
+            KeyCode::Tab | KeyCode::Enter => {
+                self.carregar_empresa();
+                self.carregar_empregador();
+                self.editar = Editar::Tipo
+            }
 
             _ => self.empresa.handle_key(key),
         }
     }
 
-    fn handle_key_alt_data(&mut self, key: KeyEvent) {
+    fn handle_key_alt_tipo(&mut self, key: KeyEvent, terminal: &mut DefaultTerminal) {
         match key.code {
             KeyCode::BackTab => self.editar = Editar::Empresa,
-            KeyCode::Tab | KeyCode::Enter => {
+            KeyCode::Tab => self.editar = Editar::Competencia,
+
+            _ => self.tipo.handle_key(key, terminal),
+        }
+    }
+
+    fn handle_key_alt_competencia(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::BackTab => self.editar = Editar::Tipo,
+            KeyCode::Tab | KeyCode::Enter => self.editar = Editar::Data,
+
+            _ => self.competencia.handle_key(key),
+        }
+    }
+
+    fn handle_key_alt_data(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::BackTab => self.editar = Editar::Competencia,
+            KeyCode::Tab | KeyCode::Enter => self.editar = Editar::Conta,
+
+            _ => self.data_pagamento.handle_key(key),
+        }
+    }
+
+    fn handle_key_alt_conta(&mut self, key: KeyEvent, terminal: &mut DefaultTerminal) {
+        match key.code {
+            KeyCode::BackTab => self.editar = Editar::Data,
+            KeyCode::Tab => {
                 self.editar = Editar::Tupla(Tupla {
                     coluna: 0,
                     linha: 0,
                 })
             }
 
-            _ => self.data_pagamento.handle_key(key),
+            _ => self.conta.handle_key(key, terminal),
         }
     }
 
-    fn handle_key_alt_coluna(&mut self, key: KeyEvent) {
+    fn handle_key_alt_coluna(&mut self, key: KeyEvent, terminal: &mut DefaultTerminal) {
         match key.code {
+            KeyCode::Enter if self.na_categoria() => self.abrir_categoria(key, terminal),
             KeyCode::Tab => self.para_direita(),
             KeyCode::BackTab => self.para_esquerda(),
             KeyCode::Up => self.para_cima(),
@@ -288,9 +526,24 @@ impl ContraCheque {
         }
     }
 
+    fn na_categoria(&self) -> bool {
+        matches!(&self.editar, Editar::Tupla(tupla) if tupla.coluna % 3 == 2)
+    }
+
+    fn abrir_categoria(&mut self, key: KeyEvent, terminal: &mut DefaultTerminal) {
+        if let Editar::Tupla(tupla) = self.editar.clone() {
+            let item = if tupla.coluna < 3 {
+                &mut self.entradas[tupla.linha]
+            } else {
+                &mut self.saidas[tupla.linha]
+            };
+            item.categoria.handle_key(key, terminal);
+        }
+    }
+
     fn remover_item(&mut self) {
         if let Editar::Tupla(mut tupla) = self.editar.clone() {
-            if tupla.coluna < 2 {
+            if tupla.coluna < 3 {
                 self.entradas.remove(tupla.linha);
             } else {
                 self.saidas.remove(tupla.linha);
@@ -305,7 +558,7 @@ impl ContraCheque {
             if tupla.coluna > 0 {
                 tupla.coluna -= 1;
             } else {
-                tupla.coluna = 3;
+                tupla.coluna = 5;
             }
 
             self.obedecer_o_ultimo(&mut tupla);
@@ -315,7 +568,7 @@ impl ContraCheque {
 
     fn para_direita(&mut self) {
         if let Editar::Tupla(mut tupla) = self.editar.clone() {
-            if tupla.coluna < 3 {
+            if tupla.coluna < 5 {
                 tupla.coluna += 1;
             } else {
                 tupla.coluna = 0;
@@ -334,7 +587,7 @@ impl ContraCheque {
                 self.obedecer_o_ultimo(&mut tupla);
                 self.editar = Editar::Tupla(tupla);
             } else {
-                self.editar = Editar::Data;
+                self.editar = Editar::Conta;
             }
         }
     }
@@ -342,7 +595,7 @@ impl ContraCheque {
     fn para_baixo(&mut self, quebra: bool) {
         if let Editar::Tupla(mut tupla) = self.editar.clone() {
             tupla.linha += 1;
-            if quebra && (tupla.coluna == 1 || tupla.coluna == 3) {
+            if quebra && (tupla.coluna == 1 || tupla.coluna == 4) {
                 tupla.coluna -= 1;
             }
             self.obedecer_o_ultimo(&mut tupla);
@@ -351,7 +604,7 @@ impl ContraCheque {
     }
 
     fn obedecer_o_ultimo(&mut self, tupla: &mut Tupla) {
-        if tupla.coluna < 2 {
+        if tupla.coluna < 3 {
             while tupla.linha >= self.entradas.len() {
                 tupla.linha -= 1;
             }
@@ -367,8 +620,8 @@ impl ContraCheque {
             match tupla.coluna {
                 0 => self.entradas[tupla.linha].nome.handle_key(key),
                 1 => self.entradas[tupla.linha].valor.handle_key(key),
-                2 => self.saidas[tupla.linha].nome.handle_key(key),
-                3 => self.saidas[tupla.linha].valor.handle_key(key),
+                3 => self.saidas[tupla.linha].nome.handle_key(key),
+                4 => self.saidas[tupla.linha].valor.handle_key(key),
                 _ => {}
             }
         }
@@ -376,34 +629,59 @@ impl ContraCheque {
 
     fn atualizar_listas(&mut self) {
         if self.entradas.len() == 0 {
-            self.entradas.push(ContraChequeItem {
-                nome: Input::new_texto("Desc", String::new()),
-                valor: Input::new_monetario("Valor", 0.0),
-            });
+            self.entradas
+                .push(novo_item(String::new(), &self.categorias));
         } else if let Some(entrada) = self.entradas.last() {
             if entrada.nome.to_string().len() != 0usize {
-                self.entradas.push(ContraChequeItem {
-                    nome: Input::new_texto("Desc", String::new()),
-                    valor: Input::new_monetario("Valor", 0.0),
-                });
+                self.entradas
+                    .push(novo_item(String::new(), &self.categorias));
             }
         }
 
         if self.saidas.len() == 0 {
-            self.saidas.push(ContraChequeItem {
-                nome: Input::new_texto("Desc", String::new()),
-                valor: Input::new_monetario("Valor", 0.0),
-            });
+            self.saidas.push(novo_item(String::new(), &self.categorias));
         } else if let Some(saida) = self.saidas.last() {
             if saida.nome.to_string().len() != 0usize {
-                self.saidas.push(ContraChequeItem {
-                    nome: Input::new_texto("Desc", String::new()),
-                    valor: Input::new_monetario("Valor", 0.0),
-                });
+                self.saidas.push(novo_item(String::new(), &self.categorias));
+            }
+        }
+    }
+
+    /// Recarrega as tabelas de INSS e IRRF quando o ano do pagamento muda.
+    fn atualizar_tabela(&mut self) {
+        if let Ok(data) = self.data_pagamento.to_naivedate() {
+            if data.year() != self.tabela.ano {
+                self.tabela = TabelaTributaria::buscar(data.year());
             }
         }
     }
 
+    fn tipo_selecionado(&self) -> TipoContracheque {
+        TIPOS
+            .iter()
+            .find(|t| self.tipo.id_selecionado_eh(t.to_string()))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// `None` para os tipos que não seguem a tabela mensal.
+    fn conferencia(&self) -> Option<ConferenciaContracheque> {
+        let valores = |itens: &Vec<ContraChequeItem>| -> Vec<(String, f64)> {
+            itens
+                .iter()
+                .map(|i| (i.nome.to_string(), i.valor.to_f64()))
+                .collect()
+        };
+
+        conferir_contracheque(
+            &self.tabela,
+            &self.tipo_selecionado(),
+            &valores(&self.entradas),
+            &valores(&self.saidas),
+            self.dependentes,
+        )
+    }
+
     fn render(&mut self, area: Rect, buf: &mut Buffer) {
         let [cabecalho, valores, resumo] = Layout::vertical([
             Constraint::Length(3),
@@ -412,17 +690,23 @@ impl ContraCheque {
         ])
         .areas(area);
 
-        let [empresa, data, _] = Layout::horizontal([
+        let [empresa, tipo, competencia, data, conta] = Layout::horizontal([
             Constraint::Fill(2),
+            Constraint::Length(20),
             Constraint::Length(15),
-            Constraint::Fill(3),
+            Constraint::Length(15),
+            Constraint::Fill(2),
         ])
         .areas(cabecalho);
 
         self.empresa
             .render(self.editar == Editar::Empresa, empresa, buf);
+        self.tipo.render(self.editar == Editar::Tipo, tipo, buf);
+        self.competencia
+            .render(self.editar == Editar::Competencia, competencia, buf);
         self.data_pagamento
             .render(self.editar == Editar::Data, data, buf);
+        self.conta.render(self.editar == Editar::Conta, conta, buf);
 
         let [entradas, _separador, saidas] = Layout::horizontal([
             Constraint::Fill(1),
@@ -473,20 +757,28 @@ impl ContraCheque {
 
         for (i, item) in itens.iter_mut().enumerate() {
             if let Some(rect) = chunks.get(i + 1) {
-                let [nome, valor] =
-                    Layout::horizontal([Constraint::Fill(3), Constraint::Fill(1)]).areas(*rect);
+                let [nome, valor, categoria] = Layout::horizontal([
+                    Constraint::Fill(3),
+                    Constraint::Fill(2),
+                    Constraint::Fill(3),
+                ])
+                .areas(*rect);
                 item.nome
                     .render(editar.foco(i, &bloco, Tipo::Nome), nome, buf);
                 item.valor
                     .render(editar.foco(i, &bloco, Tipo::Valor), valor, buf);
+                item.categoria
+                    .render(editar.foco(i, &bloco, Tipo::Categoria), categoria, buf);
             }
         }
     }
 
     fn render_resumo(&mut self, area: Rect, buf: &mut Buffer) {
-        let [_, resumo] =
+        let [conferencia, resumo] =
             Layout::horizontal([Constraint::Fill(1), Constraint::Length(30)]).areas(area);
 
+        self.render_conferencia(conferencia, buf);
+
         let [entradas_rect, saidas_rect, total_rect] = Layout::vertical([
             Constraint::Length(2),
             Constraint::Length(2),
@@ -511,6 +803,75 @@ impl ContraCheque {
             .render(total_rect, buf);
     }
 
+    fn render_conferencia(&self, area: Rect, buf: &mut Buffer) {
+        let Some(c) = self.conferencia() else {
+            let texto = match self.tipo_selecionado() {
+                TipoContracheque::DecimoTerceiroPrimeira => {
+                    "A 1ª parcela do 13º não tem INSS nem IRRF; a conferência é na 2ª"
+                }
+                _ => "PLR tem tributação exclusiva na fonte, fora da tabela mensal",
+            };
+            Paragraph::new(texto).fg(GERAL_TEXT_FG).render(area, buf);
+            return;
+        };
+
+        let linha = |imposto: &str, esperado: f64, informado: f64, diferenca: f64| {
+            let texto = format!(
+                "{:<5} esperado R$ {:>9.2}   informado R$ {:>9.2}{}",
+                imposto,
+                esperado,
+                informado,
+                if divergente(diferenca) {
+                    format!("   [ DIFERENÇA R$ {:.2} ]", diferenca)
+                } else {
+                    String::new()
+                }
+            );
+            Line::styled(
+                texto,
+                if divergente(diferenca) {
+                    AMBER.c500
+                } else {
+                    GERAL_TEXT_FG
+                },
+            )
+        };
+
+        Paragraph::new(vec![
+            Line::raw(format!("Conferência pelas tabelas de {}", self.tabela.ano)).bold(),
+            linha(
+                "INSS",
+                c.inss_esperado,
+                c.inss_informado,
+                c.diferenca_inss(),
+            ),
+            linha(
+                "IRRF",
+                c.irrf_esperado,
+                c.irrf_informado,
+                c.diferenca_irrf(),
+            ),
+            Line::styled(
+                format!(
+                    "      base do IRRF R$ {:.2} ({}, {} dependentes)",
+                    c.base_irrf,
+                    if c.simplificado {
+                        "desconto simplificado"
+                    } else {
+                        "deduções legais"
+                    },
+                    self.dependentes
+                ),
+                GERAL_TEXT_FG,
+            ),
+            Line::styled(
+                format!("Alíquota efetiva: {:.2}%", c.aliquota_efetiva),
+                GERAL_TEXT_FG,
+            ),
+        ])
+        .render(area, buf);
+    }
+
     fn build_constraints_for_rows_in_area(item_count: usize) -> Vec<Constraint> {
         let mut constraints = Vec::with_capacity(item_count + 1);
 
@@ -524,6 +885,42 @@ impl ContraCheque {
     }
 }
 
+fn divergente(diferenca: f64) -> bool {
+    diferenca.abs() >= 0.01
+}
+
+fn novo_item(nome: String, categorias: &[ItemListaSuspensa]) -> ContraChequeItem {
+    ContraChequeItem {
+        nome: Input::new_texto("Desc", nome),
+        valor: Input::new_monetario("Valor", 0.0),
+        categoria: ListaSuspensa::new("Categoria", categorias.to_vec(), true),
+        nome_sugerido: String::new(),
+    }
+}
+
+fn categoria_do_item(item: &mut ContraChequeItem) -> OptionalLazy<Categoria> {
+    let id = item.categoria.get_id_selecionado();
+    if id.is_empty() {
+        OptionalLazy::None
+    } else {
+        OptionalLazy::Id(id)
+    }
+}
+
+/// A conta da empresa em "Salario" seguida de todas as contas cadastradas.
+fn contas_destino() -> Vec<ItemListaSuspensa> {
+    let mut contas = vec![ItemListaSuspensa::new2("", "Conta da empresa em Salario")];
+    Banco::listar().iter().for_each(|b| {
+        b.contas.iter().for_each(|c| {
+            contas.push(ItemListaSuspensa::new2(
+                &format!("{}:{}", b.id, c.id),
+                &format!("{} - {}", b.nome, c.nome),
+            ));
+        });
+    });
+    contas
+}
+
 fn buscar_conta(nome: String) -> Conta {
     let mut banco = if let Some(atual) = Banco::buscar_id("Salario".to_string()) {
         atual
@@ -539,6 +936,7 @@ fn buscar_conta(nome: String) -> Conta {
         let conta = Conta {
             id: nome.clone(),
             nome: nome.clone(),
+            ..Default::default()
         };
         banco.contas.push(conta.clone());
         Banco::salvar(banco);
@@ -0,0 +1,137 @@
+use chrono::Local;
+use color_eyre::eyre::Result;
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
+    layout::{Constraint, Layout, Rect},
+    widgets::Widget,
+    DefaultTerminal,
+};
+
+use crate::{
+    componentes::input_wgt::Input,
+    dto::{Ativo, Cotacao},
+    estilo::{principal_comandos, principal_titulo},
+};
+
+#[derive(PartialEq)]
+enum Status {
+    AltData,
+    AltPreco,
+
+    Sair(Option<Cotacao>),
+}
+
+pub struct EditarCotacao {
+    status: Status,
+    ativo: Ativo,
+
+    data: Input,
+    preco: Input,
+}
+
+impl Widget for &mut EditarCotacao {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let [titulo, corpo, rodape] = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Fill(1),
+            Constraint::Length(1),
+        ])
+        .areas(area);
+
+        principal_titulo(&format!("Cotação de {}", self.ativo.nome), titulo, buf);
+        principal_comandos(
+            vec!["(Editar)", "TAB (próximo)", "Esc (sair)", "F5 (salvar)"],
+            rodape,
+            buf,
+        );
+        self.render(corpo, buf)
+    }
+}
+
+impl EditarCotacao {
+    pub fn new(ativo: Ativo, preco: f64) -> Self {
+        Self {
+            status: Status::AltPreco,
+            ativo,
+
+            data: Input::new_data(
+                "Data",
+                Local::now().date_naive().format("%d/%m/%y").to_string(),
+            ),
+            preco: Input::new_monetario("Preço", preco),
+        }
+    }
+
+    pub fn run(mut self, terminal: &mut DefaultTerminal) -> Result<Option<Cotacao>> {
+        while !matches!(self.status, Status::Sair(_)) {
+            if let Err(erro) = terminal.draw(|frame| frame.render_widget(&mut self, frame.area())) {
+                log::error!("Erro ao desenhar tela EditarCotacao: {}", erro);
+            };
+
+            if let Event::Key(key) = event::read()? {
+                self.handle_key(key);
+            };
+        }
+
+        if let Status::Sair(cotacao) = self.status {
+            return Ok(cotacao);
+        }
+        Ok(None)
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        if key.kind != KeyEventKind::Press {
+            return;
+        }
+
+        match key.code {
+            KeyCode::Esc => self.status = Status::Sair(None),
+            KeyCode::Tab | KeyCode::BackTab => self.proximo_input(),
+            KeyCode::F(5) => self.salvar(),
+            _ => self.alterar_input(key),
+        }
+    }
+
+    fn proximo_input(&mut self) {
+        match self.status {
+            Status::AltData => self.status = Status::AltPreco,
+            Status::AltPreco => self.status = Status::AltData,
+
+            Status::Sair(_) => {}
+        }
+    }
+
+    fn salvar(&mut self) {
+        if let Ok(data) = self.data.to_naivedate() {
+            self.status = Status::Sair(Some(Cotacao {
+                ativo: self.ativo.id.clone(),
+                data,
+                preco: self.preco.to_f64(),
+            }));
+        }
+    }
+
+    fn alterar_input(&mut self, key: KeyEvent) {
+        match self.status {
+            Status::AltData => self.data.handle_key(key),
+            Status::AltPreco => self.preco.handle_key(key),
+
+            Status::Sair(_) => {}
+        }
+    }
+
+    fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        let [linha1, _] = Layout::vertical([Constraint::Length(3), Constraint::Fill(1)]).areas(area);
+
+        let [data, preco, _] = Layout::horizontal([
+            Constraint::Length(20),
+            Constraint::Length(20),
+            Constraint::Fill(1),
+        ])
+        .areas(linha1);
+
+        self.data.render(self.status == Status::AltData, data, buf);
+        self.preco.render(self.status == Status::AltPreco, preco, buf);
+    }
+}
@@ -1,11 +1,23 @@
 use crate::{
-    componentes::{check_wgt::Check, input_wgt::Input},
-    dto::{DadosDivida, Divida, ParcelaDivida},
+    calc::{
+        alterar_valor_parcelas, antecipar_parcelas, gerar_parcelas_financiamento,
+        possivelmente_em_atraso, recalcular_saldos, renegociar_divida,
+    },
+    componentes::{
+        check_wgt::Check,
+        input_wgt::Input,
+        lista_suspensa::{ItemListaSuspensa, ListaSuspensa},
+    },
+    dto::{
+        Banco, DadosDivida, Divida, Financiamento, IndiceCorrecao, ParcelaDivida,
+        SistemaAmortizacao,
+    },
     estilo::{
         alternate_colors, principal_comandos, principal_titulo, GERAL_BG, LISTA_BORDA_ESTILO,
         LISTA_SELECIONADO_ESTILO,
     },
 };
+use chrono::{Local, NaiveDate};
 use color_eyre::Result;
 use ratatui::{
     buffer::Buffer,
@@ -30,10 +42,26 @@ enum Status {
     AltInicio,
     AltPagos,
     AltCobrancaAuto,
+    AltSistema,
+    AltTaxa,
+    AltIndice,
+    AltCorrecao,
+    AltPadrao,
+    AltConta,
+    AltOperacao,
+    AltOpQuant,
+    AltOpValor,
+    AltOpInicio,
     AltLista,
+    AltParcelaValor,
+    AltParcelaData,
     Quitar,
-    Sair(Option<Divida>),
+    Sair(Vec<Divida>),
 }
+
+const RENEGOCIAR: &str = "Renegociar";
+const ANTECIPAR: &str = "Antecipar";
+
 pub struct EditarDivida {
     status: Status,
 
@@ -45,9 +73,23 @@ pub struct EditarDivida {
     inicio: Input,
     pagos: Input,
     cobranca_auto: Check,
+    sistema: ListaSuspensa,
+    taxa: Input,
+    indice: ListaSuspensa,
+    correcao: Input,
+    padrao: Input,
+    conta: ListaSuspensa,
+    operacao: ListaSuspensa,
+    op_quant: Input,
+    op_valor: Input,
+    op_inicio: Input,
+    parcela_valor: Input,
+    parcela_data: Input,
     aberto: Input,
     pago: Input,
     total: Input,
+    saldo_devedor: Input,
+    juros_a_pagar: Input,
     quitar: Check,
     state: ListState,
 }
@@ -67,9 +109,13 @@ impl Widget for &mut EditarDivida {
                 Status::AltLista => vec![
                     "↓↑ (mover)",
                     "Enter (alterar pago)",
+                    "E (editar parcela)",
                     "ESC Sair",
                     "F5 (salvar)",
                 ],
+                Status::AltParcelaValor | Status::AltParcelaData => {
+                    vec!["Editar", "Tab (próximo)", "Enter (aplicar)", "ESC Sair"]
+                }
                 _ => vec!["Editar", "Tab (próximo)", "ESC Sair", "F5 (salvar)"],
             },
             rodape,
@@ -86,20 +132,44 @@ impl EditarDivida {
             divida: Divida::default(),
             nome: Input::new_texto("Nome", String::new()),
             prioritario: Check::new("Prioritário", false),
-            quant: Input::new_inteiro("Quant", 0),
-            valor: Input::new_monetario("valor", 0.0),
+            quant: Input::new_inteiro("Quant (prazo)", 0),
+            valor: Input::new_monetario("Valor (parcela ou financiado)", 0.0),
             inicio: Input::new_data("Início", "00/00/00".to_string()),
             pagos: Input::new_inteiro("Pagos", 0),
             cobranca_auto: Check::new("Cobrança automática", false),
+            sistema: lista_sistemas(),
+            taxa: Input::new_monetario("Juros % a.m.", 0.0),
+            indice: lista_indices(),
+            correcao: Input::new_monetario("Índice % a.m. (estimado)", 0.0),
+            padrao: Input::new_texto("Descrição do débito no extrato", String::new()),
+            conta: lista_contas(),
+            operacao: lista_operacoes(),
+            op_quant: Input::new_inteiro("Parcelas", 0),
+            op_valor: Input::new_monetario("Valor (nova parcela ou total pago)", 0.0),
+            op_inicio: Input::new_data("Início do novo cronograma", "00/00/00".to_string()),
+            parcela_valor: Input::new_monetario("Valor da parcela", 0.0),
+            parcela_data: Input::new_data("Vencimento", "00/00/00".to_string()),
             aberto: Input::new_texto("Aberto", String::new()),
             pago: Input::new_texto("Pago", String::new()),
             total: Input::new_texto("Total", String::new()),
+            saldo_devedor: Input::new_texto("Saldo devedor", String::new()),
+            juros_a_pagar: Input::new_texto("Juros a pagar", String::new()),
             quitar: Check::new("Quitar dívida", false),
             state: ListState::default(),
         }
     }
 
     pub fn from(divida: &Divida) -> Self {
+        let mut sistema = lista_sistemas();
+        let mut indice = lista_indices();
+        let financiamento = divida.financiamento.clone().unwrap_or_default();
+        if divida.financiamento.is_some() {
+            sistema.set_id_selecionado(financiamento.sistema.to_string());
+            indice.set_id_selecionado(financiamento.indice.to_string());
+        }
+        let mut conta = lista_contas();
+        conta.set_id_selecionado(divida.conta.clone().unwrap_or_default());
+
         Self {
             status: Status::AltNome,
             divida: divida.clone(),
@@ -118,38 +188,65 @@ impl EditarDivida {
             ),
             pagos: Input::new_inteiro("Pagos", divida.parcelas.pagas().quant()),
             cobranca_auto: Check::new("Cobrança automática", divida.cobranca_automatica),
+            sistema,
+            taxa: Input::new_monetario("Juros % a.m.", financiamento.taxa_mensal),
+            indice,
+            correcao: Input::new_monetario(
+                "Índice % a.m. (estimado)",
+                financiamento.correcao_mensal,
+            ),
+            padrao: Input::new_texto(
+                "Descrição do débito no extrato",
+                divida.padrao_descricao.clone(),
+            ),
+            conta,
+            operacao: lista_operacoes(),
+            op_quant: Input::new_inteiro("Parcelas", 0),
+            op_valor: Input::new_monetario("Valor (nova parcela ou total pago)", 0.0),
+            op_inicio: Input::new_data("Início do novo cronograma", "00/00/00".to_string()),
+            parcela_valor: Input::new_monetario("Valor da parcela", 0.0),
+            parcela_data: Input::new_data("Vencimento", "00/00/00".to_string()),
             aberto: Input::new_monetario("Aberto", divida.parcelas.aberta().valor_total()),
             pago: Input::new_monetario("Pago", divida.parcelas.pagas().valor_total()),
             total: Input::new_monetario("Total", divida.parcelas.valor_total()),
+            saldo_devedor: Input::new_monetario(
+                "Saldo devedor",
+                divida.parcelas.aberta().saldo_devedor(),
+            ),
+            juros_a_pagar: Input::new_monetario(
+                "Juros a pagar",
+                divida.parcelas.aberta().juros_total(),
+            ),
             quitar: Check::new("Quitar dívida", false),
             state: ListState::default(),
         }
     }
 
-    pub fn run(mut self, terminal: &mut DefaultTerminal) -> Result<Option<Divida>> {
+    /// Retorna as dívidas a salvar: a editada e, numa renegociação, a nova; vazio se cancelado.
+    pub fn run(mut self, terminal: &mut DefaultTerminal) -> Result<Vec<Divida>> {
         while !matches!(self.status, Status::Sair(_)) {
             if let Err(erro) = terminal.draw(|frame| frame.render_widget(&mut self, frame.area())){
                 log::error!("Erro ao desenhar tela Editar Divida: {}", erro);
             };
             
             if let Event::Key(key) = event::read()? {
-                self.handle_key(key);
+                self.handle_key(key, terminal);
             };
         }
 
-        if let Status::Sair(divida) = self.status {
-            return Ok(divida);
+        if let Status::Sair(dividas) = self.status {
+            return Ok(dividas);
         }
-        Ok(None)
+        Ok(Vec::new())
     }
-    pub fn handle_key(&mut self, key: KeyEvent) {
+    pub fn handle_key(&mut self, key: KeyEvent, terminal: &mut DefaultTerminal) {
         if key.kind != KeyEventKind::Press {
             return;
         }
 
         match key.code {
             KeyCode::F(5) => self.salvar(),
-            KeyCode::Esc => self.status = Status::Sair(None),
+            KeyCode::Esc => self.status = Status::Sair(Vec::new()),
             _ => match self.status {
                 Status::AltNome => self.handle_key_alt_nome(key),
                 Status::AltPrioritario => self.handle_key_alt_prioritario(key),
@@ -158,7 +255,19 @@ impl EditarDivida {
                 Status::AltInicio => self.handle_key_alt_inicio(key),
                 Status::AltPagos => self.handle_key_alt_pagos(key),
                 Status::AltCobrancaAuto => self.handle_key_alt_cobranca_auto(key),
+                Status::AltSistema => self.handle_key_alt_sistema(key, terminal),
+                Status::AltTaxa => self.handle_key_alt_taxa(key),
+                Status::AltIndice => self.handle_key_alt_indice(key, terminal),
+                Status::AltCorrecao => self.handle_key_alt_correcao(key),
+                Status::AltPadrao => self.handle_key_alt_padrao(key),
+                Status::AltConta => self.handle_key_alt_conta(key, terminal),
+                Status::AltOperacao => self.handle_key_alt_operacao(key, terminal),
+                Status::AltOpQuant => self.handle_key_alt_op_quant(key),
+                Status::AltOpValor => self.handle_key_alt_op_valor(key),
+                Status::AltOpInicio => self.handle_key_alt_op_inicio(key),
                 Status::AltLista => self.handle_key_alt_lista(key),
+                Status::AltParcelaValor => self.handle_key_alt_parcela_valor(key),
+                Status::AltParcelaData => self.handle_key_alt_parcela_data(key),
                 Status::Quitar => self.handle_key_alt_quitar(key),
                 Status::Sair(_) => {}
             },
@@ -242,7 +351,7 @@ impl EditarDivida {
     fn handle_key_alt_cobranca_auto(&mut self, key: KeyEvent) {
         if self.divida.id.is_empty() {
             match key.code {
-                KeyCode::Tab | KeyCode::Down | KeyCode::Right => self.status = Status::AltNome,
+                KeyCode::Tab | KeyCode::Down | KeyCode::Right => self.status = Status::AltSistema,
                 KeyCode::BackTab | KeyCode::Left => self.status = Status::AltPagos,
                 KeyCode::Up => self.status = Status::AltValor,
                 _ => self.cobranca_auto.handle_key(key),
@@ -258,26 +367,144 @@ impl EditarDivida {
         }
     }
 
+    fn handle_key_alt_sistema(&mut self, key: KeyEvent, terminal: &mut DefaultTerminal) {
+        match key.code {
+            KeyCode::Tab | KeyCode::Right => self.status = Status::AltTaxa,
+            KeyCode::BackTab | KeyCode::Left => self.status = Status::AltCobrancaAuto,
+            _ => self.sistema.handle_key(key, terminal),
+        }
+    }
+
+    fn handle_key_alt_taxa(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Tab => self.status = Status::AltIndice,
+            KeyCode::Down => self.status = Status::AltNome,
+            KeyCode::BackTab | KeyCode::Up => self.status = Status::AltSistema,
+            _ => self.taxa.handle_key(key),
+        }
+    }
+
+    fn handle_key_alt_indice(&mut self, key: KeyEvent, terminal: &mut DefaultTerminal) {
+        match key.code {
+            KeyCode::Tab | KeyCode::Right => self.status = Status::AltCorrecao,
+            KeyCode::BackTab | KeyCode::Left => self.status = Status::AltTaxa,
+            _ => self.indice.handle_key(key, terminal),
+        }
+    }
+
+    fn handle_key_alt_correcao(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Tab | KeyCode::Down => self.status = Status::AltPadrao,
+            KeyCode::BackTab | KeyCode::Up => self.status = Status::AltIndice,
+            _ => self.correcao.handle_key(key),
+        }
+    }
+
+    fn handle_key_alt_padrao(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Tab | KeyCode::Down => self.status = Status::AltConta,
+            KeyCode::BackTab | KeyCode::Up => {
+                if self.divida.id.is_empty() {
+                    self.status = Status::AltCorrecao;
+                } else {
+                    self.status = Status::Quitar;
+                }
+            }
+            _ => self.padrao.handle_key(key),
+        }
+    }
+
+    fn handle_key_alt_conta(&mut self, key: KeyEvent, terminal: &mut DefaultTerminal) {
+        match key.code {
+            KeyCode::Tab | KeyCode::Right => {
+                if self.divida.id.is_empty() {
+                    self.status = Status::AltNome;
+                } else {
+                    self.status = Status::AltOperacao;
+                }
+            }
+            KeyCode::BackTab | KeyCode::Left => self.status = Status::AltPadrao,
+            _ => self.conta.handle_key(key, terminal),
+        }
+    }
+
+    fn handle_key_alt_operacao(&mut self, key: KeyEvent, terminal: &mut DefaultTerminal) {
+        match key.code {
+            KeyCode::Tab | KeyCode::Right => self.status = Status::AltOpQuant,
+            KeyCode::BackTab | KeyCode::Left => self.status = Status::AltConta,
+            _ => self.operacao.handle_key(key, terminal),
+        }
+    }
+
+    fn handle_key_alt_op_quant(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Tab => self.status = Status::AltOpValor,
+            KeyCode::Down => self.set_alterar_lista(),
+            KeyCode::BackTab | KeyCode::Up => self.status = Status::AltOperacao,
+            _ => self.op_quant.handle_key(key),
+        }
+    }
+
+    fn handle_key_alt_op_valor(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Tab => self.status = Status::AltOpInicio,
+            KeyCode::Down => self.set_alterar_lista(),
+            KeyCode::BackTab => self.status = Status::AltOpQuant,
+            KeyCode::Up => self.status = Status::AltOperacao,
+            _ => self.op_valor.handle_key(key),
+        }
+    }
+
+    fn handle_key_alt_op_inicio(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Tab | KeyCode::Down => self.set_alterar_lista(),
+            KeyCode::BackTab => self.status = Status::AltOpValor,
+            KeyCode::Up => self.status = Status::AltOperacao,
+            _ => self.op_inicio.handle_key(key),
+        }
+    }
+
     fn handle_key_alt_lista(&mut self, key: KeyEvent) {
         match key.code {
             KeyCode::Down => self.select_next(),
             KeyCode::Up => self.select_previous(),
             KeyCode::Enter => self.mudar_pagamento(),
+            KeyCode::Char('e') | KeyCode::Char('E') => self.editar_parcela(),
             KeyCode::Tab | KeyCode::Right => {
                 self.status = Status::AltNome;
                 self.state.select(None);
             }
             KeyCode::BackTab | KeyCode::Left => {
-                self.status = Status::Quitar;
+                if self.divida.id.is_empty() {
+                    self.status = Status::AltConta;
+                } else {
+                    self.status = Status::AltOpInicio;
+                }
                 self.state.select(None);
             }
             _ => {}
         }
     }
 
+    fn handle_key_alt_parcela_valor(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Tab | KeyCode::BackTab => self.status = Status::AltParcelaData,
+            KeyCode::Enter => self.aplicar_parcela(),
+            _ => self.parcela_valor.handle_key(key),
+        }
+    }
+
+    fn handle_key_alt_parcela_data(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Tab | KeyCode::BackTab => self.status = Status::AltParcelaValor,
+            KeyCode::Enter => self.aplicar_parcela(),
+            _ => self.parcela_data.handle_key(key),
+        }
+    }
+
     fn handle_key_alt_quitar(&mut self, key: KeyEvent) {
         match key.code {
-            KeyCode::Tab | KeyCode::Down => self.set_alterar_lista(),
+            KeyCode::Tab | KeyCode::Down => self.status = Status::AltPadrao,
             KeyCode::BackTab => self.status = Status::AltCobrancaAuto,
             KeyCode::Up => self.status = Status::AltValor,
             _ => self.quitar.handle_key(key),
@@ -320,7 +547,7 @@ impl EditarDivida {
     fn render(&mut self, area: Rect, buf: &mut Buffer) {
         let [titulo, parcelas, resumo, lista_parcelas] = Layout::vertical([
             Constraint::Length(3),
-            Constraint::Length(8),
+            Constraint::Length(17),
             Constraint::Length(5),
             Constraint::Fill(1),
         ])
@@ -344,10 +571,13 @@ impl EditarDivida {
      }
 
     fn render_parcelas(&mut self, area: Rect, buf: &mut Buffer) {
-        let [area1, area2, area3] = Layout::vertical([
+        let [area1, area2, area3, area4, area5, area6] = Layout::vertical([
             Constraint::Length(2),
             Constraint::Length(3),
             Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(3),
         ])
         .areas(area);
 
@@ -384,6 +614,50 @@ impl EditarDivida {
 
         self.quitar
             .render(self.status == Status::Quitar, quitar, buf);
+
+        let [sistema, taxa, indice, correcao] = Layout::horizontal([
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+        ])
+        .areas(area4);
+
+        self.sistema
+            .render(self.status == Status::AltSistema, sistema, buf);
+        self.taxa.render(self.status == Status::AltTaxa, taxa, buf);
+        self.indice
+            .render(self.status == Status::AltIndice, indice, buf);
+        self.correcao
+            .render(self.status == Status::AltCorrecao, correcao, buf);
+
+        let [padrao, conta] =
+            Layout::horizontal([Constraint::Fill(2), Constraint::Fill(1)]).areas(area5);
+
+        self.padrao
+            .render(self.status == Status::AltPadrao, padrao, buf);
+        self.conta.render(self.status == Status::AltConta, conta, buf);
+
+        if self.divida.id.is_empty() {
+            return;
+        }
+
+        let [operacao, op_quant, op_valor, op_inicio] = Layout::horizontal([
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+        ])
+        .areas(area6);
+
+        self.operacao
+            .render(self.status == Status::AltOperacao, operacao, buf);
+        self.op_quant
+            .render(self.status == Status::AltOpQuant, op_quant, buf);
+        self.op_valor
+            .render(self.status == Status::AltOpValor, op_valor, buf);
+        self.op_inicio
+            .render(self.status == Status::AltOpInicio, op_inicio, buf);
     }
 
     fn render_resumo(&mut self, area: Rect, buf: &mut Buffer) {
@@ -395,7 +669,9 @@ impl EditarDivida {
             .left_aligned()
             .render(area1, buf);
 
-        let [aberto, pago, total] = Layout::horizontal([
+        let [aberto, pago, total, saldo_devedor, juros_a_pagar] = Layout::horizontal([
+            Constraint::Fill(1),
+            Constraint::Fill(1),
             Constraint::Fill(1),
             Constraint::Fill(1),
             Constraint::Fill(1),
@@ -405,9 +681,26 @@ impl EditarDivida {
         self.aberto.render(false, aberto, buf);
         self.pago.render(false, pago, buf);
         self.total.render(false, total, buf);
+        self.saldo_devedor.render(false, saldo_devedor, buf);
+        self.juros_a_pagar.render(false, juros_a_pagar, buf);
     }
 
     fn render_lista_parcelas(&mut self, area: Rect, buf: &mut Buffer) {
+        let area = if matches!(self.status, Status::AltParcelaValor | Status::AltParcelaData) {
+            let [edicao, area] =
+                Layout::vertical([Constraint::Length(3), Constraint::Fill(1)]).areas(area);
+            let [valor, data] =
+                Layout::horizontal([Constraint::Fill(1), Constraint::Fill(1)]).areas(edicao);
+
+            self.parcela_valor
+                .render(self.status == Status::AltParcelaValor, valor, buf);
+            self.parcela_data
+                .render(self.status == Status::AltParcelaData, data, buf);
+            area
+        } else {
+            area
+        };
+
         let block = Block::new()
             .title(Line::raw("Parcelas").centered())
             .borders(Borders::TOP)
@@ -415,14 +708,15 @@ impl EditarDivida {
             .border_style(LISTA_BORDA_ESTILO)
             .bg(GERAL_BG);
 
+        let hoje = Local::now().date_naive();
         let items: Vec<ListItem> = self
             .divida
             .parcelas
             .iter()
             .enumerate()
-            .map(|(i, todo_item)| {
+            .map(|(i, parcela)| {
                 let color = alternate_colors(i);
-                ListItem::from(todo_item).bg(color)
+                item_parcela(&self.divida, parcela, hoje).bg(color)
             })
             .collect();
 
@@ -447,15 +741,49 @@ impl EditarDivida {
         self.divida.nome = self.nome.to_string();
         self.divida.cobranca_automatica = self.cobranca_auto.get_checked();
         self.divida.prioritaria = self.prioritario.get_checked();
+        self.conciliacao();
 
-        if let Some(aberta) = self.divida.parcelas.aberta().first() {
+        // parcelas de financiamento têm juros e amortização próprios
+        if let Some(aberta) = self
+            .divida
+            .parcelas
+            .aberta()
+            .first()
+            .filter(|_| self.divida.financiamento.is_none())
+        {
             if self.valor.to_f64() != aberta.valor {
-                self.divida.parcelas.iter_mut().for_each(|p| {
-                    if !p.pago {
-                        p.valor = self.valor.to_f64();
+                alterar_valor_parcelas(&mut self.divida, self.valor.to_f64());
+            }
+        }
+
+        let mut dividas: Vec<Divida> = Vec::new();
+        match self.operacao.get_id_selecionado().as_str() {
+            RENEGOCIAR => {
+                let Ok(inicio) = self.op_inicio.to_naivedate() else {
+                    self.status = Status::AltOpInicio;
+                    return;
+                };
+                match renegociar_divida(
+                    &mut self.divida,
+                    self.op_quant.to_i32(),
+                    self.op_valor.to_f64(),
+                    inicio,
+                ) {
+                    Some(nova) => dividas.push(nova),
+                    None => {
+                        self.status = Status::AltOpQuant;
+                        return;
                     }
-                });
+                }
+            }
+            ANTECIPAR => {
+                let quant = self.op_quant.to_i32().max(0) as usize;
+                if antecipar_parcelas(&mut self.divida, quant, self.op_valor.to_f64()).is_none() {
+                    self.status = Status::AltOpQuant;
+                    return;
+                }
             }
+            _ => {}
         }
 
         if self.quitar.get_checked() {
@@ -464,13 +792,28 @@ impl EditarDivida {
             }
         }
 
-        self.status = Status::Sair(Some(self.divida.clone()));
+        dividas.insert(0, self.divida.clone());
+        self.status = Status::Sair(dividas);
     }
 
     fn salvar_novo(&mut self) {
         match self.inicio.to_naivedate() {
+            Ok(data) if !self.sistema.id_selecionado_eh(String::new()) => {
+                let financiamento = self.financiamento();
+                let parcelas =
+                    gerar_parcelas_financiamento(&financiamento, data, self.pagos.to_i32());
+                self.divida = Divida::new_financiamento(
+                    self.nome.to_string(),
+                    self.prioritario.get_checked(),
+                    self.cobranca_auto.get_checked(),
+                    financiamento,
+                    parcelas,
+                );
+                self.conciliacao();
+                self.status = Status::Sair(vec![self.divida.clone()]);
+            }
             Ok(data) => {
-                self.status = Status::Sair(Some(Divida::new(
+                self.divida = Divida::new(
                     self.nome.to_string(),
                     self.prioritario.get_checked(),
                     self.cobranca_auto.get_checked(),
@@ -478,7 +821,9 @@ impl EditarDivida {
                     self.valor.to_f64(),
                     data,
                     self.pagos.to_i32(),
-                )));
+                );
+                self.conciliacao();
+                self.status = Status::Sair(vec![self.divida.clone()]);
             }
             Err(_) => {
                 self.status = Status::AltInicio;
@@ -486,27 +831,162 @@ impl EditarDivida {
         }
     }
 
+    fn conciliacao(&mut self) {
+        self.divida.padrao_descricao = self.padrao.to_string().trim().to_string();
+        let conta = self.conta.get_id_selecionado();
+        self.divida.conta = if conta.is_empty() { None } else { Some(conta) };
+    }
+
+    /// Com sistema escolhido, o valor informado é o total financiado e a quantidade, o prazo.
+    fn financiamento(&mut self) -> Financiamento {
+        Financiamento {
+            principal: self.valor.to_f64(),
+            taxa_mensal: self.taxa.to_f64(),
+            prazo: self.quant.to_i32(),
+            sistema: if self.sistema.id_selecionado_eh(SistemaAmortizacao::Sac.to_string()) {
+                SistemaAmortizacao::Sac
+            } else {
+                SistemaAmortizacao::Price
+            },
+            indice: match self.indice.get_id_selecionado().as_str() {
+                "TR" => IndiceCorrecao::Tr,
+                "IPCA" => IndiceCorrecao::Ipca,
+                _ => IndiceCorrecao::Nenhum,
+            },
+            correcao_mensal: self.correcao.to_f64(),
+        }
+    }
+
     fn mudar_pagamento(&mut self) {
         if let Some(i) = self.state.selected() {
             self.divida.parcelas[i].pago = !self.divida.parcelas[i].pago;
         }
 
+        self.atualizar_resumo();
+    }
+
+    fn editar_parcela(&mut self) {
+        if let Some(parcela) = self.state.selected().and_then(|i| self.divida.parcelas.get(i)) {
+            self.parcela_valor.set_monetario(parcela.valor);
+            self.parcela_data
+                .set_data(parcela.data_vencimento.format("%d/%m/%y").to_string());
+            self.status = Status::AltParcelaValor;
+        }
+    }
+
+    /// Parcelas variáveis: o novo valor mantém os juros e ajusta a amortização.
+    fn aplicar_parcela(&mut self) {
+        let Ok(data) = self.parcela_data.to_naivedate() else {
+            self.status = Status::AltParcelaData;
+            return;
+        };
+
+        if let Some(i) = self.state.selected() {
+            let primeira_aberta = self.divida.parcelas.iter().position(|p| !p.pago) == Some(i);
+            let parcela = &mut self.divida.parcelas[i];
+            parcela.valor = self.parcela_valor.to_f64();
+            parcela.amortizacao = (parcela.valor - parcela.juros).max(0.0);
+            parcela.data_vencimento = data;
+
+            // evita que o valor da tela sobrescreva as parcelas ao salvar
+            if primeira_aberta {
+                self.valor.set_monetario(parcela.valor);
+            }
+            recalcular_saldos(&mut self.divida);
+        }
+
+        self.atualizar_resumo();
+        self.status = Status::AltLista;
+    }
+
+    fn atualizar_resumo(&mut self) {
         self.aberto
             .set_monetario(self.divida.parcelas.aberta().valor_total());
         self.pago
             .set_monetario(self.divida.parcelas.pagas().valor_total());
         self.total.set_monetario(self.divida.parcelas.valor_total());
+        self.saldo_devedor
+            .set_monetario(self.divida.parcelas.aberta().saldo_devedor());
+        self.juros_a_pagar
+            .set_monetario(self.divida.parcelas.aberta().juros_total());
     }
 }
 
-impl From<&ParcelaDivida> for ListItem<'_> {
-    fn from(parcela: &ParcelaDivida) -> Self {
-        ListItem::new(format!(
-            "{:02} - {} {}{}",
-            parcela.num_parcela,
-            parcela.data_vencimento.format("%d/%m/%Y"),
-            parcela.valor,
-            if parcela.pago { " (Pago)" } else { "" }
-        ))
-    }
+fn lista_sistemas() -> ListaSuspensa {
+    ListaSuspensa::new(
+        "Sistema",
+        vec![
+            ItemListaSuspensa::new2("", "Parcelas fixas, sem juros"),
+            ItemListaSuspensa::new(&SistemaAmortizacao::Price.to_string()),
+            ItemListaSuspensa::new(&SistemaAmortizacao::Sac.to_string()),
+        ],
+        false,
+    )
+}
+
+fn lista_contas() -> ListaSuspensa {
+    let mut contas = vec![ItemListaSuspensa::new2("", "Qualquer conta")];
+    Banco::listar().iter().for_each(|b| {
+        b.contas.iter().for_each(|c| {
+            contas.push(ItemListaSuspensa::new2(
+                &c.id,
+                &format!("{} - {}", b.nome, c.nome),
+            ))
+        })
+    });
+    ListaSuspensa::new("Conta do débito", contas, false)
+}
+
+fn lista_operacoes() -> ListaSuspensa {
+    ListaSuspensa::new(
+        "Operação",
+        vec![
+            ItemListaSuspensa::new2("", "Nenhuma"),
+            ItemListaSuspensa::new(RENEGOCIAR),
+            ItemListaSuspensa::new(ANTECIPAR),
+        ],
+        false,
+    )
+}
+
+fn lista_indices() -> ListaSuspensa {
+    ListaSuspensa::new(
+        "Índice",
+        vec![
+            ItemListaSuspensa::new(&IndiceCorrecao::Nenhum.to_string()),
+            ItemListaSuspensa::new(&IndiceCorrecao::Tr.to_string()),
+            ItemListaSuspensa::new(&IndiceCorrecao::Ipca.to_string()),
+        ],
+        false,
+    )
+}
+
+fn item_parcela<'a>(divida: &Divida, parcela: &ParcelaDivida, hoje: NaiveDate) -> ListItem<'a> {
+    ListItem::new(format!(
+        "{:02} - {} {}{}{}",
+        parcela.num_parcela,
+        parcela.data_vencimento.format("%d/%m/%Y"),
+        parcela.valor,
+        if parcela.juros > 0.0 {
+            format!(
+                " (juros {:.2}, amortização {:.2}, saldo {:.2})",
+                parcela.juros, parcela.amortizacao, parcela.saldo_devedor
+            )
+        } else {
+            String::new()
+        },
+        if parcela.renegociada {
+            " (Renegociada)".to_string()
+        } else if parcela.pago && parcela.desconto > 0.0 {
+            format!(" (Pago, antecipada com desconto de {:.2})", parcela.desconto)
+        } else if parcela.pago && parcela.lancamento.is_some() {
+            " (Pago, conciliado)".to_string()
+        } else if parcela.pago {
+            " (Pago)".to_string()
+        } else if possivelmente_em_atraso(divida, parcela, hoje) {
+            " [ POSSIVELMENTE EM ATRASO ]".to_string()
+        } else {
+            String::new()
+        }
+    ))
 }
@@ -1,3 +1,4 @@
+use chrono::Local;
 use color_eyre::Result;
 use ratatui::{
     buffer::Buffer,
@@ -13,12 +14,17 @@ use ratatui::{
 
 use crate::{
     calc::{
-        self, calcular_gasto_por_categoria_ano, calcular_gasto_por_categoria_d30,
-        calcular_gasto_por_conta_d30, calcular_resumo, ordenar_orientacoes,
+        self, avaliar_metas, calcular_gasto_por_categoria_ano, calcular_gasto_por_categoria_d30,
+        calcular_gasto_por_conta_d30, calcular_objetivos, calcular_orcamentos, calcular_patrimonio,
+        calcular_posicoes, calcular_resumo, historico_contracheques, ordem_grupos_categoria,
+        ordenar_orientacoes, projetar_fluxo_caixa, simular_quitacao,
     },
     dto::{
-        Categoria, Configuracao, DashDivida, DashGastoPor, DashGastoPorCategoria,
-        DashGastoPorCategoriaAno, DashResumo, Divida, Lancamento, OptionalLazy, Orientacao, ParcelaDivida,
+        Ativo, Banco, Categoria, Configuracao, Contracheque, Cotacao, DashContracheque, DashDivida,
+        DashFluxoCaixa, DashGastoPor, DashGastoPorCategoria, DashGastoPorCategoriaAno,
+        DashInvestimento, DashMeta, DashObjetivo, DashOrcamento, DashPatrimonio, DashQuitacao,
+        DashResumo, Divida, Lancamento, Meta, Objetivo, OperacaoInvestimento, OptionalLazy,
+        Orcamento, Orientacao, ParcelaDivida, PlanoQuitacao,
     },
     estilo::{
         alternate_colors, principal_comandos, principal_titulo, GERAL_BG, GERAL_TEXT_FG,
@@ -34,7 +40,15 @@ enum Etapa {
     Resumo,
     GastoPorConta,
     GastoPorCategoria,
+    Orcamentos,
+    Metas,
+    Objetivos,
     Dividas,
+    Quitacao,
+    FluxoCaixa,
+    Patrimonio,
+    Investimentos,
+    Contracheques,
     Orientacoes,
     Finalizado,
     Sair,
@@ -48,7 +62,15 @@ impl Etapa {
             Etapa::Resumo => "Resumo dos Gastos".to_string(),
             Etapa::GastoPorConta => "Gasto por conta".to_string(),
             Etapa::GastoPorCategoria => "Gasto por categoria".to_string(),
+            Etapa::Orcamentos => "Orçado x realizado".to_string(),
+            Etapa::Metas => "Metas".to_string(),
+            Etapa::Objetivos => "Objetivos de poupança".to_string(),
             Etapa::Dividas => "Dívidas".to_string(),
+            Etapa::Quitacao => "Plano de quitação".to_string(),
+            Etapa::FluxoCaixa => "Fluxo de caixa projetado".to_string(),
+            Etapa::Patrimonio => "Patrimônio".to_string(),
+            Etapa::Investimentos => "Investimentos".to_string(),
+            Etapa::Contracheques => "Contra-cheques".to_string(),
             Etapa::Orientacoes => "Orientações".to_string(),
             Etapa::Finalizado => "Finalizado".to_string(),
             Etapa::Sair => "Sair".to_string(),
@@ -62,6 +84,7 @@ pub struct GeradorDash {
     sair: bool,
 
     config: Configuracao,
+    dividas: Vec<Divida>,
     lista_dividas: Vec<ParcelaDivida>,
     lista_lancamentos: Vec<Lancamento>,
     categorias: Vec<Categoria>,
@@ -103,7 +126,15 @@ impl GeradorDash {
                 Etapa::Resumo,
                 Etapa::GastoPorConta,
                 Etapa::GastoPorCategoria,
+                Etapa::Orcamentos,
+                Etapa::Metas,
+                Etapa::Objetivos,
                 Etapa::Dividas,
+                Etapa::Quitacao,
+                Etapa::FluxoCaixa,
+                Etapa::Patrimonio,
+                Etapa::Investimentos,
+                Etapa::Contracheques,
                 Etapa::Orientacoes,
                 Etapa::Finalizado,
                 Etapa::Sair,
@@ -112,9 +143,10 @@ impl GeradorDash {
             sair: false,
 
             config: Configuracao::buscar(),
+            dividas: Vec::new(),
             lista_dividas: Vec::new(),
             lista_lancamentos: lancamentos,
-            categorias: Categoria::listar(),
+            categorias,
             orientacoes: Vec::new(),
         }
     }
@@ -133,26 +165,53 @@ impl GeradorDash {
         Ok(())
     }
 
+    /// Gera o dash sem terminal, para a linha de comando; devolve os nomes das etapas executadas.
+    pub fn gerar(mut self) -> Vec<String> {
+        let mut resp = Vec::new();
+        for i in 0..self.items.len() {
+            if matches!(self.items[i], Etapa::Finalizado | Etapa::Sair) {
+                continue;
+            }
+            self.processar(i);
+            resp.push(self.items[i].to_string());
+        }
+        resp
+    }
+
     fn executar_etapa(&mut self, terminal: &mut DefaultTerminal) {
         if let Some(i) = self.state.selected() {
             match self.items[i] {
-                Etapa::Iniciando => self.inicializar(),
-                Etapa::Base => self.atualizar_base(),
-                Etapa::Resumo => self.resumo_valores(),
-                Etapa::Dividas => self.calcular_dividas(),
-                Etapa::Orientacoes => self.gerar_orientacoes(),
-                Etapa::GastoPorConta => self.calcular_gasto_por_conta(),
-                Etapa::GastoPorCategoria => self.calcular_gasto_por_categoria(),
                 Etapa::Finalizado => {
                     let _ = Alerta::atencao(vec!["Dashboard concluído".to_string()]).run(terminal);
                 }
-
                 Etapa::Sair => self.sair = true,
+                _ => self.processar(i),
             }
             self.state.select_next();
         }
     }
 
+    fn processar(&mut self, i: usize) {
+        match self.items[i] {
+            Etapa::Iniciando => self.inicializar(),
+            Etapa::Base => self.atualizar_base(),
+            Etapa::Resumo => self.resumo_valores(),
+            Etapa::Dividas => self.calcular_dividas(),
+            Etapa::Quitacao => self.simular_quitacao(),
+            Etapa::FluxoCaixa => self.projetar_fluxo_caixa(),
+            Etapa::Patrimonio => self.calcular_patrimonio(),
+            Etapa::Investimentos => self.calcular_investimentos(),
+            Etapa::Contracheques => self.historico_contracheques(),
+            Etapa::Orientacoes => self.gerar_orientacoes(),
+            Etapa::GastoPorConta => self.calcular_gasto_por_conta(),
+            Etapa::GastoPorCategoria => self.calcular_gasto_por_categoria(),
+            Etapa::Orcamentos => self.calcular_orcamentos(),
+            Etapa::Metas => self.avaliar_metas(),
+            Etapa::Objetivos => self.calcular_objetivos(),
+            Etapa::Finalizado | Etapa::Sair => {}
+        }
+    }
+
     fn render_list(&mut self, area: Rect, buf: &mut Buffer) {
         let block = Block::new()
             .title(Line::raw("Gerar dash").centered())
@@ -185,7 +244,9 @@ impl GeradorDash {
      ******************************************************************************************************************/
 
     fn inicializar(&mut self) {
-        self.lista_dividas = Divida::listar()
+        self.dividas = Divida::listar();
+        self.lista_dividas = self
+            .dividas
             .iter()
             .flat_map(|d| d.parcelas.clone())
             .collect();
@@ -206,11 +267,76 @@ impl GeradorDash {
         ));
     }
 
+    fn simular_quitacao(&mut self) {
+        DashQuitacao::salvar(simular_quitacao(
+            &self.dividas,
+            &PlanoQuitacao::buscar(),
+            Local::now().date_naive(),
+        ));
+    }
+
+    fn projetar_fluxo_caixa(&mut self) {
+        // o saldo de partida depende de todos os lançamentos, categorizados ou não
+        let mut lancamentos = self.lista_lancamentos.clone();
+        lancamentos.extend(Lancamento::nao_categorizados_listar());
+
+        DashFluxoCaixa::salvar(projetar_fluxo_caixa(
+            &self.config,
+            &Banco::listar(),
+            &lancamentos,
+            &self.dividas,
+            Local::now().date_naive(),
+        ));
+    }
+
+    fn calcular_patrimonio(&mut self) {
+        // o saldo depende de todos os lançamentos da conta, categorizados ou não
+        let mut lancamentos = self.lista_lancamentos.clone();
+        lancamentos.extend(Lancamento::nao_categorizados_listar());
+        let mut dividas = self.dividas.clone();
+        dividas.extend(Divida::listar_arquivadas());
+
+        DashPatrimonio::salvar(calcular_patrimonio(
+            &Banco::listar(),
+            &lancamentos,
+            &dividas,
+            &Ativo::listar(),
+            &OperacaoInvestimento::listar(),
+            &Cotacao::listar(),
+            Local::now().date_naive(),
+        ));
+    }
+
+    fn calcular_investimentos(&mut self) {
+        DashInvestimento::salvar(calcular_posicoes(
+            &Ativo::listar(),
+            &OperacaoInvestimento::listar(),
+            &Cotacao::listar(),
+            Local::now().date_naive(),
+        ));
+    }
+
+    fn historico_contracheques(&mut self) {
+        DashContracheque::salvar(historico_contracheques(&Contracheque::listar()));
+    }
+
     fn gerar_orientacoes(&mut self) {
         // TODO: remover orientações fictícias
-        self.orientacoes.push(Orientacao { prioridade: 10, icone: "🚨".to_string(), texto: "Suas saídas superaram as entradas neste mês".to_string() });
-        self.orientacoes.push(Orientacao { prioridade: 30, icone: "⚠️".to_string(), texto: "Gasto com Alimentação acima da média dos últimos 3 meses".to_string() });
-        self.orientacoes.push(Orientacao { prioridade: 50, icone: "💡".to_string(), texto: "Você tem parcelas vencendo nos próximos 7 dias".to_string() });
+        self.orientacoes.push(Orientacao {
+            prioridade: 10,
+            icone: "🚨".to_string(),
+            texto: "Suas saídas superaram as entradas neste mês".to_string(),
+        });
+        self.orientacoes.push(Orientacao {
+            prioridade: 30,
+            icone: "⚠️".to_string(),
+            texto: "Gasto com Alimentação acima da média dos últimos 3 meses".to_string(),
+        });
+        self.orientacoes.push(Orientacao {
+            prioridade: 50,
+            icone: "💡".to_string(),
+            texto: "Você tem parcelas vencendo nos próximos 7 dias".to_string(),
+        });
 
         ordenar_orientacoes(&mut self.orientacoes);
         Orientacao::salvar(&self.orientacoes);
@@ -221,51 +347,45 @@ impl GeradorDash {
     }
 
     fn calcular_gasto_por_categoria(&mut self) {
-        let ordem = self.gerar_ordem_categorias();
+        let ordem = ordem_grupos_categoria(&self.categorias);
 
         DashGastoPorCategoria::salvar(calcular_gasto_por_categoria_d30(
             &ordem,
+            &self.categorias,
             self.lista_lancamentos.clone(),
         ));
 
         DashGastoPorCategoriaAno::salvar(calcular_gasto_por_categoria_ano(
             &ordem,
+            &self.categorias,
             self.lista_lancamentos.clone(),
         ));
     }
 
-    fn gerar_ordem_categorias(&self) -> Vec<String> {
-        let mut atuais = vec![String::new(), String::new(), String::new(), String::new()];
-
-        let mut resp: Vec<String> = Vec::new();
-
-        self.categorias.iter().for_each(|c| {
-            let nomes = split_categoria_nome(c);
-            for i in 0..nomes.len() {
-                if atuais[i] != nomes[i] {
-                    if i == 0 {
-                        resp.insert(0, format!("Saídas >> {}", nomes[i].clone()));
-                    } else {
-                        resp.insert(
-                            0,
-                            format!("{} >> {}", nomes[i - 1].clone(), nomes[i].clone()),
-                        );
-                    }
-                    atuais[i] = nomes[i].clone();
-                }
-            }
-        });
-        resp.push("Tipo Lancamento".to_string());
+    fn calcular_orcamentos(&mut self) {
+        DashOrcamento::salvar(calcular_orcamentos(
+            &Orcamento::listar(),
+            &self.categorias,
+            &self.lista_lancamentos,
+            Local::now().date_naive(),
+        ));
+    }
 
-        resp
+    fn avaliar_metas(&mut self) {
+        DashMeta::salvar(avaliar_metas(
+            &Meta::listar(),
+            &self.categorias,
+            &self.lista_lancamentos,
+            Local::now().date_naive(),
+        ));
     }
-}
 
-fn split_categoria_nome(cat: &Categoria) -> Vec<String> {
-    let cat_str = cat.to_string();
-    let nomes = cat_str
-        .split(";")
-        .map(|s| s.trim().to_string())
-        .collect::<Vec<_>>();
-    nomes
+    fn calcular_objetivos(&mut self) {
+        DashObjetivo::salvar(calcular_objetivos(
+            &Objetivo::listar(),
+            &self.categorias,
+            &self.lista_lancamentos,
+            Local::now().date_naive(),
+        ));
+    }
 }
@@ -0,0 +1,246 @@
+use chrono::{Datelike, Utc};
+use color_eyre::eyre::Result;
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
+    layout::{Constraint, Layout, Rect},
+    style::Stylize,
+    text::Line,
+    widgets::{Block, Padding, Paragraph, Widget},
+    DefaultTerminal,
+};
+
+use crate::{
+    calc::{
+        bens_irpf, carne_leao_irpf, deducoes_irpf, dividas_irpf, outros_rendimentos_irpf,
+        rendimentos_irpf,
+    },
+    dto::{
+        Ativo, Banco, BemIrpf, CarneLeao, Categoria, Contracheque, Divida, Lancamento,
+        OperacaoInvestimento, RelatorioIrpf,
+    },
+    estilo::{principal_comandos, principal_titulo, GERAL_BG, GERAL_TEXT_FG},
+};
+
+/// Relatório do ano-calendário para a declaração do IRPF.
+pub fn gerar_relatorio_irpf(ano: i32) -> RelatorioIrpf {
+    let lancamentos = Lancamento::lancamentos_listar();
+    let bancos = Banco::listar();
+    let categorias = Categoria::listar();
+    let contracheques = Contracheque::listar();
+    // quitadas no ano ainda tinham saldo em 31/12 do ano anterior
+    let mut dividas = Divida::listar();
+    dividas.extend(Divida::listar_arquivadas());
+
+    RelatorioIrpf {
+        ano,
+        rendimentos: rendimentos_irpf(ano, &contracheques),
+        carne_leao: carne_leao_irpf(ano, &lancamentos, &categorias, &CarneLeao::listar()),
+        outros_rendimentos: outros_rendimentos_irpf(ano, &lancamentos, &categorias, &contracheques),
+        deducoes: deducoes_irpf(ano, &lancamentos, &categorias),
+        bens: bens_irpf(
+            ano,
+            &bancos,
+            &lancamentos,
+            &Ativo::listar(),
+            &OperacaoInvestimento::listar(),
+        ),
+        dividas: dividas_irpf(ano, &bancos, &lancamentos, &dividas),
+    }
+}
+
+pub struct DeclaracaoIrpf {
+    sair: bool,
+    rolagem: u16,
+    mensagem: String,
+    relatorio: RelatorioIrpf,
+}
+
+impl Default for DeclaracaoIrpf {
+    fn default() -> Self {
+        // a declaração é feita sobre o ano anterior
+        let ano = Utc::now().naive_utc().date().year() - 1;
+        Self {
+            sair: false,
+            rolagem: 0,
+            mensagem: String::new(),
+            relatorio: gerar_relatorio_irpf(ano),
+        }
+    }
+}
+
+impl Widget for &mut DeclaracaoIrpf {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let [titulo, corpo, mensagem, rodape] = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Fill(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .areas(area);
+
+        principal_titulo(
+            &format!("Relatório do IRPF - ano-calendário {}", self.relatorio.ano),
+            titulo,
+            buf,
+        );
+        principal_comandos(
+            vec!["←→ (ano)", "↓↑ (rolar)", "E (exportar)", "ESC (sair)"],
+            rodape,
+            buf,
+        );
+
+        Paragraph::new(self.linhas())
+            .block(Block::new().bg(GERAL_BG).padding(Padding::horizontal(1)))
+            .scroll((self.rolagem, 0))
+            .render(corpo, buf);
+
+        Paragraph::new(self.mensagem.clone())
+            .fg(GERAL_TEXT_FG)
+            .render(mensagem, buf);
+    }
+}
+
+impl DeclaracaoIrpf {
+    pub fn run(mut self, terminal: &mut DefaultTerminal) -> Result<()> {
+        while !self.sair {
+            if let Err(erro) = terminal.draw(|frame| frame.render_widget(&mut self, frame.area())) {
+                log::error!("Erro ao desenhar tela DeclaracaoIrpf: {}", erro);
+            }
+            if let Event::Key(key) = event::read()? {
+                self.handle_key(key);
+            };
+        }
+        Ok(())
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        if key.kind != KeyEventKind::Press {
+            return;
+        }
+        match key.code {
+            KeyCode::Esc => self.sair = true,
+            KeyCode::Down => self.rolagem = self.rolagem.saturating_add(1),
+            KeyCode::Up => self.rolagem = self.rolagem.saturating_sub(1),
+            KeyCode::Left => self.trocar_ano(-1),
+            KeyCode::Right => self.trocar_ano(1),
+            KeyCode::Char('e') | KeyCode::Char('E') => self.exportar(),
+            _ => {}
+        }
+    }
+
+    fn trocar_ano(&mut self, delta: i32) {
+        self.relatorio = gerar_relatorio_irpf(self.relatorio.ano + delta);
+        self.rolagem = 0;
+        self.mensagem = String::new();
+    }
+
+    fn exportar(&mut self) {
+        self.mensagem = match self.relatorio.exportar() {
+            Some(arquivo) => format!("Relatório exportado para {}", arquivo),
+            None => "Falha ao exportar o relatório".to_string(),
+        };
+    }
+
+    fn linhas(&self) -> Vec<Line<'static>> {
+        let r = &self.relatorio;
+        let mut linhas: Vec<Line> = Vec::new();
+        let secao = |linhas: &mut Vec<Line<'static>>, titulo: &str| {
+            linhas.push(Line::raw(""));
+            linhas.push(Line::raw(titulo.to_string()).bold());
+        };
+        let texto = |t: String| Line::styled(t, GERAL_TEXT_FG);
+
+        secao(
+            &mut linhas,
+            "Rendimentos tributáveis recebidos de pessoa jurídica",
+        );
+        if r.rendimentos.is_empty() {
+            linhas.push(texto("  nenhum contracheque pago no ano".to_string()));
+        }
+        for f in r.rendimentos.iter() {
+            linhas.push(texto(format!(
+                "  {:<30} rendimentos R$ {:>11.2}   INSS R$ {:>9.2}   IRRF R$ {:>9.2}",
+                f.fonte, f.tributavel, f.inss, f.irrf
+            )));
+        }
+
+        secao(
+            &mut linhas,
+            "Rendimentos tributáveis recebidos de pessoa física (carnê-leão)",
+        );
+        if r.carne_leao.is_empty() {
+            linhas.push(texto(
+                "  nenhum rendimento de carnê-leão no ano".to_string(),
+            ));
+        }
+        for m in r.carne_leao.iter() {
+            linhas.push(texto(format!(
+                "  {:<30} rendimentos R$ {:>11.2}   imposto pago R$ {:>9.2}",
+                m.mes.format("%m/%Y"),
+                m.rendimentos,
+                m.imposto_pago
+            )));
+        }
+
+        secao(&mut linhas, "Outros rendimentos tributáveis");
+        for o in r.outros_rendimentos.iter() {
+            linhas.push(texto(format!("  {:<61} R$ {:>11.2}", o.categoria, o.total)));
+        }
+
+        secao(&mut linhas, "Rendimentos sujeitos à tributação exclusiva");
+        for f in r
+            .rendimentos
+            .iter()
+            .filter(|f| f.decimo_terceiro > 0.0 || f.plr > 0.0)
+        {
+            linhas.push(texto(format!(
+                "  {:<30} 13º R$ {:>11.2}   PLR R$ {:>11.2}   IRRF R$ {:>9.2}",
+                f.fonte, f.decimo_terceiro, f.plr, f.irrf_exclusivo
+            )));
+        }
+
+        secao(&mut linhas, "Pagamentos dedutíveis");
+        for d in r.deducoes.iter() {
+            linhas.push(texto(format!(
+                "  {:<20} {:<40} R$ {:>11.2}",
+                d.tipo, d.beneficiario, d.total
+            )));
+        }
+        let mut tipos: Vec<&str> = r.deducoes.iter().map(|d| d.tipo.as_str()).collect();
+        tipos.dedup();
+        for tipo in tipos {
+            let total: f64 = r
+                .deducoes
+                .iter()
+                .filter(|d| d.tipo == tipo)
+                .map(|d| d.total)
+                .sum();
+            linhas.push(texto(format!("  Total {:<55} R$ {:>11.2}", tipo, total)).bold());
+        }
+
+        let bens = |linhas: &mut Vec<Line<'static>>, itens: &[BemIrpf]| {
+            linhas.push(texto(format!(
+                "  {:<20} {:<40} {:>16} {:>16}",
+                "",
+                "",
+                format!("31/12/{}", r.ano - 1),
+                format!("31/12/{}", r.ano)
+            )));
+            for b in itens.iter() {
+                linhas.push(texto(format!(
+                    "  {:<20} {:<40} R$ {:>13.2} R$ {:>13.2}",
+                    b.tipo, b.descricao, b.anterior, b.atual
+                )));
+            }
+        };
+
+        secao(&mut linhas, "Bens e direitos");
+        bens(&mut linhas, &r.bens);
+
+        secao(&mut linhas, "Dívidas e ônus reais");
+        bens(&mut linhas, &r.dividas);
+
+        linhas
+    }
+}
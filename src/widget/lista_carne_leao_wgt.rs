@@ -0,0 +1,206 @@
+use chrono::{Datelike, NaiveDate, Utc};
+use color_eyre::eyre::Result;
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
+    layout::{Constraint, Layout, Rect},
+    style::{
+        palette::tailwind::{AMBER, GREEN, RED},
+        Color, Stylize,
+    },
+    symbols,
+    text::Line,
+    widgets::{
+        Block, Borders, HighlightSpacing, List, ListItem, ListState, Paragraph, StatefulWidget,
+        Widget,
+    },
+    DefaultTerminal,
+};
+
+use crate::{
+    calc::{calcular_carne_leao, conciliar_carne_leao},
+    dto::{CarneLeao, Categoria, Configuracao, Lancamento, TabelaTributaria},
+    estilo::{
+        alternate_colors, principal_comandos, principal_titulo, GERAL_BG, GERAL_TEXT_FG,
+        LISTA_BORDA_ESTILO, LISTA_SELECIONADO_ESTILO,
+    },
+};
+
+pub struct ListaCarneLeao {
+    sair: bool,
+    state: ListState,
+
+    hoje: NaiveDate,
+    ano: i32,
+    meses: Vec<CarneLeao>,
+}
+
+impl Default for ListaCarneLeao {
+    fn default() -> Self {
+        let hoje = Utc::now().naive_utc().date();
+        let mut resp = Self {
+            sair: false,
+            state: Default::default(),
+
+            hoje,
+            ano: hoje.year(),
+            meses: Vec::new(),
+        };
+        resp.recarregar();
+        resp
+    }
+}
+
+impl Widget for &mut ListaCarneLeao {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let [titulo, corpo, total, rodape] = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Fill(1),
+            Constraint::Length(2),
+            Constraint::Length(1),
+        ])
+        .areas(area);
+
+        principal_titulo(&format!("Carnê-leão {}", self.ano), titulo, buf);
+        principal_comandos(vec!["←→ (ano)", "↓↑ (mover)", "ESC (sair)"], rodape, buf);
+
+        self.render_list(corpo, buf);
+        self.render_total(total, buf);
+    }
+}
+
+impl ListaCarneLeao {
+    pub fn run(mut self, terminal: &mut DefaultTerminal) -> Result<()> {
+        while !self.sair {
+            if let Err(erro) = terminal.draw(|frame| frame.render_widget(&mut self, frame.area())) {
+                log::error!("Erro ao desenhar tela ListaCarneLeao: {}", erro);
+            }
+            if let Event::Key(key) = event::read()? {
+                self.handle_key(key);
+            };
+        }
+        Ok(())
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        if key.kind != KeyEventKind::Press {
+            return;
+        }
+        match key.code {
+            KeyCode::Esc => self.sair = true,
+            KeyCode::Down => self.state.select_next(),
+            KeyCode::Up => self.state.select_previous(),
+            KeyCode::Left => self.trocar_ano(-1),
+            KeyCode::Right => self.trocar_ano(1),
+            _ => {}
+        }
+    }
+
+    fn trocar_ano(&mut self, delta: i32) {
+        self.ano += delta;
+        self.recarregar();
+    }
+
+    /// Recalcula os meses do ano até o atual, mantendo os pagamentos já
+    /// encontrados, procura os DARFs que faltam e grava os meses com rendimento.
+    fn recarregar(&mut self) {
+        let tabela = TabelaTributaria::buscar(self.ano);
+        let lancamentos = Lancamento::lancamentos_listar();
+        let categorias = Categoria::listar();
+        let dependentes = Configuracao::buscar().dependentes;
+        let gravados = CarneLeao::listar();
+
+        self.meses = (1..=12)
+            .filter_map(|m| NaiveDate::from_ymd_opt(self.ano, m, 1))
+            .filter(|mes| *mes <= self.hoje)
+            .map(|mes| {
+                let mut resp = calcular_carne_leao(&tabela, mes, &lancamentos, &categorias, dependentes);
+                if let Some(gravado) = gravados.iter().find(|g| g.mes == mes && g.pago()) {
+                    resp.lancamento = gravado.lancamento.clone();
+                    resp.data_pagamento = gravado.data_pagamento;
+                }
+                resp
+            })
+            .filter(|m| m.rendimentos > 0.0)
+            .collect();
+
+        conciliar_carne_leao(&mut self.meses, &lancamentos);
+        CarneLeao::salvar_lista(&self.meses);
+        self.state.select_last();
+    }
+
+    fn situacao(&self, m: &CarneLeao) -> (String, Color) {
+        if m.imposto <= 0.0 {
+            ("Isento".to_string(), GERAL_TEXT_FG)
+        } else if let Some(data) = m.data_pagamento {
+            (format!("Pago em {}", data.format("%d/%m/%Y")), GREEN.c500)
+        } else if m.atrasado(self.hoje) {
+            ("Atrasado".to_string(), RED.c500)
+        } else {
+            ("Pendente".to_string(), AMBER.c500)
+        }
+    }
+
+    fn render_list(&mut self, area: Rect, buf: &mut Buffer) {
+        let block = Block::new()
+            .title(Line::raw("Apuração mensal").centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(LISTA_BORDA_ESTILO)
+            .bg(GERAL_BG);
+
+        let items: Vec<ListItem> = self
+            .meses
+            .iter()
+            .enumerate()
+            .map(|(i, m)| {
+                let (situacao, cor) = self.situacao(m);
+                ListItem::new(Line::styled(
+                    format!(
+                        " {}   rendimentos R$ {:>10.2}   {} R$ {:>9.2}   base R$ {:>10.2}   imposto R$ {:>9.2}   vence {}   {}",
+                        m.mes.format("%m/%Y"),
+                        m.rendimentos,
+                        if m.simplificado { "simplificado" } else { "deduções    " },
+                        if m.simplificado {
+                            m.rendimentos - m.base
+                        } else {
+                            m.livro_caixa + m.dependentes
+                        },
+                        m.base,
+                        m.imposto,
+                        m.vencimento.format("%d/%m/%Y"),
+                        situacao
+                    ),
+                    cor,
+                ))
+                .bg(alternate_colors(i))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(LISTA_SELECIONADO_ESTILO)
+            .highlight_symbol("▶")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(list, area, buf, &mut self.state);
+    }
+
+    fn render_total(&self, area: Rect, buf: &mut Buffer) {
+        let rendimentos: f64 = self.meses.iter().map(|m| m.rendimentos).sum();
+        let imposto: f64 = self.meses.iter().map(|m| m.imposto).sum();
+        let pendente: f64 = self
+            .meses
+            .iter()
+            .filter(|m| !m.pago())
+            .map(|m| m.imposto)
+            .sum();
+
+        Paragraph::new(format!(
+            " Ano: rendimentos R$ {:.2}   imposto R$ {:.2}   a pagar R$ {:.2}",
+            rendimentos, imposto, pendente
+        ))
+        .bold()
+        .render(area, buf);
+    }
+}
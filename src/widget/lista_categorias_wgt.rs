@@ -13,7 +13,7 @@ use ratatui::{
 };
 
 use crate::{
-    dto::{Categoria, Lancamento, LazyFn, OptionalLazyFn, Regra},
+    dto::{ArvoreCategorias, Categoria, Lancamento, LazyFn, OptionalLazyFn, Regra},
     estilo::{
         alternate_colors, principal_comandos, principal_titulo, GERAL_BG, LISTA_BORDA_ESTILO,
         LISTA_SELECIONADO_ESTILO,
@@ -125,6 +125,13 @@ impl ListaCategoria {
                 aviso.push(format!("{} regras serão removidas", regras.len()));
             }
 
+            let filhos = self.categorias.filhos(&categoria.id).len();
+            if filhos == 1 {
+                aviso.push("1 subcategoria subirá um nível".to_string());
+            } else if filhos > 1 {
+                aviso.push(format!("{} subcategorias subirão um nível", filhos));
+            }
+
             if let Ok(resp) = Alerta::atencao(aviso).run(terminal) {
                 if resp {
                     lancamentos.iter().for_each(|l| {
@@ -0,0 +1,230 @@
+use chrono::Local;
+use color_eyre::eyre::Result;
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
+    layout::{Constraint, Layout, Rect},
+    style::{palette::tailwind::AMBER, Stylize},
+    symbols,
+    text::Line,
+    widgets::{
+        Block, Borders, HighlightSpacing, List, ListItem, ListState, Padding, Paragraph,
+        StatefulWidget, Widget,
+    },
+    DefaultTerminal,
+};
+
+use crate::{
+    calc::calcular_patrimonio,
+    dto::{
+        Ativo, Banco, Cotacao, DashPatrimonio, DashSaldoConta, Divida, Lancamento,
+        OperacaoInvestimento,
+    },
+    estilo::{
+        alternate_colors, principal_comandos, principal_titulo, GERAL_BG, GERAL_TEXT_FG,
+        LISTA_BORDA_ESTILO, LISTA_SELECIONADO_ESTILO,
+    },
+};
+
+use super::conta_wgt::EditarConta;
+
+pub struct ListaContas {
+    sair: bool,
+    state: ListState,
+
+    bancos: Vec<Banco>,
+    lancamentos: Vec<Lancamento>,
+    dividas: Vec<Divida>,
+    ativos: Vec<Ativo>,
+    operacoes: Vec<OperacaoInvestimento>,
+    cotacoes: Vec<Cotacao>,
+    patrimonio: DashPatrimonio,
+}
+
+impl Default for ListaContas {
+    fn default() -> Self {
+        let mut lancamentos = Lancamento::lancamentos_listar();
+        lancamentos.extend(Lancamento::nao_categorizados_listar());
+        let mut dividas = Divida::listar();
+        dividas.extend(Divida::listar_arquivadas());
+
+        let mut resp = Self {
+            sair: false,
+            state: Default::default(),
+
+            bancos: Vec::new(),
+            lancamentos,
+            dividas,
+            ativos: Ativo::listar(),
+            operacoes: OperacaoInvestimento::listar(),
+            cotacoes: Cotacao::listar(),
+            patrimonio: DashPatrimonio {
+                contas: Vec::new(),
+                dias: Vec::new(),
+                meses: Vec::new(),
+            },
+        };
+        resp.recarregar();
+        resp
+    }
+}
+
+impl Widget for &mut ListaContas {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let [titulo, corpo, rodape] = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Fill(1),
+            Constraint::Length(1),
+        ])
+        .areas(area);
+
+        principal_titulo("Contas e patrimônio", titulo, buf);
+        principal_comandos(
+            vec!["↓↑ (mover)", "ENTER (editar conta)", "ESC (sair)"],
+            rodape,
+            buf,
+        );
+
+        let [list_area, patrimonio_area] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Fill(1)]).areas(corpo);
+
+        self.render_list(list_area, buf);
+        self.render_patrimonio(patrimonio_area, buf);
+    }
+}
+
+impl ListaContas {
+    pub fn run(mut self, terminal: &mut DefaultTerminal) -> Result<()> {
+        self.state.select_first();
+        while !self.sair {
+            if let Err(erro) = terminal.draw(|frame| frame.render_widget(&mut self, frame.area())) {
+                log::error!("Erro ao desenhar tela ListaContas: {}", erro);
+            }
+            if let Event::Key(key) = event::read()? {
+                self.handle_key(key, terminal);
+            };
+        }
+        Ok(())
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent, terminal: &mut DefaultTerminal) {
+        if key.kind != KeyEventKind::Press {
+            return;
+        }
+        match key.code {
+            KeyCode::Esc => self.sair = true,
+            KeyCode::Down => self.state.select_next(),
+            KeyCode::Up => self.state.select_previous(),
+            KeyCode::Right | KeyCode::Enter => self.alterar_conta(terminal),
+            _ => {}
+        }
+    }
+
+    fn recarregar(&mut self) {
+        self.bancos = Banco::listar();
+        self.patrimonio = calcular_patrimonio(
+            &self.bancos,
+            &self.lancamentos,
+            &self.dividas,
+            &self.ativos,
+            &self.operacoes,
+            &self.cotacoes,
+            Local::now().date_naive(),
+        );
+    }
+
+    fn alterar_conta(&mut self, terminal: &mut DefaultTerminal) {
+        let selecionada = self.state.selected().and_then(|i| {
+            self.bancos
+                .iter()
+                .flat_map(|b| b.contas.iter().map(move |c| (b.id.clone(), c.clone())))
+                .nth(i)
+        });
+
+        if let Some((banco_id, conta)) = selecionada {
+            match EditarConta::set(conta).run(terminal) {
+                Ok(Some(conta)) => {
+                    Banco::alterar_conta(&banco_id, conta);
+                    self.recarregar();
+                }
+                Ok(None) => {}
+                Err(erro) => log::error!("Erro ao editar conta: {}", erro),
+            }
+        }
+    }
+
+    fn render_list(&mut self, area: Rect, buf: &mut Buffer) {
+        let block = Block::new()
+            .title(Line::raw("Saldo por conta").centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(LISTA_BORDA_ESTILO)
+            .bg(GERAL_BG);
+
+        let items: Vec<ListItem> = self
+            .patrimonio
+            .contas
+            .iter()
+            .enumerate()
+            .map(|(i, conta)| ListItem::from(conta).bg(alternate_colors(i)))
+            .collect();
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(LISTA_SELECIONADO_ESTILO)
+            .highlight_symbol("▶")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(list, area, buf, &mut self.state);
+    }
+
+    fn render_patrimonio(&self, area: Rect, buf: &mut Buffer) {
+        let mut info: Vec<String> = vec![format!(
+            "{:<8} {:>14} {:>14} {:>14} {:>14}",
+            "Mês", "Ativos", "Carteira", "Passivos", "Líquido"
+        )];
+        info.extend(self.patrimonio.meses.iter().rev().map(|m| {
+            format!(
+                "{:<8} {:>14.2} {:>14.2} {:>14.2} {:>14.2}",
+                m.mes, m.ativos, m.investimentos, m.passivos, m.liquido
+            )
+        }));
+
+        let block = Block::new()
+            .title(Line::raw("Patrimônio líquido").centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(LISTA_BORDA_ESTILO)
+            .bg(GERAL_BG)
+            .padding(Padding::horizontal(1));
+
+        Paragraph::new(info.join("\n"))
+            .block(block)
+            .fg(GERAL_TEXT_FG)
+            .render(area, buf);
+    }
+}
+
+impl From<&DashSaldoConta> for ListItem<'_> {
+    fn from(conta: &DashSaldoConta) -> Self {
+        let divergente = conta.diferenca.is_some_and(|d| d.abs() >= 0.01);
+        let line = Line::styled(
+            format!(
+                " {:<30} {:<13} R$ {:>12.2}   {}",
+                format!("{} - {}", conta.banco, conta.conta),
+                conta.tipo,
+                conta.saldo,
+                match (&conta.data_extrato, conta.extrato, conta.diferenca) {
+                    (Some(data), Some(extrato), Some(diferenca)) if divergente => format!(
+                        "[ EXTRATO DE {} R$ {:.2}, DIFERENÇA {:.2} ]",
+                        data, extrato, diferenca
+                    ),
+                    (Some(data), Some(_), _) => format!("confere com o extrato de {}", data),
+                    _ => String::new(),
+                }
+            ),
+            if divergente { AMBER.c500 } else { GERAL_TEXT_FG },
+        );
+        ListItem::new(line)
+    }
+}
@@ -0,0 +1,271 @@
+use color_eyre::eyre::Result;
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
+    layout::{Constraint, Layout, Rect},
+    style::{
+        palette::tailwind::{GREEN, RED},
+        Stylize,
+    },
+    symbols,
+    text::Line,
+    widgets::{
+        Block, Borders, HighlightSpacing, List, ListItem, ListState, Padding, Paragraph,
+        StatefulWidget, Widget,
+    },
+    DefaultTerminal,
+};
+
+use crate::{
+    calc::comparar_contracheques,
+    dto::Contracheque,
+    estilo::{
+        alternate_colors, principal_comandos, principal_titulo, GERAL_BG, GERAL_TEXT_FG,
+        LISTA_BORDA_ESTILO, LISTA_SELECIONADO_ESTILO,
+    },
+};
+
+pub struct ListaContracheques {
+    sair: bool,
+    state: ListState,
+
+    contracheques: Vec<Contracheque>,
+    /// Ids marcados para comparação, no máximo dois.
+    marcados: Vec<String>,
+}
+
+impl Default for ListaContracheques {
+    fn default() -> Self {
+        let mut resp = Self {
+            sair: false,
+            state: Default::default(),
+
+            contracheques: Vec::new(),
+            marcados: Vec::new(),
+        };
+        resp.recarregar();
+        resp
+    }
+}
+
+impl Widget for &mut ListaContracheques {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let [titulo, corpo, rodape] = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Fill(1),
+            Constraint::Length(1),
+        ])
+        .areas(area);
+
+        principal_titulo("Histórico de contra-cheques", titulo, buf);
+        principal_comandos(
+            vec![
+                "↓↑ (mover)",
+                "ESPAÇO (marcar para comparar)",
+                "DEL (remover)",
+                "ESC (sair)",
+            ],
+            rodape,
+            buf,
+        );
+
+        let [list_area, item_area] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Fill(1)]).areas(corpo);
+
+        self.render_list(list_area, buf);
+        self.render_comparacao(item_area, buf);
+    }
+}
+
+impl ListaContracheques {
+    pub fn run(mut self, terminal: &mut DefaultTerminal) -> Result<()> {
+        self.state.select_last();
+        while !self.sair {
+            if let Err(erro) = terminal.draw(|frame| frame.render_widget(&mut self, frame.area())) {
+                log::error!("Erro ao desenhar tela ListaContracheques: {}", erro);
+            }
+            if let Event::Key(key) = event::read()? {
+                self.handle_key(key);
+            };
+        }
+        Ok(())
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        if key.kind != KeyEventKind::Press {
+            return;
+        }
+        match key.code {
+            KeyCode::Esc => self.sair = true,
+            KeyCode::Down => self.state.select_next(),
+            KeyCode::Up => self.state.select_previous(),
+            KeyCode::Char(' ') => self.marcar(),
+            KeyCode::Delete => self.deletar(),
+            _ => {}
+        }
+    }
+
+    fn recarregar(&mut self) {
+        self.contracheques = Contracheque::listar();
+        self.marcados
+            .retain(|id| self.contracheques.iter().any(|c| &c.id == id));
+    }
+
+    fn marcar(&mut self) {
+        if let Some(c) = self
+            .state
+            .selected()
+            .and_then(|i| self.contracheques.get(i))
+        {
+            if let Some(pos) = self.marcados.iter().position(|id| id == &c.id) {
+                self.marcados.remove(pos);
+            } else {
+                if self.marcados.len() == 2 {
+                    self.marcados.remove(0);
+                }
+                self.marcados.push(c.id.clone());
+            }
+        }
+    }
+
+    fn deletar(&mut self) {
+        if let Some(c) = self
+            .state
+            .selected()
+            .and_then(|i| self.contracheques.get(i))
+        {
+            c.deletar();
+            self.recarregar();
+        }
+    }
+
+    /// Os dois marcados ou, sem eles, o selecionado e o anterior da mesma
+    /// empresa e tipo. O mais antigo fica à esquerda.
+    fn comparados(&self) -> Option<(&Contracheque, &Contracheque)> {
+        let marcados: Vec<&Contracheque> = self
+            .contracheques
+            .iter()
+            .filter(|c| self.marcados.contains(&c.id))
+            .collect();
+        if let [a, b] = marcados[..] {
+            return Some((a, b));
+        }
+
+        let i = self.state.selected()?;
+        let b = self.contracheques.get(i)?;
+        let a = self.contracheques[..i]
+            .iter()
+            .rfind(|c| c.empresa == b.empresa && c.tipo == b.tipo)?;
+        Some((a, b))
+    }
+
+    fn render_list(&mut self, area: Rect, buf: &mut Buffer) {
+        let block = Block::new()
+            .title(Line::raw("Contra-cheques").centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(LISTA_BORDA_ESTILO)
+            .bg(GERAL_BG);
+
+        let items: Vec<ListItem> =
+            self.contracheques
+                .iter()
+                .enumerate()
+                .map(|(i, c)| {
+                    ListItem::new(Line::styled(
+                        format!(
+                        " {} {} {:<25} {:<15} comp. {}   bruto R$ {:>10.2}   líquido R$ {:>10.2}",
+                        if self.marcados.contains(&c.id) { "●" } else { " " },
+                        c.data_pagamento.format("%d/%m/%Y"),
+                        c.empresa,
+                        c.tipo.to_string(),
+                        c.competencia.format("%m/%Y"),
+                        c.bruto(),
+                        c.liquido()
+                    ),
+                        GERAL_TEXT_FG,
+                    ))
+                    .bg(alternate_colors(i))
+                })
+                .collect();
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(LISTA_SELECIONADO_ESTILO)
+            .highlight_symbol("▶")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(list, area, buf, &mut self.state);
+    }
+
+    fn render_comparacao(&self, area: Rect, buf: &mut Buffer) {
+        let (titulo, linhas) = match self.comparados() {
+            Some((a, b)) => {
+                let titulo = format!(
+                    "{} {} ({}) x {} {} ({})",
+                    a.empresa,
+                    a.competencia.format("%m/%Y"),
+                    a.tipo,
+                    b.empresa,
+                    b.competencia.format("%m/%Y"),
+                    b.tipo
+                );
+
+                let mut linhas: Vec<Line> = comparar_contracheques(a, b)
+                    .iter()
+                    .map(|item| {
+                        // para o bolso, mais entrada é bom e mais desconto é ruim
+                        let efeito = if item.saida { -item.diferenca } else { item.diferenca };
+                        Line::styled(
+                            format!(
+                                "{:<8} {:<30} R$ {:>10.2}   R$ {:>10.2}   {:>+11.2}",
+                                if item.saida { "Desconto" } else { "Provento" },
+                                item.nome,
+                                item.valor_a,
+                                item.valor_b,
+                                item.diferenca
+                            ),
+                            if efeito < -0.005 {
+                                RED.c500
+                            } else if efeito > 0.005 {
+                                GREEN.c500
+                            } else {
+                                GERAL_TEXT_FG
+                            },
+                        )
+                    })
+                    .collect();
+                linhas.push(Line::raw(""));
+                linhas.push(Line::styled(
+                    format!(
+                        "{:<8} {:<30} R$ {:>10.2}   R$ {:>10.2}   {:>+11.2}",
+                        "",
+                        "Líquido",
+                        a.liquido(),
+                        b.liquido(),
+                        b.liquido() - a.liquido()
+                    ),
+                    GERAL_TEXT_FG,
+                ));
+                (titulo, linhas)
+            }
+            None => (
+                "Comparação".to_string(),
+                vec![Line::styled(
+                    "Marque dois contra-cheques ou selecione um com anterior da mesma empresa e tipo",
+                    GERAL_TEXT_FG,
+                )],
+            ),
+        };
+
+        let block = Block::new()
+            .title(Line::raw(titulo).centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(LISTA_BORDA_ESTILO)
+            .bg(GERAL_BG)
+            .padding(Padding::horizontal(1));
+
+        Paragraph::new(linhas).block(block).render(area, buf);
+    }
+}
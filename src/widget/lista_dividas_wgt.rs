@@ -1,5 +1,6 @@
 use crate::{
-    dto::{DadosDivida, Divida},
+    calc::{historico_dividas_por_ano, possivelmente_em_atraso},
+    dto::{DadosDivida, DashHistoricoDivida, Divida},
     estilo::{
         alternate_colors, principal_comandos, principal_titulo, GERAL_BG, GERAL_TEXT_FG,
         LISTA_BORDA_ESTILO, LISTA_SELECIONADO_ESTILO,
@@ -24,7 +25,10 @@ use ratatui::{
     DefaultTerminal,
 };
 
-use super::divida_wgt::EditarDivida;
+use super::{
+    divida_wgt::EditarDivida, simular_compra_wgt::SimularCompra,
+    simulador_quitacao_wgt::SimuladorQuitacao,
+};
 
 const COMPLETED_TEXT_FG_COLOR: Color = GREEN.c500;
 
@@ -32,6 +36,10 @@ pub struct ListaDividas {
     sair: bool,
     state: ListState,
     dividas: Vec<Divida>,
+
+    /// Mostrando as dívidas quitadas em vez das ativas.
+    arquivo: bool,
+    historico: Vec<DashHistoricoDivida>,
 }
 
 impl Default for ListaDividas {
@@ -40,6 +48,8 @@ impl Default for ListaDividas {
             sair: Default::default(),
             dividas: Divida::listar(),
             state: Default::default(),
+            arquivo: false,
+            historico: Vec::new(),
         }
     }
 }
@@ -66,12 +76,32 @@ impl ListaDividas {
             KeyCode::Esc => self.sair = true,
             KeyCode::Down => self.select_next(),
             KeyCode::Up => self.select_previous(),
+            KeyCode::Char('a') | KeyCode::Char('A') => self.alternar_arquivo(),
+            _ if self.arquivo => {}
             KeyCode::Char('n') | KeyCode::Char('N') => self.nova_divida(terminal),
             KeyCode::Right | KeyCode::Enter => self.alterar_divida(terminal),
+            KeyCode::Char('s') | KeyCode::Char('S') => self.simular_quitacao(terminal),
+            KeyCode::Char('c') | KeyCode::Char('C') => self.simular_compra(terminal),
             _ => {}
         }
     }
 
+    fn alternar_arquivo(&mut self) {
+        self.arquivo = !self.arquivo;
+        if self.arquivo {
+            self.dividas = Divida::listar_arquivadas();
+            self.historico = historico_dividas_por_ano(&self.dividas);
+        } else {
+            self.dividas = Divida::listar();
+        }
+
+        if self.dividas.is_empty() {
+            self.state.select(None);
+        } else {
+            self.state.select_first();
+        }
+    }
+
     fn select_next(&mut self) {
         self.state.select_next();
     }
@@ -82,8 +112,8 @@ impl ListaDividas {
 
     fn nova_divida(&mut self, terminal: &mut DefaultTerminal) {
         match EditarDivida::new().run(terminal) {
-            Ok(ok) => match ok {
-                Some(divida) => {
+            Ok(dividas) => {
+                if let Some(divida) = dividas.first() {
                     divida.salvar();
                     self.dividas = Divida::listar();
 
@@ -92,23 +122,34 @@ impl ListaDividas {
                         self.alterar_divida(terminal);
                     }
                 }
-                None => {}
-            },
+            }
             Err(erro) => log::error!("Erro ao editar nova divida: {}", erro),
         }
     }
 
+    fn simular_quitacao(&mut self, terminal: &mut DefaultTerminal) {
+        if let Err(erro) = SimuladorQuitacao::new().run(terminal) {
+            log::error!("Erro ao simular quitação: {}", erro);
+        }
+    }
+
+    fn simular_compra(&mut self, terminal: &mut DefaultTerminal) {
+        if let Err(erro) = SimularCompra::new().run(terminal) {
+            log::error!("Erro ao simular compra: {}", erro);
+        }
+        self.dividas = Divida::listar();
+    }
+
     fn alterar_divida(&mut self, terminal: &mut DefaultTerminal) {
         if let Some(i) = self.state.selected() {
             let divida = self.dividas[i].clone();
             match EditarDivida::from(&divida).run(terminal) {
-                Ok(op) => match op {
-                    Some(divida) => {
-                        divida.salvar();
+                Ok(dividas) => {
+                    if !dividas.is_empty() {
+                        dividas.iter().for_each(|d| d.salvar());
                         self.dividas = Divida::listar();
                     }
-                    None => {}
-                },
+                }
                 Err(erro) => log::error!("Erro ao editar divida: {}", erro),
             }
         }
@@ -124,25 +165,52 @@ impl Widget for &mut ListaDividas {
         ])
         .areas(area);
 
-        principal_titulo("Controle de dívidas", titulo, buf);
-        principal_comandos(
-            vec!["↓↑ (mover)", "ENTER (selecionar)", "N (nova)", "ESC (sair)"],
-            rodape,
-            buf,
-        );
+        if self.arquivo {
+            principal_titulo("Dívidas quitadas", titulo, buf);
+            principal_comandos(vec!["↓↑ (mover)", "A (ativas)", "ESC (sair)"], rodape, buf);
+        } else {
+            principal_titulo("Controle de dívidas", titulo, buf);
+            principal_comandos(
+                vec![
+                    "↓↑ (mover)",
+                    "ENTER (selecionar)",
+                    "N (nova)",
+                    "S (simular quitação)",
+                    "C (simular compra)",
+                    "A (quitadas)",
+                    "ESC (sair)",
+                ],
+                rodape,
+                buf,
+            );
+        }
 
         let [list_area, item_area] =
             Layout::vertical([Constraint::Fill(1), Constraint::Fill(1)]).areas(corpo);
 
         self.render_list(list_area, buf);
-        self.render_selected_item(item_area, buf);
+        if self.arquivo {
+            let [item_area, historico_area] =
+                Layout::horizontal([Constraint::Fill(3), Constraint::Fill(2)]).areas(item_area);
+            self.render_selected_item(item_area, buf);
+            self.render_historico(historico_area, buf);
+        } else {
+            self.render_selected_item(item_area, buf);
+        }
     }
 }
 
 impl ListaDividas {
     fn render_list(&mut self, area: Rect, buf: &mut Buffer) {
         let block = Block::new()
-            .title(Line::raw("Dividas ativas").centered())
+            .title(
+                Line::raw(if self.arquivo {
+                    "Dívidas arquivadas"
+                } else {
+                    "Dividas ativas"
+                })
+                .centered(),
+            )
             .borders(Borders::TOP)
             .border_set(symbols::border::EMPTY)
             .border_style(LISTA_BORDA_ESTILO)
@@ -171,6 +239,36 @@ impl ListaDividas {
         StatefulWidget::render(list, area, buf, &mut self.state);
     }
 
+    fn render_historico(&self, area: Rect, buf: &mut Buffer) {
+        let mut info: Vec<String> = vec![format!(
+            "{:<6} {:>8} {:>12} {:>12} {:>12}",
+            "Ano", "Parcelas", "Principal", "Juros", "Total"
+        )];
+        info.extend(self.historico.iter().map(|h| {
+            format!(
+                "{:<6} {:>8} {:>12.2} {:>12.2} {:>12.2}",
+                h.ano,
+                h.parcelas,
+                h.principal,
+                h.juros,
+                h.total()
+            )
+        }));
+
+        let block = Block::new()
+            .title(Line::raw("Pago por ano").centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(LISTA_BORDA_ESTILO)
+            .bg(GERAL_BG)
+            .padding(Padding::horizontal(1));
+
+        Paragraph::new(info.join("\n"))
+            .block(block)
+            .fg(GERAL_TEXT_FG)
+            .render(area, buf);
+    }
+
     fn render_selected_item(&self, area: Rect, buf: &mut Buffer) {
         let hoje = Utc::now().naive_utc().date();
         // We get the info depending on the item's state.
@@ -187,6 +285,12 @@ impl ListaDividas {
                     ""
                 }
             ));
+            if divida.conciliada() {
+                info.push(format!(
+                    "Conciliada com lançamentos contendo \"{}\"",
+                    divida.padrao_descricao
+                ));
+            }
 
             if divida.parcelas.aberta().len() > 0 {
                 let meses_faltando =
@@ -246,7 +350,11 @@ impl ListaDividas {
                         l.num_parcela,
                         l.valor,
                         l.data_vencimento.format("%d/%m/%y"),
-                        if l.data_vencimento < hoje {
+                        if possivelmente_em_atraso(&divida, &l, hoje) {
+                            " [ POSSIVELMENTE EM ATRASO ]"
+                        } else if l.data_vencimento < hoje && divida.conciliada() {
+                            " [ AGUARDANDO DÉBITO ]"
+                        } else if l.data_vencimento < hoje {
                             " [ ATRASADA ]"
                         } else {
                             ""
@@ -0,0 +1,336 @@
+use chrono::Local;
+use color_eyre::eyre::Result;
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
+    layout::{Constraint, Layout, Rect},
+    style::{
+        palette::tailwind::{GREEN, RED},
+        Stylize,
+    },
+    symbols,
+    text::Line,
+    widgets::{
+        Block, Borders, HighlightSpacing, List, ListItem, ListState, Padding, Paragraph,
+        StatefulWidget, Widget,
+    },
+    DefaultTerminal,
+};
+
+use crate::{
+    calc::calcular_posicoes,
+    dto::{
+        Ativo, Categoria, Cotacao, DashInvestimento, Lancamento, OperacaoInvestimento,
+        OptionalLazyFn, TipoFluxo,
+    },
+    estilo::{
+        alternate_colors, principal_comandos, principal_titulo, GERAL_BG, GERAL_TEXT_FG,
+        LISTA_BORDA_ESTILO, LISTA_SELECIONADO_ESTILO,
+    },
+};
+
+use super::{
+    ativo_wgt::EditarAtivo, cotacao_wgt::EditarCotacao, operacao_investimento_wgt::EditarOperacao,
+};
+
+pub struct ListaInvestimentos {
+    sair: bool,
+    state: ListState,
+
+    lancamentos: Vec<Lancamento>,
+    ativos: Vec<Ativo>,
+    operacoes: Vec<OperacaoInvestimento>,
+    posicoes: Vec<DashInvestimento>,
+}
+
+impl Default for ListaInvestimentos {
+    fn default() -> Self {
+        let categorias: Vec<String> = Categoria::listar()
+            .into_iter()
+            .filter(|c| c.tipo == TipoFluxo::Investimento)
+            .map(|c| c.id)
+            .collect();
+
+        let mut resp = Self {
+            sair: false,
+            state: Default::default(),
+
+            lancamentos: Lancamento::lancamentos_listar()
+                .into_iter()
+                .filter(|l| categorias.contains(&l.categoria.id()))
+                .collect(),
+            ativos: Vec::new(),
+            operacoes: Vec::new(),
+            posicoes: Vec::new(),
+        };
+        resp.recarregar();
+        resp
+    }
+}
+
+impl Widget for &mut ListaInvestimentos {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let [titulo, corpo, rodape] = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Fill(1),
+            Constraint::Length(1),
+        ])
+        .areas(area);
+
+        principal_titulo("Investimentos", titulo, buf);
+        principal_comandos(
+            vec![
+                "↓↑ (mover)",
+                "N (nova operação)",
+                "A (novo ativo)",
+                "C (cotação)",
+                "DEL (remover última operação)",
+                "ESC (sair)",
+            ],
+            rodape,
+            buf,
+        );
+
+        let [list_area, item_area] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Fill(1)]).areas(corpo);
+
+        self.render_list(list_area, buf);
+        self.render_selected_item(item_area, buf);
+    }
+}
+
+impl ListaInvestimentos {
+    pub fn run(mut self, terminal: &mut DefaultTerminal) -> Result<()> {
+        self.state.select_first();
+        while !self.sair {
+            if let Err(erro) = terminal.draw(|frame| frame.render_widget(&mut self, frame.area())) {
+                log::error!("Erro ao desenhar tela ListaInvestimentos: {}", erro);
+            }
+            if let Event::Key(key) = event::read()? {
+                self.handle_key(key, terminal);
+            };
+        }
+        Ok(())
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent, terminal: &mut DefaultTerminal) {
+        if key.kind != KeyEventKind::Press {
+            return;
+        }
+        match key.code {
+            KeyCode::Esc => self.sair = true,
+            KeyCode::Down => self.state.select_next(),
+            KeyCode::Up => self.state.select_previous(),
+            KeyCode::Char('n') | KeyCode::Char('N') => self.nova_operacao(terminal),
+            KeyCode::Char('a') | KeyCode::Char('A') => self.novo_ativo(terminal),
+            KeyCode::Char('c') | KeyCode::Char('C') => self.nova_cotacao(terminal),
+            KeyCode::Delete => self.deletar(),
+            _ => {}
+        }
+    }
+
+    fn recarregar(&mut self) {
+        self.ativos = Ativo::listar();
+        self.operacoes = OperacaoInvestimento::listar();
+        self.posicoes = calcular_posicoes(
+            &self.ativos,
+            &self.operacoes,
+            &Cotacao::listar(),
+            Local::now().date_naive(),
+        );
+    }
+
+    fn selecionada(&self) -> Option<&DashInvestimento> {
+        self.state.selected().and_then(|i| self.posicoes.get(i))
+    }
+
+    fn operacoes_do_ativo(&self, ativo: &str) -> Vec<&OperacaoInvestimento> {
+        self.operacoes.iter().filter(|o| o.ativo == ativo).collect()
+    }
+
+    /// Lançamentos de investimento ainda sem operação, mais o da própria operação.
+    fn lancamentos_livres(&self) -> Vec<Lancamento> {
+        self.lancamentos
+            .iter()
+            .filter(|l| {
+                !self
+                    .operacoes
+                    .iter()
+                    .any(|o| o.lancamento.as_deref() == Some(l.id.as_str()))
+            })
+            .cloned()
+            .collect()
+    }
+
+    fn nova_operacao(&mut self, terminal: &mut DefaultTerminal) {
+        if self.ativos.is_empty() {
+            self.novo_ativo(terminal);
+            if self.ativos.is_empty() {
+                return;
+            }
+        }
+
+        let ativo = self.selecionada().map(|p| p.ativo.clone());
+        match EditarOperacao::new(&self.ativos, &self.lancamentos_livres(), ativo).run(terminal) {
+            Ok(Some(operacao)) => {
+                operacao.salvar();
+                self.recarregar();
+            }
+            Ok(None) => {}
+            Err(erro) => log::error!("Erro ao criar operação: {}", erro),
+        }
+    }
+
+    fn novo_ativo(&mut self, terminal: &mut DefaultTerminal) {
+        match EditarAtivo::new().run(terminal) {
+            Ok(Some(ativo)) => {
+                ativo.salvar();
+                self.recarregar();
+            }
+            Ok(None) => {}
+            Err(erro) => log::error!("Erro ao criar ativo: {}", erro),
+        }
+    }
+
+    fn nova_cotacao(&mut self, terminal: &mut DefaultTerminal) {
+        let selecionado = self.selecionada().and_then(|p| {
+            self.ativos
+                .iter()
+                .find(|a| a.id == p.ativo)
+                .map(|a| (a.clone(), p.preco_atual))
+        });
+
+        if let Some((ativo, preco)) = selecionado {
+            match EditarCotacao::new(ativo, preco).run(terminal) {
+                Ok(Some(cotacao)) => {
+                    cotacao.salvar();
+                    self.recarregar();
+                }
+                Ok(None) => {}
+                Err(erro) => log::error!("Erro ao informar cotação: {}", erro),
+            }
+        }
+    }
+
+    fn deletar(&mut self) {
+        let ultima = self
+            .selecionada()
+            .and_then(|p| self.operacoes_do_ativo(&p.ativo).last().cloned().cloned());
+
+        if let Some(operacao) = ultima {
+            operacao.deletar();
+            self.recarregar();
+        }
+    }
+
+    fn render_list(&mut self, area: Rect, buf: &mut Buffer) {
+        let block = Block::new()
+            .title(Line::raw("Posições").centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(LISTA_BORDA_ESTILO)
+            .bg(GERAL_BG);
+
+        let items: Vec<ListItem> = self
+            .posicoes
+            .iter()
+            .enumerate()
+            .map(|(i, posicao)| ListItem::from(posicao).bg(alternate_colors(i)))
+            .collect();
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(LISTA_SELECIONADO_ESTILO)
+            .highlight_symbol("▶")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(list, area, buf, &mut self.state);
+    }
+
+    fn render_selected_item(&self, area: Rect, buf: &mut Buffer) {
+        let info = match self.selecionada() {
+            Some(p) => {
+                let mut info: Vec<String> = Vec::new();
+
+                info.push(format!("{} - {} ({})", p.ativo, p.nome, p.tipo));
+                info.push(format!(
+                    "Quantidade {:.2}, preço médio R$ {:.2}, custo R$ {:.2}",
+                    p.quantidade, p.preco_medio, p.custo
+                ));
+                info.push(format!(
+                    "Preço atual R$ {:.2} {}",
+                    p.preco_atual,
+                    match &p.data_preco {
+                        Some(data) => format!("(cotação de {})", data),
+                        None => "(sem cotação, preço da última operação)".to_string(),
+                    }
+                ));
+                info.push(format!(
+                    "Ganho realizado R$ {:.2}, não realizado R$ {:.2}, proventos R$ {:.2}, rentabilidade {:.2}%",
+                    p.ganho_realizado, p.ganho_nao_realizado, p.proventos, p.rentabilidade
+                ));
+                info.push(String::new());
+
+                info.extend(self.operacoes_do_ativo(&p.ativo).iter().rev().map(|o| {
+                    format!(
+                        "{} {:<9} {:>10.2} x R$ {:>10.2} + taxas {:>8.2} = R$ {:>11.2}{}",
+                        o.data.format("%d/%m/%Y"),
+                        o.tipo.to_string(),
+                        o.quantidade,
+                        o.preco,
+                        o.taxas,
+                        o.valor(),
+                        match o.lancamento.as_ref().and_then(|id| {
+                            self.lancamentos.iter().find(|l| &l.id == id)
+                        }) {
+                            Some(l) => format!("   [ {} ]", l.descricao),
+                            None => String::new(),
+                        }
+                    )
+                }));
+
+                info.join("\n")
+            }
+            None => "Cadastre um ativo (A) e registre uma operação (N)".to_string(),
+        };
+
+        let block = Block::new()
+            .title(Line::raw("Detalhes").centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(LISTA_BORDA_ESTILO)
+            .bg(GERAL_BG)
+            .padding(Padding::horizontal(1));
+
+        Paragraph::new(info)
+            .block(block)
+            .fg(GERAL_TEXT_FG)
+            .render(area, buf);
+    }
+}
+
+impl From<&DashInvestimento> for ListItem<'_> {
+    fn from(posicao: &DashInvestimento) -> Self {
+        let ganho = posicao.ganho_realizado + posicao.ganho_nao_realizado + posicao.proventos;
+        let line = Line::styled(
+            format!(
+                " {:<10} {:<8} {:>10.2} R$ {:>12.2}   {:>+12.2} {:>+7.2}%{}",
+                posicao.ativo,
+                posicao.tipo,
+                posicao.quantidade,
+                posicao.valor_atual,
+                ganho,
+                posicao.rentabilidade,
+                if posicao.quantidade > 0.0 { "" } else { "   (encerrada)" }
+            ),
+            if ganho < 0.0 {
+                RED.c500
+            } else if ganho > 0.0 {
+                GREEN.c500
+            } else {
+                GERAL_TEXT_FG
+            },
+        );
+        ListItem::new(line)
+    }
+}
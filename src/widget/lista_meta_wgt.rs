@@ -1,9 +1,13 @@
+use chrono::Local;
 use color_eyre::eyre::Result;
 use ratatui::{
     buffer::Buffer,
     crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
     layout::{Constraint, Layout, Rect},
-    style::Stylize,
+    style::{
+        palette::tailwind::{AMBER, GREEN, RED, SLATE},
+        Stylize,
+    },
     symbols,
     text::Line,
     widgets::{
@@ -13,7 +17,8 @@ use ratatui::{
 };
 
 use crate::{
-    dto::Meta,
+    calc::avaliar_metas,
+    dto::{Categoria, DashMeta, Lancamento, Meta, SituacaoMeta},
     estilo::{
         alternate_colors, principal_comandos, principal_titulo, GERAL_BG, GERAL_TEXT_FG,
         LISTA_BORDA_ESTILO, LISTA_SELECIONADO_ESTILO,
@@ -25,16 +30,26 @@ use super::meta_wgt::EditarMeta;
 pub struct ListaMeta {
     sair: bool,
     metas: Vec<Meta>,
+    avaliacoes: Vec<DashMeta>,
     state: ListState,
+
+    categorias: Vec<Categoria>,
+    lancamentos: Vec<Lancamento>,
 }
 
 impl Default for ListaMeta {
     fn default() -> Self {
-        Self {
+        let mut resp = Self {
             sair: false,
-            metas: Meta::listar(),
+            metas: Vec::new(),
+            avaliacoes: Vec::new(),
             state: Default::default(),
-        }
+
+            categorias: Categoria::listar(),
+            lancamentos: Lancamento::lancamentos_listar(),
+        };
+        resp.recarregar();
+        resp
     }
 }
 
@@ -97,10 +112,20 @@ impl ListaMeta {
             let meta = self.metas[i].clone();
 
             meta.deletar();
-            self.metas = Meta::listar();
+            self.recarregar();
         }
     }
 
+    fn recarregar(&mut self) {
+        self.metas = Meta::listar();
+        self.avaliacoes = avaliar_metas(
+            &self.metas,
+            &self.categorias,
+            &self.lancamentos,
+            Local::now().date_naive(),
+        );
+    }
+
     fn select_next(&mut self) {
         self.state.select_next();
     }
@@ -114,7 +139,7 @@ impl ListaMeta {
             Ok(ok) => match ok {
                 Some(meta) => {
                     meta.salvar();
-                    self.metas = Meta::listar();
+                    self.recarregar();
                 }
                 None => {}
             },
@@ -129,7 +154,7 @@ impl ListaMeta {
                 Ok(ok) => match ok {
                     Some(meta) => {
                         meta.salvar();
-                        self.metas = Meta::listar();
+                        self.recarregar();
                     }
                     None => {}
                 },
@@ -150,7 +175,10 @@ impl ListaMeta {
             .metas
             .iter()
             .enumerate()
-            .map(|(i, todo_item)| ListItem::from(todo_item).bg(alternate_colors(i)))
+            .map(|(i, meta)| {
+                let avaliacao = self.avaliacoes.iter().find(|a| a.id == meta.id);
+                item_meta(meta, avaliacao).bg(alternate_colors(i))
+            })
             .collect();
 
         let list = List::new(items)
@@ -163,9 +191,27 @@ impl ListaMeta {
     }
 }
 
-impl From<&Meta> for ListItem<'_> {
-    fn from(meta: &Meta) -> Self {
-        let line = Line::styled(format!(" {}", meta.nome), GERAL_TEXT_FG);
-        ListItem::new(line)
-    }
+fn item_meta<'a>(meta: &Meta, avaliacao: Option<&DashMeta>) -> ListItem<'a> {
+    let line = match avaliacao {
+        Some(a) => Line::styled(
+            format!(
+                " {:<40} R$ {:>10.2} de {:>10.2} {:>5.0}%  falta {:>10.2}  {} {}",
+                meta.nome,
+                a.realizado,
+                a.valor,
+                a.progresso,
+                a.restante,
+                a.tendencia.icone(),
+                a.situacao,
+            ),
+            match a.situacao {
+                SituacaoMeta::Atingida => GREEN.c500,
+                SituacaoMeta::NoCaminho => GERAL_TEXT_FG,
+                SituacaoMeta::EmRisco => AMBER.c500,
+                SituacaoMeta::NaoAtingida => RED.c500,
+            },
+        ),
+        None => Line::styled(format!(" {:<40} (inativa)", meta.nome), SLATE.c500),
+    };
+    ListItem::new(line)
 }
@@ -0,0 +1,253 @@
+use chrono::Local;
+use color_eyre::eyre::Result;
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
+    layout::{Constraint, Layout, Rect},
+    style::{
+        palette::tailwind::{AMBER, GREEN},
+        Stylize,
+    },
+    symbols,
+    text::Line,
+    widgets::{
+        Block, Borders, HighlightSpacing, List, ListItem, ListState, Padding, Paragraph,
+        StatefulWidget, Widget, Wrap,
+    },
+    DefaultTerminal,
+};
+
+use crate::{
+    calc::calcular_objetivos,
+    dto::{Categoria, DashObjetivo, Lancamento, Objetivo},
+    estilo::{
+        alternate_colors, principal_comandos, principal_titulo, GERAL_BG, GERAL_TEXT_FG,
+        LISTA_BORDA_ESTILO, LISTA_SELECIONADO_ESTILO,
+    },
+};
+
+use super::objetivo_wgt::EditarObjetivo;
+
+pub struct ListaObjetivos {
+    sair: bool,
+    state: ListState,
+
+    categorias: Vec<Categoria>,
+    lancamentos: Vec<Lancamento>,
+    objetivos: Vec<Objetivo>,
+    situacoes: Vec<DashObjetivo>,
+}
+
+impl Default for ListaObjetivos {
+    fn default() -> Self {
+        let mut resp = Self {
+            sair: false,
+            state: Default::default(),
+
+            categorias: Categoria::listar(),
+            lancamentos: Lancamento::lancamentos_listar(),
+            objetivos: Vec::new(),
+            situacoes: Vec::new(),
+        };
+        resp.recarregar();
+        resp
+    }
+}
+
+impl Widget for &mut ListaObjetivos {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let [titulo, corpo, rodape] = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Fill(1),
+            Constraint::Length(1),
+        ])
+        .areas(area);
+
+        principal_titulo("Objetivos de poupança", titulo, buf);
+        principal_comandos(
+            vec![
+                "↓↑ (mover)",
+                "N (novo)",
+                "ENTER (selecionar)",
+                "ESC (sair)",
+                "DEL (remover)",
+            ],
+            rodape,
+            buf,
+        );
+
+        let [list_area, item_area] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Fill(1)]).areas(corpo);
+
+        self.render_list(list_area, buf);
+        self.render_selected_item(item_area, buf);
+    }
+}
+
+impl ListaObjetivos {
+    pub fn run(mut self, terminal: &mut DefaultTerminal) -> Result<()> {
+        self.state.select_first();
+        while !self.sair {
+            if let Err(erro) = terminal.draw(|frame| frame.render_widget(&mut self, frame.area())) {
+                log::error!("Erro ao desenhar tela ListaObjetivos: {}", erro);
+            }
+            if let Event::Key(key) = event::read()? {
+                self.handle_key(key, terminal);
+            };
+        }
+        Ok(())
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent, terminal: &mut DefaultTerminal) {
+        if key.kind != KeyEventKind::Press {
+            return;
+        }
+        match key.code {
+            KeyCode::Esc => self.sair = true,
+            KeyCode::Down => self.state.select_next(),
+            KeyCode::Up => self.state.select_previous(),
+            KeyCode::Char('n') | KeyCode::Char('N') => self.novo_objetivo(terminal),
+            KeyCode::Right | KeyCode::Enter => self.alterar_objetivo(terminal),
+            KeyCode::Delete => self.deletar(),
+            _ => {}
+        }
+    }
+
+    fn recarregar(&mut self) {
+        self.objetivos = Objetivo::listar();
+        self.situacoes = calcular_objetivos(
+            &self.objetivos,
+            &self.categorias,
+            &self.lancamentos,
+            Local::now().date_naive(),
+        );
+    }
+
+    fn deletar(&mut self) {
+        if let Some(i) = self.state.selected() {
+            if let Some(objetivo) = self.objetivos.get(i) {
+                objetivo.deletar();
+                self.recarregar();
+            }
+        }
+    }
+
+    fn novo_objetivo(&mut self, terminal: &mut DefaultTerminal) {
+        match EditarObjetivo::new().run(terminal) {
+            Ok(Some(objetivo)) => {
+                objetivo.salvar();
+                self.recarregar();
+            }
+            Ok(None) => {}
+            Err(erro) => log::error!("Erro ao criar objetivo: {}", erro),
+        }
+    }
+
+    fn alterar_objetivo(&mut self, terminal: &mut DefaultTerminal) {
+        if let Some(i) = self.state.selected() {
+            if i >= self.objetivos.len() {
+                return;
+            }
+            match EditarObjetivo::set(self.objetivos[i].clone()).run(terminal) {
+                Ok(Some(objetivo)) => {
+                    objetivo.salvar();
+                    self.recarregar();
+                }
+                Ok(None) => {}
+                Err(erro) => log::error!("Erro ao editar objetivo: {}", erro),
+            }
+        }
+    }
+
+    fn render_list(&mut self, area: Rect, buf: &mut Buffer) {
+        let block = Block::new()
+            .title(Line::raw("Objetivos").centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(LISTA_BORDA_ESTILO)
+            .bg(GERAL_BG);
+
+        let items: Vec<ListItem> = self
+            .situacoes
+            .iter()
+            .enumerate()
+            .map(|(i, situacao)| ListItem::from(situacao).bg(alternate_colors(i)))
+            .collect();
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(LISTA_SELECIONADO_ESTILO)
+            .highlight_symbol("▶")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(list, area, buf, &mut self.state);
+    }
+
+    fn render_selected_item(&self, area: Rect, buf: &mut Buffer) {
+        let info = match self.state.selected().and_then(|i| self.situacoes.get(i)) {
+            Some(s) => {
+                let mut info: Vec<String> = Vec::new();
+
+                info.push(format!("{} até {}", s.nome, s.data_alvo));
+                info.push(String::new());
+                info.push(format!("Guardado               R$ {:>10.2}", s.acumulado));
+                info.push(format!("Alvo                   R$ {:>10.2}", s.valor_alvo));
+                info.push(format!("Falta                  R$ {:>10.2}", s.falta));
+                info.push(String::new());
+                info.push(format!(
+                    "Aporte mensal necessário R$ {:>8.2}",
+                    s.aporte_necessario
+                ));
+                info.push(format!("Ritmo recente          R$ {:>10.2} / mês", s.ritmo_mensal));
+                info.push(match &s.previsao {
+                    Some(previsao) => format!(
+                        "Conclusão prevista em {}{}",
+                        previsao,
+                        if s.no_prazo { "" } else { " [ FORA DO PRAZO ]" }
+                    ),
+                    None => "Sem aportes recentes, não há previsão de conclusão".to_string(),
+                });
+
+                info.join("\n")
+            }
+            None => "Selecione um objetivo".to_string(),
+        };
+
+        let block = Block::new()
+            .title(Line::raw("Detalhes").centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(LISTA_BORDA_ESTILO)
+            .bg(GERAL_BG)
+            .padding(Padding::horizontal(1));
+
+        Paragraph::new(info)
+            .block(block)
+            .fg(GERAL_TEXT_FG)
+            .wrap(Wrap { trim: false })
+            .render(area, buf);
+    }
+}
+
+impl From<&DashObjetivo> for ListItem<'_> {
+    fn from(situacao: &DashObjetivo) -> Self {
+        let line = Line::styled(
+            format!(
+                " {:<40} R$ {:>10.2} de {:>10.2} {:>5.0}%   {}",
+                situacao.nome,
+                situacao.acumulado,
+                situacao.valor_alvo,
+                situacao.progresso,
+                situacao.data_alvo,
+            ),
+            if situacao.falta <= 0.0 {
+                GREEN.c500
+            } else if !situacao.no_prazo {
+                AMBER.c500
+            } else {
+                GERAL_TEXT_FG
+            },
+        );
+        ListItem::new(line)
+    }
+}
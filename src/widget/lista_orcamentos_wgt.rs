@@ -0,0 +1,212 @@
+use chrono::Local;
+use color_eyre::eyre::Result;
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
+    layout::{Constraint, Layout, Rect},
+    style::{
+        palette::tailwind::{AMBER, RED},
+        Stylize,
+    },
+    symbols,
+    text::Line,
+    widgets::{
+        Block, Borders, HighlightSpacing, List, ListItem, ListState, Paragraph, StatefulWidget,
+        Widget,
+    },
+    DefaultTerminal,
+};
+
+use crate::{
+    calc::calcular_orcamentos,
+    dto::{Categoria, DashOrcamento, Lancamento, Orcamento},
+    estilo::{
+        alternate_colors, principal_comandos, principal_titulo, GERAL_BG, GERAL_TEXT_FG,
+        LISTA_BORDA_ESTILO, LISTA_SELECIONADO_ESTILO,
+    },
+};
+
+use super::orcamento_wgt::EditarOrcamento;
+
+pub struct ListaOrcamentos {
+    sair: bool,
+    state: ListState,
+
+    lancamentos: Vec<Lancamento>,
+    orcamentos: Vec<Orcamento>,
+    situacoes: Vec<DashOrcamento>,
+}
+
+impl Default for ListaOrcamentos {
+    fn default() -> Self {
+        let mut resp = Self {
+            sair: false,
+            state: Default::default(),
+
+            lancamentos: Lancamento::lancamentos_listar(),
+            orcamentos: Vec::new(),
+            situacoes: Vec::new(),
+        };
+        resp.recarregar();
+        resp
+    }
+}
+
+impl Widget for &mut ListaOrcamentos {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let [titulo, cabecalho, corpo, rodape] = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Length(1),
+            Constraint::Fill(1),
+            Constraint::Length(1),
+        ])
+        .areas(area);
+
+        principal_titulo("Orçamentos do mês", titulo, buf);
+        principal_comandos(
+            vec![
+                "↓↑ (mover)",
+                "N (novo)",
+                "ENTER (selecionar)",
+                "ESC (sair)",
+                "DEL (remover)",
+            ],
+            rodape,
+            buf,
+        );
+
+        Paragraph::new(format!(
+            "  {:<50} {:>12} {:>12} {:>12} {:>12}",
+            "Categoria", "Orçado", "Gasto", "Restante", "Projetado"
+        ))
+        .fg(GERAL_TEXT_FG)
+        .bg(GERAL_BG)
+        .render(cabecalho, buf);
+
+        self.render_list(corpo, buf);
+    }
+}
+
+impl ListaOrcamentos {
+    pub fn run(mut self, terminal: &mut DefaultTerminal) -> Result<()> {
+        self.state.select_first();
+        while !self.sair {
+            if let Err(erro) = terminal.draw(|frame| frame.render_widget(&mut self, frame.area())) {
+                log::error!("Erro ao desenhar tela ListaOrcamentos: {}", erro);
+            }
+            if let Event::Key(key) = event::read()? {
+                self.handle_key(key, terminal);
+            };
+        }
+        Ok(())
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent, terminal: &mut DefaultTerminal) {
+        if key.kind != KeyEventKind::Press {
+            return;
+        }
+        match key.code {
+            KeyCode::Esc => self.sair = true,
+            KeyCode::Down => self.state.select_next(),
+            KeyCode::Up => self.state.select_previous(),
+            KeyCode::Char('n') | KeyCode::Char('N') => self.novo_orcamento(terminal),
+            KeyCode::Right | KeyCode::Enter => self.alterar_orcamento(terminal),
+            KeyCode::Delete => self.deletar(),
+            _ => {}
+        }
+    }
+
+    fn recarregar(&mut self) {
+        self.orcamentos = Orcamento::listar();
+        self.situacoes = calcular_orcamentos(
+            &self.orcamentos,
+            &Categoria::listar(),
+            &self.lancamentos,
+            Local::now().date_naive(),
+        );
+    }
+
+    fn deletar(&mut self) {
+        if let Some(i) = self.state.selected() {
+            if let Some(orcamento) = self.orcamentos.get(i) {
+                orcamento.deletar();
+                self.recarregar();
+            }
+        }
+    }
+
+    fn novo_orcamento(&mut self, terminal: &mut DefaultTerminal) {
+        match EditarOrcamento::new().run(terminal) {
+            Ok(Some(orcamento)) => {
+                orcamento.salvar();
+                self.recarregar();
+            }
+            Ok(None) => {}
+            Err(erro) => log::error!("Erro ao criar orçamento: {}", erro),
+        }
+    }
+
+    fn alterar_orcamento(&mut self, terminal: &mut DefaultTerminal) {
+        if let Some(i) = self.state.selected() {
+            let anterior = self.orcamentos[i].clone();
+            match EditarOrcamento::set(anterior.clone()).run(terminal) {
+                Ok(Some(orcamento)) => {
+                    if orcamento.id != anterior.id {
+                        anterior.deletar();
+                    }
+                    orcamento.salvar();
+                    self.recarregar();
+                }
+                Ok(None) => {}
+                Err(erro) => log::error!("Erro ao editar orçamento: {}", erro),
+            }
+        }
+    }
+
+    fn render_list(&mut self, area: Rect, buf: &mut Buffer) {
+        let block = Block::new()
+            .title(Line::raw("Orçado x realizado").centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(LISTA_BORDA_ESTILO)
+            .bg(GERAL_BG);
+
+        let items: Vec<ListItem> = self
+            .situacoes
+            .iter()
+            .enumerate()
+            .map(|(i, situacao)| ListItem::from(situacao).bg(alternate_colors(i)))
+            .collect();
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(LISTA_SELECIONADO_ESTILO)
+            .highlight_symbol("▶")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(list, area, buf, &mut self.state);
+    }
+}
+
+impl From<&DashOrcamento> for ListItem<'_> {
+    fn from(situacao: &DashOrcamento) -> Self {
+        let line = Line::styled(
+            format!(
+                " {:<50} {:>12.2} {:>12.2} {:>12.2} {:>12.2}",
+                situacao.categoria,
+                situacao.disponivel(),
+                situacao.gasto,
+                situacao.restante,
+                situacao.projetado,
+            ),
+            if situacao.restante < 0.0 {
+                RED.c500
+            } else if situacao.projetado > situacao.disponivel() {
+                AMBER.c500
+            } else {
+                GERAL_TEXT_FG
+            },
+        );
+        ListItem::new(line)
+    }
+}
@@ -0,0 +1,280 @@
+use color_eyre::eyre::Result;
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
+    layout::{Constraint, Layout, Rect},
+    style::{
+        palette::tailwind::{GREEN, RED},
+        Stylize,
+    },
+    symbols,
+    text::Line,
+    widgets::{
+        Block, Borders, HighlightSpacing, List, ListItem, ListState, Paragraph, StatefulWidget,
+        Widget,
+    },
+    DefaultTerminal,
+};
+
+use crate::{
+    app::Etapa,
+    dto::{ComparacaoSnapshot, GatilhoSnapshot, Snapshot},
+    estilo::{
+        alternate_colors, principal_comandos, principal_titulo, GERAL_BG, GERAL_TEXT_FG,
+        LISTA_BORDA_ESTILO, LISTA_SELECIONADO_ESTILO,
+    },
+    widget::alerta_wgt::Alerta,
+};
+
+pub struct ListaSnapshots {
+    sair: bool,
+    etapa: Etapa,
+    state: ListState,
+
+    snapshots: Vec<Snapshot>,
+    /// Comparação do snapshot selecionado com os dados atuais, calculada ao pedir.
+    comparacao: Option<(usize, Vec<ComparacaoSnapshot>)>,
+}
+
+impl Default for ListaSnapshots {
+    fn default() -> Self {
+        let mut resp = Self {
+            sair: false,
+            etapa: Etapa::Menu,
+            state: Default::default(),
+
+            snapshots: Vec::new(),
+            comparacao: None,
+        };
+        resp.recarregar();
+        resp
+    }
+}
+
+impl Widget for &mut ListaSnapshots {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let [titulo, corpo, comparacao, rodape] = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+            Constraint::Length(1),
+        ])
+        .areas(area);
+
+        principal_titulo("Snapshots", titulo, buf);
+        principal_comandos(
+            vec![
+                "↓↑ (mover)",
+                "→ (comparar)",
+                "N (novo)",
+                "R (restaurar)",
+                "ESC (sair)",
+            ],
+            rodape,
+            buf,
+        );
+
+        self.render_list(corpo, buf);
+        self.render_comparacao(comparacao, buf);
+    }
+}
+
+impl ListaSnapshots {
+    pub fn run(mut self, terminal: &mut DefaultTerminal) -> Result<Etapa> {
+        while !self.sair {
+            if let Err(erro) = terminal.draw(|frame| frame.render_widget(&mut self, frame.area())) {
+                log::error!("Erro ao desenhar tela ListaSnapshots: {}", erro);
+            }
+            if let Event::Key(key) = event::read()? {
+                self.handle_key(key, terminal);
+            };
+        }
+        Ok(self.etapa)
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent, terminal: &mut DefaultTerminal) {
+        if key.kind != KeyEventKind::Press {
+            return;
+        }
+        match key.code {
+            KeyCode::Esc => self.sair = true,
+            KeyCode::Down => self.state.select_next(),
+            KeyCode::Up => self.state.select_previous(),
+            KeyCode::Right | KeyCode::Enter => self.comparar(),
+            KeyCode::Char('n') | KeyCode::Char('N') => self.novo(),
+            KeyCode::Char('r') | KeyCode::Char('R') => self.restaurar(terminal),
+            _ => {}
+        }
+    }
+
+    fn recarregar(&mut self) {
+        self.snapshots = Snapshot::listar();
+        self.comparacao = None;
+        self.state.select_first();
+    }
+
+    fn novo(&mut self) {
+        if Snapshot::criar(GatilhoSnapshot::Manual).is_some() {
+            self.recarregar();
+        }
+    }
+
+    fn comparar(&mut self) {
+        if let Some(i) = self.state.selected() {
+            if let Some(snapshot) = self.snapshots.get(i) {
+                self.comparacao = Some((i, snapshot.comparar()));
+            }
+        }
+    }
+
+    /// Depois de restaurar, o programa fecha para que nenhuma tela grave por cima
+    /// dos dados restaurados o que ainda tem em memória.
+    fn restaurar(&mut self, terminal: &mut DefaultTerminal) {
+        let Some(snapshot) = self.state.selected().and_then(|i| self.snapshots.get(i)) else {
+            return;
+        };
+
+        let aviso = vec![
+            format!(
+                "Restaurar o snapshot de {} ({})?",
+                snapshot.data.format("%d/%m/%Y %H:%M:%S"),
+                snapshot.gatilho.descricao()
+            ),
+            String::new(),
+            "Os dados atuais serão guardados num novo snapshot antes da restauração,".to_string(),
+            "e o programa será fechado em seguida.".to_string(),
+        ];
+        if !matches!(Alerta::atencao(aviso).run(terminal), Ok(true)) {
+            return;
+        }
+
+        let mensagem = match snapshot.restaurar() {
+            Ok(_) => {
+                self.etapa = Etapa::Sair;
+                self.sair = true;
+                "Snapshot restaurado. Abra o programa novamente.".to_string()
+            }
+            Err(erro) => {
+                log::error!("Erro ao restaurar snapshot: {}", erro);
+                format!("Falha ao restaurar: {}", erro)
+            }
+        };
+        let _ = Alerta::atencao(vec![mensagem]).run(terminal);
+        self.recarregar();
+    }
+
+    fn render_list(&mut self, area: Rect, buf: &mut Buffer) {
+        let block = Block::new()
+            .title(Line::raw(format!("{} snapshots", self.snapshots.len())).centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(LISTA_BORDA_ESTILO)
+            .bg(GERAL_BG);
+
+        let items: Vec<ListItem> = self
+            .snapshots
+            .iter()
+            .enumerate()
+            .map(|(i, s)| {
+                ListItem::new(Line::styled(
+                    format!(
+                        " {}   {:<32}   {:>3} arquivos   {:>10}   compactado {:>10}",
+                        s.data.format("%d/%m/%Y %H:%M:%S"),
+                        s.gatilho.descricao(),
+                        s.arquivos.len(),
+                        tamanho(s.tamanho()),
+                        tamanho(s.compactado()),
+                    ),
+                    GERAL_TEXT_FG,
+                ))
+                .bg(alternate_colors(i))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(LISTA_SELECIONADO_ESTILO)
+            .highlight_symbol("▶")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(list, area, buf, &mut self.state);
+    }
+
+    fn render_comparacao(&self, area: Rect, buf: &mut Buffer) {
+        let block = Block::new()
+            .title(Line::raw("Snapshot x dados atuais").centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(LISTA_BORDA_ESTILO)
+            .bg(GERAL_BG);
+
+        let comparacao = match &self.comparacao {
+            Some((i, comparacao)) if self.state.selected() == Some(*i) => comparacao,
+            _ => {
+                Paragraph::new(" → para comparar o snapshot selecionado com os dados atuais")
+                    .fg(GERAL_TEXT_FG)
+                    .block(block)
+                    .render(area, buf);
+                return;
+            }
+        };
+
+        let items: Vec<ListItem> = comparacao
+            .iter()
+            .enumerate()
+            .map(|(i, c)| {
+                let (texto, cor) = linha_comparacao(c);
+                ListItem::new(Line::styled(texto, cor)).bg(alternate_colors(i))
+            })
+            .collect();
+
+        Widget::render(List::new(items).block(block), area, buf);
+    }
+}
+
+fn linha_comparacao(c: &ComparacaoSnapshot) -> (String, ratatui::style::Color) {
+    let cor = if c.tamanho_snapshot == c.tamanho_atual {
+        GERAL_TEXT_FG
+    } else if c.registros_atual < c.registros_snapshot || c.tamanho_atual.is_none() {
+        RED.c500
+    } else {
+        GREEN.c500
+    };
+
+    let (snapshot, atual, diferenca) = match (c.registros_snapshot, c.registros_atual) {
+        (None, None) => (
+            c.tamanho_snapshot.map(tamanho),
+            c.tamanho_atual.map(tamanho),
+            String::new(),
+        ),
+        (snapshot, atual) => (
+            snapshot.map(|r| format!("{} reg.", r)),
+            atual.map(|r| format!("{} reg.", r)),
+            match atual.unwrap_or(0) as i64 - snapshot.unwrap_or(0) as i64 {
+                0 => String::new(),
+                d => format!("{:+}", d),
+            },
+        ),
+    };
+
+    (
+        format!(
+            " {:<32} snapshot {:>12}   atual {:>12}   {}",
+            c.arquivo,
+            snapshot.unwrap_or("—".to_string()),
+            atual.unwrap_or("—".to_string()),
+            diferenca
+        ),
+        cor,
+    )
+}
+
+fn tamanho(bytes: u64) -> String {
+    if bytes < 1024 {
+        format!("{} B", bytes)
+    } else if bytes < 1024 * 1024 {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    } else {
+        format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+    }
+}
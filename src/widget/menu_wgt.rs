@@ -16,6 +16,7 @@ use ratatui::{
 
 use crate::{
     app::Etapa,
+    config_caminhos::{caminhos, PERFIL_PADRAO},
     estilo::{
         alternate_colors, principal_comandos, principal_titulo, GERAL_BG, GERAL_TEXT_FG,
         LISTA_BORDA_ESTILO, LISTA_SELECIONADO_ESTILO,
@@ -87,7 +88,12 @@ impl Widget for &mut Menu {
 
         let versao = vec!["V.:", env!("CARGO_PKG_VERSION")].join(" ");
 
-        principal_titulo("Financeiro", titulo, buf);
+        let perfil = &caminhos().perfil;
+        if perfil == PERFIL_PADRAO {
+            principal_titulo("Financeiro", titulo, buf);
+        } else {
+            principal_titulo(&format!("Financeiro - {}", perfil), titulo, buf);
+        }
         principal_comandos(
             vec!["↓↑ mover", "→ selecionar", "ESC Sair", versao.as_str()],
             rodape,
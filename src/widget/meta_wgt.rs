@@ -31,7 +31,7 @@ enum Status {
     AltPeriodo,
     AltValor,
 
-    Sair(Option<Meta>),
+    Sair(Option<Box<Meta>>),
 }
 
 pub struct EditarMeta {
@@ -114,8 +114,10 @@ impl EditarMeta {
 
         resp.id_meta = meta.id;
         resp.nome.set_texto(meta.nome);
+        resp.ativo.set_checked(meta.ativo);
         resp.desc.set_texto(meta.desc);
         resp.meta_tipo.set_id_selecionado(meta.tipo_meta);
+        resp.atualizar_filtro();
         resp.meta_filtro.set_id_selecionado(meta.filtro);
         resp.metrica.set_id_selecionado(meta.metrica);
         resp.fluxo.set_id_selecionado(meta.fluxo);
@@ -136,7 +138,7 @@ impl EditarMeta {
         }
 
         if let Status::Sair(meta) = self.status {
-            return Ok(meta);
+            return Ok(meta.map(|meta| *meta));
         }
         Ok(None)
     }
@@ -159,6 +161,7 @@ impl EditarMeta {
         let mut meta = Meta {
             id: self.id_meta.clone(),
             nome: self.nome.to_string(),
+            ativo: self.ativo.get_checked(),
             desc: self.desc.to_string(),
             tipo_meta: self.meta_tipo.get_id_selecionado(),
             filtro: self.meta_filtro.get_id_selecionado(),
@@ -172,7 +175,7 @@ impl EditarMeta {
             meta.gerar_id();
         }
 
-        self.status = Status::Sair(Some(meta));
+        self.status = Status::Sair(Some(Box::new(meta)));
     }
 
     fn proximo_input(&mut self) {
@@ -260,6 +263,13 @@ impl EditarMeta {
         self.meta_tipo
             .render(self.status == Status::AltTipoMeta, tipo_meta, buf);
 
+        self.atualizar_filtro();
+
+        self.meta_filtro
+            .render(self.status == Status::AltFiltroMeta, filtro, buf);
+    }
+
+    fn atualizar_filtro(&mut self) {
         if self.meta_tipo.texto_selecionado_eh("") {
             if self.meta_filtro.nome != "Filtro" {
                 self.meta_filtro.nome = "Filtro".to_string();
@@ -293,9 +303,6 @@ impl EditarMeta {
                 self.meta_filtro.set_lista(itens);
             }
         }
-
-        self.meta_filtro
-            .render(self.status == Status::AltFiltroMeta, filtro, buf);
     }
 
     fn render_linha_4(&mut self, buf: &mut Buffer, linha_4: Rect) {
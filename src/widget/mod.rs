@@ -12,13 +12,37 @@ mod lista_categorias_wgt;
 mod categoria_wgt;
 mod alerta_wgt;
 mod contracheque_wgt;
+mod lista_objetivos_wgt;
+mod lista_orcamentos_wgt;
+mod objetivo_wgt;
+mod orcamento_wgt;
+mod simulador_quitacao_wgt;
+mod simular_compra_wgt;
+mod conta_wgt;
+mod lista_contas_wgt;
+mod ativo_wgt;
+mod cotacao_wgt;
+mod operacao_investimento_wgt;
+mod lista_investimentos_wgt;
+mod lista_contracheques_wgt;
+mod irpf_wgt;
+mod lista_carne_leao_wgt;
+mod lista_snapshots_wgt;
 
 pub use categorizador_wgt::Categorizador;
 pub use lista_dividas_wgt::ListaDividas;
+pub use lista_contas_wgt::ListaContas;
+pub use lista_investimentos_wgt::ListaInvestimentos;
 pub use menu_wgt::Menu;
 pub use selecionar_categoria_wgt::SelecionarCategoria;
 pub use gerador_dash::GeradorDash;
 pub use lista_meta_wgt::ListaMeta;
 pub use config_wgt::EditarConfiguracoes;
 pub use lista_categorias_wgt::ListaCategoria;
-pub use contracheque_wgt::ContraCheque;
\ No newline at end of file
+pub use contracheque_wgt::ContraCheque;
+pub use lista_contracheques_wgt::ListaContracheques;
+pub use irpf_wgt::DeclaracaoIrpf;
+pub use lista_carne_leao_wgt::ListaCarneLeao;
+pub use lista_snapshots_wgt::ListaSnapshots;
+pub use lista_objetivos_wgt::ListaObjetivos;
+pub use lista_orcamentos_wgt::ListaOrcamentos;
\ No newline at end of file
@@ -0,0 +1,340 @@
+use chrono::Local;
+use color_eyre::eyre::Result;
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
+    layout::{Constraint, Layout, Rect},
+    style::Stylize,
+    symbols,
+    text::Line,
+    widgets::{
+        Block, Borders, HighlightSpacing, List, ListItem, ListState, StatefulWidget, Widget,
+    },
+    DefaultTerminal,
+};
+
+use crate::{
+    componentes::input_wgt::Input,
+    dto::{Banco, Categoria, Objetivo, TipoFluxo, Unico},
+    estilo::{
+        alternate_colors, estilo_input, estilo_input_foco, principal_comandos, principal_titulo,
+        LISTA_SELECIONADO_ESTILO,
+    },
+};
+
+#[derive(PartialEq)]
+enum Status {
+    AltNome,
+    AltValorAlvo,
+    AltDataAlvo,
+    AltSaldoInicial,
+    AltVinculos,
+
+    Sair(Option<Objetivo>),
+}
+
+#[derive(PartialEq)]
+enum TipoVinculo {
+    Conta,
+    Categoria,
+}
+
+struct Vinculo {
+    tipo: TipoVinculo,
+    id: String,
+    texto: String,
+    marcado: bool,
+}
+
+pub struct EditarObjetivo {
+    status: Status,
+    objetivo: Option<Objetivo>,
+
+    nome: Input,
+    valor_alvo: Input,
+    data_alvo: Input,
+    saldo_inicial: Input,
+
+    vinculos: Vec<Vinculo>,
+    state: ListState,
+}
+
+impl Widget for &mut EditarObjetivo {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let [titulo, corpo, rodape] = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Fill(1),
+            Constraint::Length(1),
+        ])
+        .areas(area);
+
+        principal_titulo(
+            if self.objetivo.is_none() {
+                "Novo Objetivo"
+            } else {
+                "Edição de Objetivo"
+            },
+            titulo,
+            buf,
+        );
+        principal_comandos(
+            match self.status {
+                Status::AltVinculos => vec![
+                    "↓↑ (mover)",
+                    "Enter (vincular)",
+                    "TAB (próximo)",
+                    "Esc (sair)",
+                    "F5 (salvar)",
+                ],
+                _ => vec!["(Editar)", "TAB (próximo)", "Esc (sair)", "F5 (salvar)"],
+            },
+            rodape,
+            buf,
+        );
+        self.render(corpo, buf)
+    }
+}
+
+impl EditarObjetivo {
+    pub fn new() -> Self {
+        let mut vinculos: Vec<Vinculo> = Vec::new();
+
+        Banco::listar().iter().for_each(|b| {
+            b.contas.iter().for_each(|c| {
+                vinculos.push(Vinculo {
+                    tipo: TipoVinculo::Conta,
+                    id: c.id.clone(),
+                    texto: format!("Conta: {} - {}", b.nome, c.nome),
+                    marcado: false,
+                });
+            });
+        });
+
+        Categoria::listar()
+            .into_iter()
+            .filter(|c| c.tipo == TipoFluxo::Investimento)
+            .for_each(|c| {
+                vinculos.push(Vinculo {
+                    tipo: TipoVinculo::Categoria,
+                    id: c.id.clone(),
+                    texto: format!("Categoria: {}", c),
+                    marcado: false,
+                });
+            });
+
+        Self {
+            status: Status::AltNome,
+            objetivo: None,
+
+            nome: Input::new_texto("Nome", String::new()),
+            valor_alvo: Input::new_monetario("Valor alvo", 0.0f64),
+            data_alvo: Input::new_data("Data alvo", "00/00/00".to_string()),
+            saldo_inicial: Input::new_monetario("Já guardado", 0.0f64),
+
+            vinculos,
+            state: ListState::default(),
+        }
+    }
+
+    pub fn set(objetivo: Objetivo) -> Self {
+        let mut resp = Self::new();
+
+        resp.nome.set_texto(objetivo.nome.clone());
+        resp.valor_alvo.set_monetario(objetivo.valor_alvo);
+        resp.data_alvo = Input::new_data(
+            "Data alvo",
+            objetivo.data_alvo.format("%d/%m/%y").to_string(),
+        );
+        resp.saldo_inicial.set_monetario(objetivo.saldo_inicial);
+
+        resp.vinculos.iter_mut().for_each(|v| {
+            v.marcado = match v.tipo {
+                TipoVinculo::Conta => objetivo.contas.contains(&v.id),
+                TipoVinculo::Categoria => objetivo.categorias.contains(&v.id),
+            }
+        });
+        resp.objetivo = Some(objetivo);
+
+        resp
+    }
+
+    pub fn run(mut self, terminal: &mut DefaultTerminal) -> Result<Option<Objetivo>> {
+        while !matches!(self.status, Status::Sair(_)) {
+            if let Err(erro) = terminal.draw(|frame| frame.render_widget(&mut self, frame.area())) {
+                log::error!("Erro ao desenhar tela EditarObjetivo: {}", erro);
+            };
+
+            if let Event::Key(key) = event::read()? {
+                self.handle_key(key);
+            };
+        }
+
+        if let Status::Sair(objetivo) = self.status {
+            return Ok(objetivo);
+        }
+        Ok(None)
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        if key.kind != KeyEventKind::Press {
+            return;
+        }
+
+        match key.code {
+            KeyCode::Esc => self.status = Status::Sair(None),
+            KeyCode::Tab => self.proximo_input(),
+            KeyCode::BackTab => self.anterior_input(),
+            KeyCode::F(5) => self.salvar(),
+            _ => self.alterar_input(key),
+        }
+    }
+
+    fn proximo_input(&mut self) {
+        match self.status {
+            Status::AltNome => self.status = Status::AltValorAlvo,
+            Status::AltValorAlvo => self.status = Status::AltDataAlvo,
+            Status::AltDataAlvo => self.status = Status::AltSaldoInicial,
+            Status::AltSaldoInicial => {
+                self.state.select_first();
+                self.status = Status::AltVinculos
+            }
+            Status::AltVinculos => self.status = Status::AltNome,
+
+            Status::Sair(_) => {}
+        }
+    }
+
+    fn anterior_input(&mut self) {
+        match self.status {
+            Status::AltNome => self.status = Status::AltVinculos,
+            Status::AltValorAlvo => self.status = Status::AltNome,
+            Status::AltDataAlvo => self.status = Status::AltValorAlvo,
+            Status::AltSaldoInicial => self.status = Status::AltDataAlvo,
+            Status::AltVinculos => self.status = Status::AltSaldoInicial,
+
+            Status::Sair(_) => {}
+        }
+    }
+
+    fn salvar(&mut self) {
+        let data_alvo = match self.data_alvo.to_naivedate() {
+            Ok(data) => data,
+            Err(_) => {
+                self.status = Status::AltDataAlvo;
+                return;
+            }
+        };
+
+        let mut objetivo = Objetivo {
+            id: String::new(),
+            nome: self.nome.to_string(),
+            valor_alvo: self.valor_alvo.to_f64(),
+            data_alvo,
+            saldo_inicial: self.saldo_inicial.to_f64(),
+            inicio: match &self.objetivo {
+                Some(o) => o.inicio,
+                None => Local::now().date_naive(),
+            },
+            contas: self.marcados(TipoVinculo::Conta),
+            categorias: self.marcados(TipoVinculo::Categoria),
+        };
+
+        match &self.objetivo {
+            Some(o) => objetivo.id = o.id.clone(),
+            None => objetivo.gerar_id(),
+        }
+
+        self.status = Status::Sair(Some(objetivo));
+    }
+
+    fn marcados(&self, tipo: TipoVinculo) -> Vec<String> {
+        self.vinculos
+            .iter()
+            .filter(|v| v.tipo == tipo && v.marcado)
+            .map(|v| v.id.clone())
+            .collect()
+    }
+
+    fn alterar_input(&mut self, key: KeyEvent) {
+        match self.status {
+            Status::AltNome => self.nome.handle_key(key),
+            Status::AltValorAlvo => self.valor_alvo.handle_key(key),
+            Status::AltDataAlvo => self.data_alvo.handle_key(key),
+            Status::AltSaldoInicial => self.saldo_inicial.handle_key(key),
+            Status::AltVinculos => match key.code {
+                KeyCode::Down => self.state.select_next(),
+                KeyCode::Up => self.state.select_previous(),
+                KeyCode::Enter | KeyCode::Char(' ') => {
+                    if let Some(i) = self.state.selected() {
+                        if let Some(v) = self.vinculos.get_mut(i) {
+                            v.marcado = !v.marcado;
+                        }
+                    }
+                }
+                _ => {}
+            },
+
+            Status::Sair(_) => {}
+        }
+    }
+
+    fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        let [linha1, linha2, linha3] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Fill(1),
+        ])
+        .areas(area);
+
+        let [valor_alvo, data_alvo, saldo_inicial] = Layout::horizontal([
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+        ])
+        .areas(linha2);
+
+        self.nome.render(self.status == Status::AltNome, linha1, buf);
+        self.valor_alvo
+            .render(self.status == Status::AltValorAlvo, valor_alvo, buf);
+        self.data_alvo
+            .render(self.status == Status::AltDataAlvo, data_alvo, buf);
+        self.saldo_inicial
+            .render(self.status == Status::AltSaldoInicial, saldo_inicial, buf);
+
+        self.render_vinculos(linha3, buf);
+    }
+
+    fn render_vinculos(&mut self, area: Rect, buf: &mut Buffer) {
+        let block = Block::new()
+            .title(Line::raw("Contas e investimentos que contam como aporte").left_aligned())
+            .borders(Borders::all())
+            .border_set(symbols::border::PLAIN)
+            .style(if self.status == Status::AltVinculos {
+                estilo_input_foco()
+            } else {
+                estilo_input()
+            });
+
+        let items: Vec<ListItem> = self
+            .vinculos
+            .iter()
+            .enumerate()
+            .map(|(i, v)| {
+                ListItem::new(format!(
+                    " ({}) {}",
+                    if v.marcado { 'X' } else { ' ' },
+                    v.texto
+                ))
+                .bg(alternate_colors(i))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(LISTA_SELECIONADO_ESTILO)
+            .highlight_symbol("▶")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(list, area, buf, &mut self.state);
+    }
+}
@@ -0,0 +1,304 @@
+use chrono::Local;
+use color_eyre::eyre::Result;
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
+    layout::{Constraint, Layout, Rect},
+    widgets::Widget,
+    DefaultTerminal,
+};
+
+use crate::{
+    componentes::{
+        input_wgt::Input,
+        lista_suspensa::{ItemListaSuspensa, ListaSuspensa},
+    },
+    dto::{Ativo, Lancamento, OperacaoInvestimento, TipoOperacao, Unico},
+    estilo::{principal_comandos, principal_titulo},
+};
+
+const TIPOS: [TipoOperacao; 3] = [
+    TipoOperacao::Compra,
+    TipoOperacao::Venda,
+    TipoOperacao::Provento,
+];
+
+#[derive(PartialEq)]
+enum Status {
+    AltAtivo,
+    AltTipo,
+    AltData,
+    AltQuantidade,
+    AltPreco,
+    AltTaxas,
+    AltLancamento,
+
+    Sair(Option<OperacaoInvestimento>),
+}
+
+pub struct EditarOperacao {
+    status: Status,
+    operacao: Option<OperacaoInvestimento>,
+
+    ativo: ListaSuspensa,
+    tipo: ListaSuspensa,
+    data: Input,
+    quantidade: Input,
+    preco: Input,
+    taxas: Input,
+    lancamento: ListaSuspensa,
+}
+
+impl Widget for &mut EditarOperacao {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let [titulo, corpo, rodape] = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Fill(1),
+            Constraint::Length(1),
+        ])
+        .areas(area);
+
+        principal_titulo(
+            if self.operacao.is_none() {
+                "Nova Operação"
+            } else {
+                "Edição de Operação"
+            },
+            titulo,
+            buf,
+        );
+        principal_comandos(
+            vec!["(Editar)", "TAB (próximo)", "Esc (sair)", "F5 (salvar)"],
+            rodape,
+            buf,
+        );
+        self.render(corpo, buf)
+    }
+}
+
+impl EditarOperacao {
+    /// `lancamentos` são os lançamentos de categorias `Investimento` que podem
+    /// ser vinculados à operação.
+    pub fn new(ativos: &[Ativo], lancamentos: &[Lancamento], ativo: Option<String>) -> Self {
+        let mut lista_ativos = ListaSuspensa::new(
+            "Ativo",
+            ativos
+                .iter()
+                .map(|a| ItemListaSuspensa::new2(&a.id, &format!("{} - {}", a.id, a.nome)))
+                .collect(),
+            false,
+        );
+        if let Some(ativo) = ativo {
+            lista_ativos.set_id_selecionado(ativo);
+        }
+
+        Self {
+            status: Status::AltAtivo,
+            operacao: None,
+
+            ativo: lista_ativos,
+            tipo: ListaSuspensa::new(
+                "Tipo",
+                TIPOS
+                    .iter()
+                    .map(|t| ItemListaSuspensa::new(&t.to_string()))
+                    .collect(),
+                false,
+            ),
+            data: Input::new_data(
+                "Data",
+                Local::now().date_naive().format("%d/%m/%y").to_string(),
+            ),
+            quantidade: Input::new_monetario("Quantidade", 0.0f64),
+            preco: Input::new_monetario("Preço (por cota no provento)", 0.0f64),
+            taxas: Input::new_monetario("Taxas", 0.0f64),
+            lancamento: ListaSuspensa::new(
+                "Lançamento vinculado",
+                lancamentos
+                    .iter()
+                    .map(|l| {
+                        ItemListaSuspensa::new2(
+                            &l.id,
+                            &format!(
+                                "{} {} R$ {:.2}",
+                                l.data.format("%d/%m/%y"),
+                                l.descricao,
+                                l.valor
+                            ),
+                        )
+                    })
+                    .collect(),
+                true,
+            ),
+        }
+    }
+
+    pub fn set(ativos: &[Ativo], lancamentos: &[Lancamento], operacao: OperacaoInvestimento) -> Self {
+        let mut resp = Self::new(ativos, lancamentos, Some(operacao.ativo.clone()));
+
+        resp.tipo.set_id_selecionado(operacao.tipo.to_string());
+        resp.data.set_data(operacao.data.format("%d/%m/%y").to_string());
+        resp.quantidade.set_monetario(operacao.quantidade);
+        resp.preco.set_monetario(operacao.preco);
+        resp.taxas.set_monetario(operacao.taxas);
+        if let Some(lancamento) = &operacao.lancamento {
+            resp.lancamento.set_id_selecionado(lancamento.clone());
+        }
+        resp.operacao = Some(operacao);
+        resp
+    }
+
+    pub fn run(mut self, terminal: &mut DefaultTerminal) -> Result<Option<OperacaoInvestimento>> {
+        while !matches!(self.status, Status::Sair(_)) {
+            if let Err(erro) = terminal.draw(|frame| frame.render_widget(&mut self, frame.area())) {
+                log::error!("Erro ao desenhar tela EditarOperacao: {}", erro);
+            };
+
+            if let Event::Key(key) = event::read()? {
+                self.handle_key(key, terminal);
+            };
+        }
+
+        if let Status::Sair(operacao) = self.status {
+            return Ok(operacao);
+        }
+        Ok(None)
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent, terminal: &mut DefaultTerminal) {
+        if key.kind != KeyEventKind::Press {
+            return;
+        }
+
+        match key.code {
+            KeyCode::Esc => self.status = Status::Sair(None),
+            KeyCode::Tab => self.proximo_input(),
+            KeyCode::BackTab => self.anterior_input(),
+            KeyCode::F(5) => self.salvar(),
+            _ => self.alterar_input(key, terminal),
+        }
+    }
+
+    fn proximo_input(&mut self) {
+        match self.status {
+            Status::AltAtivo => self.status = Status::AltTipo,
+            Status::AltTipo => self.status = Status::AltData,
+            Status::AltData => self.status = Status::AltQuantidade,
+            Status::AltQuantidade => self.status = Status::AltPreco,
+            Status::AltPreco => self.status = Status::AltTaxas,
+            Status::AltTaxas => self.status = Status::AltLancamento,
+            Status::AltLancamento => self.status = Status::AltAtivo,
+
+            Status::Sair(_) => {}
+        }
+    }
+
+    fn anterior_input(&mut self) {
+        match self.status {
+            Status::AltAtivo => self.status = Status::AltLancamento,
+            Status::AltTipo => self.status = Status::AltAtivo,
+            Status::AltData => self.status = Status::AltTipo,
+            Status::AltQuantidade => self.status = Status::AltData,
+            Status::AltPreco => self.status = Status::AltQuantidade,
+            Status::AltTaxas => self.status = Status::AltPreco,
+            Status::AltLancamento => self.status = Status::AltTaxas,
+
+            Status::Sair(_) => {}
+        }
+    }
+
+    fn salvar(&mut self) {
+        let ativo = self.ativo.get_id_selecionado();
+        let quantidade = self.quantidade.to_f64();
+        let data = match self.data.to_naivedate() {
+            Ok(data) => data,
+            Err(_) => return,
+        };
+        if ativo.is_empty() || quantidade <= 0.0 {
+            return;
+        }
+
+        let lancamento = self.lancamento.get_id_selecionado();
+        let mut operacao = OperacaoInvestimento {
+            id: String::new(),
+            ativo,
+            tipo: TIPOS
+                .iter()
+                .find(|t| self.tipo.id_selecionado_eh(t.to_string()))
+                .cloned()
+                .unwrap_or_default(),
+            data,
+            quantidade,
+            preco: self.preco.to_f64(),
+            taxas: self.taxas.to_f64(),
+            lancamento: if lancamento.is_empty() {
+                None
+            } else {
+                Some(lancamento)
+            },
+            referencia: String::new(),
+        };
+
+        match &self.operacao {
+            Some(anterior) => {
+                operacao.id = anterior.id.clone();
+                operacao.referencia = anterior.referencia.clone();
+            }
+            None => {
+                operacao.referencia = Local::now()
+                    .naive_local()
+                    .format("%Y%m%d%H%M%S%.f")
+                    .to_string();
+                operacao.gerar_id();
+            }
+        }
+
+        self.status = Status::Sair(Some(operacao));
+    }
+
+    fn alterar_input(&mut self, key: KeyEvent, terminal: &mut DefaultTerminal) {
+        match self.status {
+            Status::AltAtivo => self.ativo.handle_key(key, terminal),
+            Status::AltTipo => self.tipo.handle_key(key, terminal),
+            Status::AltData => self.data.handle_key(key),
+            Status::AltQuantidade => self.quantidade.handle_key(key),
+            Status::AltPreco => self.preco.handle_key(key),
+            Status::AltTaxas => self.taxas.handle_key(key),
+            Status::AltLancamento => self.lancamento.handle_key(key, terminal),
+
+            Status::Sair(_) => {}
+        }
+    }
+
+    fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        let [linha1, linha2, linha3] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(3),
+        ])
+        .areas(area);
+
+        let [ativo, tipo, data] = Layout::horizontal([
+            Constraint::Fill(2),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+        ])
+        .areas(linha1);
+        let [quantidade, preco, taxas] = Layout::horizontal([
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+        ])
+        .areas(linha2);
+
+        self.ativo.render(self.status == Status::AltAtivo, ativo, buf);
+        self.tipo.render(self.status == Status::AltTipo, tipo, buf);
+        self.data.render(self.status == Status::AltData, data, buf);
+        self.quantidade
+            .render(self.status == Status::AltQuantidade, quantidade, buf);
+        self.preco.render(self.status == Status::AltPreco, preco, buf);
+        self.taxas.render(self.status == Status::AltTaxas, taxas, buf);
+        self.lancamento
+            .render(self.status == Status::AltLancamento, linha3, buf);
+    }
+}
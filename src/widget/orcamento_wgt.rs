@@ -0,0 +1,193 @@
+use chrono::{Datelike, Local};
+use color_eyre::eyre::Result;
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
+    layout::{Constraint, Layout, Rect},
+    widgets::Widget,
+    DefaultTerminal,
+};
+
+use crate::{
+    componentes::{
+        check_wgt::Check,
+        input_wgt::Input,
+        lista_suspensa::{ItemListaSuspensa, ListaSuspensa},
+    },
+    dto::{Categoria, Lazy, LazyFn, Orcamento, TipoFluxo, Unico},
+    estilo::{principal_comandos, principal_titulo},
+};
+
+#[derive(PartialEq)]
+enum Status {
+    AltCategoria,
+    AltValor,
+    AltAcumular,
+
+    Sair(Option<Orcamento>),
+}
+
+pub struct EditarOrcamento {
+    status: Status,
+    orcamento: Option<Orcamento>,
+
+    categoria: ListaSuspensa,
+    valor: Input,
+    acumular: Check,
+}
+
+impl Widget for &mut EditarOrcamento {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let [titulo, corpo, rodape] = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Fill(1),
+            Constraint::Length(1),
+        ])
+        .areas(area);
+
+        principal_titulo(
+            if self.orcamento.is_none() {
+                "Novo Orçamento"
+            } else {
+                "Edição de Orçamento"
+            },
+            titulo,
+            buf,
+        );
+        principal_comandos(
+            vec!["(Editar)", "TAB (próximo)", "Esc (sair)", "F5 (salvar)"],
+            rodape,
+            buf,
+        );
+        self.render(corpo, buf)
+    }
+}
+
+impl EditarOrcamento {
+    pub fn new() -> Self {
+        let categorias: Vec<ItemListaSuspensa> = Categoria::listar()
+            .into_iter()
+            .filter(|c| matches!(c.tipo, TipoFluxo::Despesa(_)))
+            .map(|c| ItemListaSuspensa::new2(&c.id, &c.to_string()))
+            .collect();
+
+        Self {
+            status: Status::AltCategoria,
+            orcamento: None,
+
+            categoria: ListaSuspensa::new("Categoria ou grupo", categorias, true),
+            valor: Input::new_monetario("Valor mensal", 0.0f64),
+            acumular: Check::new("Acumular saldo para o próximo mês", false),
+        }
+    }
+
+    pub fn set(orcamento: Orcamento) -> Self {
+        let mut resp = Self::new();
+
+        resp.categoria.set_id_selecionado(orcamento.categoria.id());
+        resp.valor.set_monetario(orcamento.valor);
+        resp.acumular.set_checked(orcamento.acumular);
+        resp.orcamento = Some(orcamento);
+
+        resp
+    }
+
+    pub fn run(mut self, terminal: &mut DefaultTerminal) -> Result<Option<Orcamento>> {
+        while !matches!(self.status, Status::Sair(_)) {
+            if let Err(erro) = terminal.draw(|frame| frame.render_widget(&mut self, frame.area())) {
+                log::error!("Erro ao desenhar tela EditarOrcamento: {}", erro);
+            };
+
+            if let Event::Key(key) = event::read()? {
+                self.handle_key(key, terminal);
+            };
+        }
+
+        if let Status::Sair(orcamento) = self.status {
+            return Ok(orcamento);
+        }
+        Ok(None)
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent, terminal: &mut DefaultTerminal) {
+        if key.kind != KeyEventKind::Press {
+            return;
+        }
+
+        match key.code {
+            KeyCode::Esc => self.status = Status::Sair(None),
+            KeyCode::Tab => self.proximo_input(),
+            KeyCode::BackTab => self.anterior_input(),
+            KeyCode::F(5) => self.salvar(),
+            _ => self.alterar_input(key, terminal),
+        }
+    }
+
+    fn proximo_input(&mut self) {
+        match self.status {
+            Status::AltCategoria => {
+                if !self.categoria.id_selecionado_eh(String::new()) {
+                    self.status = Status::AltValor
+                }
+            }
+            Status::AltValor => self.status = Status::AltAcumular,
+            Status::AltAcumular => self.status = Status::AltCategoria,
+
+            Status::Sair(_) => {}
+        }
+    }
+
+    fn anterior_input(&mut self) {
+        match self.status {
+            Status::AltValor => self.status = Status::AltCategoria,
+            Status::AltAcumular => self.status = Status::AltValor,
+
+            Status::AltCategoria | Status::Sair(_) => {}
+        }
+    }
+
+    fn salvar(&mut self) {
+        if self.categoria.id_selecionado_eh(String::new()) {
+            return;
+        }
+
+        let hoje = Local::now().date_naive();
+        let mut orcamento = Orcamento {
+            id: String::new(),
+            categoria: Lazy::Id(self.categoria.get_id_selecionado()),
+            valor: self.valor.to_f64(),
+            acumular: self.acumular.get_checked(),
+            inicio: match &self.orcamento {
+                Some(o) => o.inicio,
+                None => hoje.with_day(1).unwrap_or(hoje),
+            },
+        };
+        orcamento.gerar_id();
+
+        self.status = Status::Sair(Some(orcamento));
+    }
+
+    fn alterar_input(&mut self, key: KeyEvent, terminal: &mut DefaultTerminal) {
+        match self.status {
+            Status::AltCategoria => self.categoria.handle_key(key, terminal),
+            Status::AltValor => self.valor.handle_key(key),
+            Status::AltAcumular => self.acumular.handle_key(key),
+
+            Status::Sair(_) => {}
+        }
+    }
+
+    fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        let [linha1, linha2] =
+            Layout::vertical([Constraint::Length(3), Constraint::Length(3)]).areas(area);
+
+        let [valor, acumular] =
+            Layout::horizontal([Constraint::Fill(1), Constraint::Fill(2)]).areas(linha2);
+
+        self.categoria
+            .render(self.status == Status::AltCategoria, linha1, buf);
+        self.valor.render(self.status == Status::AltValor, valor, buf);
+        self.acumular
+            .render(self.status == Status::AltAcumular, acumular, buf);
+    }
+}
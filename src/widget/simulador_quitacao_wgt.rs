@@ -0,0 +1,382 @@
+use chrono::Local;
+use color_eyre::eyre::Result;
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
+    layout::{Constraint, Layout, Rect},
+    style::Stylize,
+    symbols,
+    text::Line,
+    widgets::{
+        Block, Borders, HighlightSpacing, List, ListItem, ListState, Padding, Paragraph,
+        StatefulWidget, Widget,
+    },
+    DefaultTerminal,
+};
+
+use crate::{
+    calc::{simular_cronograma, simular_quitacao},
+    componentes::{
+        input_wgt::Input,
+        lista_suspensa::{ItemListaSuspensa, ListaSuspensa},
+    },
+    dto::{DadosDivida, DashQuitacao, Divida, EstrategiaQuitacao, PlanoQuitacao},
+    estilo::{
+        alternate_colors, principal_comandos, principal_titulo, GERAL_BG, GERAL_TEXT_FG,
+        LISTA_BORDA_ESTILO, LISTA_SELECIONADO_ESTILO,
+    },
+};
+
+const ESTRATEGIAS: [EstrategiaQuitacao; 3] = [
+    EstrategiaQuitacao::BolaDeNeve,
+    EstrategiaQuitacao::Avalanche,
+    EstrategiaQuitacao::Personalizada,
+];
+
+#[derive(PartialEq)]
+enum Status {
+    AltExtra,
+    AltEstrategia,
+    AltOrdem,
+    Sair,
+}
+
+pub struct SimuladorQuitacao {
+    status: Status,
+    state: ListState,
+
+    extra: Input,
+    estrategia: ListaSuspensa,
+
+    dividas: Vec<Divida>,
+    /// Ids das dívidas abertas na ordem da estratégia personalizada.
+    ordem: Vec<String>,
+
+    cronograma: DashQuitacao,
+    simulacoes: Vec<DashQuitacao>,
+}
+
+impl Widget for &mut SimuladorQuitacao {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let [titulo, campos, comparativo, corpo, rodape] = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Length(3),
+            Constraint::Length(7),
+            Constraint::Fill(1),
+            Constraint::Length(1),
+        ])
+        .areas(area);
+
+        principal_titulo("Simulador de quitação", titulo, buf);
+        principal_comandos(
+            match self.status {
+                Status::AltOrdem => vec![
+                    "↓↑ (mover)",
+                    "+/- (ordem personalizada)",
+                    "TAB (próximo)",
+                    "F5 (salvar plano)",
+                    "ESC (sair)",
+                ],
+                _ => vec!["(Editar)", "TAB (próximo)", "F5 (salvar plano)", "ESC (sair)"],
+            },
+            rodape,
+            buf,
+        );
+
+        let [extra, estrategia] =
+            Layout::horizontal([Constraint::Fill(1), Constraint::Fill(1)]).areas(campos);
+        self.extra.render(self.status == Status::AltExtra, extra, buf);
+        self.estrategia
+            .render(self.status == Status::AltEstrategia, estrategia, buf);
+
+        self.render_comparativo(comparativo, buf);
+
+        let [dividas, meses] =
+            Layout::horizontal([Constraint::Percentage(60), Constraint::Percentage(40)]).areas(corpo);
+        self.render_dividas(dividas, buf);
+        self.render_meses(meses, buf);
+    }
+}
+
+impl SimuladorQuitacao {
+    pub fn new() -> Self {
+        let plano = PlanoQuitacao::buscar();
+        let dividas: Vec<Divida> = Divida::listar()
+            .into_iter()
+            .filter(|d| d.parcelas.aberta().quant() > 0)
+            .collect();
+
+        // mantém a ordem salva e acrescenta as dívidas novas no fim
+        let mut ordem: Vec<String> = plano
+            .ordem
+            .iter()
+            .filter(|id| dividas.iter().any(|d| d.id == **id))
+            .cloned()
+            .collect();
+        dividas.iter().for_each(|d| {
+            if !ordem.contains(&d.id) {
+                ordem.push(d.id.clone());
+            }
+        });
+
+        let mut estrategia = ListaSuspensa::new(
+            "Plano do dashboard",
+            ESTRATEGIAS
+                .iter()
+                .map(|e| ItemListaSuspensa::new(&e.to_string()))
+                .collect(),
+            false,
+        );
+        estrategia.set_id_selecionado(plano.estrategia.to_string());
+
+        let mut resp = Self {
+            status: Status::AltExtra,
+            state: ListState::default(),
+
+            extra: Input::new_monetario("Extra por mês", plano.extra_mensal),
+            estrategia,
+
+            cronograma: simular_cronograma(&dividas, Local::now().date_naive()),
+            dividas,
+            ordem,
+            simulacoes: Vec::new(),
+        };
+        resp.simular();
+        resp
+    }
+
+    pub fn run(mut self, terminal: &mut DefaultTerminal) -> Result<()> {
+        while self.status != Status::Sair {
+            if let Err(erro) = terminal.draw(|frame| frame.render_widget(&mut self, frame.area())) {
+                log::error!("Erro ao desenhar tela SimuladorQuitacao: {}", erro);
+            }
+            if let Event::Key(key) = event::read()? {
+                self.handle_key(key, terminal);
+            };
+        }
+        Ok(())
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent, terminal: &mut DefaultTerminal) {
+        if key.kind != KeyEventKind::Press {
+            return;
+        }
+
+        match key.code {
+            KeyCode::Esc => self.status = Status::Sair,
+            KeyCode::F(5) => self.salvar(),
+            KeyCode::Tab => self.proximo(),
+            KeyCode::BackTab => self.anterior(),
+            _ => match self.status {
+                Status::AltExtra => {
+                    self.extra.handle_key(key);
+                    self.simular();
+                }
+                Status::AltEstrategia => self.estrategia.handle_key(key, terminal),
+                Status::AltOrdem => self.handle_key_ordem(key),
+                Status::Sair => {}
+            },
+        }
+    }
+
+    fn proximo(&mut self) {
+        match self.status {
+            Status::AltExtra => self.status = Status::AltEstrategia,
+            Status::AltEstrategia => {
+                self.state.select_first();
+                self.status = Status::AltOrdem
+            }
+            Status::AltOrdem => self.status = Status::AltExtra,
+            Status::Sair => {}
+        }
+    }
+
+    fn anterior(&mut self) {
+        match self.status {
+            Status::AltExtra => {
+                self.state.select_first();
+                self.status = Status::AltOrdem
+            }
+            Status::AltEstrategia => self.status = Status::AltExtra,
+            Status::AltOrdem => self.status = Status::AltEstrategia,
+            Status::Sair => {}
+        }
+    }
+
+    fn handle_key_ordem(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Down => self.state.select_next(),
+            KeyCode::Up => self.state.select_previous(),
+            KeyCode::Char('+') => self.mover(-1),
+            KeyCode::Char('-') => self.mover(1),
+            _ => {}
+        }
+    }
+
+    fn mover(&mut self, deslocamento: i32) {
+        if let Some(i) = self.state.selected() {
+            let destino = i as i32 + deslocamento;
+            if destino >= 0 && (destino as usize) < self.ordem.len() {
+                self.ordem.swap(i, destino as usize);
+                self.state.select(Some(destino as usize));
+                self.simular();
+            }
+        }
+    }
+
+    fn plano(&self, estrategia: EstrategiaQuitacao) -> PlanoQuitacao {
+        PlanoQuitacao {
+            estrategia,
+            extra_mensal: self.extra.to_f64(),
+            ordem: self.ordem.clone(),
+        }
+    }
+
+    fn simular(&mut self) {
+        let hoje = Local::now().date_naive();
+        self.simulacoes = ESTRATEGIAS
+            .iter()
+            .map(|e| simular_quitacao(&self.dividas, &self.plano(e.clone()), hoje))
+            .collect();
+    }
+
+    fn estrategia_escolhida(&self) -> EstrategiaQuitacao {
+        ESTRATEGIAS
+            .iter()
+            .find(|e| self.estrategia.id_selecionado_eh(e.to_string()))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn salvar(&mut self) {
+        self.plano(self.estrategia_escolhida()).salvar();
+        self.status = Status::Sair;
+    }
+
+    fn render_comparativo(&self, area: Rect, buf: &mut Buffer) {
+        let todas: Vec<&DashQuitacao> = std::iter::once(&self.cronograma)
+            .chain(self.simulacoes.iter())
+            .collect();
+
+        let linha = |titulo: &str, valor: &dyn Fn(&DashQuitacao) -> String| {
+            format!(
+                "{:<20}{}",
+                titulo,
+                todas
+                    .iter()
+                    .map(|s| format!("{:>18}", valor(s)))
+                    .collect::<String>()
+            )
+        };
+
+        let texto = [
+            linha("", &|s| s.estrategia.clone()),
+            linha("Quitação", &|s| {
+                s.quitacao.clone().unwrap_or("-".to_string())
+            }),
+            linha("Total de juros", &|s| format!("{:.2}", s.total_juros)),
+            linha("Total pago", &|s| format!("{:.2}", s.total_pago)),
+            linha("Economia de juros", &|s| {
+                format!("{:.2}", self.cronograma.total_juros - s.total_juros)
+            }),
+        ];
+
+        let block = Block::new()
+            .title(Line::raw("Comparativo").centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(LISTA_BORDA_ESTILO)
+            .bg(GERAL_BG)
+            .padding(Padding::horizontal(1));
+
+        Paragraph::new(texto.join("\n"))
+            .block(block)
+            .fg(GERAL_TEXT_FG)
+            .render(area, buf);
+    }
+
+    fn render_dividas(&mut self, area: Rect, buf: &mut Buffer) {
+        let block = Block::new()
+            .title(Line::raw("Ordem personalizada e quitação por estratégia").centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(LISTA_BORDA_ESTILO)
+            .bg(GERAL_BG);
+
+        let quitacao = |simulacao: &DashQuitacao, id: &str| {
+            simulacao
+                .dividas
+                .iter()
+                .find(|d| d.id == id)
+                .and_then(|d| d.quitacao.clone())
+                .unwrap_or("-".to_string())
+        };
+
+        let mut items: Vec<ListItem> = vec![ListItem::new(format!(
+            " {:<30} {:>10} {:>7} {:>8} {:>8} {:>8} {:>8}",
+            "Dívida", "Saldo", "% a.m.", "Atual", "Neve", "Avalan.", "Pers."
+        ))];
+        items.extend(self.ordem.iter().enumerate().filter_map(|(i, id)| {
+            self.cronograma.dividas.iter().find(|d| d.id == *id).map(|d| {
+                ListItem::new(format!(
+                    " {:<30} {:>10.2} {:>7.2} {:>8} {}",
+                    d.nome,
+                    d.saldo,
+                    d.taxa_mensal,
+                    d.quitacao.clone().unwrap_or("-".to_string()),
+                    self.simulacoes
+                        .iter()
+                        .map(|s| format!("{:>8}", quitacao(s, id)))
+                        .collect::<Vec<String>>()
+                        .join(" "),
+                ))
+                .bg(alternate_colors(i))
+            })
+        }));
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(LISTA_SELECIONADO_ESTILO)
+            .highlight_symbol("▶")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        // a primeira linha é o cabeçalho
+        let mut state = match self.state.selected() {
+            Some(i) if self.status == Status::AltOrdem => {
+                let i = i.min(self.ordem.len().saturating_sub(1));
+                self.state.select(Some(i));
+                ListState::default().with_selected(Some(i + 1))
+            }
+            _ => ListState::default(),
+        };
+        StatefulWidget::render(list, area, buf, &mut state);
+    }
+
+    fn render_meses(&self, area: Rect, buf: &mut Buffer) {
+        let escolhida = self.estrategia_escolhida().to_string();
+        let simulacao = self.simulacoes.iter().find(|s| s.estrategia == escolhida);
+
+        let block = Block::new()
+            .title(Line::raw(format!("Mês a mês - {}", escolhida)).centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(LISTA_BORDA_ESTILO)
+            .bg(GERAL_BG);
+
+        let mut items: Vec<ListItem> = vec![ListItem::new(format!(
+            " {:<8} {:>10} {:>9} {:>11} {:>9}",
+            "Mês", "Pago", "Juros", "Saldo", "Liberado"
+        ))];
+        if let Some(simulacao) = simulacao {
+            items.extend(simulacao.meses.iter().enumerate().map(|(i, m)| {
+                ListItem::new(format!(
+                    " {:<8} {:>10.2} {:>9.2} {:>11.2} {:>9.2}",
+                    m.mes, m.pago, m.juros, m.saldo, m.liberado
+                ))
+                .bg(alternate_colors(i))
+            }));
+        }
+
+        Widget::render(List::new(items).block(block).fg(GERAL_TEXT_FG), area, buf);
+    }
+}
@@ -0,0 +1,270 @@
+use chrono::{Local, Months};
+use color_eyre::eyre::Result;
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
+    layout::{Constraint, Layout, Rect},
+    style::Stylize,
+    symbols,
+    text::Line,
+    widgets::{Block, Borders, List, ListItem, Padding, Paragraph, Widget},
+    DefaultTerminal,
+};
+
+use crate::{
+    calc::simular_compra,
+    componentes::input_wgt::Input,
+    dto::{Configuracao, Divida, SimulacaoCompraMes},
+    estilo::{
+        alternate_colors, principal_comandos, principal_titulo, GERAL_BG, GERAL_TEXT_FG,
+        LISTA_BORDA_ESTILO,
+    },
+};
+
+#[derive(PartialEq)]
+enum Status {
+    AltNome,
+    AltValor,
+    AltQuant,
+    AltInicio,
+    Sair,
+}
+
+pub struct SimularCompra {
+    status: Status,
+
+    nome: Input,
+    valor: Input,
+    quant: Input,
+    inicio: Input,
+
+    dividas: Vec<Divida>,
+    limite: f64,
+    meses: Vec<SimulacaoCompraMes>,
+}
+
+impl Widget for &mut SimularCompra {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let [titulo, campos, resumo, corpo, rodape] = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Fill(1),
+            Constraint::Length(1),
+        ])
+        .areas(area);
+
+        principal_titulo("Simular compra parcelada", titulo, buf);
+        principal_comandos(
+            vec!["(Editar)", "TAB (próximo)", "F5 (salvar como dívida)", "ESC (sair)"],
+            rodape,
+            buf,
+        );
+
+        let [nome, valor, quant, inicio] = Layout::horizontal([
+            Constraint::Fill(2),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+        ])
+        .areas(campos);
+        self.nome.render(self.status == Status::AltNome, nome, buf);
+        self.valor.render(self.status == Status::AltValor, valor, buf);
+        self.quant.render(self.status == Status::AltQuant, quant, buf);
+        self.inicio
+            .render(self.status == Status::AltInicio, inicio, buf);
+
+        self.render_resumo(resumo, buf);
+        self.render_meses(corpo, buf);
+    }
+}
+
+impl SimularCompra {
+    pub fn new() -> Self {
+        let proximo_mes = Local::now().date_naive() + Months::new(1);
+
+        let mut resp = Self {
+            status: Status::AltNome,
+
+            nome: Input::new_texto("Descrição", String::new()),
+            valor: Input::new_monetario("Valor total", 0.0),
+            quant: Input::new_inteiro("Parcelas", 1),
+            inicio: Input::new_data(
+                "Primeira parcela",
+                proximo_mes.format("%d/%m/%y").to_string(),
+            ),
+
+            dividas: Divida::listar(),
+            limite: Configuracao::buscar().endividamento_max,
+            meses: Vec::new(),
+        };
+        resp.simular();
+        resp
+    }
+
+    pub fn run(mut self, terminal: &mut DefaultTerminal) -> Result<()> {
+        while self.status != Status::Sair {
+            if let Err(erro) = terminal.draw(|frame| frame.render_widget(&mut self, frame.area())) {
+                log::error!("Erro ao desenhar tela SimularCompra: {}", erro);
+            }
+            if let Event::Key(key) = event::read()? {
+                self.handle_key(key);
+            };
+        }
+        Ok(())
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        if key.kind != KeyEventKind::Press {
+            return;
+        }
+
+        match key.code {
+            KeyCode::Esc => self.status = Status::Sair,
+            KeyCode::F(5) => self.salvar(),
+            KeyCode::Tab => self.proximo(),
+            KeyCode::BackTab => self.anterior(),
+            _ => {
+                match self.status {
+                    Status::AltNome => self.nome.handle_key(key),
+                    Status::AltValor => self.valor.handle_key(key),
+                    Status::AltQuant => self.quant.handle_key(key),
+                    Status::AltInicio => self.inicio.handle_key(key),
+                    Status::Sair => {}
+                }
+                self.simular();
+            }
+        }
+    }
+
+    fn proximo(&mut self) {
+        self.status = match self.status {
+            Status::AltNome => Status::AltValor,
+            Status::AltValor => Status::AltQuant,
+            Status::AltQuant => Status::AltInicio,
+            Status::AltInicio => Status::AltNome,
+            Status::Sair => Status::Sair,
+        }
+    }
+
+    fn anterior(&mut self) {
+        self.status = match self.status {
+            Status::AltNome => Status::AltInicio,
+            Status::AltValor => Status::AltNome,
+            Status::AltQuant => Status::AltValor,
+            Status::AltInicio => Status::AltQuant,
+            Status::Sair => Status::Sair,
+        }
+    }
+
+    /// A compra como dívida de parcelas fixas; `None` enquanto os campos estão incompletos.
+    fn compra(&self) -> Option<Divida> {
+        let quant = self.quant.to_i32();
+        let valor = self.valor.to_f64();
+        match self.inicio.to_naivedate() {
+            Ok(inicio) if quant > 0 && valor > 0.0 => Some(Divida::new(
+                self.nome.to_string(),
+                false,
+                false,
+                quant,
+                (valor / quant as f64 * 100.0).round() / 100.0,
+                inicio,
+                0,
+            )),
+            _ => None,
+        }
+    }
+
+    fn simular(&mut self) {
+        let compra = self.compra().unwrap_or_default();
+        self.meses = simular_compra(
+            &self.dividas,
+            &compra,
+            self.limite,
+            Local::now().date_naive(),
+        );
+    }
+
+    fn salvar(&mut self) {
+        if self.nome.to_string().trim().is_empty() {
+            self.status = Status::AltNome;
+            return;
+        }
+
+        match self.compra() {
+            Some(divida) => {
+                divida.salvar();
+                self.status = Status::Sair;
+            }
+            None => self.status = Status::AltValor,
+        }
+    }
+
+    fn render_resumo(&self, area: Rect, buf: &mut Buffer) {
+        let estourados: Vec<&SimulacaoCompraMes> =
+            self.meses.iter().filter(|m| m.excesso > 0.0).collect();
+
+        let texto = match estourados.iter().map(|m| m.excesso).reduce(f64::max) {
+            None => format!(
+                "Cabe no limite de endividamento de {:.2} em todos os meses",
+                self.limite
+            ),
+            Some(maior) => format!(
+                "Ultrapassa o limite de {:.2} em {} mês(es), a partir de {}; maior excesso {:.2}",
+                self.limite,
+                estourados.len(),
+                estourados[0].mes,
+                maior
+            ),
+        };
+
+        let block = Block::new()
+            .title(Line::raw("Resultado").centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(LISTA_BORDA_ESTILO)
+            .bg(GERAL_BG)
+            .padding(Padding::horizontal(1));
+
+        Paragraph::new(texto)
+            .block(block)
+            .fg(GERAL_TEXT_FG)
+            .render(area, buf);
+    }
+
+    fn render_meses(&self, area: Rect, buf: &mut Buffer) {
+        let block = Block::new()
+            .title(Line::raw("Mês a mês").centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(LISTA_BORDA_ESTILO)
+            .bg(GERAL_BG);
+
+        let mut items: Vec<ListItem> = vec![ListItem::new(format!(
+            " {:<8} {:>12} {:>12} {:>12} {:>12}",
+            "Mês", "Dívidas", "Compra", "Total", "Excesso"
+        ))];
+        items.extend(self.meses.iter().enumerate().map(|(i, m)| {
+            ListItem::new(format!(
+                " {:<8} {:>12.2} {:>12.2} {:>12.2} {:>12}{}",
+                m.mes,
+                m.dividas,
+                m.compra,
+                m.total,
+                if m.excesso > 0.0 {
+                    format!("{:.2}", m.excesso)
+                } else {
+                    "-".to_string()
+                },
+                if m.excesso > 0.0 {
+                    " [ ACIMA DO LIMITE ]"
+                } else {
+                    ""
+                }
+            ))
+            .bg(alternate_colors(i))
+        }));
+
+        Widget::render(List::new(items).block(block).fg(GERAL_TEXT_FG), area, buf);
+    }
+}